@@ -1,5 +1,6 @@
 create user sibyl identified by Or4cl3;
 grant connect, resource, unlimited tablespace, select_catalog_role to sibyl;
+grant execute on sys.dbms_lock to sibyl;
 
 begin
     for r in (
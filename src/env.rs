@@ -8,8 +8,15 @@ mod blocking;
 #[cfg_attr(docsrs, doc(cfg(feature="nonblocking")))]
 mod nonblocking;
 
-use std::{ptr, sync::Arc};
-use crate::{Error, Result, oci::*, types::Ctx};
+mod connstr;
+mod handles;
+
+pub use connstr::ConnectString;
+pub use handles::HandleCounts;
+pub(crate) use handles::{ActiveHandles, ActiveHandle, HandleKind};
+
+use std::{ptr, sync::{Arc, atomic::{AtomicU32, Ordering}}};
+use crate::{Error, Result, Number, err, oci::*, types::{Ctx, number::{Integer, Real}}};
 
 /// Represents an OCI environment.
 pub struct Environment {
@@ -17,6 +24,30 @@ pub struct Environment {
     // `OCIEnv` handle must be behind Arc as it needs to survive the Environment drop,
     // so that `OCIEnv` is still available to async-drop used, for example, in `Session`.
     env: Arc<Handle<OCIEnv>>,
+    // Statement cache size for the session pools created in this environment
+    stmt_cache_size: AtomicU32,
+    // Counters of the sessions, statements and LOBs created in this environment
+    handles: Arc<ActiveHandles>,
+}
+
+/// Marks the default statement cache size as not set
+const STMT_CACHE_SIZE_NOT_SET : u32 = u32::MAX;
+
+/// Name of the environment variable with the database name (TNS alias or connect string)
+pub const DBNAME_VAR : &str = "DBNAME";
+/// Name of the environment variable with the user name
+pub const DBUSER_VAR : &str = "DBUSER";
+/// Name of the environment variable with the user password
+pub const DBPASS_VAR : &str = "DBPASS";
+
+/// Reads the value of the environment variable with the connection parameter
+fn connect_param(name: &str) -> Result<String> {
+    std::env::var(name).map_err(|err| Error::msg(format!("cannot read {}: {}", name, err)))
+}
+
+/// Reads the database name, the user name and the password from the environment
+fn connect_params_from_env() -> Result<(String, String, String)> {
+    Ok((connect_param(DBNAME_VAR)?, connect_param(DBUSER_VAR)?, connect_param(DBPASS_VAR)?))
 }
 
 impl AsRef<OCIEnv> for Environment {
@@ -53,6 +84,7 @@ impl Environment {
     */
     pub fn new() -> Result<Self> {
         let mut env = Ptr::<OCIEnv>::null();
+        trace_oci_call!(OCIEnvNlsCreate);
         let res = unsafe {
             OCIEnvNlsCreate(
                 env.as_mut_ptr(), OCI_OBJECT | OCI_THREADED,
@@ -66,13 +98,148 @@ impl Environment {
         let env = Handle::from(env);
         let err = Handle::<OCIError>::new(&env)?;
         let env = Arc::new(env);
-        Ok(Self { env, err })
+        Ok(Self { env, err, stmt_cache_size: AtomicU32::new(STMT_CACHE_SIZE_NOT_SET), handles: Arc::new(ActiveHandles::default()) })
     }
 
     pub(crate) fn get_env(&self) -> Arc<Handle<OCIEnv>> {
         self.env.clone()
     }
 
+    pub(crate) fn get_handles(&self) -> Arc<ActiveHandles> {
+        self.handles.clone()
+    }
+
+    /**
+    Releases environment handles and detaches the process from the OCI shared memory subsystem
+    via `OCITerminate`.
+
+    **Note** that `OCITerminate` is process-wide - it releases the OCI resources of the entire process,
+    not just of this environment. Therefore shutdown should only be used when the process is done with
+    OCI, for example right before it exits, and when there are no other environments that are still in use.
+
+    The environment is consumed by the shutdown. OCI is not terminated while any sessions, statements
+    or LOB locators that were created in this environment are still open (see [`Environment::active_handle_counts()`]),
+    or while the environment is still used by a session or connection pool. Borrowing rules ensure that
+    for sessions and pools themselves. However, in `nonblocking` mode sessions and statements are released
+    asynchronously after they are dropped, and thus they might remain open for a short while after they
+    have gone out of scope. In these cases the method returns an error, and the environment is dropped
+    without terminating OCI.
+
+    # Example
+
+    ```
+    let oracle = sibyl::env()?;
+    oracle.shutdown()?;
+    # Ok::<(),sibyl::Error>(())
+    ```
+
+    ```
+    # #[cfg(feature="blocking")]
+    # fn main() -> sibyl::Result<()> {
+    let oracle = sibyl::env()?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    {
+        let session = oracle.connect(&dbname, &dbuser, &dbpass)?;
+        session.ping()?;
+    }
+    oracle.shutdown()?;
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> sibyl::Result<()> {
+    # let oracle = sibyl::env()?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    # sibyl::block_on(async {
+    #     let session = oracle.connect(&dbname, &dbuser, &dbpass).await?;
+    #     session.ping().await
+    # })?;
+    # // `block_on` waits for all async drops to complete
+    # oracle.shutdown()
+    # }
+    ```
+    */
+    pub fn shutdown(self) -> Result<()> {
+        let counts = self.handles.counts();
+        if counts != HandleCounts::default() {
+            return Err( Error::msg(format!(
+                "environment still has {} open sessions, {} statements and {} LOB locators",
+                counts.sessions, counts.statements, counts.lobs
+            )) );
+        }
+        let Self { env, err, .. } = self;
+        let env = Arc::try_unwrap(env).map_err(|_| Error::new("environment is still in use"))?;
+        drop(err);
+        drop(env);
+        let res = unsafe { OCITerminate(OCI_DEFAULT) };
+        if res != OCI_SUCCESS {
+            return Err( Error::new("Cannot terminate OCI environment") );
+        }
+        Ok(())
+    }
+
+    /**
+    Creates a new Number from an integer.
+
+    Numbers that are created by the environment use its error handle. Thus they can be
+    created (and used) before any session is established.
+
+    # Example
+
+    ```
+    let oracle = sibyl::env()?;
+
+    let num = oracle.number_from_int(42)?;
+
+    assert!(num.is_int()?);
+    assert_eq!(num.to_int::<i32>()?, 42);
+    # Ok::<(),sibyl::Error>(())
+    ```
+    */
+    pub fn number_from_int<T: Integer>(&self, val: T) -> Result<Number<'_>> {
+        Number::from_int(val, self)
+    }
+
+    /**
+    Creates a new Number from a floating point number.
+
+    # Example
+
+    ```
+    let oracle = sibyl::env()?;
+
+    let num = oracle.number_from_real(2.5)?;
+    let two = oracle.number_from_int(2)?;
+
+    assert_eq!(num.mul(&two)?.to_int::<i32>()?, 5);
+    # Ok::<(),sibyl::Error>(())
+    ```
+    */
+    pub fn number_from_real<T: Real>(&self, val: T) -> Result<Number<'_>> {
+        Number::from_real(val, self)
+    }
+
+    /**
+    Creates a new Number from a string using specified format.
+
+    # Example
+
+    ```
+    let oracle = sibyl::env()?;
+
+    let num = oracle.number_from_string("6.62607004E-34", "9D999999999EEEE")?;
+
+    assert_eq!(num.to_string("TME")?, "6.62607004E-34");
+    # Ok::<(),sibyl::Error>(())
+    ```
+    */
+    pub fn number_from_string(&self, txt: &str, fmt: &str) -> Result<Number<'_>> {
+        Number::from_string(txt, fmt, self)
+    }
+
     fn get_attr<V: attr::AttrGet>(&self, attr_type: u32) -> Result<V> {
         self.env.get_attr(attr_type, self.as_ref())
     }
@@ -187,6 +354,34 @@ impl Environment {
         self.set_attr(OCI_ATTR_CACHE_OPT_SIZE, size)
     }
 
+    /**
+    Configures the client-side object cache. This is a shortcut for [`Environment::set_cache_opt_size()`]
+    followed by [`Environment::set_cache_max_size()`].
+
+    Applications that use objects or named types heavily might benefit from a larger object cache.
+
+    # Parameters
+
+    * `opt_size` - The optimal size of the client-side object cache in bytes
+    * `max_size` - The maximum size for the client-side object cache as a percentage of the cache optimal size
+
+    # Example
+
+    ```
+    let oracle = sibyl::env()?;
+
+    oracle.set_object_cache(32*1024*1024, 25)?;
+
+    assert_eq!(oracle.optimal_cache_size()?, 32*1024*1024);
+    assert_eq!(oracle.max_cache_size()?, 25);
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn set_object_cache(&self, opt_size: u32, max_size: u32) -> Result<()> {
+        self.set_cache_opt_size(opt_size)?;
+        self.set_cache_max_size(max_size)
+    }
+
     /**
     Returns the name of the language used for the database sessions created in the current environment.
 
@@ -278,4 +473,136 @@ impl Environment {
     pub fn set_nls_territory(&self, territory: &str) -> Result<()> {
         self.set_attr(OCI_ATTR_ENV_NLS_TERRITORY, territory)
     }
+
+    /**
+    Sets the default statement cache size (in number of statements) for session pools that
+    will be created in this environment. Sessions that are provided by these pools inherit
+    the pool statement cache size.
+
+    Pools that were created before this call are not affected. Their cache size can be changed
+    with [`SessionPool::set_statement_cache_size`](crate::SessionPool::set_statement_cache_size).
+
+    # Parameters
+
+    * `size` - cache size in number of statements
+
+    # Example
+
+    ```
+    let oracle = sibyl::env()?;
+    assert!(oracle.default_stmt_cache_size().is_none());
+
+    oracle.set_default_stmt_cache_size(50);
+
+    assert_eq!(oracle.default_stmt_cache_size(), Some(50));
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn set_default_stmt_cache_size(&self, size: u32) {
+        self.stmt_cache_size.store(size, Ordering::Relaxed);
+    }
+
+    /**
+    Returns the numbers of sessions, statements and LOB locators that were created in this environment
+    and that are still alive.
+
+    The counts help to detect resources that are kept alive for longer than expected - for example,
+    statements that are cached by the application and never dropped - before they exhaust OCI handles
+    and server resources. Sessions that were obtained from session and connection pools are counted
+    while they are checked out.
+
+    Note that the counts are decremented as soon as the objects are dropped. In `nonblocking` mode their
+    OCI handles are released asynchronously and might still be in use for a short while after that.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # #[cfg(feature="blocking")]
+    # fn main() -> sibyl::Result<()> {
+    let oracle = sibyl::env()?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    let baseline = oracle.active_handle_counts();
+    {
+        let session = oracle.connect(&dbname, &dbuser, &dbpass)?;
+        let stmt = session.prepare("SELECT * FROM dual")?;
+        stmt.execute(())?;
+
+        let counts = oracle.active_handle_counts();
+        assert_eq!(counts.sessions, baseline.sessions + 1);
+        assert_eq!(counts.statements, baseline.statements + 1);
+    }
+    assert_eq!(oracle.active_handle_counts(), baseline);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> sibyl::Result<()> {
+    # let oracle = sibyl::env()?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    # let baseline = oracle.active_handle_counts();
+    # sibyl::block_on(async {
+    #     let session = oracle.connect(&dbname, &dbuser, &dbpass).await?;
+    #     let stmt = session.prepare("SELECT * FROM dual").await?;
+    #     stmt.execute(()).await?;
+    #     let counts = oracle.active_handle_counts();
+    #     assert_eq!(counts.sessions, baseline.sessions + 1);
+    #     assert_eq!(counts.statements, baseline.statements + 1);
+    #     Ok::<(),sibyl::Error>(())
+    # })?;
+    # // `block_on` waits for all async drops to complete
+    # assert_eq!(oracle.active_handle_counts(), baseline);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn active_handle_counts(&self) -> HandleCounts {
+        self.handles.counts()
+    }
+
+    /**
+    Sets the size of the buffer into which the text of error messages is retrieved from OCI.
+
+    By default the buffer is 3072 bytes, which might be too small for stacked errors - for example,
+    errors re-raised by PL/SQL exception handlers that keep the error stack - and their messages
+    get truncated. A larger buffer captures the entire error stack. Sizes that are smaller than
+    the default restore the default size.
+
+    Note that the buffer size is shared by all environments created by the process.
+
+    # Parameters
+
+    * `size` - the buffer size in bytes
+
+    # Example
+
+    ```
+    let oracle = sibyl::env()?;
+
+    oracle.set_error_buffer_size(16384);
+
+    assert_eq!(oracle.error_buffer_size(), 16384);
+    # Ok::<(),sibyl::Error>(())
+    ```
+    */
+    pub fn set_error_buffer_size(&self, size: usize) {
+        err::set_error_buffer_size(size)
+    }
+
+    /// Returns the size of the buffer into which the text of error messages is retrieved.
+    pub fn error_buffer_size(&self) -> usize {
+        err::error_buffer_size()
+    }
+
+    /// Returns the default statement cache size for new session pools or `None` if it was not set,
+    /// in which case the pools use the OCI default (20 statements).
+    pub fn default_stmt_cache_size(&self) -> Option<u32> {
+        let size = self.stmt_cache_size.load(Ordering::Relaxed);
+        if size == STMT_CACHE_SIZE_NOT_SET { None } else { Some(size) }
+    }
 }
@@ -1,6 +1,6 @@
 //! Blocking mode OCI environment methods.
 
-use super::Environment;
+use super::{Environment, connect_params_from_env};
 use crate::{Session, ConnectionPool, Result, SessionPool};
 
 impl Environment {
@@ -41,6 +41,33 @@ impl Environment {
         Session::new(self, dbname, username, password)
     }
 
+    /**
+    Creates and begins a session for the server using the connection parameters from the environment.
+
+    The database name, the user name, and the password are read from the `DBNAME`, `DBUSER`, and
+    `DBPASS` environment variables respectively (see [`DBNAME_VAR`](crate::DBNAME_VAR),
+    [`DBUSER_VAR`](crate::DBUSER_VAR), and [`DBPASS_VAR`](crate::DBPASS_VAR)).
+
+    # Failures
+
+    Returns an error if any of the variables is not set or the session cannot be started.
+
+    # Example
+
+    ```
+    let oracle = sibyl::env()?;
+
+    let session = oracle.connect_from_env()?;
+
+    assert!(session.ping().is_ok());
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn connect_from_env(&self) -> Result<Session<'_>> {
+        let (dbname, username, password) = connect_params_from_env()?;
+        self.connect(&dbname, &username, &password)
+    }
+
     /**
     Creates new session pool.
 
@@ -88,6 +115,41 @@ impl Environment {
         SessionPool::new(self, dbname, username, password, min, inc, max)
     }
 
+    /**
+    Creates new session pool using the connection parameters from the environment.
+
+    The database name, the user name, and the password are read from the same environment variables
+    that [`connect_from_env`](Environment::connect_from_env) uses.
+
+    # Parameters
+
+    * `min` - The minimum number of sessions in the session pool.
+    * `inc` - The next increment for sessions to be started if the current number of sessions is less than `max`.
+    * `max` - The maximum number of sessions that can be opened in the session pool.
+
+    See [`create_session_pool`](Environment::create_session_pool) for details.
+
+    # Failures
+
+    Returns an error if any of the variables is not set or the pool cannot be created.
+
+    # Example
+
+    ```
+    let oracle = sibyl::env()?;
+
+    let pool = oracle.create_session_pool_from_env(0, 1, 10)?;
+
+    let session = pool.get_session()?;
+    assert!(session.ping().is_ok());
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn create_session_pool_from_env(&self, min: usize, inc: usize, max: usize) -> Result<SessionPool<'_>> {
+        let (dbname, username, password) = connect_params_from_env()?;
+        self.create_session_pool(&dbname, &username, &password, min, inc, max)
+    }
+
     /**
     Creates new connection pool.
 
@@ -0,0 +1,229 @@
+//! Easy Connect connection strings
+
+use std::{fmt, time::Duration};
+
+/**
+Builds [Easy Connect][1] connection strings that can be used in place of the TNS alias
+when connecting to a database or creating a connection or a session pool.
+
+Easy Connect strings take the form of `host[:port][/service_name][:server][/instance_name][?parameters]`.
+Optional connect and transport connect timeouts are passed to Oracle Net as `connect_timeout`
+and `transport_connect_timeout` parameters. Note that Easy Connect parameters are supported
+by 19c and later client libraries.
+
+[1]: https://docs.oracle.com/en/database/oracle/oracle-database/19/netag/configuring-naming-methods.html
+
+# Example
+
+```
+use sibyl::ConnectString;
+use std::time::Duration;
+
+let dbname = ConnectString::new("db.example.com")
+    .port(1522)
+    .service_name("orclpdb")
+    .connect_timeout(Duration::from_secs(5))
+    .transport_connect_timeout(Duration::from_millis(500))
+    .to_string();
+
+assert_eq!(dbname, "db.example.com:1522/orclpdb?connect_timeout=5&transport_connect_timeout=500ms");
+```
+
+A connection attempt to an unreachable host fails as soon as the connect timeout expires:
+
+```
+# #[cfg(feature="blocking")]
+# fn main() -> sibyl::Result<()> {
+use sibyl::ConnectString;
+use std::time::{Duration, Instant};
+
+let oracle = sibyl::env()?;
+
+let dbname = ConnectString::new("10.255.255.1")
+    .service_name("nosuchdb")
+    .connect_timeout(Duration::from_secs(2))
+    .transport_connect_timeout(Duration::from_secs(1))
+    .to_string();
+
+let start = Instant::now();
+let res = oracle.connect(&dbname, "nouser", "nopass");
+assert!(res.is_err());
+assert!(start.elapsed() < Duration::from_secs(10));
+# Ok(())
+# }
+# #[cfg(feature="nonblocking")]
+# fn main() -> sibyl::Result<()> {
+# sibyl::block_on(async {
+#     use sibyl::ConnectString;
+#     use std::time::{Duration, Instant};
+#     let oracle = sibyl::env()?;
+#     let dbname = ConnectString::new("10.255.255.1")
+#         .service_name("nosuchdb")
+#         .connect_timeout(Duration::from_secs(2))
+#         .transport_connect_timeout(Duration::from_secs(1))
+#         .to_string();
+#     let start = Instant::now();
+#     let res = oracle.connect(&dbname, "nouser", "nopass").await;
+#     assert!(res.is_err());
+#     assert!(start.elapsed() < Duration::from_secs(10));
+#     Ok(())
+# })
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectString {
+    host: String,
+    port: Option<u16>,
+    service_name: Option<String>,
+    server: Option<String>,
+    instance_name: Option<String>,
+    connect_timeout: Option<Duration>,
+    transport_connect_timeout: Option<Duration>,
+}
+
+impl ConnectString {
+    /**
+    Starts a new connection string for the specified database host.
+
+    # Parameters
+
+    * `host` - Host name or IP address of the database listener.
+    */
+    pub fn new(host: &str) -> Self {
+        Self {
+            host: host.to_string(),
+            port: None,
+            service_name: None,
+            server: None,
+            instance_name: None,
+            connect_timeout: None,
+            transport_connect_timeout: None,
+        }
+    }
+
+    /// Sets the listener port. Oracle Net uses 1521 when the port is not specified.
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Sets the database service name.
+    pub fn service_name(mut self, service_name: &str) -> Self {
+        self.service_name = Some(service_name.to_string());
+        self
+    }
+
+    /// Sets the type of service handler - `dedicated`, `shared`, or `pooled`.
+    pub fn server(mut self, server: &str) -> Self {
+        self.server = Some(server.to_string());
+        self
+    }
+
+    /// Sets the database instance name.
+    pub fn instance_name(mut self, instance_name: &str) -> Self {
+        self.instance_name = Some(instance_name.to_string());
+        self
+    }
+
+    /**
+    Sets the time limit for the client to establish an Oracle Net connection. The
+    timeout covers the TCP connect, TLS negotiation (if any) and the connection
+    handshake with the database listener.
+    */
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the time limit for the client to establish the TCP connection to the database host.
+    pub fn transport_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.transport_connect_timeout = Some(timeout);
+        self
+    }
+}
+
+/// Formats the timeout using the largest Oracle Net unit that represents it exactly.
+/// Fractions of a millisecond are rounded up, so a non-zero timeout never becomes 0.
+fn fmt_timeout(timeout: &Duration) -> String {
+    let mut millis = timeout.as_millis();
+    if timeout.as_nanos() > millis * 1_000_000 {
+        millis += 1;
+    }
+    if millis % 1000 == 0 {
+        format!("{}", millis / 1000)
+    } else {
+        format!("{}ms", millis)
+    }
+}
+
+impl fmt::Display for ConnectString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.host.contains(':') && !self.host.starts_with('[') {
+            // IPv6 address
+            write!(f, "[{}]", self.host)?;
+        } else {
+            f.write_str(&self.host)?;
+        }
+        if let Some(port) = self.port {
+            write!(f, ":{}", port)?;
+        }
+        if self.service_name.is_some() || self.server.is_some() || self.instance_name.is_some() {
+            write!(f, "/{}", self.service_name.as_deref().unwrap_or(""))?;
+        }
+        if let Some(server) = &self.server {
+            write!(f, ":{}", server)?;
+        }
+        if let Some(instance_name) = &self.instance_name {
+            write!(f, "/{}", instance_name)?;
+        }
+        let mut sep = '?';
+        if let Some(timeout) = &self.connect_timeout {
+            write!(f, "{}connect_timeout={}", sep, fmt_timeout(timeout))?;
+            sep = '&';
+        }
+        if let Some(timeout) = &self.transport_connect_timeout {
+            write!(f, "{}transport_connect_timeout={}", sep, fmt_timeout(timeout))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn host_only() {
+        assert_eq!(ConnectString::new("localhost").to_string(), "localhost");
+    }
+
+    #[test]
+    fn full() {
+        let dbname = ConnectString::new("::1")
+            .port(1521)
+            .service_name("orcl")
+            .server("dedicated")
+            .instance_name("orcl1")
+            .connect_timeout(Duration::from_millis(1500))
+            .to_string();
+        assert_eq!(dbname, "[::1]:1521/orcl:dedicated/orcl1?connect_timeout=1500ms");
+    }
+
+    #[test]
+    fn timeouts_only() {
+        let dbname = ConnectString::new("dbhost")
+            .transport_connect_timeout(Duration::from_secs(3))
+            .to_string();
+        assert_eq!(dbname, "dbhost?transport_connect_timeout=3");
+    }
+
+    #[test]
+    fn sub_millisecond_timeout() {
+        let dbname = ConnectString::new("dbhost")
+            .connect_timeout(Duration::from_micros(200))
+            .transport_connect_timeout(Duration::from_micros(1_999_500))
+            .to_string();
+        assert_eq!(dbname, "dbhost?connect_timeout=1ms&transport_connect_timeout=2");
+    }
+}
@@ -0,0 +1,78 @@
+//! Accounting of the outstanding sessions, statements and LOB locators
+
+use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+
+/**
+Numbers of sessions, statements and LOB locators that were created in an environment
+and have not been dropped yet.
+
+Returned by [`Environment::active_handle_counts()`](crate::Environment::active_handle_counts).
+*/
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct HandleCounts {
+    /// Number of sessions
+    pub sessions: usize,
+    /// Number of prepared statements
+    pub statements: usize,
+    /// Number of LOB locators
+    pub lobs: usize,
+}
+
+/// Kinds of the tracked resources
+#[derive(Clone, Copy)]
+pub(crate) enum HandleKind {
+    Session,
+    Statement,
+    Lob,
+}
+
+/// Counters of the active resources that are shared by all the objects created in an environment.
+#[derive(Default)]
+pub(crate) struct ActiveHandles {
+    sessions: AtomicUsize,
+    statements: AtomicUsize,
+    lobs: AtomicUsize,
+}
+
+impl ActiveHandles {
+    fn counter(&self, kind: HandleKind) -> &AtomicUsize {
+        match kind {
+            HandleKind::Session   => &self.sessions,
+            HandleKind::Statement => &self.statements,
+            HandleKind::Lob       => &self.lobs,
+        }
+    }
+
+    /// Counts a new resource. The returned guard "uncounts" it when the guard is dropped.
+    pub(crate) fn track(self: &Arc<Self>, kind: HandleKind) -> ActiveHandle {
+        self.counter(kind).fetch_add(1, Ordering::Relaxed);
+        ActiveHandle { handles: self.clone(), kind }
+    }
+
+    pub(crate) fn counts(&self) -> HandleCounts {
+        HandleCounts {
+            sessions:   self.sessions.load(Ordering::Relaxed),
+            statements: self.statements.load(Ordering::Relaxed),
+            lobs:       self.lobs.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Keeps a resource counted while it is alive.
+pub(crate) struct ActiveHandle {
+    handles: Arc<ActiveHandles>,
+    kind: HandleKind,
+}
+
+impl ActiveHandle {
+    /// Returns counters of the environment where the resource was created.
+    pub(crate) fn handles(&self) -> &Arc<ActiveHandles> {
+        &self.handles
+    }
+}
+
+impl Drop for ActiveHandle {
+    fn drop(&mut self) {
+        self.handles.counter(self.kind).fetch_sub(1, Ordering::Relaxed);
+    }
+}
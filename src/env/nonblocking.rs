@@ -1,6 +1,6 @@
 //! Nonblocking mode OCI environment methods.
 
-use super::Environment;
+use super::{Environment, connect_params_from_env};
 use crate::{Result, Session, SessionPool};
 
 impl Environment {
@@ -33,6 +33,34 @@ impl Environment {
         Session::new(self, dbname, username, password).await
     }
 
+    /**
+    Creates and begins a session for the server using the connection parameters from the environment.
+
+    The database name, the user name, and the password are read from the `DBNAME`, `DBUSER`, and
+    `DBPASS` environment variables respectively (see [`DBNAME_VAR`](crate::DBNAME_VAR),
+    [`DBUSER_VAR`](crate::DBUSER_VAR), and [`DBPASS_VAR`](crate::DBPASS_VAR)).
+
+    # Failures
+
+    Returns an error if any of the variables is not set or the session cannot be started.
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    let oracle = sibyl::env()?;
+
+    let session = oracle.connect_from_env().await?;
+
+    session.ping().await?;
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn connect_from_env(&self) -> Result<Session<'_>> {
+        let (dbname, username, password) = connect_params_from_env()?;
+        self.connect(&dbname, &username, &password).await
+    }
+
     /**
     Creates new session pool.
 
@@ -80,4 +108,40 @@ impl Environment {
     pub async fn create_session_pool(&self, dbname: &str, username: &str, password: &str, min: usize, inc: usize, max: usize) -> Result<SessionPool<'_>> {
         SessionPool::new(self, dbname, username, password, min, inc, max).await
     }
+
+    /**
+    Creates new session pool using the connection parameters from the environment.
+
+    The database name, the user name, and the password are read from the same environment variables
+    that [`connect_from_env`](Environment::connect_from_env) uses.
+
+    # Parameters
+
+    * `min` - The minimum number of sessions in the session pool.
+    * `inc` - The next increment for sessions to be started if the current number of sessions is less than `max`.
+    * `max` - The maximum number of sessions that can be opened in the session pool.
+
+    See [`create_session_pool`](Environment::create_session_pool) for details.
+
+    # Failures
+
+    Returns an error if any of the variables is not set or the pool cannot be created.
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    let oracle = sibyl::env()?;
+
+    let pool = oracle.create_session_pool_from_env(0, 1, 10).await?;
+
+    let session = pool.get_session().await?;
+    session.ping().await?;
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn create_session_pool_from_env(&self, min: usize, inc: usize, max: usize) -> Result<SessionPool<'_>> {
+        let (dbname, username, password) = connect_params_from_env()?;
+        self.create_session_pool(&dbname, &username, &password, min, inc, max).await
+    }
 }
@@ -1,7 +1,7 @@
 //! Errors returned by Sibyl
 
 use crate::oci::*;
-use std::{ptr, cmp, fmt, error, io, ffi::CStr};
+use std::{ptr, cmp, fmt, error, io, ffi::CStr, sync::atomic::{AtomicUsize, Ordering}};
 use libc::{c_void, c_char};
 
 #[cfg(all(feature="nonblocking",feature="tokio"))]
@@ -10,13 +10,25 @@ use tokio_rt::task::JoinError;
 #[cfg(all(feature="nonblocking",feature="actix"))]
 use actix_rt::task::JoinError;
 
+/// Size of the buffer into which error messages are retrieved
+static ERROR_BUFFER_SIZE : AtomicUsize = AtomicUsize::new(OCI_ERROR_MAXMSG_SIZE);
+
+pub(crate) fn set_error_buffer_size(size: usize) {
+    ERROR_BUFFER_SIZE.store(size.max(OCI_ERROR_MAXMSG_SIZE), Ordering::Relaxed);
+}
+
+pub(crate) fn error_buffer_size() -> usize {
+    ERROR_BUFFER_SIZE.load(Ordering::Relaxed)
+}
+
 fn get_oracle_error(rc: i32, errhp: *mut c_void, htype: u32) -> (i32, String) {
     let mut errcode = rc;
-    let mut errmsg : Vec<u8> = Vec::with_capacity(OCI_ERROR_MAXMSG_SIZE);
+    let buf_size = error_buffer_size();
+    let mut errmsg : Vec<u8> = Vec::with_capacity(buf_size);
     let errmsg_ptr = errmsg.as_mut_ptr();
     let res = unsafe {
         *errmsg_ptr = 0;
-        OCIErrorGet(errhp, 1, ptr::null(), &mut errcode, errmsg_ptr, OCI_ERROR_MAXMSG_SIZE as u32, htype)
+        OCIErrorGet(errhp, 1, ptr::null(), &mut errcode, errmsg_ptr, buf_size as u32, htype)
     };
     let msg = if res == OCI_SUCCESS {
         let msg = unsafe { CStr::from_ptr(errmsg_ptr as *const c_char) };
@@ -38,6 +50,8 @@ pub enum Error {
     Interface(String),
     /// Errors returned by OCI
     Oracle(i32,String),
+    /// An attempt to change a LOB that was opened in read-only mode
+    ReadOnlyLob,
     #[cfg(all(feature="nonblocking",any(feature="tokio",feature="actix")))]
     #[cfg_attr(docsrs, doc(cfg(feature="nonblocking")))]
     JoinError(JoinError),
@@ -55,6 +69,7 @@ impl fmt::Display for Error {
         match self {
             Error::Oracle(errcode, errmsg) => if errmsg.starts_with("ORA-") { write!(f, "{}", errmsg) } else { write!(f, "ORA-{:05}: {}", errcode, errmsg) },
             Error::Interface(errmsg) => write!(f, "{}", errmsg),
+            Error::ReadOnlyLob => write!(f, "LOB is opened in read-only mode"),
             #[cfg(all(feature="nonblocking",any(feature="tokio",feature="actix")))]
             Error::JoinError(src) => src.fmt(f)
         }
@@ -68,6 +83,7 @@ impl cmp::PartialEq for Error {
         match (self, other) {
             (Error::Oracle(this_code, _), Error::Oracle(other_code, _)) => this_code == other_code,
             (Error::Interface(this_msg),  Error::Interface(other_msg))  => this_msg  == other_msg,
+            (Error::ReadOnlyLob,          Error::ReadOnlyLob)           => true,
             _ => false,
         }
     }
@@ -79,7 +95,137 @@ impl From<Error> for io::Error {
     }
 }
 
+/**
+Classification of errors that applications commonly need to handle.
+
+Use [`Error::kind()`] to match errors without comparing raw ORA error codes.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// ORA-00001: unique constraint violated
+    UniqueConstraint,
+    /// ORA-02291: integrity constraint violated - parent key not found
+    ParentKeyNotFound,
+    /// ORA-02292: integrity constraint violated - child record found
+    ChildRecordFound,
+    /// ORA-01400: cannot insert NULL
+    CannotInsertNull,
+    /// ORA-01722: invalid number
+    InvalidNumber,
+    /// ORA-00054: resource busy and acquire with NOWAIT specified or timeout expired
+    ResourceBusy,
+    /// ORA-00060: deadlock detected while waiting for resource
+    DeadlockDetected,
+    /// ORA-01013: user requested cancel of current operation
+    Cancelled,
+    /// Connection to the server has been lost. See [`Error::is_connection_lost()`].
+    ConnectionLost,
+    /// An attempt to change a LOB that was opened in read-only mode
+    ReadOnlyLob,
+    /// Error condition detected by Sibyl
+    Interface,
+    /// Any other error
+    Other,
+}
+
+/// ORA errors that report that the connection to the server has been lost or the session has been terminated
+const CONNECTION_LOST_ERRORS : [i32; 12] = [
+    28,     // your session has been killed
+    1012,   // not logged on
+    1092,   // ORACLE instance terminated. Disconnection forced
+    2396,   // exceeded maximum idle time, please connect again
+    3113,   // end-of-file on communication channel
+    3114,   // not connected to ORACLE
+    3135,   // connection lost contact
+    12153,  // TNS:not connected
+    12537,  // TNS:connection closed
+    12547,  // TNS:lost contact
+    12570,  // TNS:packet reader failure
+    12583,  // TNS:no reader
+];
+
 impl Error {
+    /**
+    Returns `true` if this error reports that the connection to the database server has been
+    lost - for example, ORA-3113 or ORA-3114 - or that the session has been terminated on
+    the server. Sessions that failed with these errors cannot be used anymore.
+
+    # Example
+
+    ```
+    use sibyl::Error;
+
+    let err = Error::Oracle(3113, "ORA-03113: end-of-file on communication channel".to_string());
+    assert!(err.is_connection_lost());
+
+    let err = Error::Oracle(1, "ORA-00001: unique constraint violated".to_string());
+    assert!(!err.is_connection_lost());
+    ```
+    */
+    pub fn is_connection_lost(&self) -> bool {
+        match self {
+            Error::Oracle(code, _) => CONNECTION_LOST_ERRORS.contains(code),
+            _ => false,
+        }
+    }
+
+    /**
+    Returns the classification of this error.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    use sibyl::ErrorKind;
+
+    # #[cfg(feature="blocking")]
+    # fn main() -> sibyl::Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        INSERT INTO hr.regions (region_id, region_name) VALUES (:id, :name)
+    ")?;
+    // Region 1 already exists
+    let err = stmt.execute(((":ID", 1), (":NAME", "Europe"))).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UniqueConstraint);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> sibyl::Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     INSERT INTO hr.regions (region_id, region_name) VALUES (:id, :name)
+    # ").await?;
+    # let err = stmt.execute(((":ID", 1), (":NAME", "Europe"))).await.unwrap_err();
+    # assert_eq!(err.kind(), ErrorKind::UniqueConstraint);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::Oracle(code, _) => match code {
+                1    => ErrorKind::UniqueConstraint,
+                2291 => ErrorKind::ParentKeyNotFound,
+                2292 => ErrorKind::ChildRecordFound,
+                1400 => ErrorKind::CannotInsertNull,
+                1722 => ErrorKind::InvalidNumber,
+                54   => ErrorKind::ResourceBusy,
+                60   => ErrorKind::DeadlockDetected,
+                1013 => ErrorKind::Cancelled,
+                _ if self.is_connection_lost() => ErrorKind::ConnectionLost,
+                _ => ErrorKind::Other,
+            },
+            Error::Interface(_) => ErrorKind::Interface,
+            Error::ReadOnlyLob => ErrorKind::ReadOnlyLob,
+            #[cfg(all(feature="nonblocking",any(feature="tokio",feature="actix")))]
+            Error::JoinError(_) => ErrorKind::Other,
+        }
+    }
+
     pub(crate) fn new(msg: &str) -> Self {
         Error::Interface( msg.to_owned() )
     }
@@ -98,3 +244,123 @@ impl Error {
         Error::Oracle(code, msg)
     }
 }
+
+#[cfg(all(test,feature="blocking"))]
+mod tests {
+    use crate::{*, oci::OCI_ERROR_MAXMSG_SIZE};
+
+    #[test]
+    fn error_buffer_size() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+        let stmt = session.prepare("
+            BEGIN
+                BEGIN
+                    BEGIN
+                        Raise_Application_Error(-20001, RPad('a', 2000, 'a'));
+                    EXCEPTION
+                        WHEN OTHERS THEN
+                            Raise_Application_Error(-20002, RPad('b', 2000, 'b'), TRUE);
+                    END;
+                EXCEPTION
+                    WHEN OTHERS THEN
+                        Raise_Application_Error(-20003, RPad('c', 2000, 'c'), TRUE);
+                END;
+            END;
+        ")?;
+
+        let oracle = crate::env()?;
+        oracle.set_error_buffer_size(16384);
+        assert_eq!(oracle.error_buffer_size(), 16384);
+
+        let res = stmt.execute(());
+        match res {
+            Err(Error::Oracle(code, msg)) => {
+                assert_eq!(code, 20003);
+                assert!(msg.len() > OCI_ERROR_MAXMSG_SIZE, "message is {} bytes", msg.len());
+                assert!(msg.contains("ORA-20001: "));
+                assert!(msg.contains(&"a".repeat(2000)));
+            },
+            _ => panic!("unexpected result {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn unique_constraint_kind() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+        let stmt = session.prepare("
+            INSERT INTO hr.regions (region_id, region_name) VALUES (:id, :name)
+        ")?;
+        let res = stmt.execute(((":ID", 1), (":NAME", "Europe")));
+        match res {
+            Err(err) => {
+                assert_eq!(err.kind(), ErrorKind::UniqueConstraint);
+                assert!(!err.is_connection_lost());
+            },
+            _ => panic!("unexpected result {:?}", res),
+        }
+        session.rollback()?;
+
+        assert_eq!(Error::ReadOnlyLob.kind(), ErrorKind::ReadOnlyLob);
+        assert_eq!(Error::new("test").kind(), ErrorKind::Interface);
+        assert_eq!(Error::Oracle(3113, String::new()).kind(), ErrorKind::ConnectionLost);
+        Ok(())
+    }
+
+    #[test]
+    fn deadlock_kind() -> Result<()> {
+        let session1 = crate::test_env::get_session()?;
+        let stmt = session1.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_error_kinds (
+                        id      NUMBER PRIMARY KEY,
+                        val     NUMBER
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_error_kinds
+                    ';
+            END;
+        ")?;
+        stmt.execute(())?;
+        let stmt = session1.prepare("INSERT INTO test_error_kinds (id, val) VALUES (:id, 0)")?;
+        stmt.execute(1)?;
+        stmt.execute(2)?;
+        session1.commit()?;
+
+        let session2 = session1.try_clone()?;
+        let update = "UPDATE test_error_kinds SET val = val + 1 WHERE id = :id";
+        session1.execute(update, 1)?;
+        session2.execute(update, 2)?;
+
+        // Each session now waits for the row that is locked by the other one.
+        // Oracle detects the deadlock and fails one of the waiting statements.
+        let (res1, res2) = std::thread::scope(|s| {
+            let handle = s.spawn(|| {
+                let res = session1.execute(update, 2);
+                if res.is_err() {
+                    // release locks to let the other session continue
+                    session1.rollback().ok();
+                }
+                res
+            });
+            std::thread::sleep(std::time::Duration::from_millis(500));
+            let res2 = session2.execute(update, 1);
+            if res2.is_err() {
+                session2.rollback().ok();
+            }
+            (handle.join().expect("thread result"), res2)
+        });
+        session1.rollback()?;
+        session2.rollback()?;
+
+        let kinds: Vec<ErrorKind> = [res1, res2].iter().filter_map(|res| res.as_ref().err()).map(Error::kind).collect();
+        assert_eq!(kinds, [ErrorKind::DeadlockDetected]);
+        Ok(())
+    }
+}
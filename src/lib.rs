@@ -108,6 +108,19 @@ Or, when Sibyl is used in nonblocking mode as:
 sibyl = { version = "0.6", features = ["nonblocking", "tokio"] }
 ```
 
+# Thread Safety
+
+Sibyl initializes OCI environments in the `OCI_THREADED` mode. OCI then protects its handles with mutexes,
+so all of Sibyl's public types - [`Environment`], [`Session`], [`SessionPool`], `ConnectionPool`, [`Statement`],
+[`Rows`], [`Row`], [`LOB`]s and the Oracle data types - are `Send` and `Sync`. A session can be moved to
+another thread, shared between threads via `Arc`, or, in `nonblocking` mode, held by a spawned task across
+`.await` points. No `unsafe impl` is needed on the application side.
+
+**Note** however, that OCI executes calls on the same session one at a time. Threads that share a session
+serialize their calls and some operations - like piece-wise LOB reads and writes - cannot be interleaved
+with other calls on that session. Applications that need concurrency should use a session per thread (or
+task) or get them from a [`SessionPool`].
+
 [1]: https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/index.html
 [2]: https://crates.io/crates/tokio
 [3]: https://crates.io/crates/actix-rt
@@ -135,6 +148,11 @@ mod types;
 mod stmt;
 mod lob;
 mod nvl;
+mod null;
+mod like;
+mod nchar;
+mod streamed;
+mod lob_arg;
 
 #[cfg(feature="blocking")]
 pub use pool::ConnectionPool;
@@ -143,12 +161,16 @@ pub use pool::ConnectionPool;
 #[doc(hidden)]
 pub use task::{spawn, block_on};
 
-pub use err::Error;
-pub use env::Environment;
-pub use session::Session;
-pub use pool::{SessionPool, SessionPoolGetMode};
-pub use stmt::{Statement, Cursor, Rows, Row, ToSql, FromSql, ColumnType, Position};
-pub use types::{Date, Raw, Number, Varchar, RowID, DateTime, Interval};
+#[cfg(feature="nonblocking")]
+#[cfg_attr(docsrs, doc(cfg(feature="nonblocking")))]
+pub use task::flush_async_drops;
+
+pub use err::{Error, ErrorKind};
+pub use env::{Environment, ConnectString, HandleCounts, DBNAME_VAR, DBUSER_VAR, DBPASS_VAR};
+pub use session::{Session, StatementCache};
+pub use pool::{SessionPool, SessionPoolGetMode, Purity};
+pub use stmt::{Statement, Cursor, Rows, Row, ToSql, FromSql, FromRow, FromSqlTuple, ColumnType, ColumnInfo, Position, Binds, ToRow, ToSqlDyn, BindMap, BatchResult, Snapshot};
+pub use types::{Date, Raw, Number, Varchar, RowID, DateTime, Interval, Object, FromAttr};
 pub use types::number::Integer;
 pub use oci::{Cache, CharSetForm, client_version};
 #[cfg(feature="nonblocking")]
@@ -156,6 +178,18 @@ pub use oci::{Cache, CharSetForm, client_version};
 pub use oci::futures::NUM_ACTIVE_ASYNC_DROPS;
 pub use lob::LOB;
 pub use nvl::Nvl;
+pub use null::Null;
+pub use like::Like;
+pub use nchar::NChar;
+pub use streamed::Streamed;
+pub use lob_arg::LobArg;
+
+/// Items that are used by the exported macros. They are not a part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::stmt::{Params, Column, check_arg_names};
+    pub use crate::oci::{OCIStmt, OCIError};
+}
 
 /// A specialized `Result` type for Sibyl.
 pub type Result<T>        = std::result::Result<T, Error>;
@@ -217,6 +251,37 @@ pub fn env() -> Result<Environment> {
     Environment::new()
 }
 
+/// Compile-time check that the public types can be sent to and shared between threads.
+#[allow(dead_code)]
+fn assert_send_sync() {
+    fn check<T: Send + Sync>() {}
+    check::<Environment>();
+    check::<Session>();
+    check::<SessionPool>();
+    #[cfg(feature="blocking")]
+    check::<ConnectionPool>();
+    check::<StatementCache>();
+    check::<Statement>();
+    check::<Cursor>();
+    check::<Rows>();
+    check::<Row>();
+    check::<Snapshot>();
+    check::<CLOB>();
+    check::<BLOB>();
+    check::<BFile>();
+    check::<Date>();
+    check::<Number>();
+    check::<Varchar>();
+    check::<Raw>();
+    check::<RowID>();
+    check::<Timestamp>();
+    check::<TimestampTZ>();
+    check::<TimestampLTZ>();
+    check::<IntervalYM>();
+    check::<IntervalDS>();
+    check::<Error>();
+}
+
 #[doc(hidden)]
 pub mod test_env {
     #[cfg(all(feature="blocking",not(docsrs)))]
@@ -229,11 +294,8 @@ pub mod test_env {
 
         pub fn get_session() -> Result<Session<'static>> {
             let pool = POOL.get_or_try_init(|| {
-                let dbname = std::env::var("DBNAME").expect("database name");
-                let dbuser = std::env::var("DBUSER").expect("user name");
-                let dbpass = std::env::var("DBPASS").expect("password");    
                 let oracle = ORACLE.get_or_try_init(|| Environment::new())?;
-                oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 10)
+                oracle.create_session_pool_from_env(0, 1, 10)
             })?;
             pool.get_session()
         }
@@ -253,11 +315,8 @@ pub mod test_env {
 
         pub async fn get_session() -> Result<Session<'static>> {
             let pool = POOL.get_or_try_init(async {
-                let dbname = std::env::var("DBNAME").expect("database name");
-                let dbuser = std::env::var("DBUSER").expect("user name");
-                let dbpass = std::env::var("DBPASS").expect("password");    
                 let oracle = ORACLE.get_or_try_init(|| Environment::new())?;
-                oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 10).await
+                oracle.create_session_pool_from_env(0, 1, 10).await
             }).await?;
             pool.get_session().await
         }
@@ -0,0 +1,199 @@
+//! A LIKE pattern argument that matches the provided value literally.
+//!
+//! Values that are used to build LIKE patterns might contain `%` and `_`, which LIKE would treat
+//! as wildcards. `Like` escapes them (and the escape character itself) before the pattern is bound
+//! to the parameter placeholder. The SQL must declare the escape character with the `ESCAPE` clause:
+//!
+//! ```sql
+//! WHERE column LIKE :pattern ESCAPE '\'
+//! ```
+//!
+//! # Example
+//!
+//! ```
+//! # use sibyl::*;
+//! # #[cfg(feature="blocking")]
+//! # fn main() -> Result<()> {
+//! # let session = sibyl::test_env::get_session()?;
+//! let stmt = session.prepare("
+//!     SELECT job_title
+//!       FROM hr.jobs
+//!      WHERE job_title LIKE :title ESCAPE '\\'
+//!      ORDER BY job_title
+//! ")?;
+//! let row = stmt.query_single(Like::starts_with("Sales M"))?.unwrap();
+//! let title : &str = row.get(0)?;
+//! assert_eq!(title, "Sales Manager");
+//! # Ok(())
+//! # }
+//! # #[cfg(feature="nonblocking")]
+//! # fn main() -> Result<()> {
+//! # sibyl::block_on(async {
+//! # let session = sibyl::test_env::get_session().await?;
+//! # let stmt = session.prepare("
+//! #     SELECT job_title
+//! #       FROM hr.jobs
+//! #      WHERE job_title LIKE :title ESCAPE '\\'
+//! #      ORDER BY job_title
+//! # ").await?;
+//! # let row = stmt.query_single(Like::starts_with("Sales M")).await?.unwrap();
+//! # let title : &str = row.get(0)?;
+//! # assert_eq!(title, "Sales Manager");
+//! # Ok(()) })
+//! # }
+//! ```
+
+use crate::{Result, ToSql};
+use crate::oci::{SQLT_CHR, OCIStmt, OCIError};
+use crate::stmt::Params;
+
+/// Escape character that `Like` uses to escape wildcards.
+const ESCAPE : char = '\\';
+
+/// Escapes LIKE wildcards (and the escape character) in the text.
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 8);
+    for c in text.chars() {
+        if c == '%' || c == '_' || c == ESCAPE {
+            escaped.push(ESCAPE);
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// A LIKE pattern.
+///
+/// This type represents a LIKE pattern in which the provided value is matched literally.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Like(String);
+
+impl Like {
+    /// Escape character that is used in the pattern. SQL that uses `Like` must declare it
+    /// via the `ESCAPE '\'` clause.
+    pub const ESCAPE: char = ESCAPE;
+
+    /// Creates a pattern that matches the value exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sibyl::Like;
+    ///
+    /// let arg = Like::new("100%");
+    /// assert_eq!(arg.as_str(), "100\\%");
+    /// ```
+    pub fn new(value: &str) -> Self {
+        Self(escape(value))
+    }
+
+    /// Creates a pattern that matches text that starts with the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sibyl::Like;
+    ///
+    /// let arg = Like::starts_with("SA_");
+    /// assert_eq!(arg.as_str(), "SA\\_%");
+    /// ```
+    pub fn starts_with(value: &str) -> Self {
+        let mut pattern = escape(value);
+        pattern.push('%');
+        Self(pattern)
+    }
+
+    /// Creates a pattern that matches text that ends with the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sibyl::Like;
+    ///
+    /// let arg = Like::ends_with("_MGR");
+    /// assert_eq!(arg.as_str(), "%\\_MGR");
+    /// ```
+    pub fn ends_with(value: &str) -> Self {
+        Self(format!("%{}", escape(value)))
+    }
+
+    /// Creates a pattern that matches text that contains the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sibyl::Like;
+    ///
+    /// let arg = Like::contains("50%");
+    /// assert_eq!(arg.as_str(), "%50\\%%");
+    /// ```
+    pub fn contains(value: &str) -> Self {
+        Self(format!("%{}%", escape(value)))
+    }
+
+    /// Returns the pattern.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl ToSql for Like {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.bind_in(pos, SQLT_CHR, self.0.as_ptr() as _, self.0.len(), stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl ToSql for &Like {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.bind_in(pos, SQLT_CHR, self.0.as_ptr() as _, self.0.len(), stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_wildcards() {
+        assert_eq!(escape("plain"), "plain");
+        assert_eq!(escape("100%"), "100\\%");
+        assert_eq!(escape("a_b"), "a\\_b");
+        assert_eq!(escape("c:\\dir"), "c:\\\\dir");
+    }
+
+    #[cfg(feature="blocking")]
+    #[test]
+    fn literal_match() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            WITH t (txt) AS (
+                SELECT '100% sure' FROM dual UNION ALL
+                SELECT '1000 times' FROM dual UNION ALL
+                SELECT '100 percent' FROM dual
+            )
+            SELECT txt
+              FROM t
+             WHERE txt LIKE :pattern ESCAPE '\\'
+        ")?;
+        let rows = stmt.query(Like::starts_with("100%"))?;
+        let mut found = Vec::new();
+        while let Some(row) = rows.next()? {
+            let txt : String = row.get(0)?;
+            found.push(txt);
+        }
+        assert_eq!(found, ["100% sure"]);
+
+        // unescaped pattern matches all of them
+        let rows = stmt.query("100%")?;
+        let mut num_found = 0;
+        while let Some(_row) = rows.next()? {
+            num_found += 1;
+        }
+        assert_eq!(num_found, 3);
+
+        Ok(())
+    }
+}
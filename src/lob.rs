@@ -9,7 +9,8 @@ mod blocking;
 mod nonblocking;
 
 use std::sync::{Arc, atomic::{AtomicU32, Ordering}};
-use crate::{Result, Session, oci::{self, *}, stmt::{ToSql, Params}, session::SvcCtx};
+use libc::c_void;
+use crate::{Error, Result, Session, oci::{self, *}, stmt::{ToSql, Params}, session::SvcCtx, env::{ActiveHandle, HandleKind}};
 #[cfg(feature="nonblocking")]
 use crate::task;
 
@@ -29,50 +30,64 @@ where T: DescriptorType<OCIType=OCILobLocator>
 pub(crate) const LOB_IS_TEMP       : u32 = 1;
 pub(crate) const LOB_IS_OPEN       : u32 = 2;
 pub(crate) const LOB_FILE_IS_OPEN  : u32 = 4;
+pub(crate) const LOB_IS_READONLY   : u32 = 8;
 
 struct LobInner<T> where T: DescriptorType<OCIType=OCILobLocator>  + 'static {
     locator: Descriptor<T>,
     svc: Arc<SvcCtx>,
     status_flags: AtomicU32,
+    _handle: ActiveHandle,
+}
+
+/// A LOB that is not tied to the session reference. It keeps the locator - and the temporary
+/// LOB it points to - alive while the statement, to which the LOB is bound, is executed.
+pub(crate) struct BoundLob<T>(LobInner<T>) where T: DescriptorType<OCIType=OCILobLocator> + 'static;
+
+impl<T> BoundLob<T> where T: DescriptorType<OCIType=OCILobLocator> {
+    /// Returns a pointer to the LOB locator that can be used to bind this LOB.
+    pub(crate) fn locator_ptr(&self) -> *mut c_void {
+        self.0.locator.as_ptr() as _
+    }
+
+    /// Returns the character set form of the bound LOB - `SQLCS_NCHAR` for NCLOBs, `SQLCS_IMPLICIT` otherwise.
+    pub(crate) fn charset_form(&self, err: &OCIError) -> u8 {
+        if self.0.is_nchar(err) { SQLCS_NCHAR } else { SQLCS_IMPLICIT }
+    }
 }
 
 #[cfg(not(docsrs))]
 impl<T> Drop for LobInner<T> where T: DescriptorType<OCIType=OCILobLocator> + 'static {
     #[cfg(feature="blocking")]
     fn drop(&mut self) {
+        let status_flags = self.take_status_flags();
         let svc: &OCISvcCtx     = self.as_ref();
         let err: &OCIError      = self.as_ref();
         let loc: &OCILobLocator = self.as_ref();
-        let status_flags = self.status_flags.load(Ordering::Acquire);
 
         if status_flags & LOB_IS_OPEN != 0 {
+            trace_oci_call!(OCILobClose);
             unsafe {
                 OCILobClose(svc, err, loc);
             }
         } else if status_flags & LOB_FILE_IS_OPEN != 0 {
+            trace_oci_call!(OCILobFileClose);
             unsafe {
                 OCILobFileClose(svc, err, loc);
             }
         }
         if status_flags & LOB_IS_TEMP != 0 {
+            trace_oci_call!(OCILobFreeTemporary);
             unsafe {
                 OCILobFreeTemporary(svc, err, loc);
             }
-        } else {
-            let mut flag = oci::Aligned::new(0u8);
-            if oci::lob_is_temporary(svc, err, loc, flag.as_mut_ptr()).is_ok() && <u8>::from(flag) != 0 {
-                unsafe {
-                    OCILobFreeTemporary(svc, err, loc);
-                }
-            }
         }
     }
 
     #[cfg(feature="nonblocking")]
     fn drop(&mut self) {
+        let flags = self.take_status_flags();
         let ctx = self.svc.clone();
         let loc = Descriptor::take(&mut self.locator);
-        let flags = self.status_flags.load(Ordering::Acquire);
         task::spawn_detached(futures::LobDrop::new(ctx, loc, flags));
     }
 }
@@ -103,11 +118,44 @@ impl<T> AsRef<OCISvcCtx> for LobInner<T> where T: DescriptorType<OCIType=OCILobL
 
 impl<T> LobInner<T> where T: DescriptorType<OCIType=OCILobLocator> {
     fn new(locator: Descriptor<T>, svc: Arc<SvcCtx>) -> Self {
-        Self { locator, svc, status_flags: AtomicU32::new(0) }
+        let _handle = svc.track(HandleKind::Lob);
+        Self { locator, svc, status_flags: AtomicU32::new(0), _handle }
+    }
+
+    /// Checks whether the locator is an initialized NCLOB locator. This is a local call.
+    ///
+    /// NCLOBs must be bound in the NCHAR form. Otherwise their content is converted into
+    /// the database character set and the characters that it cannot represent are lost.
+    fn is_nchar(&self, err: &OCIError) -> bool {
+        if T::sql_type() != SQLT_CLOB || !matches!(is_initialized(&self.locator, self.as_ref(), err), Ok(true)) {
+            return false;
+        }
+        let mut csform = oci::Aligned::new(0u8);
+        oci::lob_char_set_form(self.as_ref(), err, self.as_ref(), csform.as_mut_ptr()).is_ok() && <u8>::from(csform) == SQLCS_NCHAR
+    }
+
+    /// Checks whether the locator points to a temporary LOB. This is a local call that does not
+    /// need a round-trip to the server.
+    fn is_temporary(&self) -> bool {
+        let mut flag = oci::Aligned::new(0u8);
+        oci::lob_is_temporary(self.as_ref(), self.as_ref(), self.as_ref(), flag.as_mut_ptr()).is_ok() && <u8>::from(flag) != 0
+    }
+
+    /// Returns the status flags for the drop and resets them, so the LOB cannot be closed or freed again.
+    /// Locators that were not created as temporary LOBs - for example, LOBs returned by queries or by
+    /// PL/SQL OUT parameters - are also checked as they might point to temporary LOBs.
+    fn take_status_flags(&self) -> u32 {
+        let status_flags = self.status_flags.swap(0, Ordering::AcqRel);
+        if status_flags & LOB_IS_TEMP == 0 && !self.locator.get_ptr().is_null() && self.is_temporary() {
+            status_flags | LOB_IS_TEMP
+        } else {
+            status_flags
+        }
     }
 
     fn new_temp(locator: Descriptor<T>, svc: Arc<SvcCtx>) -> Self {
-        Self { locator, svc, status_flags: AtomicU32::new(LOB_IS_TEMP) }
+        let _handle = svc.track(HandleKind::Lob);
+        Self { locator, svc, status_flags: AtomicU32::new(LOB_IS_TEMP), _handle }
     }
 }
 
@@ -116,6 +164,7 @@ pub struct LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> + 'static
 {
     inner: LobInner<T>,
     chunk_size: AtomicU32,
+    piece_size: AtomicU32,
     session: &'a Session<'a>,
 }
 
@@ -153,10 +202,11 @@ impl<'a,T> AsRef<OCISvcCtx> for LOB<'a,T> where T: DescriptorType<OCIType=OCILob
 
 impl<'a,T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> {
 
-    fn make_new(locator: Descriptor<T>, session: &'a Session) -> Self {
+    pub(crate) fn make_new(locator: Descriptor<T>, session: &'a Session) -> Self {
         Self {
             inner: LobInner::new(locator, session.get_svc()),
             chunk_size: AtomicU32::new(0),
+            piece_size: AtomicU32::new(0),
             session
         }
     }
@@ -165,10 +215,69 @@ impl<'a,T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> {
         Self {
             inner: LobInner::new_temp(locator, session.get_svc()),
             chunk_size: AtomicU32::new(0),
+            piece_size: AtomicU32::new(0),
             session
         }
     }
 
+    /// Detaches the LOB from the session reference.
+    pub(crate) fn into_bound(self) -> BoundLob<T> {
+        BoundLob(self.inner)
+    }
+
+    /**
+    Sets the size of the pieces in which `read` (and, for CLOBs, `read_chunks`) transfers the LOB
+    content from the server.
+
+    By default `read` requests the entire fragment in a single call and `read_chunks` uses the LOB's
+    [chunk size](LOB::chunk_size). An explicitly set piece size lets applications tune the number of
+    round-trips and the size of the transfer buffers to the characteristics of their network and
+    their LOBs. The piece size is the number of bytes for BLOBs and BFILEs and the number of characters
+    for CLOBs. Setting it to 0 restores the default behavior.
+
+    In `nonblocking` mode internal LOBs are read via PL/SQL, which limits the size of each piece
+    to 32K bytes. Larger piece sizes are reduced to fit that limit.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    use sibyl::{BLOB, Cache};
+
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let lob = BLOB::temp(&session, Cache::No)?;
+    lob.write(0, &vec![42u8; 200_000])?;
+
+    lob.set_piece_size(64 * 1024);
+    let mut data = Vec::new();
+    let num_read = lob.read(0, 200_000, &mut data)?;
+    assert_eq!(num_read, 200_000);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let lob = BLOB::temp(&session, Cache::No).await?;
+    # lob.write(0, &vec![42u8; 200_000]).await?;
+    # lob.set_piece_size(64 * 1024);
+    # let mut data = Vec::new();
+    # let num_read = lob.read(0, 200_000, &mut data).await?;
+    # assert_eq!(num_read, 200_000);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn set_piece_size(&self, size: usize) {
+        let size = std::cmp::min(size, u32::MAX as usize) as u32;
+        self.piece_size.store(size, Ordering::Relaxed);
+    }
+
     /**
     Determines whether the LOB locator belongs to a local database table or a remote database
     table. The value `true` indicates that the LOB locator is from a remote database table.
@@ -459,6 +568,53 @@ impl<'a, T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> + InternalL
     pub fn clear(&self) -> Result<()> {
         self.inner.locator.set_attr(OCI_ATTR_LOBEMPTY, 0u32, self.as_ref())
     }
+
+    /**
+    Returns `true` if the LOB was opened via this locator in read-only mode (by `open_readonly`)
+    and has not been closed yet.
+
+    Methods that change the LOB content fail with [`Error::ReadOnlyLob`](crate::Error::ReadOnlyLob)
+    while the LOB is open for reading only.
+    */
+    pub fn is_readonly(&self) -> bool {
+        self.inner.status_flags.load(Ordering::Relaxed) & LOB_IS_READONLY != 0
+    }
+
+    /**
+    Enables LOB buffering for this locator.
+
+    With buffering enabled small writes are accumulated in the client-side buffers and are sent
+    to the server when the buffers are flushed. This reduces the number of round-trips for
+    applications that write LOB data in many small pieces.
+
+    **Note** that buffered writes must be explicitly flushed via `flush_buffer`. Buffered changes
+    are lost if they are not flushed before buffering is disabled or the locator is dropped.
+
+    While buffering is enabled the LOB should be accessed only via `read` and `write`. Other LOB
+    operations - append, copy, trim, erase, etc. - are not supported by the buffering subsystem.
+    */
+    pub fn enable_buffering(&self) -> Result<()> {
+        oci::lob_enable_buffering(self.as_ref(), self.as_ref(), self.as_ref())
+    }
+
+    /**
+    Disables LOB buffering for this locator.
+
+    **Note** that this method does not flush the buffers. Call `flush_buffer` first to write
+    buffered changes to the server.
+    */
+    pub fn disable_buffering(&self) -> Result<()> {
+        oci::lob_disable_buffering(self.as_ref(), self.as_ref(), self.as_ref())
+    }
+
+    /// Returns an error if the LOB was opened in read-only mode
+    fn check_writable(&self) -> Result<()> {
+        if self.is_readonly() {
+            Err( Error::ReadOnlyLob )
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl<'a> LOB<'a,OCICLobLocator> {
@@ -606,6 +762,9 @@ impl<T> ToSql for &LOB<'_, T> where T: DescriptorType<OCIType=OCILobLocator> {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
         let len = std::mem::size_of::<*mut T::OCIType>();
         params.bind(pos, T::sql_type(), self.inner.locator.as_ptr() as _, len, len, stmt, err)?;
+        if self.inner.is_nchar(err) {
+            params.set_charset_form(pos, SQLCS_NCHAR, err)?;
+        }
         Ok(pos + 1)
     }
 }
@@ -614,6 +773,9 @@ impl<T> ToSql for &mut LOB<'_, T> where T: DescriptorType<OCIType=OCILobLocator>
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
         let len = std::mem::size_of::<*mut T::OCIType>();
         params.bind(pos, T::sql_type(), self.inner.locator.as_mut_ptr() as _, len, len, stmt, err)?;
+        if self.inner.is_nchar(err) {
+            params.set_charset_form(pos, SQLCS_NCHAR, err)?;
+        }
         Ok(pos + 1)
     }
 }
@@ -89,7 +89,7 @@ impl<'a,T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> {
     */
     pub fn close(&self) -> Result<()> {
         oci::lob_close(self.as_ref(), self.as_ref(), self.as_ref())?;
-        self.inner.status_flags.fetch_and(!LOB_IS_OPEN, Ordering::Relaxed);
+        self.inner.status_flags.fetch_and(!(LOB_IS_OPEN | LOB_IS_READONLY), Ordering::Relaxed);
         Ok(())
     }
 
@@ -143,12 +143,13 @@ impl<'a,T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> {
     # Failures
 
     - It is an error to open the same LOB twice.
-    - If a user tries to write to a LOB that was opened in read-only mode, an error is returned.
+    - If a user tries to write to a LOB that was opened in read-only mode, [`Error::ReadOnlyLob`](crate::Error::ReadOnlyLob)
+      is returned.
 
     */
     pub fn open_readonly(&self) -> Result<()> {
         oci::lob_open(self.as_ref(), self.as_ref(), self.as_ref(), OCI_LOB_READONLY)?;
-        self.inner.status_flags.fetch_or(LOB_IS_OPEN, Ordering::Relaxed);
+        self.inner.status_flags.fetch_or(LOB_IS_OPEN | LOB_IS_READONLY, Ordering::Relaxed);
         Ok(())
     }
 
@@ -167,6 +168,8 @@ impl<'a,T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> {
         }
         let mut byte_cnt = byte_len as u64;
         let mut char_cnt = char_len as u64;
+        #[cfg(test)]
+        tests::LOB_READ_CALLS.with(|calls| calls.set(calls.get() + 1));
         let res = unsafe {
             oci::lob_read(
                 self.as_ref(), self.as_ref(), self.as_ref(),
@@ -180,6 +183,26 @@ impl<'a,T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> {
         }
         Ok( (res == OCI_NEED_DATA, byte_cnt as usize, char_cnt as usize) )
     }
+
+    /// Reads up to `len` bytes in one piece or, if the piece size was set, in pieces of that size.
+    fn read_bytes(&self, mut offset: usize, len: usize, buf: &mut Vec<u8>) -> Result<usize> {
+        let piece_size = self.piece_size.load(Ordering::Relaxed) as usize;
+        if piece_size == 0 || piece_size >= len {
+            let (_, byte_count, _) = self.read_piece(OCI_ONE_PIECE, len, offset, len, 0, 0, buf)?;
+            return Ok(byte_count);
+        }
+        let mut total_read = 0;
+        while total_read < len {
+            let piece_len = std::cmp::min(piece_size, len - total_read);
+            let (_, byte_count, _) = self.read_piece(OCI_ONE_PIECE, piece_len, offset, piece_len, 0, 0, buf)?;
+            if byte_count == 0 {
+                break;
+            }
+            offset += byte_count;
+            total_read += byte_count;
+        }
+        Ok(total_read)
+    }
 }
 
 impl<'a, T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> + InternalLob {
@@ -221,6 +244,7 @@ impl<'a, T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> + InternalL
     ```
     */
     pub fn append_lob(&self, lob: &Self) -> Result<()> {
+        self.check_writable()?;
         oci::lob_append(self.as_ref(), self.as_ref(), self.as_ref(), lob.as_ref())
     }
 
@@ -325,6 +349,7 @@ impl<'a, T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> + InternalL
     ```
     */
     pub fn copy(&self, src: &Self, src_offset: usize, amount: usize, offset: usize) -> Result<()> {
+        self.check_writable()?;
         oci::lob_copy(
             self.as_ref(), self.as_ref(),
             self.as_ref(), src.as_ref(),
@@ -448,6 +473,7 @@ impl<'a, T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> + InternalL
     ```
     */
     pub fn load_from_file(&self, src: &BFile, src_offset: usize, amount: usize, offset: usize) -> Result<()> {
+        self.check_writable()?;
         oci::lob_load_from_file(
             self.as_ref(), self.as_ref(),
             self.as_ref(), src.as_ref(),
@@ -470,6 +496,7 @@ impl<'a, T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> + InternalL
     Returns the actual number of characters or bytes erased.
     */
     pub fn erase(&self, offset: usize, amount: usize) -> Result<usize> {
+        self.check_writable()?;
         let mut count: u64 = amount as u64;
         oci::lob_erase(
             self.as_ref(), self.as_ref(), self.as_ref(),
@@ -591,10 +618,69 @@ impl<'a, T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> + InternalL
     of bytes in the LOB.
     */
     pub fn trim(&self, new_len: usize) -> Result<()> {
+        self.check_writable()?;
         oci::lob_trim(self.as_ref(), self.as_ref(), self.as_ref(), new_len as u64)
     }
 
+    /**
+    Writes all buffered changes of this LOB to the server and frees the LOB buffers.
+
+    The buffering must have been enabled by `enable_buffering`.
+
+    # Example
+
+    ```
+    use sibyl::{ CLOB, RowID };
+
+    # let session = sibyl::test_env::get_session()?;
+    # let stmt = session.prepare("
+    #     declare
+    #         name_already_used exception; pragma exception_init(name_already_used, -955);
+    #     begin
+    #         execute immediate '
+    #             create table test_lobs (
+    #                 id       number generated always as identity,
+    #                 text     clob,
+    #                 data     blob,
+    #                 ext_file bfile
+    #             )
+    #         ';
+    #     exception
+    #         when name_already_used then null;
+    #     end;
+    # ")?;
+    # stmt.execute(())?;
+    let stmt = session.prepare("
+        INSERT INTO test_lobs (text) VALUES (Empty_Clob()) RETURNING rowid INTO :row_id
+    ")?;
+    let mut rowid = RowID::new(&session)?;
+    stmt.execute(&mut rowid)?;
+
+    let stmt = session.prepare("
+        SELECT text FROM test_lobs WHERE rowid = :row_id FOR UPDATE
+    ")?;
+    let row = stmt.query_single(&rowid)?.unwrap();
+    let lob : CLOB = row.get(0)?;
+
+    lob.enable_buffering()?;
+    let mut offset = 0;
+    for word in ["One", " small", " step", " for", " a", " man"].iter() {
+        offset += lob.write(offset, word)?;
+    }
+    lob.flush_buffer()?;
+    lob.disable_buffering()?;
+
+    assert_eq!(lob.len()?, 24);
+    # session.rollback()?;
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn flush_buffer(&self) -> Result<()> {
+        oci::lob_flush_buffer(self.as_ref(), self.as_ref(), self.as_ref(), OCI_LOB_BUFFER_FREE)
+    }
+
     fn write_piece(&self, piece: u8, offset: usize, cs_form: u8, data: &[u8]) -> Result<(usize,usize)> {
+        self.check_writable()?;
         let mut byte_cnt = if piece == OCI_ONE_PIECE { data.len() as u64 } else { 0u64 };
         let mut char_cnt = 0u64;
         oci::lob_write(
@@ -608,6 +694,7 @@ impl<'a, T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> + InternalL
     }
 
     fn append_piece(&self, piece: u8, cs_form: u8, data: &[u8]) -> Result<(usize,usize)> {
+        self.check_writable()?;
         let mut byte_cnt = if piece == OCI_ONE_PIECE { data.len() as u64 } else { 0u64 };
         let mut char_cnt = 0u64;
         oci::lob_write_append(
@@ -1013,6 +1100,71 @@ impl<'a> LOB<'a,OCICLobLocator> {
         *num_read = char_count;
         Ok(has_more)
     }
+
+    /**
+    Reads the content of this LOB from the specified offset to the end of the LOB piece by piece,
+    passing each piece to the provided callback. Each piece is at most one LOB chunk (see [`LOB::chunk_size()`])
+    of characters long, unless a different piece size was set via [`LOB::set_piece_size()`]. The callback
+    is given the text of the piece and the number of LOB characters in it.
+
+    Unlike `read` this method does not need a buffer that can hold the entire requested fragment of
+    the LOB, and thus it allows streaming of very large LOBs.
+
+    **Note** that Oracle counts characters as UTF-16 code units. Supplementary characters are
+    therefore counted as 2 characters.
+
+    # Parameters
+
+    - `offset` - The absolute offset (in characters) from the beginning of the LOB value.
+    - `f` - The callback that receives pieces of the LOB content.
+
+    # Returns
+
+    The total number of characters read.
+
+    # Example
+
+    ```
+    use sibyl::{CLOB, Cache, CharSetForm};
+
+    # let session = sibyl::test_env::get_session()?;
+    let lob = CLOB::temp(&session, CharSetForm::Implicit, Cache::No)?;
+    let text = "tête-à-tête\n".repeat(10000);
+    lob.write(0, &text)?;
+
+    let mut lob_content = String::new();
+    let num_chars_read = lob.read_chunks(0, |piece, _num_chars| {
+        lob_content.push_str(piece);
+    })?;
+    assert_eq!(num_chars_read, lob.len()?);
+    assert_eq!(lob_content, text);
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn read_chunks<F>(&self, mut offset: usize, mut f: F) -> Result<usize>
+    where F: FnMut(&str, usize)
+    {
+        let lob_len = self.len()?;
+        let piece_len = match self.piece_size.load(Ordering::Relaxed) {
+            0 => self.chunk_size()?,
+            size => size as usize
+        };
+        let cs_form = self.charset_form()? as u8;
+        let mut buf = Vec::with_capacity(piece_len * 4);
+        let mut total_read = 0;
+        while offset < lob_len {
+            buf.clear();
+            let (_, _, char_count) = self.read_piece(OCI_ONE_PIECE, piece_len * 4, offset, piece_len * 4, piece_len, cs_form, &mut buf)?;
+            if char_count == 0 {
+                break;
+            }
+            let text = std::str::from_utf8(&buf).map_err(|err| Error::msg(format!("LOB piece is not valid UTF-8: {}", err)))?;
+            f(text, char_count);
+            offset += char_count;
+            total_read += char_count;
+        }
+        Ok(total_read)
+    }
 }
 
 impl<'a> LOB<'a,OCIBLobLocator> {
@@ -1314,8 +1466,7 @@ impl<'a> LOB<'a,OCIBLobLocator> {
     ```
     */
     pub fn read(&self, offset: usize, len: usize, buf: &mut Vec<u8>) -> Result<usize> {
-        let (_, byte_count, _) = self.read_piece(OCI_ONE_PIECE, len, offset, len, 0, 0, buf)?;
-        Ok( byte_count )
+        self.read_bytes(offset, len, buf)
     }
 
     /**
@@ -1543,8 +1694,7 @@ impl<'a> LOB<'a,OCIBFileLocator> {
     ```
     */
     pub fn read(&self, offset: usize, len: usize, buf: &mut Vec<u8>) -> Result<usize> {
-        let (_, byte_count, _) = self.read_piece(OCI_ONE_PIECE, len, offset, len, 0, 0, buf)?;
-        Ok( byte_count )
+        self.read_bytes(offset, len, buf)
     }
 
     /**
@@ -1634,4 +1784,119 @@ impl<'a> LOB<'a,OCIBFileLocator> {
         *num_read = byte_count;
         Ok(has_more)
     }
+
+    /**
+    Returns the server's operating system path of the directory object that is associated with
+    this BFILE locator.
+
+    Returns `None` if the directory object does not exist or if the current user does not have
+    access to it.
+
+    # Example
+
+    ```
+    use sibyl::BFile;
+
+    # let session = sibyl::test_env::get_session()?;
+    let file = BFile::new(&session)?;
+    file.set_file_name("MEDIA_DIR", "hello_world.txt")?;
+    let path = file.directory_path()?;
+
+    assert!(path.is_some());
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn directory_path(&self) -> Result<Option<String>> {
+        let (dir_name, _) = self.file_name()?;
+        let stmt = self.session.prepare("SELECT directory_path FROM all_directories WHERE directory_name = :DIR_NAME")?;
+        match stmt.query_single(&dir_name)? {
+            Some(row) => row.get(0),
+            None => Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use crate::*;
+
+    thread_local! {
+        /// The number of `OCILobRead2` calls made by the current thread
+        pub(super) static LOB_READ_CALLS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn count_reads<F: FnOnce() -> Result<usize>>(f: F) -> Result<(usize,usize)> {
+        let start = LOB_READ_CALLS.with(Cell::get);
+        let res = f()?;
+        let calls = LOB_READ_CALLS.with(Cell::get) - start;
+        Ok((res, calls))
+    }
+
+    #[test]
+    fn piece_size() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let lob = BLOB::temp(&session, Cache::No)?;
+        let data = vec![42u8; 256 * 1024];
+        lob.write(0, &data)?;
+        let chunk_size = lob.chunk_size()?;
+        assert!(chunk_size < 64 * 1024);
+
+        let mut buf = Vec::new();
+        let (num_read, calls) = count_reads(|| lob.read(0, data.len(), &mut buf))?;
+        assert_eq!(num_read, data.len());
+        assert_eq!(calls, 1);
+        assert_eq!(buf, data);
+
+        lob.set_piece_size(chunk_size);
+        buf.clear();
+        let (num_read, chunk_calls) = count_reads(|| lob.read(0, data.len(), &mut buf))?;
+        assert_eq!(num_read, data.len());
+        assert_eq!(chunk_calls, (data.len() + chunk_size - 1) / chunk_size);
+        assert_eq!(buf, data);
+
+        lob.set_piece_size(64 * 1024);
+        buf.clear();
+        let (num_read, calls) = count_reads(|| lob.read(0, data.len(), &mut buf))?;
+        assert_eq!(num_read, data.len());
+        assert_eq!(calls, 4);
+        assert!(calls < chunk_calls);
+        assert_eq!(buf, data);
+
+        let lob = CLOB::temp(&session, CharSetForm::Implicit, Cache::No)?;
+        let text = "0123456789abcdef".repeat(16 * 1024);
+        lob.write(0, &text)?;
+        lob.set_piece_size(64 * 1024);
+        let mut content = String::new();
+        let mut pieces = 0;
+        let (num_read, calls) = count_reads(|| lob.read_chunks(0, |piece, _| {
+            content.push_str(piece);
+            pieces += 1;
+        }))?;
+        assert_eq!(num_read, text.len());
+        assert_eq!(pieces, 4);
+        assert_eq!(calls, 4);
+        assert_eq!(content, text);
+
+        Ok(())
+    }
+    #[test]
+    fn directory_path() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let file = BFile::new(&session)?;
+        file.set_file_name("MEDIA_DIR", "hello_world.txt")?;
+        let path = file.directory_path()?.expect("MEDIA_DIR path");
+        assert!(!path.is_empty());
+
+        let stmt = session.prepare("SELECT directory_path FROM all_directories WHERE directory_name = 'MEDIA_DIR'")?;
+        let expected : String = stmt.query_scalar(())?;
+        assert_eq!(path, expected);
+
+        file.set_file_name("NO_SUCH_DIR", "hello_world.txt")?;
+        assert!(file.directory_path()?.is_none());
+
+        Ok(())
+    }
 }
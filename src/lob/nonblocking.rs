@@ -5,7 +5,7 @@ Nonblocking mode LOB methods.
 and `write_first`, `write_next`, `write_last` methods - are not supported in nonblocking mode.
 */
 
-use super::{LOB, InternalLob, LOB_IS_OPEN, LOB_FILE_IS_OPEN, LOB_IS_TEMP};
+use super::{LOB, InternalLob, LOB_IS_OPEN, LOB_IS_READONLY, LOB_FILE_IS_OPEN, LOB_IS_TEMP};
 use crate::{Result, BFile, oci::*, session::{Session, SvcCtx}, Error};
 use std::sync::{atomic::Ordering, Arc};
 
@@ -90,7 +90,7 @@ impl<'a,T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> {
     pub async fn close(&self) -> Result<()> {
         let lob: &OCILobLocator = self.as_ref();
         futures::LobClose::new(self.get_svc(), lob).await?;
-        self.inner.status_flags.fetch_and(!LOB_IS_OPEN, Ordering::Relaxed);
+        self.inner.status_flags.fetch_and(!(LOB_IS_OPEN | LOB_IS_READONLY), Ordering::Relaxed);
         Ok(())
     }
 
@@ -144,13 +144,14 @@ impl<'a,T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> {
     # Failures
 
     - It is an error to open the same LOB twice.
-    - If a user tries to write to a LOB that was opened in read-only mode, an error is returned.
+    - If a user tries to write to a LOB that was opened in read-only mode, [`Error::ReadOnlyLob`](crate::Error::ReadOnlyLob)
+      is returned.
 
     */
     pub async fn open_readonly(&self) -> Result<()> {
         let lob: &OCILobLocator = self.as_ref();
         futures::LobOpen::new(self.get_svc(), lob, OCI_LOB_READONLY).await?;
-        self.inner.status_flags.fetch_or(LOB_IS_OPEN, Ordering::Relaxed);
+        self.inner.status_flags.fetch_or(LOB_IS_OPEN | LOB_IS_READONLY, Ordering::Relaxed);
         Ok(())
     }
 
@@ -173,20 +174,43 @@ impl<'a,T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> {
         }
         Ok( (res == OCI_NEED_DATA, num_bytes, num_chars) )
     }
+
+    /// Returns the piece size set by the application or `max` if it was not set or is larger than `max`.
+    fn piece_size_or(&self, max: usize) -> usize {
+        match self.piece_size.load(Ordering::Relaxed) as usize {
+            0 => max,
+            size => std::cmp::min(size, max)
+        }
+    }
+
+    /// Reads up to `len` bytes in one piece or, if the piece size was set, in pieces of that size.
+    async fn read_bytes(&self, mut offset: usize, len: usize, buf: &mut Vec<u8>) -> Result<usize> {
+        let piece_size = self.piece_size_or(len);
+        if piece_size >= len {
+            let (_, byte_count, _) = self.read_piece(OCI_ONE_PIECE, len, offset, len, 0, 0, buf).await?;
+            return Ok(byte_count);
+        }
+        let mut total_read = 0;
+        while total_read < len {
+            let piece_len = std::cmp::min(piece_size, len - total_read);
+            let (_, byte_count, _) = self.read_piece(OCI_ONE_PIECE, piece_len, offset, piece_len, 0, 0, buf).await?;
+            if byte_count == 0 {
+                break;
+            }
+            offset += byte_count;
+            total_read += byte_count;
+        }
+        Ok(total_read)
+    }
 }
 
 impl<'a, T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> + InternalLob {
     pub(crate) fn make(locator: Descriptor<T>, session: &'a Session) -> Self {
-        let this = Self::make_new(locator, session);
-        let _ = this.is_temp();
-        this
+        Self::make_new(locator, session)
     }
 
     pub async fn is_temp(&self) -> Result<bool> {
-        let stmt = self.session.prepare("BEGIN :RES := DBMS_LOB.ISTEMPORARY(:LOC); END;").await?;
-        let mut flag = 0;
-        stmt.execute((&mut flag, self, ())).await?;
-        let is_temp = flag != 0;
+        let is_temp = self.inner.is_temporary();
         if is_temp {
             self.inner.status_flags.fetch_or(LOB_IS_TEMP, Ordering::Release);
         } else {
@@ -235,6 +259,7 @@ impl<'a, T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> + InternalL
     ```
     */
     pub async fn append_lob(&self, other_lob: &Self) -> Result<()> {
+        self.check_writable()?;
         let lob: &OCILobLocator = self.as_ref();
         let src: &OCILobLocator = other_lob.as_ref();
         futures::LobAppend::new(self.get_svc(), lob, src).await
@@ -346,6 +371,7 @@ impl<'a, T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> + InternalL
     ```
     */
     pub async fn copy(&self, src: &Self, src_offset: usize, amount: usize, offset: usize) -> Result<()> {
+        self.check_writable()?;
         let lob: &OCILobLocator = self.as_ref();
         futures::LobCopy::new(self.get_svc(), lob, offset, src.as_ref(), src_offset, amount).await
     }
@@ -467,6 +493,7 @@ impl<'a, T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> + InternalL
     ```
     */
     pub async fn load_from_file(&self, src: &'a BFile<'a>, src_offset: usize, amount: usize, offset: usize) -> Result<()> {
+        self.check_writable()?;
         let lob: &OCILobLocator = self.as_ref();
         let src: &OCILobLocator = src.as_ref();
         futures::LobLoadFromFile::new(self.get_svc(), lob, offset, src, src_offset, amount).await
@@ -487,6 +514,7 @@ impl<'a, T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> + InternalL
     Returns the actual number of characters or bytes erased.
     */
     pub async fn erase(&self, offset: usize, amount: usize) -> Result<usize> {
+        self.check_writable()?;
         let lob: &OCILobLocator = self.as_ref();
         let count = futures::LobErase::new(self.get_svc(), lob, offset, amount).await?;
         Ok( count as usize )
@@ -594,16 +622,78 @@ impl<'a, T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> + InternalL
     of bytes in the LOB.
     */
     pub async fn trim(&self, new_len: usize) -> Result<()> {
+        self.check_writable()?;
         let lob: &OCILobLocator = self.as_ref();
         futures::LobTrim::new(self.get_svc(), lob, new_len).await
     }
 
+    /**
+    Writes all buffered changes of this LOB to the server and frees the LOB buffers.
+
+    The buffering must have been enabled by `enable_buffering`.
+
+    # Example
+
+    ```
+    use sibyl::{ CLOB, RowID };
+
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     declare
+    #         name_already_used exception; pragma exception_init(name_already_used, -955);
+    #     begin
+    #         execute immediate '
+    #             create table test_lobs (
+    #                 id       number generated always as identity,
+    #                 text     clob,
+    #                 data     blob,
+    #                 ext_file bfile
+    #             )
+    #         ';
+    #     exception
+    #         when name_already_used then null;
+    #     end;
+    # ").await?;
+    # stmt.execute(()).await?;
+    let stmt = session.prepare("
+        INSERT INTO test_lobs (text) VALUES (Empty_Clob()) RETURNING rowid INTO :row_id
+    ").await?;
+    let mut rowid = RowID::new(&session)?;
+    stmt.execute(&mut rowid).await?;
+
+    let stmt = session.prepare("
+        SELECT text FROM test_lobs WHERE rowid = :row_id FOR UPDATE
+    ").await?;
+    let row = stmt.query_single(&rowid).await?.unwrap();
+    let lob : CLOB = row.get(0)?;
+
+    lob.enable_buffering()?;
+    let mut offset = 0;
+    for word in ["One", " small", " step", " for", " a", " man"].iter() {
+        offset += lob.write(offset, word).await?;
+    }
+    lob.flush_buffer().await?;
+    lob.disable_buffering()?;
+
+    assert_eq!(lob.len().await?, 24);
+    # session.rollback().await?;
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn flush_buffer(&self) -> Result<()> {
+        let lob: &OCILobLocator = self.as_ref();
+        futures::LobFlushBuffer::new(self.get_svc(), lob).await
+    }
+
     async fn write_piece(&self, piece: u8, offset: usize, cs_form: u8, data: &[u8]) -> Result<(usize,usize)> {
+        self.check_writable()?;
         let lob: &OCILobLocator = self.as_ref();
         futures::LobWrite::new(self.get_svc(), lob, piece, cs_form, offset, data).await
     }
 
     async fn append_piece(&self, piece: u8, cs_form: u8, data: &[u8]) -> Result<(usize,usize)> {
+        self.check_writable()?;
         let lob: &OCILobLocator = self.as_ref();
         futures::LobWriteAppend::new(self.get_svc(), lob, piece, cs_form, data).await
     }
@@ -772,6 +862,78 @@ impl<'a> LOB<'a,OCICLobLocator> {
         }
         Ok( offset - 1 )
     }
+
+    /**
+    Reads the content of this LOB from the specified offset to the end of the LOB piece by piece,
+    passing each piece to the provided callback. The callback is given the text of the piece and
+    the number of LOB characters in it.
+
+    Unlike `read` this method does not need a buffer that can hold the entire requested fragment of
+    the LOB, and thus it allows streaming of very large LOBs.
+
+    **Note** that Oracle counts characters as UTF-16 code units. Supplementary characters are
+    therefore counted as 2 characters.
+
+    # Parameters
+
+    * `offset` - Offset in characters from the start of the LOB.
+    * `f` - The callback that receives pieces of the LOB content.
+
+    # Returns
+
+    The total number of characters read.
+
+    # Example
+    ```
+    use sibyl::{CLOB, Cache, CharSetForm};
+
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let lob = CLOB::temp(&session, CharSetForm::Implicit, Cache::No).await?;
+    let text = "tête-à-tête\n".repeat(10000);
+    lob.write(0, &text).await?;
+
+    let mut lob_content = String::new();
+    let num_chars_read = lob.read_chunks(0, |piece, _num_chars| {
+        lob_content.push_str(piece);
+    }).await?;
+    assert_eq!(num_chars_read, lob.len().await?);
+    assert_eq!(lob_content, text);
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn read_chunks<F>(&self, offset: usize, mut f: F) -> Result<usize>
+    where F: FnMut(&str, usize)
+    {
+        let lob_len = self.len().await?;
+        let stmt = self.session.prepare("BEGIN DBMS_LOB.READ(:LOC, :AMT, :POS, :DATA); END;").await?;
+        let mut buf = String::with_capacity(32768);
+        let mut pos = offset + 1;
+        let mut total_read = 0;
+        while pos <= lob_len {
+            // the number of characters that (in the worst case) fit into 32K PL/SQL buffer
+            let mut amount = self.piece_size_or(8191);
+            let res = stmt.execute((self, &mut amount, pos, &mut buf)).await;
+            match res {
+                Ok(num_rows) if num_rows == 0 || amount == 0 => {
+                    break;
+                }
+                Ok(_) => {
+                    f(&buf, amount);
+                    pos += amount;
+                    total_read += amount;
+                    buf.clear();
+                },
+                Err(Error::Oracle(NO_DATA_FOUND,_)) => {
+                    break;
+                },
+                Err(err) => {
+                    return Err(err);
+                }
+            }
+        }
+        Ok(total_read)
+    }
 }
 
 impl<'a> LOB<'a,OCIBLobLocator> {
@@ -904,7 +1066,7 @@ impl<'a> LOB<'a,OCIBLobLocator> {
         let mut remainder = len;
         while remainder > 0 {
             let piece_ptr = unsafe { buf_ptr.add(out.len()) };
-            let mut piece_len = std::cmp::min(remainder, 32767);
+            let mut piece_len = std::cmp::min(remainder, self.piece_size_or(32767));
             let mut piece = unsafe { std::slice::from_raw_parts_mut(piece_ptr, piece_len) };
 
             let res = stmt.execute((self, &mut piece_len, offset, &mut piece)).await;
@@ -1069,7 +1231,37 @@ impl<'a> LOB<'a,OCIBFileLocator> {
     ```
     */
     pub async fn read(&self, offset: usize, len: usize, buf: &mut Vec<u8>) -> Result<usize> {
-        let (_, byte_count, _) = self.read_piece(OCI_ONE_PIECE, len, offset, len, 0, 0, buf).await?;
-        Ok(byte_count)
+        self.read_bytes(offset, len, buf).await
+    }
+
+    /**
+    Returns the server's operating system path of the directory object that is associated with
+    this BFILE locator.
+
+    Returns `None` if the directory object does not exist or if the current user does not have
+    access to it.
+
+    # Example
+
+    ```
+    use sibyl::BFile;
+
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let file = BFile::new(&session)?;
+    file.set_file_name("MEDIA_DIR", "hello_world.txt")?;
+    let path = file.directory_path().await?;
+
+    assert!(path.is_some());
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn directory_path(&self) -> Result<Option<String>> {
+        let (dir_name, _) = self.file_name()?;
+        let stmt = self.session.prepare("SELECT directory_path FROM all_directories WHERE directory_name = :DIR_NAME").await?;
+        match stmt.query_single(&dir_name).await? {
+            Some(row) => row.get(0),
+            None => Ok(None)
+        }
     }
 }
@@ -0,0 +1,98 @@
+//! An IN argument which content is bound as a temporary LOB.
+//!
+//! Text and binary arguments are bound as `VARCHAR2` and `RAW` values. Those cannot be longer than
+//! 32767 bytes (in PL/SQL; SQL limits are even lower). `LobArg` copies its content into a temporary
+//! CLOB, NCLOB or BLOB before the statement is executed and binds that LOB instead. Thus text or binary
+//! data of any size can be passed to a LOB column or to a PL/SQL LOB parameter.
+//!
+//! The temporary LOB is freed when the statement is executed again or when it is dropped.
+//! `LobArg` is an IN-only argument. It cannot be used in batches.
+//!
+//! # Example
+//!
+//! ```
+//! # use sibyl::*;
+//! # #[cfg(feature="blocking")]
+//! # fn main() -> Result<()> {
+//! # let session = sibyl::test_env::get_session()?;
+//! let text = "Lorem ipsum dolor sit amet. ".repeat(2000);
+//! let stmt = session.prepare("SELECT dbms_lob.getlength(:TEXT) FROM dual")?;
+//! let row = stmt.query_single(LobArg::text(&text))?.unwrap();
+//! let len : usize = row.get(0)?;
+//! assert_eq!(len, 56000);
+//! # Ok(())
+//! # }
+//! # #[cfg(feature="nonblocking")]
+//! # fn main() -> Result<()> {
+//! # sibyl::block_on(async {
+//! # let session = sibyl::test_env::get_session().await?;
+//! # let text = "Lorem ipsum dolor sit amet. ".repeat(2000);
+//! # let stmt = session.prepare("SELECT dbms_lob.getlength(:TEXT) FROM dual").await?;
+//! # let row = stmt.query_single(LobArg::text(&text)).await?.unwrap();
+//! # let len : usize = row.get(0)?;
+//! # assert_eq!(len, 56000);
+//! # Ok(()) })
+//! # }
+//! ```
+
+use crate::{Result, ToSql};
+use crate::oci::{SQLT_CHR, SQLT_LBI, SQLCS_IMPLICIT, SQLCS_NCHAR, OCIStmt, OCIError};
+use crate::stmt::Params;
+
+/// Text or binary data that is bound as a temporary LOB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LobArg<'a> {
+    data: &'a [u8],
+    sql_type: u16,
+    charset_form: u8,
+}
+
+impl<'a> LobArg<'a> {
+    /// Creates an argument that is bound as a temporary CLOB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sibyl::LobArg;
+    ///
+    /// let arg = LobArg::text("Hello, World!");
+    /// assert_eq!(arg.len(), 13);
+    /// ```
+    pub fn text(text: &'a str) -> Self {
+        Self { data: text.as_bytes(), sql_type: SQLT_CHR, charset_form: SQLCS_IMPLICIT }
+    }
+
+    /// Creates an argument that is bound as a temporary NCLOB.
+    pub fn ntext(text: &'a str) -> Self {
+        Self { data: text.as_bytes(), sql_type: SQLT_CHR, charset_form: SQLCS_NCHAR }
+    }
+
+    /// Creates an argument that is bound as a temporary BLOB.
+    pub fn binary(data: &'a [u8]) -> Self {
+        Self { data, sql_type: SQLT_LBI, charset_form: SQLCS_IMPLICIT }
+    }
+
+    /// Returns the size of the argument content in bytes.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the argument has no content. Empty arguments are bound as NULLs.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl ToSql for LobArg<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.bind_lob_in(pos, self.sql_type, self.charset_form, self.data.as_ptr() as _, self.data.len(), stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl ToSql for &LobArg<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.bind_lob_in(pos, self.sql_type, self.charset_form, self.data.as_ptr() as _, self.data.len(), stmt, err)?;
+        Ok(pos + 1)
+    }
+}
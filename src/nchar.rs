@@ -0,0 +1,131 @@
+//! A text argument that is bound in the national character set.
+//!
+//! Text arguments are bound in the database character set by default. When the text is intended
+//! for an `NCHAR`, `NVARCHAR2` or `NCLOB` column, and the database character set cannot represent
+//! all of its characters, the characters that cannot be represented are replaced during conversion
+//! and the text is corrupted before it ever reaches the column. `NChar` binds the text in the
+//! national character set (NCHAR form) instead, and thus preserves it.
+//!
+//! Note that OCI does not report the types of the values expected by parameter placeholders,
+//! therefore the NCHAR form cannot be detected automatically and must be requested explicitly.
+//!
+//! # Example
+//!
+//! ```
+//! # use sibyl::*;
+//! # #[cfg(feature="blocking")]
+//! # fn main() -> Result<()> {
+//! # let session = sibyl::test_env::get_session()?;
+//! let stmt = session.prepare("SELECT '> ' || :TEXT FROM dual")?;
+//! let row = stmt.query_single(NChar::new("Привет"))?.unwrap();
+//! let text : &str = row.get(0)?;
+//! assert_eq!(text, "> Привет");
+//! # Ok(())
+//! # }
+//! # #[cfg(feature="nonblocking")]
+//! # fn main() -> Result<()> {
+//! # sibyl::block_on(async {
+//! # let session = sibyl::test_env::get_session().await?;
+//! # let stmt = session.prepare("SELECT '> ' || :TEXT FROM dual").await?;
+//! # let row = stmt.query_single(NChar::new("Привет")).await?.unwrap();
+//! # let text : &str = row.get(0)?;
+//! # assert_eq!(text, "> Привет");
+//! # Ok(()) })
+//! # }
+//! ```
+
+use crate::{Result, ToSql};
+use crate::oci::{SQLT_CHR, SQLCS_NCHAR, OCIStmt, OCIError};
+use crate::stmt::Params;
+
+/// Text that is bound in the national character set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NChar<'a>(&'a str);
+
+impl<'a> NChar<'a> {
+    /// Creates a new `NChar` argument.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sibyl::NChar;
+    ///
+    /// let arg = NChar::new("Grüße");
+    /// assert_eq!(arg.as_str(), "Grüße");
+    /// ```
+    pub const fn new(text: &'a str) -> Self {
+        Self(text)
+    }
+
+    /// Returns the text.
+    pub fn as_str(&self) -> &str {
+        self.0
+    }
+}
+
+impl ToSql for NChar<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.bind_in(pos, SQLT_CHR, self.0.as_ptr() as _, self.0.len(), stmt, err)?;
+        params.set_charset_form(pos, SQLCS_NCHAR, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl ToSql for &NChar<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.bind_in(pos, SQLT_CHR, self.0.as_ptr() as _, self.0.len(), stmt, err)?;
+        params.set_charset_form(pos, SQLCS_NCHAR, err)?;
+        Ok(pos + 1)
+    }
+}
+
+#[cfg(all(test,feature="blocking"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nchar_round_trip() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_nchar_binds (
+                        id      NUMBER GENERATED ALWAYS AS IDENTITY,
+                        ntext   NVARCHAR2(30)
+                    )
+                ';
+            EXCEPTION
+              WHEN name_already_used THEN NULL;
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let text = "Привет, 世界! ∑ ≠ ∞";
+        let stmt = session.prepare("
+            INSERT INTO test_nchar_binds (ntext) VALUES ('> ' || :TEXT)
+            RETURNING id INTO :ID
+        ")?;
+        let mut id = 0;
+        let count = stmt.execute((NChar::new(text), &mut id, ()))?;
+        assert_eq!(count, 1);
+        assert!(id > 0);
+
+        let stmt = session.prepare("SELECT ntext, Length(ntext) FROM test_nchar_binds WHERE id = :ID")?;
+        let row = stmt.query_single(id)?.unwrap();
+        let ntext : &str = row.get(0)?;
+        let num_chars : usize = row.get(1)?;
+        assert_eq!(ntext, format!("> {}", text));
+        assert_eq!(num_chars, text.chars().count() + 2);
+
+        let stmt = session.prepare("SELECT id FROM test_nchar_binds WHERE ntext = '> ' || :TEXT")?;
+        let row = stmt.query_single(&NChar::new(text))?.unwrap();
+        let found_id : usize = row.get(0)?;
+        assert_eq!(found_id, id);
+
+        session.rollback()?;
+        Ok(())
+    }
+}
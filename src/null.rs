@@ -0,0 +1,134 @@
+//! A typed NULL argument.
+//!
+//! `Option::None` and [`Nvl`](crate::Nvl) need a value (or at least a type of value) that
+//! can be bound. `Null` binds a NULL of the specified type without a value. This is helpful
+//! when the NULL must have a specific SQL type, for example when it is inserted into a `RAW`
+//! column or passed to an overloaded PL/SQL procedure.
+//!
+//! # Example
+//!
+//! ```
+//! # use sibyl::*;
+//! # #[cfg(feature="blocking")]
+//! # fn main() -> Result<()> {
+//! # let session = sibyl::test_env::get_session()?;
+//! let stmt = session.prepare("
+//!     SELECT Nvl(:VAL, Hextoraw('FF')) FROM dual
+//! ")?;
+//! let row = stmt.query_single(Null::<Raw>::new())?.unwrap();
+//! let val : &[u8] = row.get(0)?;
+//! assert_eq!(val, &[0xFF]);
+//! # Ok(())
+//! # }
+//! # #[cfg(feature="nonblocking")]
+//! # fn main() -> Result<()> {
+//! # sibyl::block_on(async {
+//! # let session = sibyl::test_env::get_session().await?;
+//! # let stmt = session.prepare("
+//! #     SELECT Nvl(:VAL, Hextoraw('FF')) FROM dual
+//! # ").await?;
+//! # let row = stmt.query_single(Null::<Raw>::new()).await?.unwrap();
+//! # let val : &[u8] = row.get(0)?;
+//! # assert_eq!(val, &[0xFF]);
+//! # Ok(()) })
+//! # }
+//! ```
+
+use std::marker::PhantomData;
+use crate::{Result, ToSql};
+use crate::oci::{SqlType, OCIStmt, OCIError};
+use crate::stmt::Params;
+
+/// A NULL of type `T`.
+///
+/// This type represents a NULL argument that is bound with the SQL type of `T`.
+pub struct Null<T>(PhantomData<fn() -> T>) where T: SqlType;
+
+impl<T> Null<T> where T: SqlType {
+    /// Creates a new `Null`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sibyl::{Null, Date};
+    ///
+    /// let arg = Null::<Date>::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for Null<T> where T: SqlType {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for Null<T> where T: SqlType {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Null<T> where T: SqlType {}
+
+impl<T> ToSql for Null<T> where T: SqlType {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.bind_null(pos, T::sql_null_type(), stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl<T> ToSql for &Null<T> where T: SqlType {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.bind_null(pos, T::sql_null_type(), stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+#[cfg(all(test,feature="blocking"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn typed_nulls() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_typed_nulls (
+                        id      NUMBER PRIMARY KEY,
+                        bin     RAW(16),
+                        ts      TIMESTAMP,
+                        dt      DATE
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_typed_nulls
+                    ';
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("
+            INSERT INTO test_typed_nulls (id, bin, ts, dt) VALUES (:ID, :BIN, :TS, :DT)
+        ")?;
+        let num_rows = stmt.execute((1, Null::<Raw>::new(), Null::<Timestamp>::new(), &Null::<Date>::new()))?;
+        assert_eq!(num_rows, 1);
+
+        let stmt = session.prepare("SELECT bin, ts, dt FROM test_typed_nulls WHERE id = :ID")?;
+        let row = stmt.query_single(1)?.unwrap();
+        assert!(row.is_null(0));
+        assert!(row.is_null(1));
+        assert!(row.is_null(2));
+
+        session.rollback()?;
+        Ok(())
+    }
+}
@@ -2,6 +2,22 @@
 
 #![allow(dead_code)]
 
+/// Enters a `TRACE` level span, which is named after the OCI function that is about to be called.
+/// The span is exited at the end of the enclosing block.
+#[cfg(feature="tracing")]
+macro_rules! trace_oci_call {
+    ($oci_fn:ident $(, $($field:tt)+)?) => {
+        let _span = tracing::trace_span!(stringify!($oci_fn) $(, $($field)+)?).entered();
+    };
+}
+
+#[cfg(not(feature="tracing"))]
+macro_rules! trace_oci_call {
+    ($oci_fn:ident $(, $($field:tt)+)?) => {};
+}
+
+pub(crate) use trace_oci_call;
+
 pub(crate) mod ptr;
 pub(crate) mod attr;
 pub(crate) mod handle;
@@ -59,18 +75,23 @@ pub(crate) const OCI_CONTINUE               : i32 = -24200;
 
 // Oracle error codes
 pub(crate) const NO_DATA_FOUND              : i32 = 1403;
+pub(crate) const ARRAY_DML_ERRORS           : i32 = 24381;
 
 // Attribute Constants
 pub(crate) const OCI_ATTR_ROW_COUNT         : u32 = 9;
 pub(crate) const OCI_ATTR_PREFETCH_ROWS     : u32 = 11;
 pub(crate) const OCI_ATTR_PARAM_COUNT       : u32 = 18;     // number of columns in the select list
 pub(crate) const OCI_ATTR_STMT_TYPE         : u32 = 24;
+pub(crate) const OCI_ATTR_NUM_DML_ERRORS    : u32 = 73;
+pub(crate) const OCI_ATTR_DML_ROW_OFFSET    : u32 = 74;
+pub(crate) const OCI_ATTR_STATEMENT         : u32 = 144;    // statement text
 pub(crate) const OCI_ATTR_STMTCACHESIZE     : u32 = 176;    // size of the stm cache
 pub(crate) const OCI_ATTR_BIND_COUNT        : u32 = 190;
 pub(crate) const OCI_ATTR_ROWS_FETCHED      : u32 = 197;
 pub(crate) const OCI_ATTR_STMT_IS_RETURNING : u32 = 218;
 pub(crate) const OCI_ATTR_UB8_ROW_COUNT     : u32 = 457;
 pub(crate) const OCI_ATTR_INVISIBLE_COL     : u32 = 461;
+pub(crate) const OCI_ATTR_IMPLICIT_RESULT_COUNT : u32 = 463;
 pub(crate) const OCI_ATTR_CALL_TIMEOUT      : u32 = 531;
 
 // Handle Types
@@ -98,6 +119,9 @@ pub(crate) const OCI_HTYPE_SPOOL            : u32 = 27;
 #[repr(C)] pub(crate) struct OCIServer      { _private: [u8; 0] }
 #[repr(C)] pub        struct OCISession     { _private: [u8; 0] }
 #[repr(C)] pub(crate) struct OCIRaw         { _private: [u8; 0] }
+#[repr(C)] pub(crate) struct OCIType        { _private: [u8; 0] }
+#[repr(C)] pub(crate) struct OCITypeElem    { _private: [u8; 0] }
+#[repr(C)] pub(crate) struct OCIColl        { _private: [u8; 0] }
 
 #[repr(C)] pub(crate) struct OCIAuthInfo    { _private: [u8; 0] }
 #[repr(C)] pub(crate) struct OCISPool       { _private: [u8; 0] }
@@ -139,6 +163,7 @@ pub(crate) const OCI_DTYPE_RSET             : u32 = 52;  // result set descripto
 pub(crate) const OCI_DTYPE_PARAM            : u32 = 53;  // a parameter descriptor obtained from ocigparm
 pub(crate) const OCI_DTYPE_ROWID            : u32 = 54;  // rowid descriptor
 pub(crate) const OCI_DTYPE_FILE             : u32 = 56;  // File Lob locator
+pub(crate) const OCI_DTYPE_SNAP             : u32 = 58;  // snapshot descriptor
 pub(crate) const OCI_DTYPE_LOCATOR          : u32 = 61;  // LOB locator
 pub(crate) const OCI_DTYPE_INTERVAL_YM      : u32 = 62;  // Interval year month
 pub(crate) const OCI_DTYPE_INTERVAL_DS      : u32 = 63;  // Interval day second
@@ -155,6 +180,7 @@ pub(crate) const OCI_DTYPE_TIMESTAMP_LTZ    : u32 = 70;  // Timestamp with local
 #[repr(C)] pub        struct OCIDateTime    { _private: [u8; 0] }
 #[repr(C)] pub        struct OCIInterval    { _private: [u8; 0] }
 #[repr(C)] pub(crate) struct OCIString      { _private: [u8; 0] }
+#[repr(C)] pub        struct OCISnapshot    { _private: [u8; 0] }
 
 // Virtual descriptors
 pub struct OCICLobLocator           {}
@@ -216,6 +242,7 @@ impl_descr_type!{
     OCIBFileLocator         => OCI_DTYPE_FILE,          SQLT_BFILE,         OCILobLocator,
     OCIParam                => OCI_DTYPE_PARAM,         SQLT_NON,           OCIParam,
     OCIRowid                => OCI_DTYPE_ROWID,         SQLT_RDD,           OCIRowid,
+    OCISnapshot             => OCI_DTYPE_SNAP,          SQLT_NON,           OCISnapshot,
     OCITimestamp            => OCI_DTYPE_TIMESTAMP,     SQLT_TIMESTAMP,     OCIDateTime,
     OCITimestampTZ          => OCI_DTYPE_TIMESTAMP_TZ,  SQLT_TIMESTAMP_TZ,  OCIDateTime,
     OCITimestampLTZ         => OCI_DTYPE_TIMESTAMP_LTZ, SQLT_TIMESTAMP_LTZ, OCIDateTime,
@@ -235,7 +262,7 @@ macro_rules! mark_as_oci {
 }
 
 mark_as_oci!(OCIEnv, OCIError, OCISvcCtx, OCIStmt, OCIBind, OCIDefine, OCIDescribe, OCIServer, OCISession, OCIAuthInfo, OCISPool, OCICPool);
-mark_as_oci!(OCIResult, OCILobLocator, OCILobRegion, OCIParam, OCIRowid, OCIDateTime, OCIInterval, OCIString, OCIRaw);
+mark_as_oci!(OCIResult, OCILobLocator, OCILobRegion, OCIParam, OCIRowid, OCIDateTime, OCIInterval, OCIString, OCIRaw, OCISnapshot);
 mark_as_oci!(OCICLobLocator, OCIBLobLocator, OCIBFileLocator, OCITimestamp, OCITimestampTZ, OCITimestampLTZ, OCIIntervalYearToMonth, OCIIntervalDayToSecond);
 
 /// C mapping of the Oracle NUMBER
@@ -328,6 +355,28 @@ pub(crate) const SQLCS_NCHAR            : u8 = 2;
 pub(crate) const OCI_DURATION_SESSION   : u16 = 10;
 pub(crate) const OCI_DURATION_STATEMENT : u16 = 13;
 
+// OCITypeByName options
+pub(crate) const OCI_TYPEGET_HEADER     : u32 = 0;
+
+// OCIObjectFree flags
+pub(crate) const OCI_OBJECTFREE_FORCE   : u16 = 1;
+
+// Type codes of the object attributes
+pub(crate) const OCI_TYPECODE_VARCHAR   : u16 = 1;
+pub(crate) const OCI_TYPECODE_NUMBER    : u16 = 2;
+pub(crate) const OCI_TYPECODE_INTEGER   : u16 = 3;
+pub(crate) const OCI_TYPECODE_FLOAT     : u16 = 4;
+pub(crate) const OCI_TYPECODE_DECIMAL   : u16 = 7;
+pub(crate) const OCI_TYPECODE_VARCHAR2  : u16 = 9;
+pub(crate) const OCI_TYPECODE_DATE      : u16 = 12;
+pub(crate) const OCI_TYPECODE_REAL      : u16 = 21;
+pub(crate) const OCI_TYPECODE_DOUBLE    : u16 = 22;
+pub(crate) const OCI_TYPECODE_CHAR      : u16 = 96;
+pub(crate) const OCI_TYPECODE_SMALLINT  : u16 = 246;
+pub(crate) const OCI_TYPECODE_NCHAR     : u16 = 286;
+pub(crate) const OCI_TYPECODE_NVARCHAR2 : u16 = 287;
+pub(crate) const OCI_TYPECODE_NAMEDCOLLECTION : u16 = 122;
+
 // Character Sets
 pub(crate) const AL32UTF8               : u16 = 873;
 pub(crate) const UTF8                   : u16 = 871;
@@ -368,6 +417,9 @@ pub(crate) const OCI_SESSGET_PURITY_NEW     : u32 = 0x0040;
 pub(crate) const OCI_SESSGET_PURITY_SELF    : u32 = 0x0080;
 pub(crate) const OCI_SESSGET_CPOOL          : u32 = 0x0200;
 
+pub(crate) const OCI_SESSRLS_DROPSESS       : u32 = 0x0001;
+pub(crate) const OCI_SESSRLS_RETAG          : u32 = 0x0002;
+
 // Server Handle Attribute Values
 // const OCI_SERVER_NOT_CONNECTED  : u32 = 0;
 pub(crate) const OCI_SERVER_NORMAL : u32 = 1;
@@ -388,6 +440,8 @@ pub(crate) const OCI_ATTR_COLLECT_CALL_TIME : u32 = 369;
 pub(crate) const OCI_ATTR_CALL_TIME         : u32 = 370;
 pub(crate) const OCI_ATTR_DRIVER_NAME       : u32 = 424;
 pub(crate) const OCI_ATTR_DEFAULT_LOBPREFETCH_SIZE : u32 = 438;
+pub(crate) const OCI_ATTR_LOBPREFETCH_SIZE   : u32 = 439;
+pub(crate) const OCI_ATTR_LOBPREFETCH_LENGTH : u32 = 440;
 pub(crate) const OCI_ATTR_LOB_REMOTE        : u32 = 520;
 pub(crate) const OCI_ATTR_LOB_TYPE          : u32 = 591;
 
@@ -396,6 +450,7 @@ pub(crate) const OCI_ATTR_SPOOL_TIMEOUT                 : u32 = 308; // session
 pub(crate) const OCI_ATTR_SPOOL_GETMODE                 : u32 = 309; // session get mode
 pub(crate) const OCI_ATTR_SPOOL_BUSY_COUNT              : u32 = 310; // busy session count
 pub(crate) const OCI_ATTR_SPOOL_OPEN_COUNT              : u32 = 311; // open session count
+pub(crate) const OCI_ATTR_SPOOL_MIN                     : u32 = 312; // min session count
 pub(crate) const OCI_ATTR_SPOOL_AUTH                    : u32 = 460; // Auth handle on pool handle
 pub(crate) const OCI_ATTR_SPOOL_MAX_LIFETIME_SESSION    : u32 = 490; // Max Lifetime for session
 pub(crate) const OCI_ATTR_SPOOL_WAIT_TIMEOUT            : u32 = 506;
@@ -416,6 +471,16 @@ pub(crate) const OCI_ERROR_MAXMSG_SIZE      : usize = 3072;
 
 pub(crate) const OCI_FETCH_NEXT             : u16 = 2;
 
+pub(crate) const OCI_DATA_AT_EXEC           : u32 = 2;
+pub(crate) const OCI_DYNAMIC_FETCH          : u32 = 2;
+pub(crate) const OCI_DESCRIBE_ONLY          : u32 = 0x10;
+pub(crate) const OCI_BATCH_ERRORS           : u32 = 0x80;
+pub(crate) const SB4MAXVAL                  : i64 = 0x7FFFFFFF;
+
+// OCINumber integer sign types
+pub(crate) const OCI_NUMBER_UNSIGNED        : u32 = 0;
+pub(crate) const OCI_NUMBER_SIGNED          : u32 = 2;
+
 pub(crate) const OCI_TEMP_BLOB              : u8 = 1;
 pub(crate) const OCI_TEMP_CLOB              : u8 = 2;
 
@@ -423,6 +488,8 @@ pub(crate) const OCI_FILE_READONLY          : u8 = 1;
 pub(crate) const OCI_LOB_READONLY           : u8 = 1;
 pub(crate) const OCI_LOB_READWRITE          : u8 = 2;
 
+pub(crate) const OCI_LOB_BUFFER_FREE        : u32 = 1;
+
 pub(crate) const OCI_ONE_PIECE              : u8 = 0;
 pub(crate) const OCI_FIRST_PIECE            : u8 = 1;
 pub(crate) const OCI_NEXT_PIECE             : u8 = 2;
@@ -438,7 +505,7 @@ pub(crate) const OCI_NTV_SYNTAX   : u32 = 1;
 pub(crate) const OCI_STMT_SELECT  : u16 = 1;
 // pub(crate) const OCI_STMT_UPDATE  : u16 = 2;
 // pub(crate) const OCI_STMT_DELETE  : u16 = 3;
-// pub(crate) const OCI_STMT_INSERT  : u16 = 4;
+pub(crate) const OCI_STMT_INSERT  : u16 = 4;
 // pub(crate) const OCI_STMT_CREATE  : u16 = 5;
 // pub(crate) const OCI_STMT_DROP    : u16 = 6;
 // pub(crate) const OCI_STMT_ALTER   : u16 = 7;
@@ -462,6 +529,7 @@ pub(crate) const OCI_ATTR_SCHEMA_NAME       : u32 =  9; // the schema name
 // pub(crate) const OCI_ATTR_PACKAGE_NAME      : u32 = 12; // package name of package type
 pub(crate) const OCI_ATTR_CHARSET_FORM      : u32 = 32;
 pub(crate) const OCI_ATTR_COL_PROPERTIES    : u32 = 104;
+pub(crate) const OCI_ATTR_MAXCHAR_SIZE      : u32 = 163; // max char size of data
 pub(crate) const OCI_ATTR_CHAR_SIZE         : u32 = 286;
 
 // Flags coresponding to the column properties
@@ -596,6 +664,11 @@ extern "C" {
         charset:    u16,
         ncharset:   u16
     ) -> i32;
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/connect-authorize-and-initialize-functions.html
+    pub(crate) fn OCITerminate(
+        mode:       u32
+    ) -> i32;
 }
 
 extern "C" {
@@ -730,6 +803,18 @@ extern "C" {
         errhp:      *const OCIError,
         mode:       u32
     ) -> i32;
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/miscellaneous-functions.html
+    fn OCIBreak(
+        hndlp:      *const c_void,
+        errhp:      *const OCIError
+    ) -> i32;
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/miscellaneous-functions.html
+    fn OCIReset(
+        hndlp:      *const c_void,
+        errhp:      *const OCIError
+    ) -> i32;
 }
 
 extern "C" {
@@ -806,14 +891,14 @@ extern "C" {
     ) -> i32;
 
     // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/bind-define-describe-functions.html#GUID-030270CB-346A-412E-B3B3-556DD6947BE2
-    // fn OCIBindDynamic(
-    //     bindp:      *const OCIBind,
-    //     errhp:      *const OCIError,
-    //     ictxp:      *const c_void,
-    //     icbfp:      OCICallbackInBind,
-    //     octxp:      *const c_void,
-    //     ocbfp:      OCICallbackOutBind
-    // ) -> i32;
+    fn OCIBindDynamic(
+        bindp:      *mut OCIBind,
+        errhp:      *const OCIError,
+        ictxp:      *mut c_void,
+        icbfp:      OCICallbackInBind,
+        octxp:      *mut c_void,
+        ocbfp:      Option<OCICallbackOutBind>
+    ) -> i32;
 
     // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/statement-functions.html#GUID-98B26708-3E02-45C0-8258-5D5544F32BE9
     fn OCIStmtExecute(
@@ -822,8 +907,8 @@ extern "C" {
         errhp:      *const OCIError,
         iters:      u32,
         rowoff:     u32,
-        snap_in:    *const c_void,  // *const OCISnapshot
-        snap_out:   *const c_void,    // *const OCISnapshot
+        snap_in:    *const OCISnapshot,
+        snap_out:   *const OCISnapshot,
         mode:       u32
     ) -> i32;
 
@@ -847,6 +932,31 @@ extern "C" {
     ) -> i32;
 }
 
+/// Callback that provides OCI with the IN data of dynamically bound (`OCI_DATA_AT_EXEC`) variables
+pub(crate) type OCICallbackInBind = extern "C" fn(
+    ictxp:      *mut c_void,
+    bindp:      *mut OCIBind,
+    iter:       u32,
+    index:      u32,
+    bufpp:      *mut *mut c_void,
+    alenp:      *mut u32,
+    piecep:     *mut u8,
+    indpp:      *mut *mut c_void
+) -> i32;
+
+/// Callback that provides OCI with buffers for the OUT data of dynamically bound (`OCI_DATA_AT_EXEC`) variables
+pub(crate) type OCICallbackOutBind = extern "C" fn(
+    octxp:      *mut c_void,
+    bindp:      *mut OCIBind,
+    iter:       u32,
+    index:      u32,
+    bufpp:      *mut *mut c_void,
+    alenpp:     *mut *mut u32,
+    piecep:     *mut u8,
+    indpp:      *mut *mut c_void,
+    rcodepp:    *mut *mut u16
+) -> i32;
+
 extern "C" {
     // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/bind-define-describe-functions.html#GUID-74939FB5-919E-4D24-B327-AFB532435061
     fn OCIDefineByPos2(
@@ -864,6 +974,133 @@ extern "C" {
     ) -> i32;
 }
 
+/// Callback that provides OCI with buffers for dynamically defined (piecewise fetched) output variables
+pub(crate) type OCICallbackDefine = extern "C" fn(
+    octxp:      *mut c_void,
+    defnp:      *mut OCIDefine,
+    iter:       u32,
+    bufpp:      *mut *mut c_void,
+    alenpp:     *mut *mut u32,
+    piecep:     *mut u8,
+    indpp:      *mut *mut c_void,
+    rcodepp:    *mut *mut u16
+) -> i32;
+
+extern "C" {
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/bind-define-describe-functions.html
+    fn OCIDefineDynamic(
+        defnp:      *mut OCIDefine,
+        errhp:      *const OCIError,
+        octxp:      *mut c_void,
+        ocbfp:      OCICallbackDefine
+    ) -> i32;
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/bind-define-describe-functions.html
+    fn OCIDefineObject(
+        defnp:      *mut OCIDefine,
+        errhp:      *const OCIError,
+        tdo:        *const OCIType,
+        pgvpp:      *mut *mut c_void,
+        pvszsp:     *mut u32,
+        indpp:      *mut *mut c_void,
+        indszp:     *mut u32
+    ) -> i32;
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/bind-define-describe-functions.html
+    fn OCIDefineArrayOfStruct(
+        defnp:      *mut OCIDefine,
+        errhp:      *const OCIError,
+        pvskip:     u32,
+        indskip:    u32,
+        rlskip:     u32,
+        rcskip:     u32
+    ) -> i32;
+}
+
+extern "C" {
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/oci-type-information-accessor-functions.html
+    fn OCITypeByName(
+        env:        *const OCIEnv,
+        err:        *const OCIError,
+        svc:        *const OCISvcCtx,
+        schema_name:*const u8,
+        s_length:   u32,
+        type_name:  *const u8,
+        t_length:   u32,
+        version:    *const u8,
+        v_length:   u32,
+        duration:   u16,
+        get_option: u32,
+        tdo:        *mut *mut OCIType
+    ) -> i32;
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/oci-type-information-accessor-functions.html
+    fn OCITypeTypeCode(
+        env:        *const OCIEnv,
+        err:        *const OCIError,
+        tdo:        *const OCIType
+    ) -> u16;
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/oci-navigational-and-type-functions.html
+    fn OCIObjectGetAttr(
+        env:        *const OCIEnv,
+        err:        *const OCIError,
+        instance:   *const c_void,
+        null_struct:*const c_void,
+        tdo:        *const OCIType,
+        names:      *const *const u8,
+        lengths:    *const u32,
+        name_count: u32,
+        indexes:    *const u32,
+        index_count:u32,
+        attr_null_status: *mut i16,
+        attr_null_struct: *mut *mut c_void,
+        attr_value: *mut *mut c_void,
+        attr_tdo:   *mut *mut OCIType
+    ) -> i32;
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/oci-navigational-and-type-functions.html
+    fn OCIObjectFree(
+        env:        *const OCIEnv,
+        err:        *const OCIError,
+        instance:   *mut c_void,
+        flags:      u16
+    ) -> i32;
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/oci-type-information-accessor-functions.html
+    fn OCITypeCollElem(
+        env:        *const OCIEnv,
+        err:        *const OCIError,
+        tdo:        *const OCIType,
+        element:    *mut *mut OCITypeElem
+    ) -> i32;
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/oci-type-information-accessor-functions.html
+    fn OCITypeElemTypeCode(
+        env:        *const OCIEnv,
+        elem:       *const OCITypeElem
+    ) -> u16;
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/oci-collection-and-iterator-functions.html
+    fn OCICollSize(
+        env:        *const OCIEnv,
+        err:        *const OCIError,
+        coll:       *const OCIColl,
+        size:       *mut i32
+    ) -> i32;
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/oci-collection-and-iterator-functions.html
+    fn OCICollGetElem(
+        env:        *const OCIEnv,
+        err:        *const OCIError,
+        coll:       *const OCIColl,
+        index:      i32,
+        exists:     *mut i32,
+        elem:       *mut *mut c_void,
+        elemind:    *mut *mut c_void
+    ) -> i32;
+}
+
 extern "C" {
     // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/miscellaneous-functions.html#GUID-064F2680-453A-40D1-9C36-518F1E2B31DF
     fn OCIRowidToChar(
@@ -946,6 +1183,20 @@ extern "C" {
         duration:   u16,
     ) -> i32;
 
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/lob-functions.html
+    fn OCILobDisableBuffering(
+        svchp:      *const OCISvcCtx,
+        errhp:      *const OCIError,
+        loc:        *const OCILobLocator,
+    ) -> i32;
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/lob-functions.html
+    fn OCILobEnableBuffering(
+        svchp:      *const OCISvcCtx,
+        errhp:      *const OCIError,
+        loc:        *const OCILobLocator,
+    ) -> i32;
+
     // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/lob-functions.html#GUID-264797B2-B3EA-4F6D-9A0E-BF8A4DDA13FA
     fn OCILobErase2(
         svchp:      *const OCISvcCtx,
@@ -1008,6 +1259,14 @@ extern "C" {
         name_len:   u16,
     ) -> i32;
 
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/lob-functions.html
+    fn OCILobFlushBuffer(
+        svchp:      *const OCISvcCtx,
+        errhp:      *const OCIError,
+        loc:        *const OCILobLocator,
+        flag:       u32,
+    ) -> i32;
+
     // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/lob-functions.html#GUID-E0FBF017-1B08-410C-9E53-F6E14008813A
     pub(crate) fn OCILobFreeTemporary(
         svchp:      *const OCISvcCtx,
@@ -1060,7 +1319,7 @@ extern "C" {
 
     // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/lob-functions.html#GUID-071D8134-F9E7-4C5A-8E63-E90831FA7AC3
     pub(crate) fn OCILobIsTemporary(
-        svchp:      *const OCISvcCtx,
+        envhp:      *const OCIEnv,
         errhp:      *const OCIError,
         loc:        *const OCILobLocator,
         flag:       *const u8,
@@ -1534,13 +1793,13 @@ extern "C" {
     ) -> i32;
 
     // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/oci-NUMBER-functions.html#GUID-E8940E06-F4EF-4172-AEE5-AF8E4F6B3AEE
-    // fn OCINumberFromInt(
-    //     err:      *const OCIError,
-    //     inum:     *const c_void,
-    //     inum_len: u32,
-    //     sign_typ: u32,
-    //     number:   *const OCINumber
-    // ) -> i32;
+    fn OCINumberFromInt(
+        err:      *const OCIError,
+        inum:     *const c_void,
+        inum_len: u32,
+        sign_typ: u32,
+        number:   *mut OCINumber
+    ) -> i32;
 
     // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/oci-NUMBER-functions.html#GUID-EC8E2C9E-BCD2-4D1E-A052-3E657B552461
     fn OCINumberFromReal(
@@ -1730,13 +1989,13 @@ extern "C" {
     ) -> i32;
 
     // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/oci-NUMBER-functions.html#GUID-067F138E-E689-4922-9ED7-4A7B0E46447E
-    // fn OCINumberToInt(
-    //     err:      *const OCIError,
-    //     number:   *const OCINumber,
-    //     res_len:  u32,
-    //     sign_typ: u32,
-    //     result:   *const c_void
-    // ) -> i32;
+    fn OCINumberToInt(
+        err:      *const OCIError,
+        number:   *const OCINumber,
+        res_len:  u32,
+        sign_typ: u32,
+        result:   *mut c_void
+    ) -> i32;
 
     // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/oci-NUMBER-functions.html#GUID-76C4BC1E-EC64-4CF6-82A4-94D5DC242649
     fn OCINumberToReal(
@@ -2080,29 +2339,44 @@ pub fn client_version() -> (i32, i32, i32, i32, i32) {
     ) }
 }
 
-pub(crate) fn oci_session_release(svc: &OCISvcCtx, err: &OCIError) -> i32 {
-    unsafe { OCISessionRelease(svc, err, std::ptr::null(), 0, OCI_DEFAULT) }
+pub(crate) fn oci_session_release(svc: &OCISvcCtx, err: &OCIError, tag: &str, mode: u32) -> i32 {
+    trace_oci_call!(OCISessionRelease);
+    let tag_ptr = if tag.is_empty() { std::ptr::null() } else { tag.as_ptr() };
+    unsafe { OCISessionRelease(svc, err, tag_ptr, tag.len() as _, mode) }
 }
 
 pub(crate) fn oci_connection_pool_destroy(pool: &OCICPool, err: &OCIError) -> i32 {
+    trace_oci_call!(OCIConnectionPoolDestroy);
     unsafe { OCIConnectionPoolDestroy(pool, err, OCI_DEFAULT) }
 }
 
 pub(crate) fn oci_session_pool_destroy(pool: &OCISPool, err: &OCIError) -> i32 {
+    trace_oci_call!(OCISessionPoolDestroy);
     unsafe { OCISessionPoolDestroy(pool, err, OCI_DEFAULT) }
 }
 
 pub(crate) fn oci_stmt_release(stmt: &OCIStmt, err: &OCIError) -> i32 {
+    trace_oci_call!(OCIStmtRelease);
     unsafe { OCIStmtRelease(stmt, err, std::ptr::null(), 0, OCI_DEFAULT) }
 }
 
 pub(crate) fn oci_trans_rollback(svchp: &OCISvcCtx, errhp: &OCIError) -> i32 {
+    trace_oci_call!(OCITransRollback);
     unsafe { OCITransRollback(svchp, errhp, OCI_DEFAULT) }
 }
 
 // ================================================================================================
 
 macro_rules! ok_or_env_err {
+    ( |$env:ident| $oci_fn:ident ( $($oci_args:tt)* ) ) => {{
+        trace_oci_call!($oci_fn);
+        let res = unsafe { $oci_fn( $($oci_args)* ) };
+        if res < 0 {
+            Err(Error::env($env, res))
+        } else {
+            Ok(())
+        }
+    }};
     ( |$env:ident| $stmt:stmt ) => {{
         let res = unsafe { $stmt };
         if res < 0 {
@@ -2132,6 +2406,15 @@ pub(crate) fn handle_alloc<T: HandleType>(
 }
 
 macro_rules! ok_or_oci_err {
+    ( |$err:ident| $oci_fn:ident ( $($oci_args:tt)* ) ) => {{
+        trace_oci_call!($oci_fn);
+        let res = unsafe { $oci_fn( $($oci_args)* ) };
+        if res < 0 {
+            Err(Error::oci($err, res))
+        } else {
+            Ok(())
+        }
+    }};
     ( |$err:ident| $stmt:stmt ) => {{
         let res = unsafe { $stmt };
         if res < 0 {
@@ -2193,6 +2476,17 @@ pub(crate) fn param_get(
     )
 }
 
+pub(crate) fn error_param_get(
+    hndlp:      &OCIError,
+    errhp:      &OCIError,
+    descr:      *mut *mut OCIError,
+    pos:        u32
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCIParamGet(hndlp as *const OCIError as _, OCI_HTYPE_ERROR, errhp, descr as _, pos)
+    )
+}
+
 pub(crate) fn session_get(
     envhp:      &OCIEnv,
     errhp:      &OCIError,
@@ -2200,11 +2494,15 @@ pub(crate) fn session_get(
     authinfop:  &OCIAuthInfo,
     dbname:     *const u8,
     dbname_len: u32,
+    tag:        &str,
     found:      *mut u8,
     mode:       u32
 ) -> Result<()> {
+    let tag_ptr = if tag.is_empty() { std::ptr::null() } else { tag.as_ptr() };
+    let mut ret_tag_ptr = std::ptr::null::<u8>();
+    let mut ret_tag_len = 0u32;
     ok_or_oci_err!(|errhp|
-        OCISessionGet(envhp, errhp, svchp, authinfop, dbname, dbname_len, std::ptr::null(), 0, std::ptr::null_mut(), std::ptr::null_mut(), found, mode)
+        OCISessionGet(envhp, errhp, svchp, authinfop, dbname, dbname_len, tag_ptr, tag.len() as _, &mut ret_tag_ptr, &mut ret_tag_len, found, mode)
     )
 }
 
@@ -2218,6 +2516,26 @@ pub(crate) fn ping(
 }
 
 
+pub(crate) fn break_call(
+    svchp: &OCISvcCtx,
+    errhp: &OCIError,
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCIBreak(svchp as *const OCISvcCtx as _, errhp)
+    )
+}
+
+#[cfg(feature="nonblocking")]
+pub(crate) fn reset(
+    svchp: &OCISvcCtx,
+    errhp: &OCIError,
+) {
+    trace_oci_call!(OCIReset);
+    unsafe {
+        OCIReset(svchp as *const OCISvcCtx as _, errhp);
+    }
+}
+
 pub(crate) fn trans_commit(
     svchp: &OCISvcCtx,
     errhp: &OCIError,
@@ -2236,6 +2554,18 @@ pub(crate) fn trans_rollback(
     )
 }
 
+pub(crate) fn session_release(
+    svchp: &OCISvcCtx,
+    errhp: &OCIError,
+    tag:   &str,
+    mode:  u32,
+) -> Result<()> {
+    let tag_ptr = if tag.is_empty() { std::ptr::null() } else { tag.as_ptr() };
+    ok_or_oci_err!(|errhp|
+        OCISessionRelease(svchp, errhp, tag_ptr, tag.len() as _, mode)
+    )
+}
+
 
 pub(crate) fn connection_pool_create (
     envhp:          &OCIEnv,
@@ -2342,16 +2672,32 @@ pub(crate) fn bind_by_pos(
     )
 }
 
+pub(crate) fn bind_dynamic(
+    bindp:      &OCIBind,
+    errhp:      &OCIError,
+    ictxp:      *mut c_void,
+    icbfp:      OCICallbackInBind,
+    octxp:      *mut c_void,
+    ocbfp:      Option<OCICallbackOutBind>
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCIBindDynamic(bindp as *const OCIBind as _, errhp, ictxp, icbfp, octxp, ocbfp)
+    )
+}
+
 pub(crate) fn stmt_execute(
     svchp:      &OCISvcCtx,
     stmtp:      &OCIStmt,
     errhp:      &OCIError,
     iters:      u32,
     rowoff:     u32,
+    snap_in:    *const OCISnapshot,
+    snap_out:   *const OCISnapshot,
     mode:       u32
 ) -> Result<i32> {
+    trace_oci_call!(OCIStmtExecute);
     let res = unsafe {
-        OCIStmtExecute(svchp, stmtp, errhp, iters, rowoff, std::ptr::null(), std::ptr::null(), mode)
+        OCIStmtExecute(svchp, stmtp, errhp, iters, rowoff, snap_in, snap_out, mode)
     };
     match res {
         OCI_ERROR | OCI_INVALID_HANDLE => { Err(Error::oci(errhp, res)) },
@@ -2390,6 +2736,144 @@ pub(crate) fn define_by_pos(
     )
 }
 
+pub(crate) fn define_array_of_struct(
+    defnp:      &OCIDefine,
+    errhp:      &OCIError,
+    pvskip:     u32,
+    indskip:    u32,
+    rlskip:     u32,
+    rcskip:     u32
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCIDefineArrayOfStruct(defnp as *const OCIDefine as _, errhp, pvskip, indskip, rlskip, rcskip)
+    )
+}
+
+pub(crate) fn define_dynamic(
+    defnp:      &OCIDefine,
+    errhp:      &OCIError,
+    octxp:      *mut c_void,
+    ocbfp:      OCICallbackDefine
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCIDefineDynamic(defnp as *const OCIDefine as _, errhp, octxp, ocbfp)
+    )
+}
+
+pub(crate) fn define_object(
+    defnp:      &OCIDefine,
+    errhp:      &OCIError,
+    tdo:        &OCIType,
+    pgvpp:      *mut *mut c_void,
+    indpp:      *mut *mut c_void
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCIDefineObject(defnp as *const OCIDefine as _, errhp, tdo, pgvpp, std::ptr::null_mut(), indpp, std::ptr::null_mut())
+    )
+}
+
+pub(crate) fn type_by_name(
+    env:        &OCIEnv,
+    err:        &OCIError,
+    svc:        &OCISvcCtx,
+    schema:     &str,
+    name:       &str,
+    tdo:        *mut *mut OCIType
+) -> Result<()> {
+    trace_oci_call!(OCITypeByName);
+    ok_or_oci_err!(|err| {
+        let mut res;
+        loop {
+            res = OCITypeByName(
+                env, err, svc,
+                schema.as_ptr(), schema.len() as _,
+                name.as_ptr(), name.len() as _,
+                std::ptr::null(), 0,
+                OCI_DURATION_SESSION, OCI_TYPEGET_HEADER, tdo
+            );
+            // In nonblocking mode the type lookup might need a round-trip, which
+            // is waited for here as it is done only once per query.
+            if res != OCI_STILL_EXECUTING {
+                break;
+            }
+            std::thread::yield_now();
+        }
+        res
+    })
+}
+
+pub(crate) fn type_code(
+    env:        &OCIEnv,
+    err:        &OCIError,
+    tdo:        &OCIType
+) -> u16 {
+    unsafe { OCITypeTypeCode(env, err, tdo) }
+}
+
+pub(crate) fn object_get_attr(
+    env:        &OCIEnv,
+    err:        &OCIError,
+    instance:   *const c_void,
+    null_struct:*const c_void,
+    tdo:        &OCIType,
+    name:       &str,
+    attr_null_status: *mut i16,
+    attr_value: *mut *mut c_void,
+    attr_tdo:   *mut *mut OCIType
+) -> Result<()> {
+    let mut attr_null_struct = std::ptr::null_mut::<c_void>();
+    ok_or_oci_err!(|err|
+        OCIObjectGetAttr(
+            env, err, instance, null_struct, tdo,
+            &name.as_ptr(), &(name.len() as u32), 1, std::ptr::null(), 0,
+            attr_null_status, &mut attr_null_struct, attr_value, attr_tdo
+        )
+    )
+}
+
+pub(crate) fn object_free(
+    env:        &OCIEnv,
+    err:        &OCIError,
+    instance:   *mut c_void
+) {
+    trace_oci_call!(OCIObjectFree);
+    unsafe {
+        OCIObjectFree(env, err, instance, OCI_OBJECTFREE_FORCE);
+    }
+}
+
+pub(crate) fn type_coll_elem_code(
+    env:        &OCIEnv,
+    err:        &OCIError,
+    tdo:        &OCIType
+) -> Result<u16> {
+    let mut elem = std::ptr::null_mut::<OCITypeElem>();
+    ok_or_oci_err!(|err| OCITypeCollElem(env, err, tdo, &mut elem))?;
+    Ok(unsafe { OCITypeElemTypeCode(env, elem) })
+}
+
+pub(crate) fn coll_size(
+    env:        &OCIEnv,
+    err:        &OCIError,
+    coll:       *const OCIColl
+) -> Result<usize> {
+    let mut size = 0i32;
+    ok_or_oci_err!(|err| OCICollSize(env, err, coll, &mut size))?;
+    Ok(size as usize)
+}
+
+pub(crate) fn coll_get_elem(
+    env:        &OCIEnv,
+    err:        &OCIError,
+    coll:       *const OCIColl,
+    index:      usize,
+    exists:     *mut i32,
+    elem:       *mut *mut c_void,
+    elemind:    *mut *mut c_void
+) -> Result<()> {
+    ok_or_oci_err!(|err| OCICollGetElem(env, err, coll, index as i32, exists, elem, elemind))
+}
+
 pub(crate) fn rowid_to_char(
     desc:   &OCIRowid,
     text:   *mut u8,
@@ -2473,6 +2957,26 @@ pub(crate) fn lob_create_temporary(
     )
 }
 
+pub(crate) fn lob_disable_buffering(
+    svchp:      &OCISvcCtx,
+    errhp:      &OCIError,
+    loc:        &OCILobLocator,
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCILobDisableBuffering(svchp, errhp, loc)
+    )
+}
+
+pub(crate) fn lob_enable_buffering(
+    svchp:      &OCISvcCtx,
+    errhp:      &OCIError,
+    loc:        &OCILobLocator,
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCILobEnableBuffering(svchp, errhp, loc)
+    )
+}
+
 pub(crate) fn lob_erase(
     svchp:      &OCISvcCtx,
     errhp:      &OCIError,
@@ -2556,6 +3060,17 @@ pub(crate) fn lob_file_set_name(
     )
 }
 
+pub(crate) fn lob_flush_buffer(
+    svchp:      &OCISvcCtx,
+    errhp:      &OCIError,
+    loc:        &OCILobLocator,
+    flag:       u32,
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCILobFlushBuffer(svchp, errhp, loc, flag)
+    )
+}
+
 pub(crate) fn lob_free_temporary(
     svchp:      &OCISvcCtx,
     errhp:      &OCIError,
@@ -2625,13 +3140,13 @@ pub(crate) fn lob_is_open(
 }
 
 pub(crate) fn lob_is_temporary(
-    svchp:      &OCISvcCtx,
+    envhp:      &OCIEnv,
     errhp:      &OCIError,
     loc:        &OCILobLocator,
     flag:       *mut u8,
 ) -> Result<()> {
     ok_or_oci_err!(|errhp|
-        OCILobIsTemporary(svchp, errhp, loc, flag)
+        OCILobIsTemporary(envhp, errhp, loc, flag)
     )
 }
 
@@ -2695,6 +3210,7 @@ pub(crate) fn lob_read(
     csid:       u16,
     csfrm:      u8,
 ) -> Result<i32> {
+    trace_oci_call!(OCILobRead2);
     let res = unsafe {
         OCILobRead2(svchp, errhp, loc, byte_cnt, char_cnt, offset, buf, buf_len, piece, std::ptr::null_mut::<c_void>(), std::ptr::null::<c_void>(), csid, csfrm)
     };
@@ -3480,6 +3996,18 @@ pub(crate) fn number_floor(
     )
 }
 
+pub(crate) fn number_from_int(
+    err:      &OCIError,
+    inum:     *const c_void,
+    inum_len: u32,
+    sign_typ: u32,
+    number:   *mut OCINumber
+) -> Result<()> {
+    ok_or_oci_err!(|err|
+        OCINumberFromInt(err, inum, inum_len, sign_typ, number)
+    )
+}
+
 pub(crate) fn number_from_real(
     err:      &OCIError,
     rnum:     *const c_void,
@@ -3722,6 +4250,18 @@ pub(crate) fn number_tan(
     )
 }
 
+pub(crate) fn number_to_int(
+    err:      &OCIError,
+    number:   &OCINumber,
+    res_len:  u32,
+    sign_typ: u32,
+    result:   *mut c_void
+) -> Result<()> {
+    ok_or_oci_err!(|err|
+        OCINumberToInt(err, number, res_len, sign_typ, result)
+    )
+}
+
 pub(crate) fn number_to_real(
     err:      &OCIError,
     number:   &OCINumber,
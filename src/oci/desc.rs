@@ -53,6 +53,7 @@ where T: DescriptorType
 {
     fn drop(&mut self) {
         if !self.0.is_null() {
+            trace_oci_call!(OCIDescriptorFree, dtype = T::get_type());
             unsafe {
                 OCIDescriptorFree(self.0.get() as _, T::get_type());
             }
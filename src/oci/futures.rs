@@ -5,13 +5,16 @@ use super::{*, ptr::Ptr};
 use std::{future::Future, pin::Pin, task::{Context, Poll}, sync::{Arc, atomic::{AtomicI32, Ordering}}};
 
 macro_rules! wait {
-    (|$this:ident, $ctx:ident| $oci_call:expr) => {{
+    (|$this:ident, $ctx:ident| $oci_fn:ident ( $($oci_args:tt)* )) => {{
         let id = $this as *mut Self as usize;
         if !$this.ctx.lock(id) {
             $ctx.waker().wake_by_ref();
             return Poll::Pending;
         }
-        let res = unsafe { $oci_call };
+        let res = {
+            trace_oci_call!($oci_fn);
+            unsafe { $oci_fn( $($oci_args)* ) }
+        };
         if res == OCI_STILL_EXECUTING {
             $ctx.waker().wake_by_ref();
             Poll::Pending
@@ -39,13 +42,16 @@ macro_rules! check_invalid_handle {
 }
 
 macro_rules! wait_result {
-    (|$this:ident, $err:expr, $ctx:ident| $oci_call:expr) => {{
+    (|$this:ident, $err:expr, $ctx:ident| $oci_fn:ident ( $($oci_args:tt)* )) => {{
         let id = $this as *mut Self as usize;
         if !$this.ctx.lock(id) {
             $ctx.waker().wake_by_ref();
             return Poll::Pending;
         }
-        let res = unsafe { $oci_call };
+        let res = {
+            trace_oci_call!($oci_fn);
+            unsafe { $oci_fn( $($oci_args)* ) }
+        };
         let res = check_invalid_handle!($err, res);
         if res == OCI_STILL_EXECUTING {
             $ctx.waker().wake_by_ref();
@@ -62,13 +68,16 @@ macro_rules! wait_result {
 }
 
 macro_rules! wait_oci_result {
-    (|$this:ident, $err:expr, $ctx:ident| $oci_call:expr) => {{
+    (|$this:ident, $err:expr, $ctx:ident| $oci_fn:ident ( $($oci_args:tt)* )) => {{
         let id = $this as *mut Self as usize;
         if !$this.ctx.lock(id) {
             $ctx.waker().wake_by_ref();
             return Poll::Pending;
         }
-        let res = unsafe { $oci_call };
+        let res = {
+            trace_oci_call!($oci_fn);
+            unsafe { $oci_fn( $($oci_args)* ) }
+        };
         let res = check_invalid_handle!($err, res);
         if res == OCI_STILL_EXECUTING {
             $ctx.waker().wake_by_ref();
@@ -85,13 +94,16 @@ macro_rules! wait_oci_result {
 }
 
 macro_rules! wait_val {
-    (|$this:ident, $err:expr, $field:expr, $ctx:ident| $oci_call:expr) => {{
+    (|$this:ident, $err:expr, $field:expr, $ctx:ident| $oci_fn:ident ( $($oci_args:tt)* )) => {{
         let id = $this as *mut Self as usize;
         if !$this.ctx.lock(id) {
             $ctx.waker().wake_by_ref();
             return Poll::Pending;
         }
-        let res = unsafe { $oci_call };
+        let res = {
+            trace_oci_call!($oci_fn);
+            unsafe { $oci_fn( $($oci_args)* ) }
+        };
         let res = check_invalid_handle!($err, res);
         if res == OCI_STILL_EXECUTING {
             $ctx.waker().wake_by_ref();
@@ -109,13 +121,16 @@ macro_rules! wait_val {
 }
 
 macro_rules! wait_bool_flag {
-    (|$this:ident, $err:expr, $field:expr, $ctx:ident| $oci_call:expr) => {{
+    (|$this:ident, $err:expr, $field:expr, $ctx:ident| $oci_fn:ident ( $($oci_args:tt)* )) => {{
         let id = $this as *mut Self as usize;
         if !$this.ctx.lock(id) {
             $ctx.waker().wake_by_ref();
             return Poll::Pending;
         }
-        let rc = unsafe { $oci_call };
+        let rc = {
+            trace_oci_call!($oci_fn);
+            unsafe { $oci_fn( $($oci_args)* ) }
+        };
         let res = check_invalid_handle!($err, rc);
         if res == OCI_STILL_EXECUTING {
             $ctx.waker().wake_by_ref();
@@ -136,6 +151,7 @@ pub static NUM_ACTIVE_ASYNC_DROPS : AtomicI32 = AtomicI32::new(0);
 
 enum SessionReleaseSteps {
     TransRollback,
+    PackageReset,
     SessionRelease,
 }
 
@@ -144,13 +160,18 @@ pub(crate) struct SessionRelease {
     err: Handle<OCIError>,
     env: Arc<Handle<OCIEnv>>,
     spool: Option<Arc<SPool>>,
+    tag: String,
+    mode: u32,
+    reset: bool,
+    /// Statement that resets the package state
+    stmt: Ptr<OCIStmt>,
     step: SessionReleaseSteps,
 }
 
 impl SessionRelease {
-    pub(crate) fn new(svc: Ptr<OCISvcCtx>, err: Handle<OCIError>, env: Arc<Handle<OCIEnv>>, spool: Option<Arc<SPool>>) -> Self {
+    pub(crate) fn new(svc: Ptr<OCISvcCtx>, err: Handle<OCIError>, env: Arc<Handle<OCIEnv>>, spool: Option<Arc<SPool>>, tag: String, mode: u32, reset: bool) -> Self {
         NUM_ACTIVE_ASYNC_DROPS.fetch_add(1, Ordering::Relaxed);
-        Self { svc, err, env, spool, step: SessionReleaseSteps::TransRollback }
+        Self { svc, err, env, spool, tag, mode, reset, stmt: Ptr::null(), step: SessionReleaseSteps::TransRollback }
     }
 }
 
@@ -168,8 +189,32 @@ impl Future for SessionRelease {
         let svc: &OCISvcCtx = &this.svc;
         let err: &OCIError  = &this.err;
         let res = match this.step {
-            SessionReleaseSteps::TransRollback  => unsafe { OCITransRollback(svc, err, OCI_DEFAULT) },
-            SessionReleaseSteps::SessionRelease => unsafe { OCISessionRelease(svc, err, std::ptr::null(), 0, OCI_DEFAULT) },
+            SessionReleaseSteps::TransRollback  => {
+                trace_oci_call!(OCITransRollback);
+                unsafe { OCITransRollback(svc, err, OCI_DEFAULT) }
+            },
+            SessionReleaseSteps::PackageReset => {
+                if this.stmt.is_null() {
+                    let sql = crate::session::RESET_PACKAGE_STATE;
+                    trace_oci_call!(OCIStmtPrepare2);
+                    let res = unsafe {
+                        OCIStmtPrepare2(svc, this.stmt.as_mut_ptr(), err, sql.as_ptr(), sql.len() as _, std::ptr::null(), 0, OCI_NTV_SYNTAX, OCI_DEFAULT)
+                    };
+                    if res < 0 {
+                        this.mode |= OCI_SESSRLS_DROPSESS;
+                        this.step = SessionReleaseSteps::SessionRelease;
+                        cx.waker().wake_by_ref();
+                        return Poll::Pending;
+                    }
+                }
+                trace_oci_call!(OCIStmtExecute);
+                unsafe { OCIStmtExecute(svc, this.stmt.get(), err, 1, 0, std::ptr::null(), std::ptr::null(), OCI_DEFAULT) }
+            },
+            SessionReleaseSteps::SessionRelease => {
+                trace_oci_call!(OCISessionRelease);
+                let tag_ptr = if this.tag.is_empty() { std::ptr::null() } else { this.tag.as_ptr() };
+                unsafe { OCISessionRelease(svc, err, tag_ptr, this.tag.len() as _, this.mode) }
+            },
         };
         if res == OCI_STILL_EXECUTING {
             cx.waker().wake_by_ref();
@@ -177,6 +222,16 @@ impl Future for SessionRelease {
         }
         match this.step {
             SessionReleaseSteps::TransRollback => {
+                this.step = if this.reset { SessionReleaseSteps::PackageReset } else { SessionReleaseSteps::SessionRelease };
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            },
+            SessionReleaseSteps::PackageReset => {
+                oci_stmt_release(&this.stmt, err);
+                if res < 0 {
+                    // A session that could not be reset is not returned into the pool
+                    this.mode |= OCI_SESSRLS_DROPSESS;
+                }
                 this.step = SessionReleaseSteps::SessionRelease;
                 cx.waker().wake_by_ref();
                 Poll::Pending
@@ -345,6 +400,16 @@ impl Future for TransRollback {
     }
 }
 
+/// Makes a step of the rollback of a transaction that was abandoned by a dropped future.
+/// Returns `true` when the rollback is complete.
+pub(crate) fn rollback_step(svc: &OCISvcCtx, err: &OCIError) -> bool {
+    let res = {
+        trace_oci_call!(OCITransRollback);
+        unsafe { OCITransRollback(svc, err, OCI_DEFAULT) }
+    };
+    check_invalid_handle!(err, res) != OCI_STILL_EXECUTING
+}
+
 
 pub(crate) struct StmtPrepare<'a> {
     ctx: Arc<SvcCtx>,
@@ -376,17 +441,42 @@ impl<'a> Future for StmtPrepare<'a> {
 }
 
 
+/// ORA-01013: user requested cancel of current operation
+const USER_REQUESTED_CANCEL : i32 = 1013;
+
+/// Resets the interrupted asynchronous operation after the call was cancelled by `OCIBreak`
+fn reset_if_cancelled(res: Poll<Result<i32>>, svc: &OCISvcCtx, err: &OCIError) -> Poll<Result<i32>> {
+    if let Poll::Ready(Err(Error::Oracle(USER_REQUESTED_CANCEL, _))) = &res {
+        super::reset(svc, err);
+    }
+    res
+}
+
 pub(crate) struct StmtExecute<'a> {
     ctx: Arc<SvcCtx>,
     err:  &'a OCIError,
     stmt: &'a OCIStmt,
     iter: u32,
+    mode: u32,
+    snap_in:  Ptr<OCISnapshot>,
+    snap_out: Ptr<OCISnapshot>,
 }
 
 impl<'a> StmtExecute<'a> {
     pub(crate) fn new(ctx: Arc<SvcCtx>, err: &'a OCIError, stmt: &'a OCIStmt, typ: u16) -> Self {
         let iter: u32 = if typ == OCI_STMT_SELECT { 0 } else { 1 };
-        Self { ctx, err, stmt, iter}
+        Self { ctx, err, stmt, iter, mode: OCI_DEFAULT, snap_in: Ptr::null(), snap_out: Ptr::null() }
+    }
+
+    pub(crate) fn batch(ctx: Arc<SvcCtx>, err: &'a OCIError, stmt: &'a OCIStmt, iter: u32, mode: u32) -> Self {
+        Self { ctx, err, stmt, iter, mode, snap_in: Ptr::null(), snap_out: Ptr::null() }
+    }
+
+    /// Sets snapshots that the execution uses and captures.
+    pub(crate) fn with_snapshots(mut self, snap_in: *const OCISnapshot, snap_out: *const OCISnapshot) -> Self {
+        self.snap_in  = Ptr::new(snap_in);
+        self.snap_out = Ptr::new(snap_out);
+        self
     }
 }
 
@@ -396,9 +486,10 @@ impl<'a> Future for StmtExecute<'a> {
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.get_mut();
         let svc: Ptr<OCISvcCtx> = Ptr::from(this.ctx.as_ref().as_ref());
-        wait_oci_result!(|this, this.err, cx|
-            OCIStmtExecute(svc.get(), this.stmt, this.err, this.iter, 0, std::ptr::null(), std::ptr::null(), OCI_DEFAULT)
-        )
+        let res = wait_oci_result!(|this, this.err, cx|
+            OCIStmtExecute(svc.get(), this.stmt, this.err, this.iter, 0, this.snap_in.get(), this.snap_out.get(), this.mode)
+        );
+        reset_if_cancelled(res, this.ctx.as_ref().as_ref(), this.err)
     }
 }
 
@@ -407,11 +498,17 @@ pub(crate) struct StmtFetch<'a> {
     ctx:  Arc<SvcCtx>,
     stmt: &'a OCIStmt,
     err:  &'a OCIError,
+    rows: u32,
 }
 
 impl<'a> StmtFetch<'a> {
     pub(crate) fn new(ctx: Arc<SvcCtx>, stmt: &'a OCIStmt, err: &'a OCIError) -> Self {
-        Self { ctx, stmt, err }
+        Self { ctx, stmt, err, rows: 1 }
+    }
+
+    /// Creates an array fetch of the next `rows` rows
+    pub(crate) fn rows(ctx: Arc<SvcCtx>, stmt: &'a OCIStmt, err: &'a OCIError, rows: u32) -> Self {
+        Self { ctx, stmt, err, rows }
     }
 }
 
@@ -420,9 +517,10 @@ impl<'a> Future for StmtFetch<'a> {
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.get_mut();
-        wait_oci_result!(|this, this.err, cx|
-            OCIStmtFetch2(this.stmt, this.err, 1, OCI_FETCH_NEXT, 0, OCI_DEFAULT)
-        )
+        let res = wait_oci_result!(|this, this.err, cx|
+            OCIStmtFetch2(this.stmt, this.err, this.rows, OCI_FETCH_NEXT, 0, OCI_DEFAULT)
+        );
+        reset_if_cancelled(res, this.ctx.as_ref().as_ref(), this.err)
     }
 }
 
@@ -498,30 +596,6 @@ impl<'a> Future for LobIsOpen<'a> {
 }
 
 
-pub(crate) struct LobIsTemporary<'a> {
-    ctx:  Arc<SvcCtx>,
-    lob:  &'a OCILobLocator,
-    flag: u8,
-}
-
-impl<'a> LobIsTemporary<'a> {
-    pub(crate) fn new(ctx: Arc<SvcCtx>, lob: &'a OCILobLocator) -> Self {
-        Self { ctx, lob, flag: 0 }
-    }
-}
-
-impl<'a> Future for LobIsTemporary<'a> {
-    type Output = Result<bool>;
-
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let this = self.get_mut();
-        let svc: Ptr<OCISvcCtx> = Ptr::from(this.ctx.as_ref().as_ref());
-        let err: Ptr<OCIError>  = Ptr::from(this.ctx.as_ref().as_ref());
-        wait_bool_flag!(|this, &err, this.flag, cx| OCILobIsTemporary(svc.get(), err.get(), this.lob, &mut this.flag))
-    }
-}
-
-
 pub(crate) struct LobClose<'a> {
     ctx:  Arc<SvcCtx>,
     lob:  &'a OCILobLocator,
@@ -1062,6 +1136,29 @@ impl<'a> Future for LobTrim<'a> {
 }
 
 
+pub(crate) struct LobFlushBuffer<'a> {
+    ctx: Arc<SvcCtx>,
+    lob: &'a OCILobLocator,
+}
+
+impl<'a> LobFlushBuffer<'a> {
+    pub(crate) fn new(ctx: Arc<SvcCtx>, lob: &'a OCILobLocator) -> Self {
+        Self { ctx, lob }
+    }
+}
+
+impl<'a> Future for LobFlushBuffer<'a> {
+    type Output = Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let svc: Ptr<OCISvcCtx> = Ptr::from(this.ctx.as_ref().as_ref());
+        let err: Ptr<OCIError>  = Ptr::from(this.ctx.as_ref().as_ref());
+        wait_result!(|this, &err, cx| OCILobFlushBuffer(svc.get(), err.get(), this.lob, OCI_LOB_BUFFER_FREE))
+    }
+}
+
+
 pub(crate) struct LobCreateTemporary<'a> {
     ctx: Arc<SvcCtx>,
     lob: &'a OCILobLocator,
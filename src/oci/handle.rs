@@ -31,6 +31,7 @@ unsafe impl<T: HandleType> Sync for Handle<T> {}
 impl<T: HandleType> Drop for Handle<T> {
     fn drop(&mut self) {
         if !self.0.is_null() {
+            trace_oci_call!(OCIHandleFree, htype = T::get_type());
             unsafe {
                 OCIHandleFree(self.0.get() as _, T::get_type());
             }
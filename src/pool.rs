@@ -2,7 +2,7 @@
 
 pub(crate) mod session;
 
-pub use session::{SessionPool, SessionPoolGetMode};
+pub use session::{SessionPool, SessionPoolGetMode, Purity};
 
 #[cfg(feature="blocking")]
 #[cfg_attr(docsrs, doc(cfg(feature="blocking")))]
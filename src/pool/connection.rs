@@ -2,7 +2,7 @@
 
 use std::{ptr, sync::Arc, marker::PhantomData};
 
-use crate::{Error, Result, oci::{self, *}, Environment, Session};
+use crate::{Error, Result, oci::{self, *}, Environment, Session, env::ActiveHandles};
 
 /**
 A shared pool of physical connections.
@@ -20,6 +20,7 @@ pub struct ConnectionPool<'a> {
     pool: Handle<OCICPool>,
     err:  Handle<OCIError>,
     env:  Arc<Handle<OCIEnv>>,
+    handles: Arc<ActiveHandles>,
     name: &'a [u8],
     phantom_env: PhantomData<&'a Environment>,
 }
@@ -50,7 +51,7 @@ impl<'a> ConnectionPool<'a> {
         let name = unsafe {
             std::slice::from_raw_parts(pool_name_ptr, pool_name_len as usize)
         };
-        Ok(Self {env: env.get_env(), err, pool, name, phantom_env: PhantomData})
+        Ok(Self {env: env.get_env(), handles: env.get_handles(), err, pool, name, phantom_env: PhantomData})
     }
 
     pub(crate) fn get_svc_ctx(&self, auth_info: &OCIAuthInfo) -> Result<Ptr<OCISvcCtx>> {
@@ -58,7 +59,7 @@ impl<'a> ConnectionPool<'a> {
         let mut found = oci::Aligned::new(0u8);
         oci::session_get(
             self.env.as_ref(), &self.err, svc.as_mut_ptr(), &auth_info,
-            self.name.as_ptr(), self.name.len() as u32, found.as_mut_ptr(),
+            self.name.as_ptr(), self.name.len() as u32, "", found.as_mut_ptr(),
             OCI_SESSGET_CPOOL | OCI_SESSGET_STMTCACHE
         )?;
         Ok(svc)
@@ -68,6 +69,14 @@ impl<'a> ConnectionPool<'a> {
         self.env.clone()
     }
 
+    pub(crate) fn get_handles(&self) -> Arc<ActiveHandles> {
+        self.handles.clone()
+    }
+
+    pub(crate) fn get_name(&self) -> &[u8] {
+        self.name
+    }
+
     /**
         Returns a new session that will be using a virtual connection from this pool.
 
@@ -8,9 +8,21 @@ mod blocking;
 #[cfg_attr(docsrs, doc(cfg(feature="nonblocking")))]
 mod nonblocking;
 
-use std::{sync::Arc, marker::PhantomData};
+use std::{sync::{Arc, atomic::{AtomicBool, Ordering}}, marker::PhantomData};
+use parking_lot::RwLock;
 
-use crate::{Error, Result, oci::*, Environment};
+use crate::{Error, Result, oci::*, Environment, Session, env::ActiveHandles};
+
+/// Tag of the pooled sessions that were initialized by the session pool initialization callback.
+const SESSION_INIT_TAG : &str = "SIBYL_SESSION_INIT=Y";
+
+/// Session initialization callback
+#[cfg(feature="blocking")]
+type SessionInit = Box<dyn Fn(&Session) -> Result<()> + Send + Sync>;
+
+/// Session initialization callback
+#[cfg(feature="nonblocking")]
+type SessionInit = Box<dyn for<'s, 'p> Fn(&'s Session<'p>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<()>> + Send + 's>> + Send + Sync>;
 
 /**
 Internal (Arc protected) details of a session pool.
@@ -24,6 +36,12 @@ pub(crate) struct SPool {
     info: Handle<OCIAuthInfo>,
     err:  Handle<OCIError>,
     env:  Arc<Handle<OCIEnv>>,
+    handles: Arc<ActiveHandles>,
+    /// Whether the package state of sessions is reset when they are released into the pool
+    reset_on_release: AtomicBool,
+    /// Session initialization callback. It is kept here, rather than in the `SessionPool`,
+    /// so sessions that are cloned via `Session::try_clone` are initialized too.
+    init: RwLock<Option<Arc<SessionInit>>>,
 }
 
 impl Drop for SPool {
@@ -34,13 +52,28 @@ impl Drop for SPool {
 }
 
 #[cfg(feature="nonblocking")]
+impl SPool {
+    pub(crate) fn get_name(&self) -> &[u8] {
+        &self.name
+    }
+}
+
 impl SPool {
     pub(crate) fn get_env(&self) -> Arc<Handle<OCIEnv>> {
         self.env.clone()
     }
 
-    pub(crate) fn get_name(&self) -> &[u8] {
-        &self.name
+    pub(crate) fn get_handles(&self) -> Arc<ActiveHandles> {
+        self.handles.clone()
+    }
+
+    pub(crate) fn resets_on_release(&self) -> bool {
+        self.reset_on_release.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn open_count(&self) -> Result<usize> {
+        let count : u32 = self.pool.get_attr(OCI_ATTR_SPOOL_OPEN_COUNT, &self.err)?;
+        Ok(count as usize)
     }
 }
 
@@ -79,14 +112,101 @@ pub enum SessionPoolGetMode {
     TimedWait,
 }
 
-impl SessionPool<'_> {
-    pub(crate) fn get_spool(&self) -> Arc<SPool> {
-        self.inner.clone()
+/**
+Purity of the server-side session that is requested from a session pool that uses
+Database Resident Connection Pooling (DRCP).
+
+Purity has no effect on pools that connect to dedicated or shared servers.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Purity {
+    /**
+    The application needs a brand new server-side session that carries no state
+    left by its previous users. Corresponds to `OCI_SESSGET_PURITY_NEW`.
+    */
+    New,
+    /**
+    The application can reuse a pooled server-side session with whatever state it might have.
+    Corresponds to `OCI_SESSGET_PURITY_SELF`. This is the purity of the sessions returned by
+    [`SessionPool::get_session()`].
+    */
+    Reuse,
+}
+
+impl Purity {
+    /// Returns the `OCISessionGet` mode that requests a pooled session with this purity.
+    pub(crate) fn session_get_mode(self) -> u32 {
+        match self {
+            Purity::New   => OCI_SESSGET_SPOOL | OCI_SESSGET_PURITY_NEW,
+            Purity::Reuse => OCI_SESSGET_SPOOL | OCI_SESSGET_PURITY_SELF,
+        }
     }
+}
 
-    #[cfg(feature="blocking")]
-    pub(crate) fn get_env(&self) -> Arc<Handle<OCIEnv>> {
-        self.inner.env.clone()
+impl SessionPool<'_> {
+    /**
+    Makes the pool reset the state of sessions when they are released into it.
+
+    When enabled, the state of all PL/SQL packages that a session instantiated - package variables,
+    open package cursors, etc. - is discarded via `DBMS_SESSION.RESET_PACKAGE` before the session
+    is returned to the pool. Thus the next user of the session does not see the package state that
+    was left by the previous one. Note that uncommitted changes are always rolled back on release
+    whether or not this option is enabled.
+
+    Sessions that could not be reset, for example because their connection is broken, are dropped
+    from the pool instead of being returned into it.
+
+    Note that the reset also discards package state that was established by the session initialization
+    callback (see `with_session_init`). The callback should therefore only set up state that is not kept
+    in packages, for example, NLS parameters.
+
+    # Parameters
+
+    * `reset` - Whether to reset the session package state when a session is released into the pool
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let oracle = sibyl::env()?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 1)?
+        .reset_on_release(true);
+
+    let session = pool.get_session()?;
+    // ...
+    drop(session);
+    // The session's package state has been reset before it was returned into the pool
+    # assert_eq!(pool.busy_count()?, 0);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # use once_cell::sync::OnceCell;
+    # static ORACLE: OnceCell<sibyl::Environment> = OnceCell::new();
+    # let oracle = ORACLE.get_or_try_init(|| sibyl::Environment::new())?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    # let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 1).await?
+    #     .reset_on_release(true);
+    # let session = pool.get_session().await?;
+    # drop(session);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn reset_on_release(self, reset: bool) -> Self {
+        self.inner.reset_on_release.store(reset, Ordering::Relaxed);
+        self
     }
 
     /**
@@ -173,7 +293,12 @@ impl SessionPool<'_> {
     ```
     */
     pub fn open_count(&self) -> Result<usize> {
-        let count : u32 = self.inner.pool.get_attr(OCI_ATTR_SPOOL_OPEN_COUNT, &self.inner.err)?;
+        self.inner.open_count()
+    }
+
+    /// Returns the minimum number of sessions the pool was configured to maintain.
+    fn min_count(&self) -> Result<usize> {
+        let count : u32 = self.inner.pool.get_attr(OCI_ATTR_SPOOL_MIN, &self.inner.err)?;
         Ok(count as usize)
     }
 
@@ -1,8 +1,9 @@
 //! Session pool blocking mode implementation
 
-use super::{SessionPool, SPool};
-use crate::{Result, oci::{self, *}, Environment, Session};
-use std::{ptr, marker::PhantomData, sync::Arc};
+use super::{SessionPool, SPool, Purity, SESSION_INIT_TAG};
+use crate::{Error, Result, oci::{self, *}, Environment, Session};
+use std::{ptr, marker::PhantomData, sync::{Arc, atomic::AtomicBool}};
+use parking_lot::RwLock;
 
 impl SPool {
     pub(crate) fn new(env: &Environment, dbname: &str, username: &str, password: &str, min: usize, inc: usize, max: usize) -> Result<Self> {
@@ -26,7 +27,75 @@ impl SPool {
         )?;
         let name = unsafe { std::slice::from_raw_parts(pool_name_ptr, pool_name_len as usize) };
         let name = name.to_vec();
-        Ok(Self {env: env.get_env(), err, info, pool, name})
+        Ok(Self {env: env.get_env(), handles: env.get_handles(), err, info, pool, name, reset_on_release: AtomicBool::new(false), init: RwLock::new(None)})
+    }
+
+    pub(crate) fn get_svc_ctx(&self, auth_info: &OCIAuthInfo, tag: &str, mode: u32) -> Result<(Ptr<OCISvcCtx>, bool)> {
+        let mut svc = Ptr::<OCISvcCtx>::null();
+        let mut found = oci::Aligned::new(0u8);
+        oci::session_get(
+            &self.env, &self.err, svc.as_mut_ptr(), &auth_info,
+            self.name.as_ptr(), self.name.len() as u32, tag, found.as_mut_ptr(),
+            mode
+        )?;
+        Ok((svc, u8::from(found) != 0))
+    }
+
+    /**
+    Checks out a session from the pool. The session is pinged, and sessions that fail the ping with
+    an error for which `discard` returns `true` - for example, because their server session was killed
+    and thus their connection was lost - are dropped from the pool and replaced. Attempts are limited
+    by the number of open sessions in the pool. When they are exhausted the last ping error is returned.
+    */
+    fn checkout<'a>(self: &Arc<Self>, tag: &str, purity: Purity, discard: fn(&Error) -> bool) -> Result<Session<'a>> {
+        let max_attempts = self.open_count()? + 1;
+        let mut attempt = 1;
+        loop {
+            let session = Session::from_session_pool(self.clone(), tag, purity)?;
+            match session.ping() {
+                Err(err) if discard(&err) => {
+                    session.drop_on_release();
+                    if attempt >= max_attempts {
+                        return Err(err);
+                    }
+                    attempt += 1;
+                },
+                res => return res.map(|_| session),
+            }
+        }
+    }
+
+    /// Returns a session with the requested purity. If the pool has a session initialization callback,
+    /// the session is initialized unless it has been initialized already.
+    pub(crate) fn get_session<'a>(self: &Arc<Self>, purity: Purity) -> Result<Session<'a>> {
+        self.get_checked_session(purity, Error::is_connection_lost)
+    }
+
+    /// Returns a session with the requested purity. Sessions that fail the ping with an error for
+    /// which `discard` returns `true` are replaced (see `checkout`).
+    pub(crate) fn get_checked_session<'a>(self: &Arc<Self>, purity: Purity, discard: fn(&Error) -> bool) -> Result<Session<'a>> {
+        if self.init.read().is_none() {
+            return self.checkout("", purity, discard);
+        }
+        let session = self.get_checked_tagged_session(SESSION_INIT_TAG, purity, discard)?;
+        session.retag_on_release(SESSION_INIT_TAG);
+        Ok(session)
+    }
+
+    /// Returns a session with the requested purity and tag, and initializes it if it was not tagged yet.
+    pub(crate) fn get_tagged_session<'a>(self: &Arc<Self>, tag: &str, purity: Purity) -> Result<Session<'a>> {
+        self.get_checked_tagged_session(tag, purity, Error::is_connection_lost)
+    }
+
+    fn get_checked_tagged_session<'a>(self: &Arc<Self>, tag: &str, purity: Purity, discard: fn(&Error) -> bool) -> Result<Session<'a>> {
+        let session = self.checkout(tag, purity, discard)?;
+        let init = self.init.read().clone();
+        if let Some(init) = init {
+            if !session.is_tag_match() {
+                init(&session)?;
+            }
+        }
+        Ok(session)
     }
 }
 
@@ -34,23 +103,67 @@ impl<'a> SessionPool<'a> {
     pub(crate) fn new(env: &'a Environment, dbname: &str, username: &str, password: &str, min: usize, inc: usize, max: usize) -> Result<Self> {
         let inner = SPool::new(env, dbname, username, password, min, inc, max)?;
         let inner = Arc::new(inner);
-        Ok(Self { inner, phantom_env: PhantomData })
+        let pool = Self { inner, phantom_env: PhantomData };
+        if let Some(size) = env.default_stmt_cache_size() {
+            pool.set_statement_cache_size(size)?;
+        }
+        Ok(pool)
     }
 
-    pub(crate) fn get_svc_ctx(&self, auth_info: &OCIAuthInfo) -> Result<Ptr<OCISvcCtx>> {
-        let mut svc = Ptr::<OCISvcCtx>::null();
-        let mut found = oci::Aligned::new(0u8);
-        oci::session_get(
-            &self.inner.env, &self.inner.err, svc.as_mut_ptr(), &auth_info,
-            self.inner.name.as_ptr(), self.inner.name.len() as u32, found.as_mut_ptr(),
-            OCI_SESSGET_SPOOL | OCI_SESSGET_PURITY_SELF
-        )?;
-        Ok(svc)
+    /**
+    Sets the callback that initializes new sessions of this pool.
+
+    The callback is executed only when the pool creates a new session (or returns one that has
+    not been initialized yet). Sessions that are reused by the pool retain the state that was
+    established by the callback, and thus the callback is not run when they are checked out again.
+
+    Note that to keep track of initialized sessions the pool tags them. See [`Session::release_with_tag()`].
+    Retagging a session by the application makes the pool re-initialize it when it is checked out
+    by [`SessionPool::get_session()`].
+
+    # Parameters
+
+    * `init` - Callback that executes the session setup, for example, sets NLS parameters
+      or application context.
+
+    # Example
+
+    ```
+    # use sibyl::Result;
+    # fn main() -> Result<()> {
+    # let oracle = sibyl::env()?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 4)?
+        .with_session_init(|session| {
+            session.set_nls_date_format("YYYY-MM-DD")
+        });
+
+    let session = pool.get_session()?;
+    let stmt = session.prepare("SELECT To_Char(To_Date('2022-12-15')) FROM dual")?;
+    let row = stmt.query_single(())?.unwrap();
+    let date: &str = row.get(0)?;
+    assert_eq!(date, "2022-12-15");
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn with_session_init<F>(self, init: F) -> Self
+    where F: Fn(&Session) -> Result<()> + Send + Sync + 'static
+    {
+        *self.inner.init.write() = Some(Arc::new(Box::new(init)));
+        self
     }
 
     /**
         Returns a new session with a new underlyng connection from this pool.
 
+        The session is pinged before it is returned. A pooled session that has lost its connection
+        to the server (see [`Error::is_connection_lost()`](crate::Error::is_connection_lost)) - for
+        example, because the server session was killed - is dropped from the pool and replaced by
+        another one.
+
         # Example
 
         ```
@@ -112,6 +225,158 @@ impl<'a> SessionPool<'a> {
         ```
     */
     pub fn get_session(&self) -> Result<Session> {
-        Session::from_session_pool(self)
+        self.get_session_with_purity(Purity::Reuse)
+    }
+
+    /**
+    Returns a session with the requested purity from this pool.
+
+    Purity is only meaningful for pools that connect via Database Resident Connection Pooling (DRCP).
+    [`Purity::New`] requests a brand new server-side session that does not carry any state left by
+    its previous users. [`Purity::Reuse`] allows DRCP to return a pooled server-side session, which
+    is what [`SessionPool::get_session()`] does.
+
+    # Parameters
+
+    * `purity` - Purity of the requested server-side session
+
+    # Example
+
+    ```
+    use sibyl::Purity;
+
+    # use sibyl::Result;
+    # fn main() -> Result<()> {
+    # let oracle = sibyl::env()?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 2)?;
+
+    let session = pool.get_session_with_purity(Purity::New)?;
+    session.ping()?;
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn get_session_with_purity(&self, purity: Purity) -> Result<Session<'_>> {
+        self.inner.get_session(purity)
+    }
+
+    /**
+    Returns a session from this pool that was released into it with the specified tag.
+
+    If the pool does not have an available session with the requested tag, a new or an untagged
+    session is returned. Use [`Session::is_tag_match()`] to find out which of these happened and
+    whether the session state that the tag represents has to be (re)established. Sessions are
+    tagged by [`Session::release_with_tag()`].
+
+    If the pool has a session initialization callback (see [`SessionPool::with_session_init()`]),
+    it is executed when the returned session does not carry the requested tag.
+
+    # Parameters
+
+    * `tag` - Session tag, a string of one or more `name=value` pairs separated by semicolons
+
+    # Example
+
+    ```
+    # use sibyl::Result;
+    # fn main() -> Result<()> {
+    # let oracle = sibyl::env()?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 2)?;
+
+    let session = pool.get_tagged_session("NLS_DATE_FORMAT=YYYY-MM-DD")?;
+    assert!(!session.is_tag_match());
+    session.set_nls_date_format("YYYY-MM-DD")?;
+    session.release_with_tag("NLS_DATE_FORMAT=YYYY-MM-DD")?;
+
+    let session = pool.get_tagged_session("NLS_DATE_FORMAT=YYYY-MM-DD")?;
+    assert!(session.is_tag_match());
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn get_tagged_session(&self, tag: &str) -> Result<Session<'_>> {
+        self.inner.get_tagged_session(tag, Purity::Reuse)
+    }
+
+    /**
+    Returns a session from this pool that is confirmed to be alive.
+
+    Each session is pinged before it is returned. A session that fails the ping - for example,
+    because the database was restarted or the session was killed - is discarded, i.e. dropped
+    from the pool rather than returned into it, and another session is requested. This is
+    repeated until a live session is found or until the number of attempts exceeds the number
+    of open sessions in the pool. In the latter case the last ping error is returned.
+
+    [`SessionPool::get_session()`] pings the session as well, but it only replaces sessions that have
+    lost their connection to the server, i.e. those that fail the ping with errors for which
+    [`Error::is_connection_lost()`](crate::Error::is_connection_lost) returns `true`. Other ping errors
+    are returned by it as is.
+
+    # Example
+
+    ```
+    # use sibyl::Result;
+    # fn main() -> Result<()> {
+    # let oracle = sibyl::env()?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 1, 1, 4)?;
+
+    let session = pool.get_session_validated()?;
+    // The session is alive...
+    session.ping()?;
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn get_session_validated(&self) -> Result<Session<'_>> {
+        self.inner.get_checked_session(Purity::Reuse, |_| true)
+    }
+
+    /**
+    Opens sessions until the number of open sessions in the pool reaches the configured minimum.
+
+    The pool might have fewer open sessions than the minimum, for example, when some sessions have been
+    terminated after they reached their lifetime or were dropped from the pool. Warming up the pool ensures
+    that the following requests for sessions do not have to wait while new sessions are created.
+
+    Sessions are opened by checking them out from the pool - thus, if the pool has a session initialization
+    callback (see [`SessionPool::with_session_init()`]), new sessions are also initialized - and then
+    all of them are returned to the pool.
+
+    # Example
+
+    ```
+    # use sibyl::Result;
+    # fn main() -> Result<()> {
+    # let oracle = sibyl::env()?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 2, 1, 4)?;
+
+    pool.warmup()?;
+
+    assert!(pool.open_count()? >= 2);
+    assert_eq!(pool.busy_count()?, 0);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn warmup(&self) -> Result<()> {
+        let min = self.min_count()?;
+        let mut sessions = Vec::with_capacity(min);
+        while self.open_count()? < min {
+            let session = self.get_session()?;
+            sessions.push(session);
+        }
+        Ok(())
     }
 }
@@ -1,8 +1,9 @@
 //! Session pool nonblocking mode implementation
 
-use super::{SessionPool, SPool};
-use crate::{Session, Result, oci::{self, *}, Environment, task};
-use std::{ptr, slice, str, marker::PhantomData, sync::Arc};
+use super::{SessionPool, SPool, Purity, SESSION_INIT_TAG};
+use crate::{Session, Error, Result, oci::{self, *}, Environment, task};
+use std::{ptr, slice, str, marker::PhantomData, sync::{Arc, atomic::AtomicBool}, pin::Pin, future::Future};
+use parking_lot::RwLock;
 
 impl SPool {
     pub(crate) async fn new(env: &Environment, dblink: &str, username: &str, password: &str, min: usize, inc: usize, max: usize) -> Result<Self> {
@@ -12,7 +13,7 @@ impl SPool {
         info.set_attr(OCI_ATTR_DRIVER_NAME, "sibyl", &err)?;
         pool.set_attr(OCI_ATTR_SPOOL_AUTH, info.get_ptr(), &err)?;
 
-        let mut spool = Self { pool, info, err, env: env.get_env(), name: Vec::new() };
+        let mut spool = Self { pool, info, err, env: env.get_env(), handles: env.get_handles(), name: Vec::new(), reset_on_release: AtomicBool::new(false), init: RwLock::new(None) };
         let dblink = String::from(dblink);
         let username = String::from(username);
         let password = String::from(password);
@@ -36,18 +37,133 @@ impl SPool {
             Ok(spool)
         }).await?
     }
+
+    /**
+    Checks out a session from the pool. The session is pinged, and sessions that fail the ping with
+    an error for which `discard` returns `true` - for example, because their server session was killed
+    and thus their connection was lost - are dropped from the pool and replaced. Attempts are limited
+    by the number of open sessions in the pool. When they are exhausted the last ping error is returned.
+    */
+    async fn checkout<'a>(self: &Arc<Self>, tag: &str, purity: Purity, discard: fn(&Error) -> bool) -> Result<Session<'a>> {
+        let max_attempts = self.open_count()? + 1;
+        let mut attempt = 1;
+        loop {
+            let session = Session::from_session_pool(self.clone(), tag, purity).await?;
+            match session.ping().await {
+                Err(err) if discard(&err) => {
+                    session.drop_on_release();
+                    if attempt >= max_attempts {
+                        return Err(err);
+                    }
+                    attempt += 1;
+                },
+                res => return res.map(|_| session),
+            }
+        }
+    }
+
+    /// Returns a session with the requested purity. If the pool has a session initialization callback,
+    /// the session is initialized unless it has been initialized already.
+    pub(crate) async fn get_session<'a>(self: &Arc<Self>, purity: Purity) -> Result<Session<'a>> {
+        self.get_checked_session(purity, Error::is_connection_lost).await
+    }
+
+    /// Returns a session with the requested purity. Sessions that fail the ping with an error for
+    /// which `discard` returns `true` are replaced (see `checkout`).
+    pub(crate) async fn get_checked_session<'a>(self: &Arc<Self>, purity: Purity, discard: fn(&Error) -> bool) -> Result<Session<'a>> {
+        if self.init.read().is_none() {
+            return self.checkout("", purity, discard).await;
+        }
+        let session = self.get_checked_tagged_session(SESSION_INIT_TAG, purity, discard).await?;
+        session.retag_on_release(SESSION_INIT_TAG);
+        Ok(session)
+    }
+
+    /// Returns a session with the requested purity and tag, and initializes it if it was not tagged yet.
+    pub(crate) async fn get_tagged_session<'a>(self: &Arc<Self>, tag: &str, purity: Purity) -> Result<Session<'a>> {
+        self.get_checked_tagged_session(tag, purity, Error::is_connection_lost).await
+    }
+
+    async fn get_checked_tagged_session<'a>(self: &Arc<Self>, tag: &str, purity: Purity, discard: fn(&Error) -> bool) -> Result<Session<'a>> {
+        let session = self.checkout(tag, purity, discard).await?;
+        let init = self.init.read().clone();
+        if let Some(init) = init {
+            if !session.is_tag_match() {
+                init(&session).await?;
+            }
+        }
+        Ok(session)
+    }
 }
 
 impl<'a> SessionPool<'a> {
     pub(crate) async fn new(env: &'a Environment, dblink: &str, username: &str, password: &str, min: usize, inc: usize, max: usize) -> Result<SessionPool<'a>> {
         let inner = SPool::new(env, dblink, username, password, min, inc, max).await?;
         let inner = Arc::new(inner);
-        Ok(Self { inner, phantom_env: PhantomData })
+        let pool = Self { inner, phantom_env: PhantomData };
+        if let Some(size) = env.default_stmt_cache_size() {
+            pool.set_statement_cache_size(size)?;
+        }
+        Ok(pool)
+    }
+
+    /**
+    Sets the callback that initializes new sessions of this pool.
+
+    The callback is executed only when the pool creates a new session (or returns one that has
+    not been initialized yet). Sessions that are reused by the pool retain the state that was
+    established by the callback, and thus the callback is not run when they are checked out again.
+
+    Note that to keep track of initialized sessions the pool tags them. See [`Session::release_with_tag()`].
+    Retagging a session by the application makes the pool re-initialize it when it is checked out
+    by [`SessionPool::get_session()`].
+
+    # Parameters
+
+    * `init` - Callback that returns a (pinned and boxed) future that executes the session setup,
+      for example, sets NLS parameters or application context.
+
+    # Example
+
+    ```
+    # use sibyl::Result;
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # use once_cell::sync::OnceCell;
+    # static ORACLE: OnceCell<sibyl::Environment> = OnceCell::new();
+    # let oracle = ORACLE.get_or_try_init(|| sibyl::Environment::new())?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 4).await?
+        .with_session_init(|session| Box::pin(async move {
+            session.set_nls_date_format("YYYY-MM-DD").await
+        }));
+
+    let session = pool.get_session().await?;
+    let stmt = session.prepare("SELECT To_Char(To_Date('2022-12-15')) FROM dual").await?;
+    let row = stmt.query_single(()).await?.unwrap();
+    let date: &str = row.get(0)?;
+    assert_eq!(date, "2022-12-15");
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn with_session_init<F>(self, init: F) -> Self
+    where F: for<'s, 'p> Fn(&'s Session<'p>) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 's>> + Send + Sync + 'static
+    {
+        *self.inner.init.write() = Some(Arc::new(Box::new(init)));
+        self
     }
 
     /**
         Returns a new session with a new underlyng connection from this pool.
 
+        The session is pinged before it is returned. A pooled session that has lost its connection
+        to the server (see [`Error::is_connection_lost()`](crate::Error::is_connection_lost)) - for
+        example, because the server session was killed - is dropped from the pool and replaced by
+        another one.
+
         # Example
 
         ```
@@ -115,7 +231,170 @@ impl<'a> SessionPool<'a> {
 
     */
     pub async fn get_session(&self) -> Result<Session<'_>> {
-        Session::from_session_pool(self).await
+        self.get_session_with_purity(Purity::Reuse).await
+    }
+
+    /**
+    Returns a session with the requested purity from this pool.
+
+    Purity is only meaningful for pools that connect via Database Resident Connection Pooling (DRCP).
+    [`Purity::New`] requests a brand new server-side session that does not carry any state left by
+    its previous users. [`Purity::Reuse`] allows DRCP to return a pooled server-side session, which
+    is what [`SessionPool::get_session()`] does.
+
+    # Parameters
+
+    * `purity` - Purity of the requested server-side session
+
+    # Example
+
+    ```
+    use sibyl::Purity;
+
+    # use sibyl::Result;
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # use once_cell::sync::OnceCell;
+    # static ORACLE: OnceCell<sibyl::Environment> = OnceCell::new();
+    # let oracle = ORACLE.get_or_try_init(|| sibyl::Environment::new())?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 2).await?;
+
+    let session = pool.get_session_with_purity(Purity::New).await?;
+    session.ping().await?;
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub async fn get_session_with_purity(&self, purity: Purity) -> Result<Session<'_>> {
+        self.inner.get_session(purity).await
+    }
+
+    /**
+    Returns a session from this pool that was released into it with the specified tag.
+
+    If the pool does not have an available session with the requested tag, a new or an untagged
+    session is returned. Use [`Session::is_tag_match()`] to find out which of these happened and
+    whether the session state that the tag represents has to be (re)established. Sessions are
+    tagged by [`Session::release_with_tag()`].
+
+    If the pool has a session initialization callback (see [`SessionPool::with_session_init()`]),
+    it is executed when the returned session does not carry the requested tag.
+
+    # Parameters
+
+    * `tag` - Session tag, a string of one or more `name=value` pairs separated by semicolons
+
+    # Example
+
+    ```
+    # use sibyl::Result;
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # use once_cell::sync::OnceCell;
+    # static ORACLE: OnceCell<sibyl::Environment> = OnceCell::new();
+    # let oracle = ORACLE.get_or_try_init(|| sibyl::Environment::new())?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 2).await?;
+
+    let session = pool.get_tagged_session("NLS_DATE_FORMAT=YYYY-MM-DD").await?;
+    if !session.is_tag_match() {
+        session.set_nls_date_format("YYYY-MM-DD").await?;
+    }
+    // ...
+    session.release_with_tag("NLS_DATE_FORMAT=YYYY-MM-DD")?;
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub async fn get_tagged_session(&self, tag: &str) -> Result<Session<'_>> {
+        self.inner.get_tagged_session(tag, Purity::Reuse).await
+    }
+
+    /**
+    Returns a session from this pool that is confirmed to be alive.
+
+    Each session is pinged before it is returned. A session that fails the ping - for example,
+    because the database was restarted or the session was killed - is discarded, i.e. dropped
+    from the pool rather than returned into it, and another session is requested. This is
+    repeated until a live session is found or until the number of attempts exceeds the number
+    of open sessions in the pool. In the latter case the last ping error is returned.
+
+    [`SessionPool::get_session()`] pings the session as well, but it only replaces sessions that have
+    lost their connection to the server, i.e. those that fail the ping with errors for which
+    [`Error::is_connection_lost()`](crate::Error::is_connection_lost) returns `true`. Other ping errors
+    are returned by it as is.
+
+    # Example
+
+    ```
+    # use sibyl::Result;
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # use once_cell::sync::OnceCell;
+    # static ORACLE: OnceCell<sibyl::Environment> = OnceCell::new();
+    # let oracle = ORACLE.get_or_try_init(|| sibyl::Environment::new())?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 1, 1, 4).await?;
+
+    let session = pool.get_session_validated().await?;
+    // The session is alive...
+    session.ping().await?;
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub async fn get_session_validated(&self) -> Result<Session<'_>> {
+        self.inner.get_checked_session(Purity::Reuse, |_| true).await
+    }
+
+    /**
+    Opens sessions until the number of open sessions in the pool reaches the configured minimum.
+
+    The pool might have fewer open sessions than the minimum, for example, when some sessions have been
+    terminated after they reached their lifetime or were dropped from the pool. Warming up the pool ensures
+    that the following requests for sessions do not have to wait while new sessions are created.
+
+    Sessions are opened by checking them out from the pool - thus, if the pool has a session initialization
+    callback (see [`SessionPool::with_session_init()`]), new sessions are also initialized - and then
+    all of them are returned to the pool.
+
+    # Example
+
+    ```
+    # use sibyl::Result;
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # use once_cell::sync::OnceCell;
+    # static ORACLE: OnceCell<sibyl::Environment> = OnceCell::new();
+    # let oracle = ORACLE.get_or_try_init(|| sibyl::Environment::new())?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 2, 1, 4).await?;
+
+    pool.warmup().await?;
+
+    assert!(pool.open_count()? >= 2);
+    assert_eq!(pool.busy_count()?, 0);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub async fn warmup(&self) -> Result<()> {
+        let min = self.min_count()?;
+        let mut sessions = Vec::with_capacity(min);
+        while self.open_count()? < min {
+            let session = self.get_session().await?;
+            sessions.push(session);
+        }
+        Ok(())
     }
 }
 
@@ -146,10 +425,10 @@ mod tests {
                 let pool = pool.clone();
                 let handle = spawn(async move {
                     let session = pool.get_session().await?;
-                    session.start_call_time_measurements()?;
+                    session.enable_call_timing()?;
                     session.ping().await?;
                     let dt = session.call_time()?;
-                    session.stop_call_time_measurements()?;
+                    session.disable_call_timing()?;
                     Ok::<_,Error>(dt)
                 });
                 workers.push(handle);
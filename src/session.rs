@@ -8,23 +8,49 @@ mod blocking;
 #[cfg_attr(docsrs, doc(cfg(feature="nonblocking")))]
 mod nonblocking;
 
-use std::{sync::Arc, marker::PhantomData};
-use crate::{Result, Environment, oci::*, types::Ctx};
-use crate::pool::session::SPool;
+mod cache;
+
+pub use cache::StatementCache;
+
+use std::{sync::{Arc, atomic::{AtomicBool, AtomicU32, Ordering}}, marker::PhantomData, collections::HashMap, ffi::c_void};
+use crate::{Error, Result, Environment, oci::*, types::Ctx, env::{ActiveHandle, HandleKind}};
+use parking_lot::{Mutex, RwLock};
+use crate::pool::{session::SPool, Purity};
 #[cfg(feature="nonblocking")]
 use crate::task;
 
+/// PL/SQL block that discards the package state of a pooled session before it is released
+pub(crate) const RESET_PACKAGE_STATE : &str = "BEGIN DBMS_SESSION.RESET_PACKAGE; END;";
+
 /// Representation of the service context.
 /// It will be behinfd `Arc` as it needs to survive the `Session`
 /// drop to allow statements and cursors to be dropped asynchronously.
 pub(crate) struct SvcCtx {
     svc: Ptr<OCISvcCtx>,
-    inf: Handle<OCIAuthInfo>,
+    inf: Arc<Handle<OCIAuthInfo>>,
     err: Handle<OCIError>,
     spool: Option<Arc<SPool>>,
     env: Arc<Handle<OCIEnv>>,
+    /// Mode for `OCISessionRelease`
+    release_mode: AtomicU32,
+    /// Tag for `OCISessionRelease`
+    release_tag: Mutex<String>,
+    /// Whether the pool returned a session with the requested tag
+    found: bool,
+    /// Database link or connection pool name that was used to get the session
+    dblink: Vec<u8>,
+    /// Mode for `OCISessionGet` that was used to get the session
+    get_mode: u32,
+    /// Keeps the session counted in its environment
+    handle: ActiveHandle,
     #[cfg(feature="nonblocking")]
     active_future: std::sync::atomic::AtomicUsize,
+    /// Generation of the keepalive task. The task stops when it changes.
+    #[cfg(feature="nonblocking")]
+    keepalive: std::sync::atomic::AtomicUsize,
+    /// Whether an abandoned transaction has to be rolled back before the next call
+    #[cfg(feature="nonblocking")]
+    rollback_pending: AtomicBool,
 }
 
 #[cfg(not(docsrs))]
@@ -33,10 +59,23 @@ impl Drop for SvcCtx {
     fn drop(&mut self) {
         let _ = &self.inf;
         let _ = &self.spool;
+        if self.svc.is_null() {
+            // the session has been closed explicitly
+            return;
+        }
         let svc : &OCISvcCtx = self.as_ref();
         let err : &OCIError  = self.as_ref();
         oci_trans_rollback(svc, err);
-        oci_session_release(svc, err);
+        if self.resets_on_release() && self.reset_package_state().is_err() {
+            self.drop_on_release();
+        }
+        #[cfg_attr(not(feature="tracing"), allow(unused_variables))]
+        let res = oci_session_release(svc, err, self.release_tag.lock().as_str(), self.release_mode.load(Ordering::Relaxed));
+        // `Drop` cannot return the error. Report it instead.
+        #[cfg(feature="tracing")]
+        if res < 0 {
+            tracing::warn!(error = %Error::oci(err, res), "session release failed");
+        }
     }
 
     #[cfg(feature="nonblocking")]
@@ -46,7 +85,39 @@ impl Drop for SvcCtx {
         svc.swap(&mut self.svc);
         let err = Handle::take(&mut self.err);
         let env = self.env.clone();
-        task::spawn_detached(futures::SessionRelease::new(svc, err, env, self.spool.clone()));
+        let tag = std::mem::take(self.release_tag.get_mut());
+        let mode = self.release_mode.load(Ordering::Relaxed);
+        let reset = self.resets_on_release();
+        task::spawn_detached(futures::SessionRelease::new(svc, err, env, self.spool.clone(), tag, mode, reset));
+    }
+}
+
+impl SvcCtx {
+    /// Counts a new statement or LOB locator that was created in this session's environment.
+    pub(crate) fn track(&self, kind: HandleKind) -> ActiveHandle {
+        self.handle.handles().track(kind)
+    }
+
+    /// Returns the purity with which this pooled session was requested.
+    fn purity(&self) -> Purity {
+        if self.get_mode & OCI_SESSGET_PURITY_NEW == OCI_SESSGET_PURITY_NEW { Purity::New } else { Purity::Reuse }
+    }
+
+    /// Makes the pool drop the session when it is released instead of keeping it for reuse.
+    pub(crate) fn drop_on_release(&self) {
+        self.release_mode.fetch_or(OCI_SESSRLS_DROPSESS, Ordering::Relaxed);
+    }
+
+    /// Whether the package state of this pooled session has to be reset before the session is released.
+    fn resets_on_release(&self) -> bool {
+        self.release_mode.load(Ordering::Relaxed) & OCI_SESSRLS_DROPSESS == 0
+            && self.spool.as_ref().is_some_and(|spool| spool.resets_on_release())
+    }
+
+    /// Makes the pool tag the session when it is released.
+    fn retag_on_release(&self, tag: &str) {
+        *self.release_tag.lock() = tag.to_string();
+        self.release_mode.fetch_or(OCI_SESSRLS_RETAG, Ordering::Relaxed);
     }
 }
 
@@ -72,6 +143,10 @@ impl AsRef<OCISvcCtx> for SvcCtx {
 pub struct Session<'a> {
     usr: Ptr<OCISession>,
     ctx: Arc<SvcCtx>,
+    /// NLS parameters set via `set_nls`
+    nls: RwLock<HashMap<String,String>>,
+    /// Whether statements fetch NUMBER columns as text
+    preserve_numbers: AtomicBool,
     phantom_env:  PhantomData<&'a Environment>
 }
 
@@ -112,6 +187,147 @@ impl Session<'_> {
         self.ctx.clone()
     }
 
+    /// Makes the session pool drop this session when it is released.
+    pub(crate) fn drop_on_release(&self) {
+        self.ctx.drop_on_release()
+    }
+
+    /// Makes the session pool tag this session when it is released.
+    pub(crate) fn retag_on_release(&self, tag: &str) {
+        self.ctx.retag_on_release(tag)
+    }
+
+    /// Returns the ALTER SESSION statement that sets the specified NLS parameter.
+    fn alter_nls_sql(param: &str, value: &str) -> Result<String> {
+        if param.is_empty() || !param.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(Error::msg(format!("Invalid NLS parameter name {}", param)));
+        }
+        Ok(format!("ALTER SESSION SET {} = '{}'", param, value.replace('\'', "''")))
+    }
+
+    /// Returns the ALTER SESSION statement that switches the session to the specified edition.
+    fn alter_edition_sql(edition: &str) -> Result<String> {
+        if edition.is_empty() || !edition.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$' || c == '#') {
+            return Err(Error::msg(format!("Invalid edition name {}", edition)));
+        }
+        Ok(format!("ALTER SESSION SET EDITION = {}", edition))
+    }
+
+    /// Caches the value of the NLS parameter that was set in this session.
+    fn cache_nls(&self, param: &str, value: &str) {
+        self.nls.write().insert(param.to_uppercase(), value.to_string());
+    }
+
+    /**
+    Returns the value of the NLS parameter as it was set by `set_nls` (or one of its typed
+    variants) in this session. Returns `None` if the parameter was not set via this session.
+
+    # Parameters
+
+    * `param` - NLS parameter name, for example `NLS_DATE_FORMAT`
+    */
+    pub fn nls(&self, param: &str) -> Option<String> {
+        self.nls.read().get(param.to_uppercase().as_str()).cloned()
+    }
+
+    /**
+    Escapes LIKE wildcards - `%` and `_` - and the escape character itself (`\`) in the text, so
+    it can be used to build LIKE patterns that match it literally. The SQL must declare the escape
+    character via `ESCAPE '\'` clause.
+
+    See also [`Like`](crate::Like), which builds and binds such patterns.
+
+    # Example
+
+    ```
+    use sibyl::Session;
+
+    let pattern = format!("{}%", Session::escape_like("SA_"));
+    assert_eq!(pattern, "SA\\_%");
+    ```
+    */
+    pub fn escape_like(text: &str) -> String {
+        crate::like::escape(text)
+    }
+
+    /**
+    Reports whether this session was retrieved from a session pool by [`SessionPool::get_tagged_session()`]
+    and the pool found a session that was released with the requested tag.
+
+    When this returns `false`, the returned session is either a new one or an untagged one, and
+    thus the session state that the tag represents must be (re)established.
+
+    [`SessionPool::get_tagged_session()`]: crate::SessionPool::get_tagged_session
+    */
+    pub fn is_tag_match(&self) -> bool {
+        self.ctx.found
+    }
+
+    /**
+    Releases this session back into the session pool it was retrieved from and tags it with
+    the specified tag. The tag can later be used by [`SessionPool::get_tagged_session()`]
+    to retrieve a session that has the session state the tag represents.
+
+    The tag is a string of one or more `name=value` pairs separated by semicolons, for example,
+    `NLS_DATE_FORMAT=YYYY-MM-DD;TIME_ZONE=UTC`.
+
+    # Parameters
+
+    * `tag` - Session tag
+
+    # Failures
+
+    * The session was not retrieved from a session pool.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let oracle = sibyl::env()?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 2)?;
+
+    let session = pool.get_tagged_session("NLS_DATE_FORMAT=YYYY-MM-DD")?;
+    if !session.is_tag_match() {
+        session.set_nls_date_format("YYYY-MM-DD")?;
+    }
+    // ...
+    session.release_with_tag("NLS_DATE_FORMAT=YYYY-MM-DD")?;
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # use once_cell::sync::OnceCell;
+    # static ORACLE: OnceCell<sibyl::Environment> = OnceCell::new();
+    # let oracle = ORACLE.get_or_try_init(|| sibyl::Environment::new())?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    # let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 2).await?;
+    # let session = pool.get_tagged_session("NLS_DATE_FORMAT=YYYY-MM-DD").await?;
+    # if !session.is_tag_match() {
+    #     session.set_nls_date_format("YYYY-MM-DD").await?;
+    # }
+    # session.release_with_tag("NLS_DATE_FORMAT=YYYY-MM-DD")?;
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn release_with_tag(self, tag: &str) -> Result<()> {
+        if self.ctx.spool.is_none() {
+            return Err(Error::new("Only sessions retrieved from a session pool can be tagged"));
+        }
+        self.retag_on_release(tag);
+        Ok(())
+    }
 
 
     /// Reports whether self is connected to the server
@@ -128,6 +344,82 @@ impl Session<'_> {
         Ok(mode != 0)
     }
 
+    /**
+    Calls the provided closure with raw pointers to the OCI service context, error and environment
+    handles of this session. This is an escape hatch for applications that need to call OCI functions
+    that are not wrapped by this crate.
+
+    # Parameters
+
+    * `f` - Closure that receives the `OCISvcCtx`, `OCIError` and `OCIEnv` handles (in that order)
+
+    # Returns
+
+    The value returned by the closure.
+
+    # Safety
+
+    The handles are owned by the session. The closure must not free them, must not keep them past
+    its return, and must not change their attributes in a way that invalidates the session state
+    that this crate maintains, for example, switch the server between blocking and nonblocking modes.
+    Calls that use the service context must not run concurrently with other calls in this session.
+
+    Note that in `nonblocking` mode the connection is in the OCI nonblocking mode. OCI calls that
+    make a round trip to the server might return `OCI_STILL_EXECUTING`, which the caller has to
+    handle by repeating the call until it completes.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    use std::ffi::c_void;
+
+    extern "C" {
+        fn OCIPing(svchp: *mut c_void, errhp: *mut c_void, mode: u32) -> i32;
+    }
+
+    # #[cfg(feature="blocking")]
+    # fn main() -> sibyl::Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let res = unsafe {
+        session.with_oci_handles(|svc, err, _env| OCIPing(svc, err, 0))
+    };
+    assert_eq!(res, 0);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> sibyl::Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let res = unsafe {
+    #     session.with_oci_handles(|svc, err, _env| {
+    #         loop {
+    #             let res = OCIPing(svc, err, 0);
+    #             // OCI_STILL_EXECUTING
+    #             if res != -3123 { break res; }
+    #         }
+    #     })
+    # };
+    # assert_eq!(res, 0);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub unsafe fn with_oci_handles<F, R>(&self, f: F) -> R
+    where F: FnOnce(*mut c_void, *mut c_void, *mut c_void) -> R
+    {
+        let svc: &OCISvcCtx = self.as_ref();
+        let err: &OCIError = self.as_ref();
+        let env: &OCIEnv = self.as_ref();
+        f(
+            svc as *const OCISvcCtx as *mut c_void,
+            err as *const OCIError as *mut c_void,
+            env as *const OCIEnv as *mut c_void
+        )
+    }
+
     /**
     Sets the statement cache size.
 
@@ -193,6 +485,56 @@ impl Session<'_> {
         attr::get(OCI_ATTR_STMTCACHESIZE, OCI_HTYPE_SVCCTX, ctx, self.as_ref())
     }
 
+    /**
+    Creates a client-side cache of prepared statements for this session.
+
+    Unlike the OCI statement cache, which still requires a statement to be prepared (even though
+    the preparation is cheap when the statement is found in the cache), statements in this cache
+    are shared and re-used without any OCI calls.
+
+    # Parameters
+
+    * `capacity` - maximum number of statements in the cache. Least recently used statements are
+      evicted from the full cache.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let cache = session.prepared_cache(50);
+    for id in [101, 102] {
+        let stmt = cache.prepare("SELECT manager_id FROM hr.employees WHERE employee_id = :id")?;
+        let manager_id : u32 = stmt.query_scalar(id)?;
+        assert_eq!(manager_id, 100);
+    }
+    assert_eq!(cache.len(), 1);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let cache = session.prepared_cache(50);
+    # for id in [101, 102] {
+    #     let stmt = cache.prepare("SELECT manager_id FROM hr.employees WHERE employee_id = :id").await?;
+    #     let manager_id : u32 = stmt.query_scalar(id).await?;
+    #     assert_eq!(manager_id, 100);
+    # }
+    # assert_eq!(cache.len(), 1);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn prepared_cache(&self, capacity: usize) -> StatementCache<'_> {
+        StatementCache::new(self, capacity)
+    }
+
     /**
     Sets the time (in milliseconds) for a database round-trip call to time out. When the call times out,
     a network timeout error is returned. Setting this value stays effective for all subsequent round-trip
@@ -267,12 +609,28 @@ impl Session<'_> {
     }
 
     /**
-    Causes the server to measure call time, in milliseconds, for each subsequent OCI call.
+    Causes the server to measure call time for each subsequent OCI call.
+
+    The measured time of the last call can be read via [`Session::call_time()`] or,
+    for calls made by a statement, via [`Statement::last_call_time()`](crate::Statement::last_call_time).
     */
-    pub fn start_call_time_measurements(&self) -> Result<()> {
+    pub fn enable_call_timing(&self) -> Result<()> {
         self.set_attr(OCI_ATTR_COLLECT_CALL_TIME, 1u32)
     }
 
+    /// Terminates call time measurements.
+    pub fn disable_call_timing(&self) -> Result<()> {
+        self.set_attr(OCI_ATTR_COLLECT_CALL_TIME, 0u32)
+    }
+
+    /**
+    Causes the server to measure call time for each subsequent OCI call.
+    */
+    #[deprecated(note="use `enable_call_timing`")]
+    pub fn start_call_time_measurements(&self) -> Result<()> {
+        self.enable_call_timing()
+    }
+
     /**
     Returns the server-side time for the preceding call in microseconds.
 
@@ -286,10 +644,10 @@ impl Session<'_> {
     # #[cfg(feature="blocking")]
     # fn main() -> Result<()> {
     # let session = sibyl::test_env::get_session()?;
-    session.start_call_time_measurements()?;
+    session.enable_call_timing()?;
     session.ping()?;
     let dt = session.call_time()?;
-    session.stop_call_time_measurements()?;
+    session.disable_call_timing()?;
     assert!(dt > 0);
     # Ok(())
     # }
@@ -297,10 +655,10 @@ impl Session<'_> {
     # fn main() -> Result<()> {
     # sibyl::block_on(async {
     # let session = sibyl::test_env::get_session().await?;
-    # session.start_call_time_measurements()?;
+    # session.enable_call_timing()?;
     # session.ping().await?;
     # let dt = session.call_time()?;
-    # session.stop_call_time_measurements()?;
+    # session.disable_call_timing()?;
     # assert!(dt > 0);
     # Ok(()) })
     # }
@@ -311,8 +669,9 @@ impl Session<'_> {
     }
 
     /// Terminates call time measurements.
+    #[deprecated(note="use `disable_call_timing`")]
     pub fn stop_call_time_measurements(&self) -> Result<()> {
-        self.set_attr(OCI_ATTR_COLLECT_CALL_TIME, 0u32)
+        self.disable_call_timing()
     }
 
     /**
@@ -655,4 +1014,47 @@ impl Session<'_> {
     pub fn lob_prefetch_size(&self) -> Result<u32> {
         self.get_attr(OCI_ATTR_DEFAULT_LOBPREFETCH_SIZE)
     }
+
+    /**
+    Sets the default for [`Statement::preserve_number_precision()`] of the statements
+    that are prepared by this session after this call.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    session.preserve_number_precision(true);
+    let stmt = session.prepare("SELECT 1234567890.1234567890123456789 FROM dual")?;
+    let row = stmt.query_single(())?.unwrap();
+    let num : &str = row.get(0)?;
+    assert_eq!(num, "1234567890.1234567890123456789");
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # session.preserve_number_precision(true);
+    # let stmt = session.prepare("SELECT 1234567890.1234567890123456789 FROM dual").await?;
+    # let row = stmt.query_single(()).await?.unwrap();
+    # let num : &str = row.get(0)?;
+    # assert_eq!(num, "1234567890.1234567890123456789");
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn preserve_number_precision(&self, preserve: bool) {
+        self.preserve_numbers.store(preserve, Ordering::Relaxed);
+    }
+
+    /// Returns whether statements of this session fetch NUMBER columns as text by default.
+    pub(crate) fn preserves_number_precision(&self) -> bool {
+        self.preserve_numbers.load(Ordering::Relaxed)
+    }
 }
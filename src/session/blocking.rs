@@ -1,8 +1,9 @@
 //! Blocking mode database session methods.
 
-use super::{SvcCtx, Session};
-use crate::{Result, Statement, oci::{self, *, attr}, Environment, SessionPool, ConnectionPool};
-use std::{marker::PhantomData, sync::Arc};
+use super::{SvcCtx, Session, SPool, RESET_PACKAGE_STATE};
+use crate::{Error, Result, Statement, Rows, ToSql, oci::{self, *, attr}, Environment, Purity, ConnectionPool, env::HandleKind};
+use std::{marker::PhantomData, sync::{Arc, atomic::{AtomicBool, AtomicU32, Ordering}}, collections::HashMap};
+use parking_lot::{Mutex, RwLock};
 
 impl SvcCtx {
     pub(crate) fn new(env: &Environment, dblink: &str, user: &str, pass: &str) -> Result<Self> {
@@ -15,17 +16,20 @@ impl SvcCtx {
         let mut found = oci::Aligned::new(0u8);
         oci::session_get(
             env.as_ref(), &err, svc.as_mut_ptr(), &inf, dblink.as_ptr(), dblink.len() as u32,
-            found.as_mut_ptr(), OCI_SESSGET_STMTCACHE
+            "", found.as_mut_ptr(), OCI_SESSGET_STMTCACHE
         )?;
-        Ok(SvcCtx { env: env.get_env(), err, inf, svc, spool: None })
+        let handle = env.get_handles().track(HandleKind::Session);
+        Ok(SvcCtx { env: env.get_env(), err, inf: Arc::new(inf), svc, spool: None, release_mode: AtomicU32::new(OCI_DEFAULT), release_tag: Mutex::new(String::new()), found: false, dblink: dblink.as_bytes().to_vec(), get_mode: OCI_SESSGET_STMTCACHE, handle })
     }
 
-    pub(crate) fn from_session_pool(pool: &SessionPool) -> Result<Self> {
-        let env = pool.get_env();
+    pub(crate) fn from_session_pool(spool: Arc<SPool>, tag: &str, purity: Purity) -> Result<Self> {
+        let env = spool.get_env();
         let err = Handle::<OCIError>::new(env.as_ref())?;
         let inf = Handle::<OCIAuthInfo>::new(env.as_ref())?;
-        let svc = pool.get_svc_ctx(&inf)?;
-        Ok(Self { svc, inf, err, env, spool: Some(pool.get_spool()) })
+        let get_mode = purity.session_get_mode();
+        let (svc, found) = spool.get_svc_ctx(&inf, tag, get_mode)?;
+        let handle = spool.get_handles().track(HandleKind::Session);
+        Ok(Self { svc, inf: Arc::new(inf), err, env, spool: Some(spool), release_mode: AtomicU32::new(OCI_DEFAULT), release_tag: Mutex::new(String::new()), found, dblink: Vec::new(), get_mode, handle })
     }
 
     pub(crate) fn from_connection_pool(pool: &ConnectionPool, username: &str, password: &str) -> Result<Self> {
@@ -37,7 +41,53 @@ impl SvcCtx {
         inf.set_attr(OCI_ATTR_PASSWORD, password, &err)?;
 
         let svc = pool.get_svc_ctx(&inf)?;
-        Ok(SvcCtx { env, err, inf, svc, spool: None })
+        let handle = pool.get_handles().track(HandleKind::Session);
+        let dblink = pool.get_name().to_vec();
+        Ok(SvcCtx { env, err, inf: Arc::new(inf), svc, spool: None, release_mode: AtomicU32::new(OCI_DEFAULT), release_tag: Mutex::new(String::new()), found: false, dblink, get_mode: OCI_SESSGET_CPOOL | OCI_SESSGET_STMTCACHE, handle })
+    }
+
+    /// Gets a new session from the same database or connection pool, and with the same credentials, as this one.
+    fn duplicate(&self) -> Result<Self> {
+        let err = Handle::<OCIError>::new(self.env.as_ref())?;
+        let mut svc = Ptr::<OCISvcCtx>::null();
+        let mut found = oci::Aligned::new(0u8);
+        oci::session_get(
+            self.env.as_ref(), &err, svc.as_mut_ptr(), &self.inf,
+            self.dblink.as_ptr(), self.dblink.len() as u32, "", found.as_mut_ptr(),
+            self.get_mode
+        )?;
+        let handle = self.track(HandleKind::Session);
+        Ok(Self {
+            env: self.env.clone(), err, inf: self.inf.clone(), svc, spool: None,
+            release_mode: AtomicU32::new(OCI_DEFAULT), release_tag: Mutex::new(String::new()), found: false,
+            dblink: self.dblink.clone(), get_mode: self.get_mode, handle
+        })
+    }
+
+    /// Rolls back the current transaction and releases the session.
+    /// Discards the state of PL/SQL packages that were instantiated by this session.
+    pub(crate) fn reset_package_state(&self) -> Result<()> {
+        let mut stmt = Ptr::<OCIStmt>::null();
+        oci::stmt_prepare(
+            self.svc.as_ref(), stmt.as_mut_ptr(), self.err.as_ref(),
+            RESET_PACKAGE_STATE.as_ptr(), RESET_PACKAGE_STATE.len() as u32,
+            OCI_NTV_SYNTAX, OCI_DEFAULT
+        )?;
+        let res = oci::stmt_execute(self.svc.as_ref(), &stmt, self.err.as_ref(), 1, 0, std::ptr::null(), std::ptr::null(), OCI_DEFAULT);
+        oci_stmt_release(&stmt, self.err.as_ref());
+        res.map(|_| ())
+    }
+
+    fn close(mut self) -> Result<()> {
+        let res = oci::trans_rollback(self.svc.as_ref(), self.err.as_ref());
+        if self.resets_on_release() && self.reset_package_state().is_err() {
+            self.drop_on_release();
+        }
+        let res = res.and(oci::session_release(self.svc.as_ref(), self.err.as_ref(), self.release_tag.lock().as_str(), self.release_mode.load(Ordering::Relaxed)));
+        // Mark the session as released. `drop` will skip it.
+        let mut svc = Ptr::<OCISvcCtx>::null();
+        svc.swap(&mut self.svc);
+        res
     }
 }
 
@@ -46,34 +96,40 @@ impl<'a> Session<'a> {
         let ctx = SvcCtx::new(env, dblink, user, pass)?;
         let usr : Ptr<OCISession> = attr::get(OCI_ATTR_SESSION, OCI_HTYPE_SVCCTX, ctx.svc.as_ref(), ctx.as_ref())?;
         let ctx = Arc::new(ctx);
-        Ok(Self { ctx, usr, phantom_env: PhantomData })
+        Ok(Self { ctx, usr, nls: RwLock::new(HashMap::new()), preserve_numbers: AtomicBool::new(false), phantom_env: PhantomData })
     }
 
-    pub(crate) fn from_session_pool(pool: &'a SessionPool) -> Result<Self> {
-        let ctx = SvcCtx::from_session_pool(pool)?;
+    pub(crate) fn from_session_pool(spool: Arc<SPool>, tag: &str, purity: Purity) -> Result<Self> {
+        let ctx = SvcCtx::from_session_pool(spool, tag, purity)?;
         let usr: Ptr<OCISession> = attr::get(OCI_ATTR_SESSION, OCI_HTYPE_SVCCTX, ctx.svc.as_ref(), ctx.as_ref())?;
         let ctx = Arc::new(ctx);
-        Ok(Self { ctx, usr, phantom_env: PhantomData })
+        Ok(Self { ctx, usr, nls: RwLock::new(HashMap::new()), preserve_numbers: AtomicBool::new(false), phantom_env: PhantomData })
     }
 
     pub(crate) fn from_connection_pool(pool: &'a ConnectionPool, user: &str, pass: &str) -> Result<Self> {
         let ctx = SvcCtx::from_connection_pool(pool, user, pass)?;
         let usr: Ptr<OCISession> = attr::get(OCI_ATTR_SESSION, OCI_HTYPE_SVCCTX, ctx.svc.as_ref(), ctx.as_ref())?;
         let ctx = Arc::new(ctx);
-        Ok(Self { ctx, usr, phantom_env: PhantomData })
+        Ok(Self { ctx, usr, nls: RwLock::new(HashMap::new()), preserve_numbers: AtomicBool::new(false), phantom_env: PhantomData })
     }
 
     /**
     Confirms that the connection and the server are active.
 
+    In `blocking` mode there is no background keepalive task. Applications that keep sessions
+    idle for long periods, and thus risk having the connection dropped by a firewall or by the
+    database idle timeout, should call `ping` periodically themselves - for example, from the
+    thread that owns the session between units of work. In `nonblocking` mode `Session::enable_keepalive`
+    does this in a background task.
+
     # Example
 
     ```
     # let session = sibyl::test_env::get_session()?;
-    # session.start_call_time_measurements()?;
+    # session.enable_call_timing()?;
     session.ping()?;
     # let dt = session.call_time()?;
-    # session.stop_call_time_measurements()?;
+    # session.disable_call_timing()?;
     # assert!(dt > 0);
     # Ok::<(),sibyl::Error>(())
     ```
@@ -82,6 +138,87 @@ impl<'a> Session<'a> {
         oci::ping(self.as_ref(), self.as_ref())
     }
 
+    /**
+    Returns a new session to the same database, and for the same user, as this session.
+
+    OCI does not allow concurrent calls via the same session. Therefore the returned session
+    is a separate one - it is connected with the same credentials and the same connect string
+    as this session or, if this session was retrieved from a session pool, it is retrieved from
+    the same pool just like [`SessionPool::get_session()`](crate::SessionPool::get_session) does it,
+    i.e. it is initialized by the pool's session initialization callback if the pool has one.
+    NLS parameters that were set via [`Session::set_nls()`] (or one of its typed variants) and the
+    [`Session::preserve_number_precision()`] default are applied to the new session as well.
+    The sessions do not share transactions or any other session state.
+
+    The returned session can be used alongside this one, for example, by another thread.
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    let other = session.try_clone()?;
+    std::thread::scope(|s| {
+        let handle = s.spawn(|| -> sibyl::Result<u32> {
+            let stmt = other.prepare("SELECT 1 FROM dual")?;
+            let row = stmt.query_single(())?.expect("one row");
+            row.get(0)
+        });
+        let stmt = session.prepare("SELECT 2 FROM dual")?;
+        let row = stmt.query_single(())?.expect("one row");
+        let num : u32 = row.get(0)?;
+        assert_eq!(num, 2);
+        let num = handle.join().expect("thread result")?;
+        assert_eq!(num, 1);
+        Ok::<(),sibyl::Error>(())
+    })?;
+    # Ok::<(),sibyl::Error>(())
+    ```
+    */
+    pub fn try_clone(&self) -> Result<Session<'a>> {
+        let session = if let Some(spool) = &self.ctx.spool {
+            // check the session out the same way the pool does it, so it is initialized
+            spool.get_session(self.ctx.purity())?
+        } else {
+            let ctx = self.ctx.duplicate()?;
+            let usr: Ptr<OCISession> = attr::get(OCI_ATTR_SESSION, OCI_HTYPE_SVCCTX, ctx.svc.as_ref(), ctx.as_ref())?;
+            Self { ctx: Arc::new(ctx), usr, nls: RwLock::new(HashMap::new()), preserve_numbers: AtomicBool::new(false), phantom_env: PhantomData }
+        };
+        session.preserve_number_precision(self.preserves_number_precision());
+        let nls = self.nls.read().clone();
+        for (param, value) in nls.iter() {
+            session.set_nls(param, value)?;
+        }
+        Ok(session)
+    }
+
+    /**
+    Closes the session.
+
+    The current transaction is rolled back and the session is released - disconnected or,
+    if the session was retrieved from a pool, returned into it. Unlike the implicit release
+    when the session is dropped, this method reports errors that the release might encounter.
+
+    # Failures
+
+    * Other objects, for example LOBs, that were created by this session are still alive.
+    * OCI reported an error when the session was rolled back or released.
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    session.ping()?;
+    session.close()?;
+    # Ok::<(),sibyl::Error>(())
+    ```
+    */
+    pub fn close(self) -> Result<()> {
+        match Arc::try_unwrap(self.ctx) {
+            Ok(ctx) => ctx.close(),
+            Err(_) => Err(Error::new("Session cannot be closed while objects created by it are still in use")),
+        }
+    }
+
     /**
     Prepares SQL or PL/SQL statement for execution.
 
@@ -112,6 +249,70 @@ impl<'a> Session<'a> {
         Statement::new(sql, self)
     }
 
+    /**
+    Prepares and executes SQL or PL/SQL statement other than SELECT in a single call.
+    Returns the number of rows affected by the statement.
+
+    This is a shortcut for one-shot statements. The statement is prepared internally, thus, when
+    the statement cache is enabled, it is retrieved from the cache if it was executed before.
+
+    # Parameters
+
+    * `sql` - SQL or PL/SQL statement
+    * `args` - statement arguments - a single argument or a tuple of arguments
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    let num_updated_rows = session.execute("
+        UPDATE hr.employees
+           SET salary = salary
+         WHERE department_id = :dept
+    ", 30)?;
+    assert_eq!(num_updated_rows, 6);
+    # session.rollback()?;
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn execute(&self, sql: &str, args: impl ToSql) -> Result<usize> {
+        let stmt = self.prepare(sql)?;
+        stmt.execute(args)
+    }
+
+    /**
+    Prepares and executes SELECT statement in a single call. Returns the result set, which
+    owns the internally prepared statement.
+
+    # Parameters
+
+    * `sql` - SELECT statement
+    * `args` - statement arguments - a single argument or a tuple of arguments
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    let rows = session.query("
+        SELECT last_name
+          FROM hr.employees
+         WHERE manager_id = :id
+         ORDER BY last_name
+    ", 103)?;
+    let mut names = Vec::new();
+    while let Some(row) = rows.next()? {
+        let name : String = row.get(0)?;
+        names.push(name);
+    }
+    assert_eq!(names, ["Austin", "Ernst", "Lorentz", "Pataballa"]);
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn query(&self, sql: &str, args: impl ToSql) -> Result<Rows<'_>> {
+        let stmt = self.prepare(sql)?;
+        stmt.query_owned(args)
+    }
+
     /**
     Commits the current transaction.
 
@@ -164,4 +365,200 @@ impl<'a> Session<'a> {
     pub fn rollback(&self) -> Result<()> {
         oci::trans_rollback(self.as_ref(), self.as_ref())
     }
+
+    /**
+    Executes the closure within a transaction. The transaction is committed if the closure
+    returns `Ok` and rolled back if it returns an error or panics.
+
+    Note that the transaction includes all the changes that were made in this session before
+    the call and have not been committed yet.
+
+    # Parameters
+
+    * `f` - closure that executes the transaction statements
+
+    # Failures
+
+    Returns the error returned by the closure or the error that `commit` has returned.
+
+    # Example
+
+    ```
+    use sibyl::Error;
+
+    # let session = sibyl::test_env::get_session()?;
+    let res : sibyl::Result<()> = session.transaction(|session| {
+        let stmt = session.prepare("
+            UPDATE hr.employees
+               SET salary = salary * 2
+             WHERE employee_id = :emp_id
+        ")?;
+        stmt.execute(107)?;
+        Err(Error::Interface("salary increase was not approved".to_string()))
+    });
+    assert!(res.is_err());
+
+    // the update has been rolled back
+    let stmt = session.prepare("
+        SELECT salary
+          FROM hr.employees
+         WHERE employee_id = :emp_id
+    ")?;
+    let row = stmt.query_single(107)?.unwrap();
+    let salary : u32 = row.get(0)?;
+    assert_eq!(salary, 4200);
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn transaction<T, F>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&Self) -> Result<T>,
+    {
+        let guard = RollbackGuard(self);
+        let res = f(self)?;
+        self.commit()?;
+        std::mem::forget(guard);
+        Ok(res)
+    }
+
+    /**
+    Sets the value of the NLS parameter for this session by executing `ALTER SESSION SET`.
+    The value that was set is cached and can be retrieved later via [`Session::nls()`].
+
+    # Parameters
+
+    * `param` - NLS parameter name, for example `NLS_SORT`
+    * `value` - the new value of the parameter
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    session.set_nls("NLS_SORT", "BINARY_CI")?;
+    assert_eq!(session.nls("nls_sort").as_deref(), Some("BINARY_CI"));
+
+    let stmt = session.prepare("
+        SELECT value FROM nls_session_parameters WHERE parameter = 'NLS_SORT'
+    ")?;
+    let row = stmt.query_single(())?.unwrap();
+    let sort : &str = row.get(0)?;
+    assert_eq!(sort, "BINARY_CI");
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn set_nls(&self, param: &str, value: &str) -> Result<()> {
+        let sql = Self::alter_nls_sql(param, value)?;
+        let stmt = self.prepare(&sql)?;
+        stmt.execute(())?;
+        self.cache_nls(param, value);
+        Ok(())
+    }
+
+    /**
+    Switches this session to the specified edition (Edition-Based Redefinition) by executing
+    `ALTER SESSION SET EDITION`. Editioned objects that the session references after the switch
+    are resolved in the new edition.
+
+    Note that the edition cannot be changed while the session has an active transaction.
+    The user must have the `USE` privilege on the edition.
+
+    # Parameters
+
+    * `edition` - name of the edition, for example `ORA$BASE`
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    session.set_edition("ORA$BASE")?;
+
+    let stmt = session.prepare("SELECT Sys_Context('USERENV', 'CURRENT_EDITION_NAME') FROM dual")?;
+    let row = stmt.query_single(())?.unwrap();
+    let edition : &str = row.get(0)?;
+    assert_eq!(edition, "ORA$BASE");
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn set_edition(&self, edition: &str) -> Result<()> {
+        let sql = Self::alter_edition_sql(edition)?;
+        let stmt = self.prepare(&sql)?;
+        stmt.execute(())?;
+        Ok(())
+    }
+
+    /**
+    Sets the default date format of this session.
+
+    # Parameters
+
+    * `fmt` - date format model, for example `YYYY-MM-DD HH24:MI:SS`
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    session.set_nls_date_format("YYYY-MM-DD\"T\"HH24:MI:SS")?;
+
+    let stmt = session.prepare("
+        SELECT TO_CHAR(SYSDATE), TO_CHAR(SYSDATE, 'YYYY-MM-DD\"T\"HH24:MI:SS') FROM dual
+    ")?;
+    let row = stmt.query_single(())?.unwrap();
+    let default_text : &str = row.get(0)?;
+    let explicit_text : &str = row.get(1)?;
+    assert_eq!(default_text, explicit_text);
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn set_nls_date_format(&self, fmt: &str) -> Result<()> {
+        self.set_nls("NLS_DATE_FORMAT", fmt)
+    }
+
+    /**
+    Sets the default timestamp format of this session.
+
+    # Parameters
+
+    * `fmt` - timestamp format model, for example `YYYY-MM-DD HH24:MI:SS.FF3`
+    */
+    pub fn set_nls_timestamp_format(&self, fmt: &str) -> Result<()> {
+        self.set_nls("NLS_TIMESTAMP_FORMAT", fmt)
+    }
+
+    /**
+    Sets the characters that this session uses as the decimal and group separators.
+
+    # Parameters
+
+    * `decimal` - decimal separator
+    * `group` - group separator
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    session.set_nls_numeric_characters(',', '.')?;
+
+    let stmt = session.prepare("
+        SELECT TO_CHAR(1234.5, '9G999D9') FROM dual
+    ")?;
+    let row = stmt.query_single(())?.unwrap();
+    let text : &str = row.get(0)?;
+    assert_eq!(text.trim(), "1.234,5");
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn set_nls_numeric_characters(&self, decimal: char, group: char) -> Result<()> {
+        self.set_nls("NLS_NUMERIC_CHARACTERS", &format!("{}{}", decimal, group))
+    }
+}
+
+/// Rolls back the transaction unless it has been committed.
+struct RollbackGuard<'s, 'a>(&'s Session<'a>);
+
+impl Drop for RollbackGuard<'_, '_> {
+    fn drop(&mut self) {
+        // `Drop` cannot report the error. The failed rollback is observed by the subsequent
+        // operations in this session.
+        let _ = self.0.rollback();
+    }
 }
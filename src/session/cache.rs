@@ -0,0 +1,139 @@
+//! Client-side cache of prepared statements
+
+#[cfg(feature="blocking")]
+#[cfg_attr(docsrs, doc(cfg(feature="blocking")))]
+mod blocking;
+
+#[cfg(feature="nonblocking")]
+#[cfg_attr(docsrs, doc(cfg(feature="nonblocking")))]
+mod nonblocking;
+
+use std::{sync::Arc, collections::HashMap};
+use parking_lot::Mutex;
+use crate::{Session, Statement};
+
+/// Cached statements and their last use "time"
+struct Entries<'s> {
+    stmts: HashMap<String, (Arc<Statement<'s>>, u64)>,
+    tick:  u64,
+}
+
+/**
+A bounded cache of prepared statements keyed by their SQL text.
+
+Statements that are prepared via the cache are shared. Preparing SQL that is already in the
+cache returns the cached statement without calling OCI. When the cache is full the least recently
+used statement is evicted from it. The evicted statement is released when the last reference
+to it is dropped.
+
+The cache is created by [`Session::prepared_cache()`].
+*/
+pub struct StatementCache<'s> {
+    session:  &'s Session<'s>,
+    capacity: usize,
+    entries:  Mutex<Entries<'s>>,
+}
+
+impl<'s> StatementCache<'s> {
+    pub(crate) fn new(session: &'s Session<'s>, capacity: usize) -> Self {
+        let entries = Entries { stmts: HashMap::with_capacity(capacity), tick: 0 };
+        Self { session, capacity: capacity.max(1), entries: Mutex::new(entries) }
+    }
+
+    /// Returns the maximum number of statements that the cache holds.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of statements in the cache.
+    pub fn len(&self) -> usize {
+        self.entries.lock().stmts.len()
+    }
+
+    /// Returns `true` if the cache has no statements.
+    pub fn is_empty(&self) -> bool {
+        self.entries.lock().stmts.is_empty()
+    }
+
+    /// Removes all statements from the cache.
+    pub fn clear(&self) {
+        self.entries.lock().stmts.clear();
+    }
+
+    /// Returns the cached statement and marks it as the most recently used.
+    fn get(&self, sql: &str) -> Option<Arc<Statement<'s>>> {
+        let mut entries = self.entries.lock();
+        entries.tick += 1;
+        let tick = entries.tick;
+        entries.stmts.get_mut(sql).map(|(stmt, last_used)| {
+            *last_used = tick;
+            stmt.clone()
+        })
+    }
+
+    /// Adds the statement to the cache evicting the least recently used one if the cache is full.
+    /// Returns the cached statement, which might be a different one if the same SQL was added
+    /// concurrently.
+    fn insert(&self, sql: &str, stmt: Statement<'s>) -> Arc<Statement<'s>> {
+        let mut entries = self.entries.lock();
+        entries.tick += 1;
+        let tick = entries.tick;
+        if let Some((cached, last_used)) = entries.stmts.get_mut(sql) {
+            *last_used = tick;
+            return cached.clone();
+        }
+        if entries.stmts.len() >= self.capacity {
+            let lru = entries.stmts.iter().min_by_key(|(_, (_, last_used))| *last_used).map(|(sql, _)| sql.clone());
+            if let Some(lru) = lru {
+                entries.stmts.remove(&lru);
+            }
+        }
+        let stmt = Arc::new(stmt);
+        entries.stmts.insert(sql.to_string(), (stmt.clone(), tick));
+        stmt
+    }
+}
+
+#[cfg(all(test,feature="blocking"))]
+mod tests {
+    use std::sync::Arc;
+    use crate::{Result, oci::OCIStmt};
+
+    #[test]
+    fn prepared_cache() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+        let cache = session.prepared_cache(2);
+        assert!(cache.is_empty());
+
+        let sql = "SELECT last_name FROM hr.employees WHERE employee_id = :ID";
+        let stmt1 = cache.prepare(sql)?;
+        let stmt2 = cache.prepare(sql)?;
+        assert!(Arc::ptr_eq(&stmt1, &stmt2));
+        let oci_stmt1 : &OCIStmt = (*stmt1).as_ref();
+        let oci_stmt2 : &OCIStmt = (*stmt2).as_ref();
+        assert!(std::ptr::eq(oci_stmt1, oci_stmt2));
+        assert_eq!(cache.len(), 1);
+
+        let row = stmt2.query_single(100)?.unwrap();
+        let name : &str = row.get(0)?;
+        assert_eq!(name, "King");
+
+        let other = cache.prepare("SELECT first_name FROM hr.employees WHERE employee_id = :ID")?;
+        // makes `sql` the most recently used
+        cache.prepare(sql)?;
+        cache.prepare("SELECT email FROM hr.employees WHERE employee_id = :ID")?;
+        assert_eq!(cache.len(), 2);
+
+        // `sql` was kept
+        let stmt3 = cache.prepare(sql)?;
+        assert!(Arc::ptr_eq(&stmt1, &stmt3));
+        // while `other` was the least recently used and has been evicted
+        let evicted = cache.prepare("SELECT first_name FROM hr.employees WHERE employee_id = :ID")?;
+        assert!(!Arc::ptr_eq(&other, &evicted));
+        assert_eq!(cache.len(), 2);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        Ok(())
+    }
+}
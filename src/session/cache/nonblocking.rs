@@ -0,0 +1,46 @@
+//! Nonblocking mode statement cache methods
+
+use std::sync::Arc;
+use crate::{Result, Statement};
+use super::StatementCache;
+
+impl<'s> StatementCache<'s> {
+    /**
+    Returns the cached statement prepared from the given SQL. If the statement is not in the
+    cache, then it is prepared and added to the cache.
+
+    # Parameters
+
+    * `sql` - SQL or PL/SQL statement
+
+    # Example
+
+    ```
+    use std::sync::Arc;
+
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let cache = session.prepared_cache(10);
+
+    let stmt = cache.prepare("
+        SELECT last_name FROM hr.employees WHERE employee_id = :id
+    ").await?;
+    let row = stmt.query_single(107).await?.unwrap();
+    let name : &str = row.get(0)?;
+    assert_eq!(name, "Lorentz");
+
+    let same_stmt = cache.prepare("
+        SELECT last_name FROM hr.employees WHERE employee_id = :id
+    ").await?;
+    assert!(Arc::ptr_eq(&stmt, &same_stmt));
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn prepare(&self, sql: &str) -> Result<Arc<Statement<'s>>> {
+        if let Some(stmt) = self.get(sql) {
+            return Ok(stmt);
+        }
+        let stmt = self.session.prepare(sql).await?;
+        Ok(self.insert(sql, stmt))
+    }
+}
@@ -1,10 +1,11 @@
 //! Nonblocking mode database session methods.
 
-use std::{sync::{Arc, atomic::{AtomicUsize, Ordering}}, marker::PhantomData};
+use std::{sync::{Arc, atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering}}, marker::PhantomData, collections::HashMap, future::Future, time::Duration};
+use parking_lot::{Mutex, RwLock};
 
-use crate::{oci::{self, *}, task, Environment, Result, pool::SessionPool, Statement};
+use crate::{oci::{self, *}, task, Environment, Result, pool::Purity, Statement, Rows, ToSql, env::HandleKind};
 
-use super::{SvcCtx, Session};
+use super::{SvcCtx, Session, SPool};
 
 impl SvcCtx {
     async fn new(env: &Environment, dblink: &str, user: &str, pass: &str) -> Result<Self> {
@@ -14,6 +15,7 @@ impl SvcCtx {
         inf.set_attr(OCI_ATTR_USERNAME, user, &err)?;
         inf.set_attr(OCI_ATTR_PASSWORD, pass, &err)?;
 
+        let handles = env.get_handles();
         let env = env.get_env();
         let dblink = String::from(dblink);
         task::execute_blocking(move || -> Result<Self> {
@@ -22,9 +24,10 @@ impl SvcCtx {
             oci::session_get(
                 env.as_ref(), err.as_ref(), svc.as_mut_ptr(), inf.as_ref(),
                 dblink.as_ptr(), dblink.len() as _,
-                found.as_mut_ptr(), OCI_SESSGET_STMTCACHE
+                "", found.as_mut_ptr(), OCI_SESSGET_STMTCACHE
             )?;
-            Ok(Self { svc, inf, err, env, spool: None, active_future: AtomicUsize::new(0) })
+            let dblink = dblink.into_bytes();
+            Ok(Self { svc, inf: Arc::new(inf), err, env, spool: None, release_mode: AtomicU32::new(OCI_DEFAULT), release_tag: Mutex::new(String::new()), found: false, dblink, get_mode: OCI_SESSGET_STMTCACHE, handle: handles.track(HandleKind::Session), active_future: AtomicUsize::new(0), keepalive: AtomicUsize::new(0), rollback_pending: AtomicBool::new(false) })
         }).await?
     }
 
@@ -33,8 +36,9 @@ impl SvcCtx {
         oci::attr_set(srv.as_ref(), OCI_HTYPE_SERVER, std::ptr::null(), 0, OCI_ATTR_NONBLOCKING_MODE, self.err.as_ref())
     }
 
-    async fn from_session_pool(pool: &SessionPool<'_>) -> Result<Self> {
-        let spool = pool.get_spool();
+    async fn from_session_pool(spool: Arc<SPool>, tag: &str, purity: Purity) -> Result<Self> {
+        let get_mode = purity.session_get_mode();
+        let tag = String::from(tag);
         let env = spool.get_env();
         let err = Handle::<OCIError>::new(env.as_ref())?;
         let inf = Handle::<OCIAuthInfo>::new(env.as_ref())?;
@@ -45,24 +49,68 @@ impl SvcCtx {
             let mut found = oci::Aligned::new(0u8);
             oci::session_get(
                 env.as_ref(), err.as_ref(), svc.as_mut_ptr(), inf.as_ref(),
-                name.as_ptr(), name.len() as _, found.as_mut_ptr(),
-                OCI_SESSGET_SPOOL | OCI_SESSGET_PURITY_SELF
+                name.as_ptr(), name.len() as _, tag.as_str(), found.as_mut_ptr(),
+                get_mode
             )?;
-            Ok(Self { svc, inf, err, env, spool: Some(spool), active_future: AtomicUsize::new(0) })
+            let found = u8::from(found) != 0;
+            let handle = spool.get_handles().track(HandleKind::Session);
+            Ok(Self { svc, inf: Arc::new(inf), err, env, spool: Some(spool), release_mode: AtomicU32::new(OCI_DEFAULT), release_tag: Mutex::new(String::new()), found, dblink: Vec::new(), get_mode, handle, active_future: AtomicUsize::new(0), keepalive: AtomicUsize::new(0), rollback_pending: AtomicBool::new(false) })
         }).await?
     }
 
+    /// Gets a new session from the same database, and with the same credentials, as this one.
+    async fn duplicate(&self) -> Result<Self> {
+        let env = self.env.clone();
+        let err = Handle::<OCIError>::new(env.as_ref())?;
+        let inf = self.inf.clone();
+        let dblink = self.dblink.clone();
+        let get_mode = self.get_mode;
+        let handle = self.track(HandleKind::Session);
+        task::execute_blocking(move || -> Result<Self> {
+            let mut svc = Ptr::<OCISvcCtx>::null();
+            let mut found = oci::Aligned::new(0u8);
+            oci::session_get(
+                env.as_ref(), err.as_ref(), svc.as_mut_ptr(), inf.as_ref(),
+                dblink.as_ptr(), dblink.len() as _, "", found.as_mut_ptr(),
+                get_mode
+            )?;
+            Ok(Self { svc, inf, err, env, spool: None, release_mode: AtomicU32::new(OCI_DEFAULT), release_tag: Mutex::new(String::new()), found: false, dblink, get_mode, handle, active_future: AtomicUsize::new(0), keepalive: AtomicUsize::new(0), rollback_pending: AtomicBool::new(false) })
+        }).await?
+    }
+
+    /// Locks the session for the future with the specified `id`. Returns `true` if the future
+    /// can make its call.
+    ///
+    /// If an abandoned transaction is pending rollback, the lock is taken but `false` is returned
+    /// until the rollback completes, so the rollback is always executed before the next call.
     pub(crate) fn lock(&self, id: usize) -> bool {
         if let Err(current) = self.active_future.compare_exchange(0, id, Ordering::AcqRel, Ordering::Relaxed) {
-            current == id
-        } else {
-            true
+            if current != id {
+                return false;
+            }
+        }
+        if self.rollback_pending.load(Ordering::Acquire) {
+            if !futures::rollback_step(self.svc.as_ref(), self.err.as_ref()) {
+                return false;
+            }
+            self.rollback_pending.store(false, Ordering::Release);
         }
+        true
     }
 
     pub(crate) fn unlock(&self) {
         self.active_future.store(0, Ordering::Release)
     }
+
+    /// Returns `true` if a call is in progress on this session.
+    fn is_locked(&self) -> bool {
+        self.active_future.load(Ordering::Acquire) != 0
+    }
+
+    /// Makes the next call on this session roll back the current transaction before it is executed.
+    fn rollback_before_next_call(&self) {
+        self.rollback_pending.store(true, Ordering::Release)
+    }
 }
 
 impl<'a> Session<'a> {
@@ -71,15 +119,15 @@ impl<'a> Session<'a> {
         ctx.set_nonblocking_mode()?;
         let usr: Ptr<OCISession> = attr::get(OCI_ATTR_SESSION, OCI_HTYPE_SVCCTX, ctx.svc.as_ref(), ctx.as_ref())?;
         let ctx = Arc::new(ctx);
-        Ok(Self { ctx, usr, phantom_env: PhantomData })
+        Ok(Self { ctx, usr, nls: RwLock::new(HashMap::new()), preserve_numbers: AtomicBool::new(false), phantom_env: PhantomData })
     }
 
-    pub(crate) async fn from_session_pool(pool: &'a SessionPool<'_>) -> Result<Session<'a>> {
-        let ctx = SvcCtx::from_session_pool(pool).await?;
+    pub(crate) async fn from_session_pool(spool: Arc<SPool>, tag: &str, purity: Purity) -> Result<Session<'a>> {
+        let ctx = SvcCtx::from_session_pool(spool, tag, purity).await?;
         ctx.set_nonblocking_mode()?;
         let usr: Ptr<OCISession> = attr::get(OCI_ATTR_SESSION, OCI_HTYPE_SVCCTX, ctx.svc.as_ref(), ctx.as_ref())?;
         let ctx = Arc::new(ctx);
-        Ok(Self { ctx, usr, phantom_env: PhantomData })
+        Ok(Self { ctx, usr, nls: RwLock::new(HashMap::new()), preserve_numbers: AtomicBool::new(false), phantom_env: PhantomData })
     }
 
     /**
@@ -90,10 +138,10 @@ impl<'a> Session<'a> {
     ```
     # sibyl::block_on(async {
     # let session = sibyl::test_env::get_session().await?;
-    # session.start_call_time_measurements()?;
+    # session.enable_call_timing()?;
     session.ping().await?;
     # let dt = session.call_time()?;
-    # session.stop_call_time_measurements()?;
+    # session.disable_call_timing()?;
     # assert!(dt > 0);
     # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
     ```
@@ -102,6 +150,126 @@ impl<'a> Session<'a> {
         futures::Ping::new(self.get_svc()).await
     }
 
+    /**
+    Returns a new session to the same database, and for the same user, as this session.
+
+    OCI does not allow concurrent calls via the same session. Therefore the returned session
+    is a separate one - it is connected with the same credentials and the same connect string
+    as this session or, if this session was retrieved from a session pool, it is retrieved from
+    the same pool just like [`SessionPool::get_session()`](crate::SessionPool::get_session) does it,
+    i.e. it is initialized by the pool's session initialization callback if the pool has one.
+    NLS parameters that were set via [`Session::set_nls()`] (or one of its typed variants) and the
+    [`Session::preserve_number_precision()`] default are applied to the new session as well.
+    The sessions do not share transactions or any other session state.
+
+    The returned session can be used alongside this one, for example, by another task.
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let other = session.try_clone().await?;
+    let handle = sibyl::spawn(async move {
+        let stmt = other.prepare("SELECT 1 FROM dual").await?;
+        let row = stmt.query_single(()).await?.expect("one row");
+        let num : u32 = row.get(0)?;
+        Ok::<_,sibyl::Error>(num)
+    });
+    let stmt = session.prepare("SELECT 2 FROM dual").await?;
+    let row = stmt.query_single(()).await?.expect("one row");
+    let num : u32 = row.get(0)?;
+    assert_eq!(num, 2);
+    # let num = handle.await;
+    # #[cfg(any(feature="tokio", feature="actix"))]
+    # let num = num.expect("task result");
+    # let num = num?;
+    # assert_eq!(num, 1);
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn try_clone(&self) -> Result<Session<'a>> {
+        let session = if let Some(spool) = &self.ctx.spool {
+            // check the session out the same way the pool does it, so it is initialized
+            spool.get_session(self.ctx.purity()).await?
+        } else {
+            let ctx = self.ctx.duplicate().await?;
+            ctx.set_nonblocking_mode()?;
+            let usr: Ptr<OCISession> = attr::get(OCI_ATTR_SESSION, OCI_HTYPE_SVCCTX, ctx.svc.as_ref(), ctx.as_ref())?;
+            Self { ctx: Arc::new(ctx), usr, nls: RwLock::new(HashMap::new()), preserve_numbers: AtomicBool::new(false), phantom_env: PhantomData }
+        };
+        session.preserve_number_precision(self.preserves_number_precision());
+        let nls = self.nls.read().clone();
+        for (param, value) in nls.iter() {
+            session.set_nls(param, value).await?;
+        }
+        Ok(session)
+    }
+
+    /**
+    Starts a background task that pings the server every `interval` to keep an otherwise
+    idle connection from being dropped by firewalls or by the database idle timeouts.
+
+    The task skips the ping when the session is executing a call. It stops when the session
+    is dropped, when [`Session::disable_keepalive()`] is called, when keepalive is enabled
+    again (with a different interval), or when a ping fails.
+
+    # Parameters
+
+    * `interval` - time between pings
+
+    # Example
+
+    ```
+    use std::time::Duration;
+
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    session.enable_keepalive(Duration::from_secs(300));
+    # session.disable_keepalive();
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub fn enable_keepalive(&self, interval: Duration) {
+        let generation = self.ctx.keepalive.fetch_add(1, Ordering::AcqRel) + 1;
+        let ctx = Arc::downgrade(&self.ctx);
+        task::spawn_detached(async move {
+            loop {
+                task::sleep(interval).await;
+                let ctx = match ctx.upgrade() {
+                    Some(ctx) => ctx,
+                    None => break,
+                };
+                if ctx.keepalive.load(Ordering::Acquire) != generation {
+                    break;
+                }
+                if !ctx.is_locked() && futures::Ping::new(ctx).await.is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    /**
+    Stops the keepalive task started by [`Session::enable_keepalive()`].
+
+    # Example
+
+    ```
+    use std::time::Duration;
+
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    session.enable_keepalive(Duration::from_secs(300));
+    // ...
+    session.disable_keepalive();
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub fn disable_keepalive(&self) {
+        self.ctx.keepalive.fetch_add(1, Ordering::AcqRel);
+    }
+
     /**
     Commits the current transaction.
 
@@ -157,6 +325,77 @@ impl<'a> Session<'a> {
         futures::TransRollback::new(self.get_svc()).await
     }
 
+    /**
+    Executes the async closure within a transaction. The transaction is committed if the future
+    returned by the closure resolves to `Ok` and rolled back if it resolves to an error.
+
+    If the transaction future is dropped before it completes (or the closure panics), the transaction
+    is rolled back by the next call made in this session before that call is executed. This is only
+    possible if the dropped future was not in the middle of a database call. Otherwise the session
+    remains locked by that call and its uncommitted changes are rolled back when the session is released.
+
+    Note that the transaction includes all the changes that were made in this session before
+    the call and have not been committed yet.
+
+    # Parameters
+
+    * `f` - closure that returns a future, which executes the transaction statements
+
+    # Failures
+
+    Returns the error returned by the closure or the error that `commit` has returned.
+
+    # Example
+
+    ```
+    use sibyl::Error;
+
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let res : sibyl::Result<()> = session.transaction(|session| async move {
+        let stmt = session.prepare("
+            UPDATE hr.employees
+               SET salary = salary * 2
+             WHERE employee_id = :emp_id
+        ").await?;
+        stmt.execute(107).await?;
+        Err(Error::Interface("salary increase was not approved".to_string()))
+    }).await;
+    assert!(res.is_err());
+
+    // the update has been rolled back
+    let stmt = session.prepare("
+        SELECT salary
+          FROM hr.employees
+         WHERE employee_id = :emp_id
+    ").await?;
+    let row = stmt.query_single(107).await?.unwrap();
+    let salary : u32 = row.get(0)?;
+    assert_eq!(salary, 4200);
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn transaction<'s, T, F, R>(&'s self, f: F) -> Result<T>
+    where
+        F: FnOnce(&'s Self) -> R,
+        R: Future<Output = Result<T>>,
+    {
+        let guard = RollbackGuard(Some(self.get_svc()));
+        match f(self).await {
+            Ok(res) => {
+                self.commit().await?;
+                guard.disarm();
+                Ok(res)
+            }
+            Err(err) => {
+                // The closure error is more relevant to the caller than the rollback one
+                let _ = self.rollback().await;
+                guard.disarm();
+                Err(err)
+            }
+        }
+    }
+
     /**
     Prepares SQL or PL/SQL statement for execution.
 
@@ -187,6 +426,229 @@ impl<'a> Session<'a> {
     pub async fn prepare(&'a self, sql: &str) -> Result<Statement<'a>> {
         Statement::new(sql, self).await
     }
+
+    /**
+    Prepares and executes SQL or PL/SQL statement other than SELECT in a single call.
+    Returns the number of rows affected by the statement.
+
+    This is a shortcut for one-shot statements. The statement is prepared internally, thus, when
+    the statement cache is enabled, it is retrieved from the cache if it was executed before.
+
+    # Parameters
+
+    * `sql` - SQL or PL/SQL statement
+    * `args` - statement arguments - a single argument or a tuple of arguments
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let num_updated_rows = session.execute("
+        UPDATE hr.employees
+           SET salary = salary
+         WHERE department_id = :dept
+    ", 30).await?;
+    assert_eq!(num_updated_rows, 6);
+    # session.rollback().await?;
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn execute(&'a self, sql: &str, args: impl ToSql) -> Result<usize> {
+        let stmt = self.prepare(sql).await?;
+        stmt.execute(args).await
+    }
+
+    /**
+    Prepares and executes SELECT statement in a single call. Returns the result set, which
+    owns the internally prepared statement.
+
+    # Parameters
+
+    * `sql` - SELECT statement
+    * `args` - statement arguments - a single argument or a tuple of arguments
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let rows = session.query("
+        SELECT last_name
+          FROM hr.employees
+         WHERE manager_id = :id
+         ORDER BY last_name
+    ", 103).await?;
+    let mut names = Vec::new();
+    while let Some(row) = rows.next().await? {
+        let name : String = row.get(0)?;
+        names.push(name);
+    }
+    assert_eq!(names, ["Austin", "Ernst", "Lorentz", "Pataballa"]);
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn query(&'a self, sql: &str, args: impl ToSql) -> Result<Rows<'a>> {
+        let stmt = self.prepare(sql).await?;
+        stmt.query_owned(args).await
+    }
+
+    /**
+    Sets the value of the NLS parameter for this session by executing `ALTER SESSION SET`.
+    The value that was set is cached and can be retrieved later via [`Session::nls()`].
+
+    # Parameters
+
+    * `param` - NLS parameter name, for example `NLS_SORT`
+    * `value` - the new value of the parameter
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    session.set_nls("NLS_SORT", "BINARY_CI").await?;
+    assert_eq!(session.nls("nls_sort").as_deref(), Some("BINARY_CI"));
+
+    let stmt = session.prepare("
+        SELECT value FROM nls_session_parameters WHERE parameter = 'NLS_SORT'
+    ").await?;
+    let row = stmt.query_single(()).await?.unwrap();
+    let sort : &str = row.get(0)?;
+    assert_eq!(sort, "BINARY_CI");
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn set_nls(&self, param: &str, value: &str) -> Result<()> {
+        let sql = Self::alter_nls_sql(param, value)?;
+        // Shorten the borrow of `self` to satisfy `prepare`
+        let session: &Session = self;
+        let stmt = session.prepare(&sql).await?;
+        stmt.execute(()).await?;
+        self.cache_nls(param, value);
+        Ok(())
+    }
+
+    /**
+    Switches this session to the specified edition (Edition-Based Redefinition) by executing
+    `ALTER SESSION SET EDITION`. Editioned objects that the session references after the switch
+    are resolved in the new edition.
+
+    Note that the edition cannot be changed while the session has an active transaction.
+    The user must have the `USE` privilege on the edition.
+
+    # Parameters
+
+    * `edition` - name of the edition, for example `ORA$BASE`
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    session.set_edition("ORA$BASE").await?;
+
+    let stmt = session.prepare("SELECT Sys_Context('USERENV', 'CURRENT_EDITION_NAME') FROM dual").await?;
+    let row = stmt.query_single(()).await?.unwrap();
+    let edition : &str = row.get(0)?;
+    assert_eq!(edition, "ORA$BASE");
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn set_edition(&self, edition: &str) -> Result<()> {
+        let sql = Self::alter_edition_sql(edition)?;
+        // Shorten the borrow of `self` to satisfy `prepare`
+        let session: &Session = self;
+        let stmt = session.prepare(&sql).await?;
+        stmt.execute(()).await?;
+        Ok(())
+    }
+
+    /**
+    Sets the default date format of this session.
+
+    # Parameters
+
+    * `fmt` - date format model, for example `YYYY-MM-DD HH24:MI:SS`
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    session.set_nls_date_format("YYYY-MM-DD\"T\"HH24:MI:SS").await?;
+
+    let stmt = session.prepare("
+        SELECT TO_CHAR(SYSDATE), TO_CHAR(SYSDATE, 'YYYY-MM-DD\"T\"HH24:MI:SS') FROM dual
+    ").await?;
+    let row = stmt.query_single(()).await?.unwrap();
+    let default_text : &str = row.get(0)?;
+    let explicit_text : &str = row.get(1)?;
+    assert_eq!(default_text, explicit_text);
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn set_nls_date_format(&self, fmt: &str) -> Result<()> {
+        self.set_nls("NLS_DATE_FORMAT", fmt).await
+    }
+
+    /**
+    Sets the default timestamp format of this session.
+
+    # Parameters
+
+    * `fmt` - timestamp format model, for example `YYYY-MM-DD HH24:MI:SS.FF3`
+    */
+    pub async fn set_nls_timestamp_format(&self, fmt: &str) -> Result<()> {
+        self.set_nls("NLS_TIMESTAMP_FORMAT", fmt).await
+    }
+
+    /**
+    Sets the characters that this session uses as the decimal and group separators.
+
+    # Parameters
+
+    * `decimal` - decimal separator
+    * `group` - group separator
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    session.set_nls_numeric_characters(',', '.').await?;
+
+    let stmt = session.prepare("
+        SELECT TO_CHAR(1234.5, '9G999D9') FROM dual
+    ").await?;
+    let row = stmt.query_single(()).await?.unwrap();
+    let text : &str = row.get(0)?;
+    assert_eq!(text.trim(), "1.234,5");
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn set_nls_numeric_characters(&self, decimal: char, group: char) -> Result<()> {
+        self.set_nls("NLS_NUMERIC_CHARACTERS", &format!("{}{}", decimal, group)).await
+    }
+}
+
+/// Rolls back the transaction that has not been completed.
+struct RollbackGuard(Option<Arc<SvcCtx>>);
+
+impl RollbackGuard {
+    fn disarm(mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for RollbackGuard {
+    fn drop(&mut self) {
+        if let Some(ctx) = self.0.take() {
+            // The rollback cannot be awaited here. Spawning it would let it run concurrently
+            // with (and roll back) the work that the caller starts next on this session.
+            ctx.rollback_before_next_call();
+        }
+    }
 }
 
 #[cfg(test)]
@@ -229,10 +691,10 @@ mod tests {
             let dbpass = env::var("DBPASS").expect("password");
 
             let session = oracle.connect(&dbname, &dbuser, &dbpass).await?;
-            session.start_call_time_measurements()?;
+            session.enable_call_timing()?;
             session.ping().await?;
             let dt = session.call_time()?;
-            session.stop_call_time_measurements()?;
+            session.disable_call_timing()?;
 
             assert!(dt > 0);
             println!("dt={}", dt);
@@ -259,4 +721,28 @@ mod tests {
             Ok(())
         })
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn flush_async_drops() -> Result<()> {
+        crate::block_on(async {
+            use std::{env, sync::atomic::Ordering};
+            use crate::oci::futures::NUM_ACTIVE_ASYNC_DROPS;
+
+            let oracle = Environment::new()?;
+
+            let dbname = env::var("DBNAME").expect("database name");
+            let dbuser = env::var("DBUSER").expect("user name");
+            let dbpass = env::var("DBPASS").expect("password");
+
+            for _ in 0..10 {
+                let session = oracle.connect(&dbname, &dbuser, &dbpass).await?;
+                let stmt = session.prepare("SELECT * FROM dual").await?;
+                stmt.query_single(()).await?;
+            }
+            crate::flush_async_drops().await;
+            assert_eq!(NUM_ACTIVE_ASYNC_DROPS.load(Ordering::Acquire), 0);
+
+            Ok(())
+        })
+    }
+}
@@ -2,10 +2,19 @@
 
 mod args;
 mod bind;
+mod binds;
 mod cols;
 mod cursor;
 mod rows;
 mod data;
+mod batch;
+mod sql_enum;
+mod out_args;
+mod query;
+mod snapshot;
+#[cfg(feature="serde_json")]
+#[cfg_attr(docsrs, doc(cfg(feature="serde_json")))]
+mod json;
 
 #[cfg(feature="blocking")]
 #[cfg_attr(docsrs, doc(cfg(feature="blocking")))]
@@ -18,20 +27,79 @@ mod nonblocking;
 pub use args::ToSql;
 pub use data::FromSql;
 pub use bind::Params;
+pub use binds::{Binds, ToRow, ToSqlDyn, BindMap};
 pub use cursor::Cursor;
-pub use rows::{Row, Rows};
-pub use cols::ColumnType;
+pub use rows::{Row, Rows, FromRow, FromSqlTuple};
+pub use cols::{ColumnType, ColumnInfo};
+#[doc(hidden)]
+pub use cols::Column;
+pub use batch::BatchResult;
+pub use snapshot::Snapshot;
+#[doc(hidden)]
+pub use query::check_arg_names;
 
 use once_cell::sync::OnceCell;
-use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use parking_lot::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use libc::c_void;
 
-use crate::{Result, session::SvcCtx, oci::*, Session, types::Ctx};
+use crate::{Error, Result, session::SvcCtx, oci::*, Session, RowID, lob::BoundLob, types::Ctx, env::ActiveHandle};
 #[cfg(feature="nonblocking")]
 use crate::task;
 
-use std::{sync::Arc, fmt::Display};
+use std::{sync::{Arc, atomic::{AtomicU32, Ordering}}, fmt::Display};
 
-use cols::{Columns, ColumnInfo};
+use cols::Columns;
+
+/// Finds the identity column of the table that stores the row with the given ROWID
+const IDENTITY_COLUMN_SQL : &str = "
+    SELECT c.owner, c.table_name, c.column_name
+      FROM all_objects o
+      JOIN all_tab_identity_cols c
+        ON c.owner = o.owner AND c.table_name = o.object_name
+     WHERE o.data_object_id = dbms_rowid.rowid_object(:ROW_ID)
+";
+
+/// Explains the query and returns the optimizer's estimate of the number of rows it returns
+const ROW_ESTIMATE_SQL : &str = "
+    BEGIN
+        DELETE FROM plan_table WHERE statement_id = 'SIBYL$ROW_ESTIMATE';
+        EXECUTE IMMEDIATE 'EXPLAIN PLAN SET STATEMENT_ID = ''SIBYL$ROW_ESTIMATE'' FOR ' || :SQL;
+        SELECT Nvl(Max(cardinality), 0)
+          INTO :NUM_ROWS
+          FROM plan_table
+         WHERE statement_id = 'SIBYL$ROW_ESTIMATE' AND id = 0;
+        DELETE FROM plan_table WHERE statement_id = 'SIBYL$ROW_ESTIMATE';
+    END;
+";
+
+/// Temporary LOB that holds the data of an argument that is too large to be bound directly
+enum TempLob {
+    Text(BoundLob<OCICLobLocator>),
+    Binary(BoundLob<OCIBLobLocator>),
+}
+
+impl TempLob {
+    fn sql_type(&self) -> u16 {
+        match self {
+            TempLob::Text(_)   => SQLT_CLOB,
+            TempLob::Binary(_) => SQLT_BLOB,
+        }
+    }
+
+    fn locator_ptr(&self) -> *mut c_void {
+        match self {
+            TempLob::Text(lob)   => lob.locator_ptr(),
+            TempLob::Binary(lob) => lob.locator_ptr(),
+        }
+    }
+
+    fn charset_form(&self, err: &OCIError) -> u8 {
+        match self {
+            TempLob::Text(lob)   => lob.charset_form(err),
+            TempLob::Binary(_)   => SQLCS_IMPLICIT,
+        }
+    }
+}
 
 /// Allows column or output variable identification by either
 /// its numeric position or its name.
@@ -51,13 +119,23 @@ impl Position for &str {
 
 /// Represents a prepared for execution SQL or PL/SQL statement
 pub struct Statement<'a> {
-    session:  &'a Session<'a>,
-    stmt:     Ptr<OCIStmt>,
-    params:   Option<RwLock<Params>>,
-    cols:     OnceCell<RwLock<Columns>>,
-    err:      Handle<OCIError>,
-    svc:      Arc<SvcCtx>,
-    max_long: u32,
+    session:        &'a Session<'a>,
+    stmt:           Ptr<OCIStmt>,
+    params:         Option<RwLock<Params>>,
+    cols:           OnceCell<RwLock<Columns>>,
+    err:            Handle<OCIError>,
+    svc:            Arc<SvcCtx>,
+    max_long:       u32,
+    piecewise_long: bool,
+    lob_prefetch:   u32,
+    prefetch_rows:  AtomicU32,
+    fetch_array:    AtomicU32,
+    preserve_numbers: bool,
+    defines:        Vec<(usize, u16)>,
+    _handle:        ActiveHandle,
+    snap_in:        Option<Snapshot<'a>>,
+    snap_out:       Option<Snapshot<'a>>,
+    temp_lobs:      Mutex<Vec<TempLob>>,
 }
 
 #[cfg(not(docsrs))]
@@ -127,10 +205,103 @@ impl<'a> Statement<'a> {
         self.cols.get().expect("locked columns").write()
     }
 
+    /// Creates and defines new output buffers for the query columns
+    pub(crate) fn new_columns(&self) -> Result<Columns> {
+        Columns::new(Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), self.max_long, self.piecewise_long, self.lob_prefetch, self.preserve_numbers, &self.defines)
+    }
+
+    /// Returns the metadata of the select-list columns of the described (or executed) statement
+    fn described_columns(&self) -> Result<Vec<ColumnInfo<'_>>> {
+        let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+        if stmt_type != OCI_STMT_SELECT {
+            return Ok(Vec::new());
+        }
+        if self.cols.get().is_none() {
+            let cols = self.new_columns()?;
+            self.cols.get_or_init(|| RwLock::new(cols));
+        }
+        let num_cols = self.column_count()?;
+        Ok((0..num_cols).filter_map(|pos| self.column(pos)).collect())
+    }
+
     pub(crate) fn session(&self) -> &Session {
         self.session
     }
 
+    /**
+    Returns the ROWID of the last row that was inserted, updated or deleted by the last execution
+    of this statement.
+
+    The returned ROWID can be used to immediately re-select the affected row.
+
+    # Failures
+
+    - If the last execution of the statement has not affected any rows.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        UPDATE hr.employees
+           SET salary = salary * 1.1
+         WHERE employee_id = :ID
+    ")?;
+    let num_updated = stmt.execute(107)?;
+    assert_eq!(num_updated, 1);
+
+    let rowid = stmt.last_rowid()?;
+
+    let stmt = session.prepare("SELECT employee_id FROM hr.employees WHERE rowid = :ROW_ID")?;
+    let id : u32 = stmt.query_scalar(&rowid)?;
+    assert_eq!(id, 107);
+    # session.rollback()?;
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     UPDATE hr.employees
+    #        SET salary = salary * 1.1
+    #      WHERE employee_id = :ID
+    # ").await?;
+    # let num_updated = stmt.execute(107).await?;
+    # assert_eq!(num_updated, 1);
+    # let rowid = stmt.last_rowid()?;
+    # let stmt = session.prepare("SELECT employee_id FROM hr.employees WHERE rowid = :ROW_ID").await?;
+    # let id : u32 = stmt.query_scalar(&rowid).await?;
+    # assert_eq!(id, 107);
+    # session.rollback().await?;
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn last_rowid(&self) -> Result<RowID> {
+        let mut rowid = RowID::new(self)?;
+        attr::get_into(OCI_ATTR_ROWID, &mut rowid, OCI_HTYPE_STMT, self.stmt.as_ref(), self.as_ref())?;
+        if !rowid.is_initialized() {
+            return Err( Error::new("statement has not affected any rows") );
+        }
+        Ok( rowid )
+    }
+
+    /// Returns the ROWID of the row inserted by the last execution of this INSERT statement
+    fn inserted_rowid(&self) -> Result<RowID> {
+        let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+        if stmt_type != OCI_STMT_INSERT {
+            return Err( Error::new("last inserted row is only available for INSERT statements") );
+        }
+        self.last_rowid()
+    }
+
     /**
     Sets the number of top-level rows to be prefetched. The default value is 10 rows.
 
@@ -171,7 +342,97 @@ impl<'a> Statement<'a> {
     ```
     */
     pub fn set_prefetch_rows(&self, num_rows: u32) -> Result<()> {
-        self.set_attr(OCI_ATTR_PREFETCH_ROWS, num_rows)
+        self.set_attr(OCI_ATTR_PREFETCH_ROWS, num_rows)?;
+        self.prefetch_rows.store(num_rows, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /**
+    Sets the number of rows that each fetch requests from the server once the rows prefetched
+    by the query execution have been consumed.
+
+    The prefetch count (see [`set_prefetch_rows`](Statement::set_prefetch_rows)) controls how many
+    rows are returned together with the execution of the query. Then, by default, it also controls
+    how many rows are transferred by each subsequent fetch round-trip. The fetch array size decouples
+    the latter from the former. For example, a query that is expected to return a few rows most of
+    the time can be executed with a small prefetch, while a large fetch array size would limit the
+    number of round-trips when it does return a large result set. Conversely, a small fetch array
+    size limits the client memory used by each fetch when rows are large.
+
+    Setting the fetch array size to zero (the default) leaves fetching to the prefetch count.
+
+    Note that [`Rows::fetch_many`](crate::Rows::fetch_many) requests the number of rows it is asked
+    to fetch regardless of this setting.
+
+    # Parameters
+
+    * `num_rows` - The number of rows to request from the server per fetch
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT employee_id, first_name, last_name
+          FROM hr.employees
+         WHERE department_id = :id
+    ")?;
+    stmt.set_prefetch_rows(5)?;
+    stmt.set_fetch_array_size(100);
+    let rows = stmt.query(50)?;
+    let mut count = 0;
+    while let Some(_row) = rows.next()? {
+        count += 1;
+    }
+    assert_eq!(count, 45);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT employee_id, first_name, last_name
+    #       FROM hr.employees
+    #      WHERE department_id = :id
+    # ").await?;
+    # stmt.set_prefetch_rows(5)?;
+    # stmt.set_fetch_array_size(100);
+    # let rows = stmt.query(50).await?;
+    # let mut count = 0;
+    # while let Some(_row) = rows.next().await? {
+    #     count += 1;
+    # }
+    # assert_eq!(count, 45);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn set_fetch_array_size(&self, num_rows: u32) {
+        self.fetch_array.store(num_rows, Ordering::Relaxed);
+    }
+
+    /// Restores the prefetch count that the fetch array size replaced after the previous execution of the query.
+    fn prefetch_for_execute(&self, stmt_type: u16) -> Result<()> {
+        if stmt_type == OCI_STMT_SELECT && self.fetch_array.load(Ordering::Relaxed) > 0 {
+            self.set_attr(OCI_ATTR_PREFETCH_ROWS, self.prefetch_rows.load(Ordering::Relaxed))?;
+        }
+        Ok(())
+    }
+
+    /// Replaces the prefetch count of the executed query with the fetch array size, if the latter is set.
+    fn prefetch_for_fetch(&self, stmt_type: u16) -> Result<()> {
+        let fetch_array = self.fetch_array.load(Ordering::Relaxed);
+        if stmt_type == OCI_STMT_SELECT && fetch_array > 0 {
+            self.set_attr(OCI_ATTR_PREFETCH_ROWS, fetch_array)?;
+        }
+        Ok(())
     }
 
     /**
@@ -272,6 +533,370 @@ impl<'a> Statement<'a> {
         self.max_long = size;
     }
 
+    /**
+    Enables or disables piecewise fetching of LONG and LONG RAW values.
+
+    By default LONG and LONG RAW values are fetched into buffers of a fixed size (see
+    [`set_max_long_size`](Statement::set_max_long_size)), and values that do not fit are
+    truncated. When piecewise fetching is enabled values are fetched in pieces into
+    buffers that grow as needed. Thus, they can be read in full without knowing their
+    size up front.
+
+    Like [`set_max_long_size`](Statement::set_max_long_size) this setting has to be changed
+    **before** the `query` is run.
+
+    # Parameters
+
+    * `piecewise` - whether LONG and LONG RAW values are fetched piecewise
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    # let stmt = session.prepare("
+    #     DECLARE
+    #         name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+    #     BEGIN
+    #         EXECUTE IMMEDIATE '
+    #             CREATE TABLE long_and_raw_test_data (
+    #                 id      NUMBER GENERATED ALWAYS AS IDENTITY,
+    #                 bin     RAW(100),
+    #                 text    LONG
+    #             )
+    #         ';
+    #     EXCEPTION
+    #       WHEN name_already_used THEN NULL;
+    #     END;
+    # ")?;
+    # stmt.execute(())?;
+    // A 200K text that is larger than the default 32K max long size
+    let text = "Lorem ipsum dolor sit amet, consectetur adipiscing elit. ".repeat(3500);
+    # let stmt = session.prepare("
+    #     INSERT INTO long_and_raw_test_data (text) VALUES (:TEXT)
+    #     RETURNING id INTO :ID
+    # ")?;
+    # let mut id = 0;
+    # stmt.execute(((":TEXT", text.as_str()), (":ID", &mut id)))?;
+    let mut stmt = session.prepare("
+        SELECT text
+          FROM long_and_raw_test_data
+         WHERE id = :id
+    ")?;
+    stmt.set_piecewise_long_fetch(true);
+    let row = stmt.query_single(&id)?.unwrap();
+    let txt : &str = row.get(0)?;
+    assert_eq!(txt.len(), text.len());
+    assert_eq!(txt, text);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     DECLARE
+    #         name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+    #     BEGIN
+    #         EXECUTE IMMEDIATE '
+    #             CREATE TABLE long_and_raw_test_data (
+    #                 id      NUMBER GENERATED ALWAYS AS IDENTITY,
+    #                 bin     RAW(100),
+    #                 text    LONG
+    #             )
+    #         ';
+    #     EXCEPTION
+    #       WHEN name_already_used THEN NULL;
+    #     END;
+    # ").await?;
+    # stmt.execute(()).await?;
+    # let text = "Lorem ipsum dolor sit amet, consectetur adipiscing elit. ".repeat(3500);
+    # let stmt = session.prepare("
+    #     INSERT INTO long_and_raw_test_data (text) VALUES (:TEXT)
+    #     RETURNING id INTO :ID
+    # ").await?;
+    # let mut id = 0;
+    # stmt.execute(((":TEXT", text.as_str()), (":ID", &mut id))).await?;
+    # let mut stmt = session.prepare("
+    #     SELECT text
+    #       FROM long_and_raw_test_data
+    #      WHERE id = :id
+    # ").await?;
+    # stmt.set_piecewise_long_fetch(true);
+    # let row = stmt.query_single(&id).await?.unwrap();
+    # let txt : &str = row.get(0)?;
+    # assert_eq!(txt.len(), text.len());
+    # assert_eq!(txt, text);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn set_piecewise_long_fetch(&mut self, piecewise: bool) {
+        self.piecewise_long = piecewise;
+    }
+
+    /**
+    Sets the size of the LOB data (in characters for CLOBs and bytes for BLOBs) that will be
+    prefetched with each row for the CLOB and BLOB columns of this query.
+
+    When LOB prefetch is enabled, the LOB length and up to `size` of its data are returned together
+    with the LOB locator. Thus, reading small LOBs does not require additional round-trips. This
+    setting overrides the session default (see [`Session::set_lob_prefetch_size`]) for this statement.
+    The default value, zero, disables the statement level prefetch.
+
+    Like [`set_max_long_size`](Statement::set_max_long_size) this setting has to be changed
+    **before** the `query` is run.
+
+    # Parameters
+
+    * `size` - the amount of LOB data to prefetch
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::{Result, CLOB};
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let mut stmt = session.prepare("
+        SELECT To_Clob('Hello, World!') FROM dual
+    ")?;
+    stmt.set_lob_prefetch_size(1024);
+    let row = stmt.query_single(())?.unwrap();
+    let lob : CLOB = row.get(0)?;
+
+    let mut text = String::new();
+    lob.read(0, 100, &mut text)?;
+    assert_eq!(text, "Hello, World!");
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let mut stmt = session.prepare("
+    #     SELECT To_Clob('Hello, World!') FROM dual
+    # ").await?;
+    # stmt.set_lob_prefetch_size(1024);
+    # let row = stmt.query_single(()).await?.unwrap();
+    # let lob : CLOB = row.get(0)?;
+    # let mut text = String::new();
+    # lob.read(0, 100, &mut text).await?;
+    # assert_eq!(text, "Hello, World!");
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn set_lob_prefetch_size(&mut self, size: u32) {
+        self.lob_prefetch = size;
+    }
+
+    /**
+    Makes the query fetch all NUMBER columns as text.
+
+    NUMBER values have up to 38 significant digits. Values that are read as `f64` (or via other
+    conversions that go through floating point numbers) lose some of them. When this option is
+    set, Oracle converts NUMBER values into text as they are fetched, and thus "generic" reads -
+    into `String`, `&str` and, with the `serde_json` feature, `serde_json::Value` - get all the
+    digits. Integers and floating point numbers can still be read from these columns. They are
+    parsed from the fetched text, which, therefore, must use `.` as the decimal separator.
+    Use [`define_as`](Statement::define_as) to fetch only specific columns as text.
+
+    The default is set by [`Session::preserve_number_precision()`]. Like [`set_max_long_size`](Statement::set_max_long_size)
+    this option has to be set **before** the `query` is run.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let mut stmt = session.prepare("
+        SELECT 12345678901234567890.123456789012345678, 42 FROM dual
+    ")?;
+    stmt.preserve_number_precision(true);
+    let row = stmt.query_single(())?.unwrap();
+    let num : String = row.get(0)?;
+    assert_eq!(num, "12345678901234567890.123456789012345678");
+    let num : u32 = row.get(1)?;
+    assert_eq!(num, 42);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let mut stmt = session.prepare("
+    #     SELECT 12345678901234567890.123456789012345678, 42 FROM dual
+    # ").await?;
+    # stmt.preserve_number_precision(true);
+    # let row = stmt.query_single(()).await?.unwrap();
+    # let num : String = row.get(0)?;
+    # assert_eq!(num, "12345678901234567890.123456789012345678");
+    # let num : u32 = row.get(1)?;
+    # assert_eq!(num, 42);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn preserve_number_precision(&mut self, preserve: bool) {
+        self.preserve_numbers = preserve;
+    }
+
+    /**
+    Makes the statement capture the snapshot of the database when it executes the query.
+
+    The captured snapshot can then be used by other statements - see [`use_snapshot`](Statement::use_snapshot) -
+    to read data that is consistent with the data read by this statement. Each execution of the
+    statement replaces the previously captured snapshot content.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    use sibyl::Snapshot;
+
+    # #[cfg(feature="blocking")]
+    # fn main() -> sibyl::Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let snapshot = Snapshot::new(&session)?;
+
+    let mut stmt = session.prepare("SELECT Count(*) FROM hr.employees")?;
+    stmt.capture_snapshot(&snapshot);
+    let row = stmt.query_single(())?.unwrap();
+    let num_employees : usize = row.get(0)?;
+
+    let mut stmt = session.prepare("SELECT Count(*) FROM hr.employees")?;
+    stmt.use_snapshot(&snapshot);
+    let row = stmt.query_single(())?.unwrap();
+    let num_employees_then : usize = row.get(0)?;
+
+    assert_eq!(num_employees_then, num_employees);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> sibyl::Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let snapshot = Snapshot::new(&session)?;
+    # let mut stmt = session.prepare("SELECT Count(*) FROM hr.employees").await?;
+    # stmt.capture_snapshot(&snapshot);
+    # let row = stmt.query_single(()).await?.unwrap();
+    # let num_employees : usize = row.get(0)?;
+    # let mut stmt = session.prepare("SELECT Count(*) FROM hr.employees").await?;
+    # stmt.use_snapshot(&snapshot);
+    # let row = stmt.query_single(()).await?.unwrap();
+    # let num_employees_then : usize = row.get(0)?;
+    # assert_eq!(num_employees_then, num_employees);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn capture_snapshot(&mut self, snapshot: &Snapshot<'a>) {
+        self.snap_out = Some(snapshot.clone());
+    }
+
+    /**
+    Makes the statement execute its query as of the snapshot that was captured by another statement.
+
+    The snapshot must have been captured - see [`capture_snapshot`](Statement::capture_snapshot) -
+    before this statement is executed. The snapshot is ignored if the statement is not a query.
+    */
+    pub fn use_snapshot(&mut self, snapshot: &Snapshot<'a>) {
+        self.snap_in = Some(snapshot.clone());
+    }
+
+    /// Returns the pointer to the snapshot that the execution uses or NULL.
+    fn snapshot_in(&self) -> *const OCISnapshot {
+        self.snap_in.as_ref().map_or(std::ptr::null(), |snap| snap.as_ptr())
+    }
+
+    /// Returns the pointer to the snapshot that the execution captures or NULL.
+    fn snapshot_out(&self) -> *const OCISnapshot {
+        self.snap_out.as_ref().map_or(std::ptr::null(), |snap| snap.as_ptr())
+    }
+
+    /**
+    Overrides the data type into which values of the specified column will be fetched.
+
+    By default column values are fetched into the buffers that match the column data types.
+    This method allows fetching them as a different type. For example, NUMBER values can be
+    fetched as text to preserve their precision, or DATE values can be fetched as text that
+    is formatted according to the session NLS settings. Oracle converts fetched values into
+    the requested type.
+
+    Like [`set_max_long_size`](Statement::set_max_long_size) the override has to be set **before**
+    the `query` is run.
+
+    # Parameters
+
+    * `pos` - zero-based index of the column in the select-list
+    * `col_type` - the type into which the column values will be converted
+
+    # Failures
+
+    * `Cannot define column as` - when the column cannot be fetched as `col_type`. Only character,
+      RAW, numeric, date, timestamp, and interval types are supported.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    use sibyl::ColumnType;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let mut stmt = session.prepare("
+        SELECT 12345678901234567890.123456789012345678 FROM dual
+    ")?;
+    stmt.define_as(0, ColumnType::Varchar)?;
+    let row = stmt.query_single(())?.unwrap();
+    let num : &str = row.get(0)?;
+
+    assert_eq!(num, "12345678901234567890.123456789012345678");
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let mut stmt = session.prepare("
+    #     SELECT 12345678901234567890.123456789012345678 FROM dual
+    # ").await?;
+    # stmt.define_as(0, ColumnType::Varchar)?;
+    # let row = stmt.query_single(()).await?.unwrap();
+    # let num : &str = row.get(0)?;
+    # assert_eq!(num, "12345678901234567890.123456789012345678");
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn define_as(&mut self, pos: usize, col_type: ColumnType) -> Result<()> {
+        let define_type = col_type.define_type().ok_or_else(||
+            Error::msg(format!("Cannot define column {} as {}", pos, col_type))
+        )?;
+        self.defines.retain(|(ix, _)| *ix != pos);
+        self.defines.push((pos, define_type));
+        Ok(())
+    }
+
     /**
     Returns he number of columns in the select-list of this statement.
 
@@ -316,6 +941,99 @@ impl<'a> Statement<'a> {
         Ok( num_columns )
     }
 
+    /**
+    Returns the number of parameter placeholders (bind positions) in the statement.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT employee_id, last_name, first_name
+          FROM hr.employees
+         WHERE manager_id = :id
+           AND hire_date > :hired_after
+    ")?;
+    let num_params = stmt.bind_count()?;
+    assert_eq!(num_params, 2);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT employee_id, last_name, first_name
+    #       FROM hr.employees
+    #      WHERE manager_id = :id
+    #        AND hire_date > :hired_after
+    # ").await?;
+    # let num_params = stmt.bind_count()?;
+    # assert_eq!(num_params, 2);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn bind_count(&self) -> Result<usize> {
+        let num_binds = self.get_attr::<u32>(OCI_ATTR_BIND_COUNT)? as usize;
+        Ok( num_binds )
+    }
+
+    /**
+    Returns names of the parameter placeholders in the order of their positions in the statement.
+
+    Names are returned as Oracle reports them - without the leading colon and, unless the placeholder
+    was quoted, in uppercase. A placeholder that is used several times in a SQL statement occupies
+    several bind positions, and thus its name is repeated for each of them.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT employee_id, last_name, first_name
+          FROM hr.employees
+         WHERE manager_id = :id
+           AND hire_date > :hired_after
+    ")?;
+    let names = stmt.bind_names();
+    assert_eq!(names, ["ID", "HIRED_AFTER"]);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT employee_id, last_name, first_name
+    #       FROM hr.employees
+    #      WHERE manager_id = :id
+    #        AND hire_date > :hired_after
+    # ").await?;
+    # let names = stmt.bind_names();
+    # assert_eq!(names, ["ID", "HIRED_AFTER"]);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn bind_names(&self) -> Vec<String> {
+        self.params.as_ref().map_or_else(Vec::new, |params| {
+            params.read().names().iter().map(|&name| name.to_string()).collect()
+        })
+    }
+
     /**
     Returns the number of rows processed/seen so far in SELECT statements.
 
@@ -384,6 +1102,111 @@ impl<'a> Statement<'a> {
         Ok( num_rows )
     }
 
+    /**
+    Returns the number of implicit results (cursors returned via `DBMS_SQL.RETURN_RESULT`)
+    that are available after the execution of a PL/SQL statement.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        DECLARE
+            c1 SYS_REFCURSOR;
+            c2 SYS_REFCURSOR;
+        BEGIN
+            OPEN c1 FOR SELECT 1 FROM dual;
+            DBMS_SQL.RETURN_RESULT(c1);
+            OPEN c2 FOR SELECT 2 FROM dual;
+            DBMS_SQL.RETURN_RESULT(c2);
+        END;
+    ")?;
+    stmt.execute(())?;
+    assert_eq!(stmt.implicit_result_count()?, 2);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     DECLARE
+    #         c1 SYS_REFCURSOR;
+    #         c2 SYS_REFCURSOR;
+    #     BEGIN
+    #         OPEN c1 FOR SELECT 1 FROM dual;
+    #         DBMS_SQL.RETURN_RESULT(c1);
+    #         OPEN c2 FOR SELECT 2 FROM dual;
+    #         DBMS_SQL.RETURN_RESULT(c2);
+    #     END;
+    # ").await?;
+    # stmt.execute(()).await?;
+    # assert_eq!(stmt.implicit_result_count()?, 2);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn implicit_result_count(&self) -> Result<usize> {
+        let num_results = self.get_attr::<u32>(OCI_ATTR_IMPLICIT_RESULT_COUNT)? as usize;
+        Ok( num_results )
+    }
+
+    /**
+    Returns the server-side time, in microseconds, of the last call made by this statement - its
+    execution or a fetch of its rows.
+
+    Call time is only measured after [`Session::enable_call_timing()`] has been called. The time
+    is tracked per session. Thus this method should be called before any other call is made
+    in the statement's session.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    session.enable_call_timing()?;
+    let stmt = session.prepare("
+        SELECT Count(*) FROM hr.employees
+    ")?;
+    let num_employees : u32 = stmt.query_scalar(())?;
+    let dt = stmt.last_call_time()?;
+    session.disable_call_timing()?;
+    # assert_eq!(num_employees, 107);
+    assert!(dt > 0);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # session.enable_call_timing()?;
+    # let stmt = session.prepare("
+    #     SELECT Count(*) FROM hr.employees
+    # ").await?;
+    # let num_employees : u32 = stmt.query_scalar(()).await?;
+    # let dt = stmt.last_call_time()?;
+    # session.disable_call_timing()?;
+    # assert_eq!(num_employees, 107);
+    # assert!(dt > 0);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn last_call_time(&self) -> Result<u64> {
+        self.session.call_time()
+    }
+
     // Indicates the number of rows that were successfully fetched into the user's buffers
     // in the last fetch or execute with nonzero iterations.
     //
@@ -579,4 +1402,73 @@ impl<'a> Statement<'a> {
                 ColumnInfo::new(param, self.as_ref())
             )
     }
+
+    /**
+    Cancels the execution or fetch of this statement that is currently in progress.
+
+    This method is intended to be called concurrently with the running `execute` or `query`
+    (or fetching of the rows) - from another thread in `blocking` mode or while the statement
+    future is pending in `nonblocking` mode. The interrupted call fails with the
+    `ORA-01013: user requested cancel of current operation` error.
+
+    If no call is in progress, the cancellation is a no-op.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    use std::{thread, time::Duration};
+    use sibyl::Error;
+
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        BEGIN
+            DBMS_LOCK.SLEEP(10);
+        END;
+    ")?;
+    thread::scope(|s| {
+        s.spawn(|| {
+            thread::sleep(Duration::from_secs(1));
+            stmt.cancel()
+        });
+        let res = stmt.execute(());
+        assert!(matches!(res, Err(Error::Oracle(1013, _))));
+    });
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # use std::{future::{Future, poll_fn}, time::Instant};
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     BEGIN
+    #         DBMS_LOCK.SLEEP(10);
+    #     END;
+    # ").await?;
+    # let started = Instant::now();
+    # let mut cancelled = false;
+    # let mut execute = Box::pin(stmt.execute(()));
+    # let res = poll_fn(|cx| {
+    #     if !cancelled && started.elapsed() >= Duration::from_secs(1) {
+    #         stmt.cancel()?;
+    #         cancelled = true;
+    #     }
+    #     execute.as_mut().poll(cx)
+    # }).await;
+    # assert!(matches!(res, Err(Error::Oracle(1013, _))));
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn cancel(&self) -> Result<()> {
+        // The statement's error handle might be in use by the call that is being cancelled
+        let err = Handle::<OCIError>::new(self)?;
+        break_call(self.as_ref(), &err)
+    }
 }
\ No newline at end of file
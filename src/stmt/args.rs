@@ -188,6 +188,28 @@ impl<T1,T2> ToSql for ((&str, T1), (&str, T2)) where T1: ToSql, T2: ToSql {
     }
 }
 
+// Generic positional pair would overlap with the named argument `(&str, T)`. Pairs that
+// start with a binary argument, however, cannot be confused with named arguments.
+macro_rules! impl_bin_pair_args {
+    ($($first:ty),+) => {
+        $(
+            impl<T> ToSql for ($first, T) where T: ToSql {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    let pos = self.0.bind_to(pos, params, stmt, err)?;
+                    self.1.bind_to(pos, params, stmt, err)
+                }
+
+                fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+                    let pos = self.0.update_from_bind(pos, params)?;
+                    self.1.update_from_bind(pos, params)
+                }
+            }
+        )+
+    };
+}
+
+impl_bin_pair_args! { &[u8], &mut [u8], Vec<u8>, &Vec<u8>, &mut Vec<u8> }
+
 macro_rules! impl_tuple_args {
     ($($item:ident)+) => {
         impl<$($item),+> ToSql for ($($item),+) where $($item: ToSql),+ {
@@ -1,8 +1,9 @@
 use super::{Params, ToSql};
-use crate::{oci::*, Result};
+use crate::{oci::*, Result, types::number::IntoNumber};
 use std::mem::size_of;
 
-macro_rules! impl_num_to_sql {
+/// Implements `ToSql` for the forms of a numeric type that are bound as IN arguments
+macro_rules! impl_num_in_to_sql {
     ($($t:ty),+ => $sqlt:ident) => {
         $(
             impl ToSql for $t {
@@ -17,12 +18,6 @@ macro_rules! impl_num_to_sql {
                     Ok(pos + 1)
                 }
             }
-            impl ToSql for &mut $t {
-                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
-                    params.bind(pos, $sqlt, *self as *mut $t as _, size_of::<$t>(), size_of::<$t>(), stmt, err)?;
-                    Ok(pos + 1)
-                }
-            }
 
             impl ToSql for Option<$t> {
                 fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
@@ -44,32 +39,46 @@ macro_rules! impl_num_to_sql {
                     Ok(pos + 1)
                 }
             }
-            impl ToSql for Option<&mut $t> {
+            impl ToSql for &Option<$t> {
                 fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
                     if let Some(val) = self {
-                        params.bind(pos, $sqlt, *val as *mut $t as _, size_of::<$t>(), size_of::<$t>(), stmt, err)?;
+                        params.bind_in(pos, $sqlt, val as *const $t as _, size_of::<$t>(), stmt, err)?;
                     } else {
-                        // There is nothing we can do if they passed None as we cannot insert mut ref back into Option
                         params.bind_null(pos, $sqlt, stmt, err)?;
                     }
                     Ok(pos + 1)
                 }
             }
-            impl ToSql for &Option<$t> {
+            impl ToSql for &Option<&$t> {
                 fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
                     if let Some(val) = self {
-                        params.bind_in(pos, $sqlt, val as *const $t as _, size_of::<$t>(), stmt, err)?;
+                        params.bind_in(pos, $sqlt, *val as *const $t as _, size_of::<$t>(), stmt, err)?;
                     } else {
                         params.bind_null(pos, $sqlt, stmt, err)?;
                     }
                     Ok(pos + 1)
                 }
             }
-            impl ToSql for &Option<&$t> {
+        )+
+    };
+}
+
+/// Implements `ToSql` for the forms of a numeric type that are bound as OUT or INOUT arguments
+macro_rules! impl_num_out_to_sql {
+    ($($t:ty),+ => $sqlt:ident) => {
+        $(
+            impl ToSql for &mut $t {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.bind(pos, $sqlt, *self as *mut $t as _, size_of::<$t>(), size_of::<$t>(), stmt, err)?;
+                    Ok(pos + 1)
+                }
+            }
+            impl ToSql for Option<&mut $t> {
                 fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
                     if let Some(val) = self {
-                        params.bind_in(pos, $sqlt, *val as *const $t as _, size_of::<$t>(), stmt, err)?;
+                        params.bind(pos, $sqlt, *val as *mut $t as _, size_of::<$t>(), size_of::<$t>(), stmt, err)?;
                     } else {
+                        // There is nothing we can do if they passed None as we cannot insert mut ref back into Option
                         params.bind_null(pos, $sqlt, stmt, err)?;
                     }
                     Ok(pos + 1)
@@ -142,12 +151,103 @@ macro_rules! impl_num_to_sql {
     };
 }
 
+macro_rules! impl_num_to_sql {
+    ($($t:ty),+ => $sqlt:ident) => {
+        impl_num_in_to_sql!{ $($t),+ => $sqlt }
+        impl_num_out_to_sql!{ $($t),+ => $sqlt }
+    };
+}
+
 impl_num_to_sql!{ i8, i16, i32, i64, isize => SQLT_INT }
-impl_num_to_sql!{ u8, u16, u32, u64, usize => SQLT_UIN }
+impl_num_to_sql!{ u8, u16, u32 => SQLT_UIN }
+impl_num_out_to_sql!{ u64, usize => SQLT_UIN }
 impl_num_to_sql!{ f32 => SQLT_BFLOAT }
 impl_num_to_sql!{ f64 => SQLT_BDOUBLE }
 
-macro_rules! impl_num_slice_to_sql {
+/// Implements `ToSql` for the IN forms of integers that might not fit into the native
+/// integer binds - unsigned 64-bit and all 128-bit integers. These are converted into
+/// Oracle numbers and bound as such.
+macro_rules! impl_int_to_number_sql {
+    ($($t:ty),+) => {
+        $(
+            impl ToSql for $t {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    let num = self.into_number(err)?;
+                    params.bind_in_mut(pos, SQLT_VNU, &num as *const OCINumber as _, size_of::<OCINumber>(), stmt, err)?;
+                    Ok(pos + 1)
+                }
+            }
+            impl ToSql for &$t {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    let mut val = **self;
+                    val.bind_to(pos, params, stmt, err)
+                }
+            }
+            impl ToSql for Option<$t> {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    if let Some(val) = self {
+                        val.bind_to(pos, params, stmt, err)
+                    } else {
+                        params.bind_null(pos, SQLT_VNU, stmt, err)?;
+                        Ok(pos + 1)
+                    }
+                }
+            }
+            impl ToSql for Option<&$t> {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    if let Some(val) = self {
+                        val.bind_to(pos, params, stmt, err)
+                    } else {
+                        params.bind_null(pos, SQLT_VNU, stmt, err)?;
+                        Ok(pos + 1)
+                    }
+                }
+            }
+            impl ToSql for &Option<$t> {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    let mut val = **self;
+                    val.bind_to(pos, params, stmt, err)
+                }
+            }
+            impl ToSql for &Option<&$t> {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    let mut val = **self;
+                    val.bind_to(pos, params, stmt, err)
+                }
+            }
+            impl ToSql for &[$t] {
+                fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    for num in self.iter() {
+                        let num = num.into_number(err)?;
+                        params.bind_in_mut(pos, SQLT_VNU, &num as *const OCINumber as _, size_of::<OCINumber>(), stmt, err)?;
+                        pos += 1;
+                    }
+                    Ok(pos)
+                }
+                fn update_from_bind(&mut self, pos: usize, _params: &Params) -> Result<usize> {
+                    Ok(pos + self.len())
+                }
+            }
+            impl ToSql for &[&$t] {
+                fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    for &num in self.iter() {
+                        let num = num.into_number(err)?;
+                        params.bind_in_mut(pos, SQLT_VNU, &num as *const OCINumber as _, size_of::<OCINumber>(), stmt, err)?;
+                        pos += 1;
+                    }
+                    Ok(pos)
+                }
+                fn update_from_bind(&mut self, pos: usize, _params: &Params) -> Result<usize> {
+                    Ok(pos + self.len())
+                }
+            }
+        )+
+    };
+}
+
+impl_int_to_number_sql!{ u64, usize, i128, u128 }
+
+macro_rules! impl_num_slice_in_to_sql {
     ($($t:ty),+ => $sqlt:ident) => {
         $(
             impl ToSql for &[$t] {
@@ -174,6 +274,13 @@ macro_rules! impl_num_slice_to_sql {
                     Ok(pos + self.len())
                 }
             }
+        )+
+    };
+}
+
+macro_rules! impl_num_slice_out_to_sql {
+    ($($t:ty),+ => $sqlt:ident) => {
+        $(
             impl ToSql for &mut [&mut $t] {
                 fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
                     for num in self.iter_mut() {
@@ -190,8 +297,16 @@ macro_rules! impl_num_slice_to_sql {
     };
 }
 
+macro_rules! impl_num_slice_to_sql {
+    ($($t:ty),+ => $sqlt:ident) => {
+        impl_num_slice_in_to_sql!{ $($t),+ => $sqlt }
+        impl_num_slice_out_to_sql!{ $($t),+ => $sqlt }
+    };
+}
+
 impl_num_slice_to_sql!{ i8, i16, i32, i64, isize => SQLT_INT }
-impl_num_slice_to_sql!{ u16, u32, u64, usize => SQLT_UIN }
+impl_num_slice_to_sql!{ u16, u32 => SQLT_UIN }
+impl_num_slice_out_to_sql!{ u64, usize => SQLT_UIN }
 impl_num_slice_to_sql!{ f32 => SQLT_BFLOAT }
 impl_num_slice_to_sql!{ f64 => SQLT_BDOUBLE }
 
@@ -203,3 +318,4 @@ impl_sql_type!{ &u16, &u32, &u64, &usize => SQLT_UIN }
 impl_sql_type!{ &mut u16, &mut u32, &mut u64, &mut usize => SQLT_UIN }
 impl_sql_type!{ f32, &f32, &mut f32 => SQLT_BFLOAT }
 impl_sql_type!{ f64, &f64, &mut f64 => SQLT_BDOUBLE }
+impl_sql_type!{ i128, u128, &i128, &u128 => SQLT_VNU }
@@ -0,0 +1,174 @@
+//! Array DML (batch) execution support
+
+use super::Statement;
+use crate::{Error, Result, oci::{self, *}, ToSql, RowID};
+use libc::c_void;
+use std::{ptr, mem::size_of};
+
+/// Outcome of a batch execution in which failing rows did not abort the entire batch.
+#[derive(Debug, Default)]
+pub struct BatchResult {
+    row_count: usize,
+    errors: Vec<(usize, Error)>,
+}
+
+impl BatchResult {
+    /// Returns the number of rows that were processed successfully.
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Returns `true` if all rows of the batch were processed successfully.
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /**
+    Returns errors of the rows that failed. Each error is reported together
+    with the zero-based offset of the failed row in the batch.
+    */
+    pub fn errors(&self) -> &[(usize, Error)] {
+        &self.errors
+    }
+}
+
+/// ROWID returned for a single row by the RETURNING clause of the batch DML
+struct ReturnedRowId {
+    rowid: Descriptor<OCIRowid>,
+    len: u32,
+    ind: i16,
+    rcode: u16,
+}
+
+/// ROWIDs that the batch DML returns via its RETURNING clause
+pub(super) struct BatchRowIds {
+    env: Ptr<OCIEnv>,
+    /// Returned ROWIDs in the order of the batch rows.
+    /// They are boxed as OCI keeps pointers to their buffers until the execution completes.
+    #[allow(clippy::vec_box)]
+    rowids: Vec<Box<ReturnedRowId>>,
+    /// Error that prevented the OUT callback from allocating a ROWID descriptor
+    error: Option<Error>,
+}
+
+impl BatchRowIds {
+    pub(super) fn new(env: &impl AsRef<OCIEnv>) -> Self {
+        Self { env: Ptr::from(env.as_ref()), rowids: Vec::new(), error: None }
+    }
+
+    /// Returns ROWIDs collected during the batch execution.
+    pub(super) fn into_rowids(self) -> Result<Vec<RowID>> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        Ok(self.rowids.into_iter().map(|ret| RowID::from(ret.rowid)).collect())
+    }
+}
+
+/// Provides OCI with the IN value - NULL - of the RETURNING ROWID placeholder.
+extern "C" fn rowid_in_callback(
+    _ictxp: *mut c_void, _bindp: *mut OCIBind, _iter: u32, _index: u32,
+    bufpp: *mut *mut c_void, alenp: *mut u32, piecep: *mut u8, indpp: *mut *mut c_void
+) -> i32 {
+    static NULL_IND : i16 = OCI_IND_NULL;
+    unsafe {
+        *bufpp  = ptr::null_mut();
+        *alenp  = 0;
+        *indpp  = &NULL_IND as *const i16 as _;
+        *piecep = OCI_ONE_PIECE;
+    }
+    OCI_CONTINUE
+}
+
+/// Provides OCI with a new ROWID descriptor for each row returned by the batch DML.
+extern "C" fn rowid_out_callback(
+    octxp: *mut c_void, _bindp: *mut OCIBind, _iter: u32, _index: u32,
+    bufpp: *mut *mut c_void, alenpp: *mut *mut u32, piecep: *mut u8,
+    indpp: *mut *mut c_void, rcodepp: *mut *mut u16
+) -> i32 {
+    let ctx = unsafe { &mut *(octxp as *mut BatchRowIds) };
+    match Descriptor::<OCIRowid>::new(&ctx.env) {
+        Ok(rowid) => {
+            let mut ret = Box::new(ReturnedRowId { rowid, len: size_of::<*mut OCIRowid>() as _, ind: OCI_IND_NOTNULL, rcode: 0 });
+            unsafe {
+                *bufpp   = ret.rowid.as_mut_ptr() as _;
+                *alenpp  = &mut ret.len;
+                *indpp   = &mut ret.ind as *mut i16 as _;
+                *rcodepp = &mut ret.rcode;
+                *piecep  = OCI_ONE_PIECE;
+            }
+            ctx.rowids.push(ret);
+            OCI_CONTINUE
+        }
+        Err(err) => {
+            ctx.error = Some(err);
+            OCI_ERROR
+        }
+    }
+}
+
+impl Statement<'_> {
+    /**
+    Binds arrays of values of the batch rows to SQL parameter placeholders and binds
+    the `rowid_param` placeholder dynamically to collect ROWIDs returned by the batch DML.
+    */
+    pub(super) fn bind_batch_returning_rowid<T: ToSql + Clone>(&self, rows: &[T], rowid_param: &str, rowids: &mut BatchRowIds) -> Result<()> {
+        if let Some(params) = &self.params {
+            let mut params = params.write();
+            let idx = params.index_of(rowid_param)?;
+            params.bind_batch(&self.stmt, &self.err, rows.iter().cloned(), Some(idx))?;
+            let ctx = rowids as *mut BatchRowIds as *mut c_void;
+            params.bind_dynamic(
+                idx, SQLT_RDD, size_of::<*mut OCIRowid>(),
+                ptr::null_mut(), rowid_in_callback,
+                ctx, Some(rowid_out_callback),
+                &self.stmt, &self.err
+            )
+        } else {
+            Err(Error::new("Statement does not have parameters to bind batch rows to"))
+        }
+    }
+
+    /// Binds arrays of values of the batch rows to SQL parameter placeholders.
+    pub(super) fn bind_batch<T: ToSql + Clone>(&self, rows: &[T]) -> Result<()> {
+        self.bind_rows(rows.iter().cloned()).map(|_| ())
+    }
+
+    /// Binds arrays of values of the batch rows to SQL parameter placeholders. Returns the number of rows.
+    pub(super) fn bind_rows<I>(&self, rows: I) -> Result<usize>
+    where I: IntoIterator, I::Item: ToSql
+    {
+        if let Some(params) = &self.params {
+            params.write().bind_batch(&self.stmt, &self.err, rows, None)
+        } else {
+            Err(Error::new("Statement does not have parameters to bind batch rows to"))
+        }
+    }
+
+    /// Checks the OCIStmtExecute result of the batch execution that was performed in the `OCI_BATCH_ERRORS` mode.
+    pub(super) fn check_batch_result(&self, res: Result<i32>) -> Result<()> {
+        match res {
+            Ok(_) => Ok(()),
+            // OCI might report the row level errors as a generic error
+            Err(Error::Oracle(ARRAY_DML_ERRORS, _)) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Collects row level errors reported by the batch execution.
+    pub(super) fn batch_result(&self) -> Result<BatchResult> {
+        let row_count = self.row_count()?;
+        let num_errors = self.get_attr::<u32>(OCI_ATTR_NUM_DML_ERRORS)? as usize;
+        let mut errors = Vec::with_capacity(num_errors);
+        if num_errors > 0 {
+            let err = Handle::<OCIError>::new(self)?;
+            for i in 0..num_errors {
+                let mut row_err = Handle::<OCIError>::new(self)?;
+                oci::error_param_get(&self.err, &err, row_err.as_mut_ptr(), i as _)?;
+                let row_offset : u32 = attr::get(OCI_ATTR_DML_ROW_OFFSET, OCI_HTYPE_ERROR, row_err.as_ref(), &err)?;
+                errors.push((row_offset as usize, Error::oci(&row_err, OCI_ERROR)));
+            }
+        }
+        Ok(BatchResult { row_count, errors })
+    }
+}
@@ -5,10 +5,110 @@ use crate::{Result, Error, oci::{self, *}, ToSql};
 use std::{ptr, collections::HashMap};
 use libc::c_void;
 
+/// An IN argument which data has to be copied into a temporary LOB, which is then bound instead
+pub(crate) struct LobBind {
+    /// Parameter placeholder index
+    pub(crate) idx: usize,
+    /// SQL type of the argument - `SQLT_CHR` or `SQLT_LBI`
+    pub(crate) sql_type: u16,
+    /// Character set form of the text argument
+    charset_form: u8,
+    data: Ptr<u8>,
+    len: usize,
+}
+
+impl LobBind {
+    /// Returns the bound data.
+    pub(crate) fn data(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.data.get(), self.len) }
+    }
+
+    /// Returns the character set form of the bound text.
+    pub(crate) fn charset_form(&self) -> CharSetForm {
+        if self.charset_form == SQLCS_NCHAR { CharSetForm::NChar } else { CharSetForm::Implicit }
+    }
+
+    /// Returns the bound text.
+    pub(crate) fn text(&self) -> Result<&str> {
+        std::str::from_utf8(self.data()).map_err(|_| Error::new("LOB argument text is not valid UTF-8"))
+    }
+}
+
+/// Array DML data of a single parameter placeholder
+struct BatchData {
+    /// SQL type of the bound values
+    sql_type: u16,
+    /// Maximum number of characters for the character length semantics binds
+    max_chars: u32,
+    /// Character set form of the text binds
+    charset_form: u8,
+    /// Values of all rows, before they are bound, or array elements after that
+    data: Vec<u8>,
+    /// Sizes of the bound values
+    data_lens: Vec<u32>,
+    /// NULL indicators
+    nulls: Vec<i16>,
+}
+
+impl BatchData {
+    fn new() -> Self {
+        Self { sql_type: 0, max_chars: 0, charset_form: 0, data: Vec::new(), data_lens: Vec::new(), nulls: Vec::new() }
+    }
+
+    /// Appends the value of the next row
+    fn push(&mut self, sql_type: u16, data: *const c_void, data_len: usize) -> Result<()> {
+        if Self::is_descriptor(sql_type) {
+            // Only the pointer to the descriptor would be copied, and the descriptor itself
+            // might be gone (with the row that owned it) by the time the batch is executed.
+            return Err(Error::new("timestamp, interval, LOB and ROWID arguments cannot be used in batches"));
+        }
+        if data_len > 0 {
+            if self.sql_type == 0 || self.nulls.iter().all(|&ind| ind == OCI_IND_NULL) {
+                self.sql_type = sql_type;
+            } else if self.sql_type != sql_type {
+                return Err(Error::new("Batch rows bind values of different types to the same parameter"));
+            }
+            let data = unsafe { std::slice::from_raw_parts(data as *const u8, data_len) };
+            self.data.extend_from_slice(data);
+        } else if self.sql_type == 0 {
+            self.sql_type = sql_type;
+        }
+        self.data_lens.push(data_len as _);
+        self.nulls.push(if data_len == 0 { OCI_IND_NULL } else { OCI_IND_NOTNULL });
+        Ok(())
+    }
+
+    /// Checks whether values of the SQL type are bound by a pointer to their descriptor
+    fn is_descriptor(sql_type: u16) -> bool {
+        matches!(sql_type,
+            SQLT_TIMESTAMP | SQLT_TIMESTAMP_TZ | SQLT_TIMESTAMP_LTZ | SQLT_INTERVAL_YM | SQLT_INTERVAL_DS |
+            SQLT_CLOB | SQLT_BLOB | SQLT_BFILE | SQLT_RDD
+        )
+    }
+
+    /// Rearranges collected values into an array of equally sized elements.
+    /// Returns the size of an element.
+    fn make_array(&mut self) -> usize {
+        let elem_size = self.data_lens.iter().map(|&len| len as usize).max().unwrap_or_default().max(1);
+        let mut data = vec![0u8; elem_size * self.data_lens.len()];
+        let mut offset = 0;
+        for (i, &len) in self.data_lens.iter().enumerate() {
+            let len = len as usize;
+            let elem_start = i * elem_size;
+            data[elem_start..elem_start + len].copy_from_slice(&self.data[offset..offset + len]);
+            offset += len;
+        }
+        self.data = data;
+        elem_size
+    }
+}
+
 /// Represents statement parameters (a.k.a. parameter placeholders)
 pub struct Params {
     /// Parameter placeholder (name) indexes
     idxs: HashMap<&'static str,usize>,
+    /// Parameter placeholder names in the order of their positions
+    names: Vec<&'static str>,
     /// OCI bind handles
     binds: Vec<Ptr<OCIBind>>,
     /// NULL indicators
@@ -18,17 +118,32 @@ pub struct Params {
     /// Map of arguments indexes (positions) to parameter placeholder indexes
     bind_order: Vec<u16>,
     /// Buffers used to keep and bind IN arguments or OUR arguments that were passed as None
-    buffers: Vec<Vec<u8>>
+    buffers: Vec<Vec<u8>>,
+    /// Array DML data that is being collected from the batch rows
+    batch: Option<Vec<BatchData>>,
+    /// Bound array DML data
+    batch_arrays: Vec<BatchData>,
+    /// Arguments that need to be rebound as temporary LOBs
+    lob_binds: Vec<LobBind>,
+    /// Environment where descriptors for the converted arguments are allocated
+    #[cfg(feature="chrono")]
+    env: Ptr<OCIEnv>,
+    /// Descriptors that were created to bind arguments of foreign types. They are kept until the next bind.
+    #[cfg(feature="chrono")]
+    arg_descs: Vec<Box<dyn std::any::Any + Send + Sync>>,
 }
 
 impl Params {
-    pub(super) fn new(stmt: &OCIStmt, err: &OCIError) -> Result<Option<Self>> {
+    pub(super) fn new(stmt: &OCIStmt, env: &OCIEnv, err: &OCIError) -> Result<Option<Self>> {
+        #[cfg(not(feature="chrono"))]
+        let _ = env;
         let num_binds : u32 = attr::get(OCI_ATTR_BIND_COUNT, OCI_HTYPE_STMT, stmt, err)?;
         if num_binds == 0 {
             Ok(None)
         } else {
             let num_binds = num_binds as usize;
             let mut idxs  = HashMap::with_capacity(num_binds);
+            let mut names = Vec::with_capacity(num_binds);
             let mut binds = Vec::with_capacity(num_binds);
 
             let mut bind_names      = vec![     ptr::null_mut::<u8>(); num_binds];
@@ -48,25 +163,33 @@ impl Params {
             )?;
 
             for i in 0..found as usize {
+                let name = unsafe { std::slice::from_raw_parts(bind_names[i], bind_name_lens[i] as usize) };
+                let name = unsafe { std::str::from_utf8_unchecked(name) };
+                // The `idxs` hash map and `names` won't outlive `Params` and the latter won't outlive `Statement`.
+                // While `str` for names that we created above will only live as long as the containing `Statement`,
+                // within `Params` they can be seen as static as they will be alive longer.
                 if dups[i] == 0 {
-                    let name = unsafe { std::slice::from_raw_parts(bind_names[i], bind_name_lens[i] as usize) };
-                    let name = unsafe { std::str::from_utf8_unchecked(name) };
-                    // The `idxs` and `names` hash maps won't outlive `Params` and the latter won't outlive `Statement`.
-                    // While `str` for names that we created above will only live as long as the containing `Statement`,
-                    // within `Params` they can be seen as static as they will be alive longer.
                     idxs.insert(name, i);
                 }
+                names.push(name);
                 binds.push(Ptr::new(oci_binds[i]));
             }
 
             let buffers = vec![Vec::new(); num_binds];
 
             Ok(Some(Self{
-                idxs, binds,
+                idxs, names, binds,
                 nulls: Vec::with_capacity(num_binds),
                 data_lens: Vec::with_capacity(num_binds),
                 bind_order: Vec::with_capacity(num_binds),
                 buffers,
+                batch: None,
+                batch_arrays: Vec::new(),
+                lob_binds: Vec::new(),
+                #[cfg(feature="chrono")]
+                env: Ptr::from(env),
+                #[cfg(feature="chrono")]
+                arg_descs: Vec::new(),
             }))
         }
     }
@@ -80,6 +203,11 @@ impl Params {
         }
     }
 
+    /// Returns parameter placeholder names in the order of their positions.
+    pub(crate) fn names(&self) -> &[&str] {
+        &self.names
+    }
+
     /// Returns index of the parameter placeholder.
     pub(crate) fn index_of(&self, name: &str) -> Result<usize> {
         // Assume `name` is already uppercase and use it as-is first.
@@ -145,6 +273,9 @@ impl Params {
 
     /// Binds an INOUT or an OUT argument to a parameter placeholder at the specified position in the SQL statement.
     pub(crate) fn bind(&mut self, idx: usize, sql_type: u16, data: *mut c_void, data_len: usize, buff_size: usize, stmt: &OCIStmt, err: &OCIError) -> Result<()> {
+        if let Some(batch) = self.batch.as_mut() {
+            return batch[idx].push(sql_type, data, data_len);
+        }
         self.bind_order.push(idx as _);
         self.nulls[idx] = if data_len == 0 { OCI_IND_NULL } else { OCI_IND_NOTNULL };
         self.data_lens[idx] = data_len as _;
@@ -157,9 +288,95 @@ impl Params {
         )
     }
 
+    /**
+    Binds an IN argument which data is copied into a temporary LOB before the statement is executed.
+
+    The placeholder is not bound here. The statement takes these arguments via `take_lob_binds`
+    after all arguments are bound, creates temporary LOBs for them, and binds those via `bind_lob`.
+    The data must therefore stay valid until the statement is executed.
+    */
+    pub(crate) fn bind_lob_in(&mut self, idx: usize, sql_type: u16, charset_form: u8, data: *const c_void, data_len: usize, stmt: &OCIStmt, err: &OCIError) -> Result<()> {
+        if self.batch.is_some() {
+            return Err(Error::new("LOB arguments cannot be used in batches"));
+        }
+        if data_len == 0 {
+            return self.bind_null(idx, sql_type, stmt, err);
+        }
+        self.bind_order.push(idx as _);
+        self.nulls[idx] = OCI_IND_NOTNULL;
+        self.lob_binds.push(LobBind { idx, sql_type, charset_form, data: Ptr::new(data as _), len: data_len });
+        Ok(())
+    }
+
+    /// Returns the environment where descriptors for the converted arguments are allocated.
+    #[cfg(feature="chrono")]
+    pub(crate) fn env(&self) -> &Ptr<OCIEnv> {
+        &self.env
+    }
+
+    /// Binds a descriptor that was created to represent an argument of a foreign type.
+    /// `Params` take ownership of the descriptor and keep it until the next bind.
+    #[cfg(feature="chrono")]
+    pub(crate) fn bind_descriptor<T>(&mut self, idx: usize, desc: Descriptor<T>, stmt: &OCIStmt, err: &OCIError) -> Result<()>
+    where T: DescriptorType + 'static
+        , T::OCIType: OCIStruct
+    {
+        let len = std::mem::size_of::<*mut T::OCIType>();
+        // Boxed descriptor won't move when it is stored
+        let desc = Box::new(desc);
+        self.bind(idx, T::sql_type(), desc.as_ptr() as _, len, len, stmt, err)?;
+        self.arg_descs.push(desc);
+        Ok(())
+    }
+
+    /// Returns arguments that were bound by `bind_lob_in`. The statement is expected
+    /// to copy their data into temporary LOBs and bind them via `bind_lob`.
+    pub(crate) fn take_lob_binds(&mut self) -> Vec<LobBind> {
+        std::mem::take(&mut self.lob_binds)
+    }
+
+    /// Binds the parameter placeholder to a LOB locator.
+    pub(crate) fn bind_lob(&mut self, idx: usize, sql_type: u16, charset_form: u8, locator: *mut c_void, stmt: &OCIStmt, err: &OCIError) -> Result<()> {
+        let len = std::mem::size_of::<*mut OCILobLocator>();
+        oci::bind_by_pos(
+            stmt, self.binds[idx].as_mut_ptr(), err,
+            (idx + 1) as _, locator, len as _, sql_type,
+            &mut self.nulls[idx],
+            ptr::null_mut(),
+            OCI_DEFAULT
+        )?;
+        attr::set(OCI_ATTR_CHARSET_FORM, charset_form, OCI_HTYPE_BIND, self.binds[idx].as_ref(), err)
+    }
+
+    /// Sets the maximum number of characters that the server reserves for the bound data.
+    /// This makes the bind use character rather than byte length semantics.
+    pub(crate) fn set_max_char_size(&mut self, idx: usize, num_chars: u32, err: &OCIError) -> Result<()> {
+        if let Some(batch) = self.batch.as_mut() {
+            batch[idx].max_chars = batch[idx].max_chars.max(num_chars);
+            return Ok(());
+        }
+        attr::set(OCI_ATTR_MAXCHAR_SIZE, num_chars, OCI_HTYPE_BIND, self.binds[idx].as_ref(), err)
+    }
+
+    /// Sets the character set form of the bound text - `SQLCS_IMPLICIT` (database character set)
+    /// or `SQLCS_NCHAR` (national character set).
+    pub(crate) fn set_charset_form(&mut self, idx: usize, form: u8, err: &OCIError) -> Result<()> {
+        if let Some(batch) = self.batch.as_mut() {
+            batch[idx].charset_form = form;
+            return Ok(());
+        }
+        attr::set(OCI_ATTR_CHARSET_FORM, form, OCI_HTYPE_BIND, self.binds[idx].as_ref(), err)
+    }
+
     /// Marks bind as having a NULL value despite having a buffer.
     pub(crate) fn mark_as_null(&mut self, idx: usize) {
-        self.nulls[idx] = OCI_IND_NULL;
+        if let Some(batch) = self.batch.as_mut() {
+            if let Some(ind) = batch[idx].nulls.last_mut() {
+                *ind = OCI_IND_NULL;
+            }
+        } else {
+            self.nulls[idx] = OCI_IND_NULL;
+        }
     }
 
     /// Checks whether previously bound placeholders are rebound.
@@ -173,6 +390,10 @@ impl Params {
     pub(crate) fn bind_args(&mut self, stmt: &OCIStmt, err: &OCIError, args: &mut impl ToSql) -> Result<()> {
         let prior_binds = self.bind_order.clone();
         self.bind_order.clear();
+        self.batch_arrays.clear();
+        self.lob_binds.clear();
+        #[cfg(feature="chrono")]
+        self.arg_descs.clear();
 
         self.nulls.clear();
         self.nulls.resize(self.nulls.capacity(), OCI_IND_NULL);
@@ -188,6 +409,107 @@ impl Params {
         }
     }
 
+    /**
+    Binds arrays of values collected from all batch rows to SQL parameter placeholders.
+
+    Each row is bound as usual, but instead of binding the values their data is appended
+    to per-placeholder arrays. These arrays are then bound, and they are kept until the
+    next (batch or regular) bind.
+
+    `dynamic_idx` is the index of the placeholder that is bound dynamically (see [`Params::bind_dynamic`])
+    and thus is not bound by the batch rows.
+    */
+    pub(crate) fn bind_batch<I>(&mut self, stmt: &OCIStmt, err: &OCIError, rows: I, dynamic_idx: Option<usize>) -> Result<usize>
+    where I: IntoIterator, I::Item: ToSql
+    {
+        let num_binds = self.binds.len();
+        self.batch = Some((0..num_binds).map(|_| BatchData::new()).collect());
+        #[cfg(feature="chrono")]
+        self.arg_descs.clear();
+
+        let res = self.collect_batch(stmt, err, rows, dynamic_idx);
+        let mut batch = self.batch.take().unwrap_or_default();
+        let num_rows = res?;
+
+        self.bind_order.clear();
+        for (idx, data) in batch.iter_mut().enumerate() {
+            if dynamic_idx == Some(idx) {
+                continue;
+            }
+            let elem_size = data.make_array();
+            oci::bind_by_pos(
+                stmt, self.binds[idx].as_mut_ptr(), err,
+                (idx + 1) as _, data.data.as_mut_ptr() as _, elem_size as _, data.sql_type,
+                data.nulls.as_mut_ptr(),
+                data.data_lens.as_mut_ptr(),
+                OCI_DEFAULT
+            )?;
+            if data.charset_form > 0 {
+                self.set_charset_form(idx, data.charset_form, err)?;
+            }
+            if data.max_chars > 0 {
+                self.set_max_char_size(idx, data.max_chars, err)?;
+            }
+            self.bind_order.push(idx as _);
+        }
+        self.batch_arrays = batch;
+        Ok(num_rows)
+    }
+
+    /// Collects values of the batch rows. Returns the number of collected rows.
+    fn collect_batch<I>(&mut self, stmt: &OCIStmt, err: &OCIError, rows: I, dynamic_idx: Option<usize>) -> Result<usize>
+    where I: IntoIterator, I::Item: ToSql
+    {
+        let mut num_rows = 0;
+        for (row_num, mut row) in rows.into_iter().enumerate() {
+            row.bind_to(0, self, stmt, err)?;
+            let is_complete = self.batch.as_ref().is_some_and(|batch|
+                batch.iter().enumerate().all(|(idx, data)|
+                    if dynamic_idx == Some(idx) { data.nulls.is_empty() } else { data.nulls.len() == row_num + 1 }
+                )
+            );
+            if !is_complete {
+                return Err(Error::msg(format!("Batch row {} does not bind all parameters exactly once", row_num)));
+            }
+            num_rows += 1;
+        }
+        Ok(num_rows)
+    }
+
+    /**
+    Binds the parameter placeholder in the `OCI_DATA_AT_EXEC` mode. OCI then calls the provided
+    callbacks during the execution to get the IN data and buffers for the OUT data of the placeholder.
+
+    The callback contexts must stay valid (and must not move) until the statement execution completes.
+    */
+    pub(crate) fn bind_dynamic(
+        &mut self, idx: usize, sql_type: u16, max_size: usize,
+        in_ctx: *mut c_void, in_callback: OCICallbackInBind,
+        out_ctx: *mut c_void, out_callback: Option<OCICallbackOutBind>,
+        stmt: &OCIStmt, err: &OCIError
+    ) -> Result<()> {
+        oci::bind_by_pos(
+            stmt, self.binds[idx].as_mut_ptr(), err,
+            (idx + 1) as _, ptr::null_mut(), max_size as _, sql_type,
+            ptr::null_mut(), ptr::null_mut(),
+            OCI_DATA_AT_EXEC
+        )?;
+        oci::bind_dynamic(self.binds[idx].as_ref(), err, in_ctx, in_callback, out_ctx, out_callback)?;
+        // Record the dynamic bind to ensure that it is replaced by the next bind
+        self.bind_order.push(idx as _);
+        Ok(())
+    }
+
+    /**
+    Binds the IN argument, which data is provided in pieces by the `callback` during the execution.
+    */
+    pub(crate) fn bind_piecewise(&mut self, idx: usize, sql_type: u16, ctx: *mut c_void, callback: OCICallbackInBind, stmt: &OCIStmt, err: &OCIError) -> Result<()> {
+        if self.batch.is_some() {
+            return Err(Error::new("piecewise arguments cannot be used in batches"));
+        }
+        self.bind_dynamic(idx, sql_type, i32::MAX as usize, ctx, callback, ptr::null_mut(), None, stmt, err)
+    }
+
     pub(crate) fn set_out_to_null(&mut self) {
         self.nulls.fill(OCI_IND_NULL);
         self.data_lens.fill(0);
@@ -290,6 +612,43 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn bind_names() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            SELECT employee_id
+              FROM hr.employees
+             WHERE department_id = :dept
+               AND hire_date >= :hired_from
+               AND salary < :max_salary
+        ")?;
+        assert_eq!(stmt.bind_count()?, 3);
+        assert_eq!(stmt.bind_names(), ["DEPT", "HIRED_FROM", "MAX_SALARY"]);
+
+        let stmt = session.prepare("
+            INSERT INTO hr.locations (location_id, state_province, city, postal_code, street_address)
+            VALUES (:id, :na, :na, :code, :na)
+        ")?;
+        assert_eq!(stmt.bind_count()?, 5);
+        assert_eq!(stmt.bind_names(), ["ID", "NA", "NA", "CODE", "NA"]);
+
+        let stmt = session.prepare("
+          BEGIN
+            INSERT INTO hr.locations (location_id, state_province, city, postal_code, street_address)
+            VALUES (:id, :na, :na, :code, :na);
+          END;
+        ")?;
+        assert_eq!(stmt.bind_count()?, 3);
+        assert_eq!(stmt.bind_names(), ["ID", "NA", "CODE"]);
+
+        let stmt = session.prepare("SELECT * FROM dual")?;
+        assert_eq!(stmt.bind_count()?, 0);
+        assert!(stmt.bind_names().is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn no_colon_arg_names() -> std::result::Result<(),Box<dyn std::error::Error>> {
         let session = crate::test_env::get_session()?;
@@ -0,0 +1,334 @@
+//! Explicitly assembled statement arguments
+
+use super::{Params, ToSql};
+use crate::{Result, oci::{OCIStmt, OCIError}};
+use std::{collections::HashMap, iter::FromIterator};
+
+/// Parameter placeholder to which an argument is bound
+enum Target<'a> {
+    Index(usize),
+    Name(&'a str),
+}
+
+impl Target<'_> {
+    fn index(&self, params: &Params) -> Result<usize> {
+        match self {
+            Target::Index(idx) => Ok(*idx),
+            Target::Name(name) => params.index_of(name),
+        }
+    }
+}
+
+/**
+Statement arguments that are bound explicitly either by the placeholder position or by
+the placeholder name.
+
+`Binds` is an alternative to tuples of arguments. It is useful when a statement has a lot
+of parameters or when the set of arguments is assembled at run time.
+
+# Example
+
+🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+```
+use sibyl::Binds;
+
+# #[cfg(feature="blocking")]
+# fn main() -> sibyl::Result<()> {
+# let session = sibyl::test_env::get_session()?;
+let stmt = session.prepare("
+    SELECT first_name, last_name
+      FROM hr.employees
+     WHERE department_id = :dept
+       AND job_id = :job
+")?;
+let dept = 30;
+let args = Binds::new()
+    .at(0, &dept)
+    .by_name(":JOB", "PU_MAN");
+let row = stmt.query_single(args)?.expect("a row");
+let last_name: &str = row.get(1)?;
+assert_eq!(last_name, "Raphaely");
+# Ok(())
+# }
+# #[cfg(feature="nonblocking")]
+# fn main() -> sibyl::Result<()> {
+# sibyl::block_on(async {
+# let session = sibyl::test_env::get_session().await?;
+# let stmt = session.prepare("
+#     SELECT first_name, last_name
+#       FROM hr.employees
+#      WHERE department_id = :dept
+#        AND job_id = :job
+# ").await?;
+# let dept = 30;
+# let args = Binds::new()
+#     .at(0, &dept)
+#     .by_name(":JOB", "PU_MAN");
+# let row = stmt.query_single(args).await?.expect("a row");
+# let last_name: &str = row.get(1)?;
+# assert_eq!(last_name, "Raphaely");
+# Ok(()) })
+# }
+```
+*/
+pub struct Binds<'a> {
+    args: Vec<(Target<'a>, Box<dyn ToSql + 'a>)>,
+}
+
+impl<'a> Binds<'a> {
+    /// Creates an empty set of arguments.
+    pub fn new() -> Self {
+        Self { args: Vec::new() }
+    }
+
+    /**
+    Adds an argument that is bound to the parameter placeholder at the specified position.
+
+    # Parameters
+
+    * `pos` - zero-based position of the parameter placeholder in the statement
+    * `arg` - argument value, reference (IN) or mutable reference (OUT or INOUT)
+    */
+    pub fn at(mut self, pos: usize, arg: impl ToSql + 'a) -> Self {
+        self.args.push((Target::Index(pos), Box::new(arg)));
+        self
+    }
+
+    /**
+    Adds an argument that is bound to the named parameter placeholder.
+
+    # Parameters
+
+    * `name` - name of the parameter placeholder, for example `":ID"`
+    * `arg` - argument value, reference (IN) or mutable reference (OUT or INOUT)
+    */
+    pub fn by_name(mut self, name: &'a str, arg: impl ToSql + 'a) -> Self {
+        self.args.push((Target::Name(name), Box::new(arg)));
+        self
+    }
+
+    /// Returns the number of arguments.
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Returns `true` if there are no arguments.
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+}
+
+impl Default for Binds<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ToSql for Binds<'_> {
+    fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        for (target, arg) in self.args.iter_mut() {
+            let idx = target.index(params)?;
+            pos = arg.bind_to(idx, params, stmt, err)?;
+        }
+        Ok(pos)
+    }
+
+    fn update_from_bind(&mut self, mut pos: usize, params: &Params) -> Result<usize> {
+        for (target, arg) in self.args.iter_mut() {
+            let idx = target.index(params)?;
+            pos = arg.update_from_bind(idx, params)?;
+        }
+        Ok(pos)
+    }
+}
+
+/**
+An object-safe counterpart of `ToSql` for IN arguments.
+
+`ToSql` binds arguments via `&mut self`, and thus its trait objects can only be created from
+mutable references. `ToSqlDyn` binds the argument via a shared reference. It is implemented for
+all types which references can be used as IN arguments. Therefore, `&dyn ToSqlDyn` trait objects
+can be collected into slices, vectors, and maps of arguments that are assembled at run time.
+See [`BindMap`].
+*/
+pub trait ToSqlDyn : Send + Sync {
+    /**
+    Binds itself to the SQL parameter placeholder as an IN argument.
+
+    # Returns
+
+    The index of the placeholder for the next argument.
+    */
+    fn bind_dyn(&self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize>;
+}
+
+impl<T> ToSqlDyn for T where T: Send + Sync, for<'r> &'r T: ToSql {
+    fn bind_dyn(&self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        let mut arg = self;
+        arg.bind_to(pos, params, stmt, err)
+    }
+}
+
+/// Binds named IN arguments. Returns the index of the placeholder for the next argument.
+fn bind_named_dyn<'a>(args: impl Iterator<Item = (&'a str, &'a dyn ToSqlDyn)>, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+    for (name, arg) in args {
+        let idx = params.index_of(name)?;
+        pos = arg.bind_dyn(idx, params, stmt, err)?;
+    }
+    Ok(pos)
+}
+
+impl ToSql for &[(&str, &dyn ToSqlDyn)] {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        bind_named_dyn(self.iter().map(|&(name, arg)| (name, arg)), pos, params, stmt, err)
+    }
+}
+
+/**
+Named IN arguments which set is decided at run time.
+
+# Example
+
+🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+```
+use sibyl::{BindMap, ToSqlDyn};
+use std::{collections::HashMap, iter::FromIterator};
+
+# #[cfg(feature="blocking")]
+# fn main() -> sibyl::Result<()> {
+# let session = sibyl::test_env::get_session()?;
+let dept = 50;
+let job = "ST_CLERK";
+let mut filters : HashMap<&str, &dyn ToSqlDyn> = HashMap::new();
+filters.insert("department_id", &dept);
+filters.insert("job_id", &job);
+
+let mut sql = String::from("SELECT Count(*) FROM hr.employees WHERE 1 = 1");
+let mut args = BindMap::new();
+for (&column, &value) in filters.iter() {
+    sql.push_str(&format!(" AND {} = :{}", column, column));
+    args.insert(column, value);
+}
+let stmt = session.prepare(&sql)?;
+let count : u32 = stmt.query_scalar(args)?;
+assert_eq!(count, 20);
+# Ok(())
+# }
+# #[cfg(feature="nonblocking")]
+# fn main() -> sibyl::Result<()> {
+# sibyl::block_on(async {
+# let session = sibyl::test_env::get_session().await?;
+# let dept = 50;
+# let job = "ST_CLERK";
+# let mut filters : HashMap<&str, &dyn ToSqlDyn> = HashMap::new();
+# filters.insert("department_id", &dept);
+# filters.insert("job_id", &job);
+# let mut sql = String::from("SELECT Count(*) FROM hr.employees WHERE 1 = 1");
+# let mut args = BindMap::new();
+# for (&column, &value) in filters.iter() {
+#     sql.push_str(&format!(" AND {} = :{}", column, column));
+#     args.insert(column, value);
+# }
+# let stmt = session.prepare(&sql).await?;
+# let count : u32 = stmt.query_scalar(args).await?;
+# assert_eq!(count, 20);
+# Ok(()) })
+# }
+```
+*/
+#[derive(Default)]
+pub struct BindMap<'a> {
+    args: HashMap<&'a str, &'a dyn ToSqlDyn>,
+}
+
+impl<'a> BindMap<'a> {
+    /// Creates an empty map of arguments.
+    pub fn new() -> Self {
+        Self { args: HashMap::new() }
+    }
+
+    /**
+    Adds the argument that is bound to the named parameter placeholder. Returns the argument that
+    was previously added for the same placeholder, if any.
+
+    # Parameters
+
+    * `name` - name of the parameter placeholder, for example `":ID"` (the leading colon is optional)
+    * `arg` - argument value
+    */
+    pub fn insert(&mut self, name: &'a str, arg: &'a dyn ToSqlDyn) -> Option<&'a dyn ToSqlDyn> {
+        self.args.insert(name, arg)
+    }
+
+    /// Returns the number of arguments.
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Returns `true` if there are no arguments.
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+}
+
+impl<'a> From<HashMap<&'a str, &'a dyn ToSqlDyn>> for BindMap<'a> {
+    fn from(args: HashMap<&'a str, &'a dyn ToSqlDyn>) -> Self {
+        Self { args }
+    }
+}
+
+impl<'a> FromIterator<(&'a str, &'a dyn ToSqlDyn)> for BindMap<'a> {
+    fn from_iter<I: IntoIterator<Item = (&'a str, &'a dyn ToSqlDyn)>>(iter: I) -> Self {
+        Self { args: iter.into_iter().collect() }
+    }
+}
+
+impl ToSql for BindMap<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        bind_named_dyn(self.args.iter().map(|(&name, &arg)| (name, arg)), pos, params, stmt, err)
+    }
+}
+
+impl ToSql for &BindMap<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        bind_named_dyn(self.args.iter().map(|(&name, &arg)| (name, arg)), pos, params, stmt, err)
+    }
+}
+
+/**
+A trait for types that can be converted into arguments of a single row of a batched DML.
+It is an inverse of `FromRow`.
+
+# Example
+
+```
+use sibyl::{ToRow, Binds};
+
+struct Region {
+    id: u32,
+    name: String,
+}
+
+impl ToRow for Region {
+    fn to_row(&self) -> Binds<'_> {
+        Binds::new()
+            .by_name(":ID", &self.id)
+            .by_name(":NAME", self.name.as_str())
+    }
+}
+```
+*/
+pub trait ToRow {
+    /// Returns arguments that bind values of this row to parameter placeholders.
+    fn to_row(&self) -> Binds<'_>;
+}
+
+impl<T: ToRow> ToRow for &T {
+    fn to_row(&self) -> Binds<'_> {
+        (*self).to_row()
+    }
+}
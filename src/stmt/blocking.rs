@@ -1,12 +1,13 @@
 //! Blocking SQL statement methods
 
 use super::{
-    Statement, Cursor, Params, Columns, Rows,
-    cols::DEFAULT_LONG_BUFFER_SIZE,
+    Statement, Cursor, Params, Rows, BatchResult, batch::BatchRowIds, TempLob, IDENTITY_COLUMN_SQL, ROW_ESTIMATE_SQL,
+    cols::{ColumnInfo, DEFAULT_LONG_BUFFER_SIZE},
 };
-use crate::{Error, Result, oci::{self, *}, Session, ToSql, Row};
-use parking_lot::RwLock;
+use crate::{Error, Result, oci::{self, *}, Session, CLOB, BLOB, ToSql, ToRow, FromSql, FromRow, Row, RowID, env::HandleKind};
+use parking_lot::{Mutex, RwLock};
 use once_cell::sync::OnceCell;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 impl<'a> Statement<'a> {
     /// Creates a new statement
@@ -18,8 +19,10 @@ impl<'a> Statement<'a> {
             sql.as_ptr(), sql.len() as u32,
             OCI_NTV_SYNTAX, OCI_DEFAULT
         )?;
-        let params = Params::new(&stmt, &err)?.map(|params| RwLock::new(params));
-        let stmt = Self {session, svc: session.get_svc(), stmt, params, cols: OnceCell::new(), err, max_long: DEFAULT_LONG_BUFFER_SIZE};
+        let params = Params::new(&stmt, session.as_ref(), &err)?.map(|params| RwLock::new(params));
+        let svc = session.get_svc();
+        let _handle = svc.track(HandleKind::Statement);
+        let stmt = Self {session, svc, stmt, params, cols: OnceCell::new(), err, max_long: DEFAULT_LONG_BUFFER_SIZE, piecewise_long: false, lob_prefetch: 0, prefetch_rows: AtomicU32::new(0), fetch_array: AtomicU32::new(0), preserve_numbers: session.preserves_number_precision(), defines: Vec::new(), _handle, snap_in: None, snap_out: None, temp_lobs: Mutex::new(Vec::new())};
         stmt.set_prefetch_rows(10)?;
         Ok(stmt)
     }
@@ -33,12 +36,39 @@ impl<'a> Statement<'a> {
         }
     }
 
+    /// Binds `LobArg` arguments as temporary LOBs.
+    fn bind_lob_args(&self) -> Result<()> {
+        let mut temp_lobs = self.temp_lobs.lock();
+        temp_lobs.clear();
+        if let Some(params) = &self.params {
+            let mut params = params.write();
+            for arg in params.take_lob_binds() {
+                let lob = if arg.sql_type == SQLT_CHR {
+                    let lob = CLOB::temp(self.session, arg.charset_form(), Cache::No)?;
+                    lob.append(arg.text()?)?;
+                    TempLob::Text(lob.into_bound())
+                } else {
+                    let lob = BLOB::temp(self.session, Cache::No)?;
+                    lob.append(arg.data())?;
+                    TempLob::Binary(lob.into_bound())
+                };
+                params.bind_lob(arg.idx, lob.sql_type(), lob.charset_form(&self.err), lob.locator_ptr(), &self.stmt, &self.err)?;
+                temp_lobs.push(lob);
+            }
+        }
+        Ok(())
+    }
+
     /// Executes the prepared statement. Returns the OCI result code from OCIStmtExecute.
     fn exec(&self, stmt_type: u16, args: &mut impl ToSql) -> Result<i32>{
         self.bind_args(args)?;
+        self.bind_lob_args()?;
 
+        self.prefetch_for_execute(stmt_type)?;
         let iters: u32 = if stmt_type == OCI_STMT_SELECT { 0 } else { 1 };
-        oci::stmt_execute(self.as_ref(), &self.stmt, &self.err, iters, 0, OCI_DEFAULT)
+        let res = oci::stmt_execute(self.as_ref(), &self.stmt, &self.err, iters, 0, self.snapshot_in(), self.snapshot_out(), OCI_DEFAULT)?;
+        self.prefetch_for_fetch(stmt_type)?;
+        Ok(res)
     }
 
     /**
@@ -98,6 +128,209 @@ impl<'a> Statement<'a> {
         Ok(num_rows)
     }
 
+    /**
+    Executes the prepared statement once for each row in the batch using array DML.
+    Returns the number of rows affected.
+
+    The execution stops at the first row that fails. The error of that row is returned,
+    while changes made by the rows that preceded it are kept.
+
+    An empty batch is not sent to the server. The statement is not executed and 0 is returned.
+
+    # Parameters
+
+    * `rows` - arguments for each statement execution. Each row is a single argument or a tuple
+      of arguments, which are bound as IN arguments. All rows must bind the same parameters.
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        INSERT INTO hr.regions (region_id, region_name) VALUES (:id, :name)
+    ")?;
+    let num_rows = stmt.execute_batch(&[
+        (( ":ID", 11 ), ( ":NAME", "Antarctica" )),
+        (( ":ID", 12 ), ( ":NAME", "Oceania"    )),
+        (( ":ID", 13 ), ( ":NAME", "Arctic"     )),
+    ])?;
+    assert_eq!(num_rows, 3);
+    # session.rollback()?;
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn execute_batch<T: ToSql + Clone>(&self, rows: &[T]) -> Result<usize> {
+        let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+        if stmt_type == OCI_STMT_SELECT {
+            return Err( Error::new("Use `query` to execute SELECT") );
+        }
+        if rows.is_empty() {
+            return Ok(0);
+        }
+        self.bind_batch(rows)?;
+        oci::stmt_execute(self.as_ref(), &self.stmt, &self.err, rows.len() as _, 0, std::ptr::null(), std::ptr::null(), OCI_DEFAULT)?;
+        self.row_count()
+    }
+
+    /**
+    Executes the prepared DML statement once for each row produced by the iterator using array DML.
+    Each row is converted into statement arguments by its [`ToRow`] implementation.
+    Returns the number of rows affected.
+
+    All rows are bound and then sent to the database in a single batched execution. As with
+    [`Statement::execute_batch()`], the execution stops at the first row that fails.
+
+    # Parameters
+
+    * `rows` - rows (or references to rows) to insert
+
+    # Example
+
+    ```
+    use sibyl::{ToRow, Binds};
+
+    struct Region {
+        id: u32,
+        name: &'static str,
+    }
+
+    impl ToRow for Region {
+        fn to_row(&self) -> Binds<'_> {
+            Binds::new()
+                .by_name(":ID", &self.id)
+                .by_name(":NAME", self.name)
+        }
+    }
+
+    # let session = sibyl::test_env::get_session()?;
+    let regions = vec![
+        Region { id: 11, name: "Antarctica" },
+        Region { id: 12, name: "Oceania" },
+    ];
+    let stmt = session.prepare("
+        INSERT INTO hr.regions (region_id, region_name) VALUES (:id, :name)
+    ")?;
+    let num_rows = stmt.insert_all(&regions)?;
+    assert_eq!(num_rows, 2);
+    # session.rollback()?;
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn insert_all<I, T>(&self, rows: I) -> Result<usize>
+    where I: IntoIterator<Item=T>, T: ToRow
+    {
+        let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+        if stmt_type == OCI_STMT_SELECT {
+            return Err( Error::new("Use `query` to execute SELECT") );
+        }
+        let rows: Vec<T> = rows.into_iter().collect();
+        if rows.is_empty() {
+            return Ok(0);
+        }
+        let num_rows = self.bind_rows(rows.iter().map(ToRow::to_row))?;
+        oci::stmt_execute(self.as_ref(), &self.stmt, &self.err, num_rows as _, 0, std::ptr::null(), std::ptr::null(), OCI_DEFAULT)?;
+        self.row_count()
+    }
+
+    /**
+    Executes the prepared statement once for each row in the batch using array DML in the
+    batch errors mode, where rows that fail do not abort the execution of the entire batch.
+    Returns the number of successfully processed rows and errors of the failed rows.
+
+    # Parameters
+
+    * `rows` - arguments for each statement execution. Each row is a single argument or a tuple
+      of arguments, which are bound as IN arguments. All rows must bind the same parameters.
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        INSERT INTO hr.regions (region_id, region_name) VALUES (:id, :name)
+    ")?;
+    // Region 2 already exists
+    let res = stmt.execute_batch_with_errors(&[
+        (( ":ID", 11 ), ( ":NAME", "Antarctica" )),
+        (( ":ID",  2 ), ( ":NAME", "Americas"   )),
+        (( ":ID", 12 ), ( ":NAME", "Oceania"    )),
+    ])?;
+    assert_eq!(res.row_count(), 2);
+    assert_eq!(res.errors().len(), 1);
+    let (row_offset, err) = &res.errors()[0];
+    assert_eq!(*row_offset, 1);
+    match err {
+        sibyl::Error::Oracle(code, _) => assert_eq!(*code, 1), // unique constraint violated
+        _ => panic!("unexpected error {:?}", err)
+    }
+    # session.rollback()?;
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn execute_batch_with_errors<T: ToSql + Clone>(&self, rows: &[T]) -> Result<BatchResult> {
+        let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+        if stmt_type == OCI_STMT_SELECT {
+            return Err( Error::new("Use `query` to execute SELECT") );
+        }
+        if rows.is_empty() {
+            return Ok(BatchResult::default());
+        }
+        self.bind_batch(rows)?;
+        let res = oci::stmt_execute(self.as_ref(), &self.stmt, &self.err, rows.len() as _, 0, std::ptr::null(), std::ptr::null(), OCI_BATCH_ERRORS);
+        self.check_batch_result(res)?;
+        self.batch_result()
+    }
+
+    /**
+    Executes the prepared DML statement once for each row in the batch using array DML and
+    returns ROWIDs that the statement's `RETURNING ROWID INTO` clause reported for the affected rows.
+
+    The returned ROWIDs follow the order of the batch rows. Thus, for an INSERT, the ROWID
+    of each inserted row is at the same index as the row in `rows`.
+
+    # Parameters
+
+    * `rows` - arguments for each statement execution. Each row is a single argument or a tuple
+      of arguments, which are bound as IN arguments. All rows must bind the same parameters.
+      Rows must not bind the ROWID placeholder.
+    * `rowid_param` - name of the placeholder into which the RETURNING clause returns the ROWID
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        INSERT INTO hr.regions (region_id, region_name) VALUES (:id, :name)
+        RETURNING ROWID INTO :row_id
+    ")?;
+    let rowids = stmt.execute_batch_returning_rowid(&[
+        (( ":ID", 11 ), ( ":NAME", "Antarctica" )),
+        (( ":ID", 12 ), ( ":NAME", "Oceania"    )),
+    ], ":ROW_ID")?;
+    assert_eq!(rowids.len(), 2);
+
+    let stmt = session.prepare("SELECT region_name FROM hr.regions WHERE rowid = :row_id")?;
+    let row = stmt.query_single(&rowids[1])?.expect("region row");
+    let name: &str = row.get(0)?;
+    assert_eq!(name, "Oceania");
+    # session.rollback()?;
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn execute_batch_returning_rowid<T: ToSql + Clone>(&self, rows: &[T], rowid_param: &str) -> Result<Vec<RowID>> {
+        let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+        if stmt_type == OCI_STMT_SELECT {
+            return Err( Error::new("Use `query` to execute SELECT") );
+        }
+        if rows.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut rowids = BatchRowIds::new(self);
+        self.bind_batch_returning_rowid(rows, rowid_param, &mut rowids)?;
+        oci::stmt_execute(self.as_ref(), &self.stmt, &self.err, rows.len() as _, 0, std::ptr::null(), std::ptr::null(), OCI_DEFAULT)?;
+        rowids.into_rowids()
+    }
+
     /**
     Executes the prepared SELECT statement. Returns "streaming iterator" over the returned rows.
 
@@ -160,7 +393,7 @@ impl<'a> Statement<'a> {
         let res = self.exec(stmt_type, &mut args)?;
 
         if self.cols.get().is_none() {
-            let cols = Columns::new(Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), self.max_long)?;
+            let cols = self.new_columns()?;
             self.cols.get_or_init(|| RwLock::new(cols));
         }
 
@@ -172,6 +405,29 @@ impl<'a> Statement<'a> {
         }
     }
 
+    /// Executes the query like [`Statement::query`] does, but returns rows that own the statement.
+    /// This allows the statement to be prepared and queried in the same expression.
+    #[doc(hidden)]
+    pub fn query_owned(self, mut args: impl ToSql) -> Result<Rows<'a>> {
+        let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+        if stmt_type != OCI_STMT_SELECT {
+            return Err( Error::new("Use `execute` to execute statements other than SELECT") );
+        }
+        let res = self.exec(stmt_type, &mut args)?;
+
+        if self.cols.get().is_none() {
+            let cols = self.new_columns()?;
+            self.cols.get_or_init(|| RwLock::new(cols));
+        }
+
+        match res {
+            OCI_SUCCESS | OCI_SUCCESS_WITH_INFO | OCI_NO_DATA => {
+                Ok( Rows::from_owned_query(res, self) )
+            }
+            _ => Err( Error::oci(&self.err, res) )
+        }
+    }
+
     /**
     Convenience method to execute a query that returns a single rows.
 
@@ -230,7 +486,7 @@ impl<'a> Statement<'a> {
         let res = self.exec(stmt_type, &mut args)?;
 
         if self.cols.get().is_none() {
-            let cols = Columns::new(Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), self.max_long)?;
+            let cols = self.new_columns()?;
             self.cols.get_or_init(|| RwLock::new(cols));
         }
 
@@ -242,149 +498,540 @@ impl<'a> Statement<'a> {
     }
 
     /**
-    Retrieves a single implicit result (cursor) in the order in which they were returned
-    from the PL/SQL procedure or block. If no more results are available, then `None` is
-    returned.
+    Executes the prepared SELECT statement that returns a single value - for example, `SELECT Count(*)` -
+    and returns the value of the first column of the first row.
 
-    PL/SQL provides a subprogram RETURN_RESULT in the DBMS_SQL package to return the result
-    of an executed statement. Only SELECT query result-sets can be implicitly returned by a
-    PL/SQL procedure or block.
+    # Parameters
 
-    `next_result` can be called iteratively by the application to retrieve each implicit
-    result from an executed PL/SQL statement. Applications retrieve each result-set sequentially
-    but can fetch rows from any result-set independently.
+    * `args` - SQL statement arguments - a single argument or a tuple of arguments
+
+    # Failures
+
+    Returns an error if the query does not return any rows or if the value of the first column
+    cannot be converted into `T`, which includes the value being NULL for types other than `Option`.
 
     # Example
 
     ```
-    use sibyl::Number;
-    use std::cmp::Ordering::Equal;
-
     # let session = sibyl::test_env::get_session()?;
     let stmt = session.prepare("
-        DECLARE
-            c1 SYS_REFCURSOR;
-            c2 SYS_REFCURSOR;
-        BEGIN
-            OPEN c1 FOR
-                SELECT department_name, first_name, last_name, salary
-                  FROM (
-                        SELECT first_name, last_name, salary, department_id
-                             , ROW_NUMBER() OVER (ORDER BY salary) ord
-                          FROM hr.employees
-                       ) e
-                  JOIN hr.departments d
-                    ON d.department_id = e.department_id
-                 WHERE ord = 1
-            ;
-            DBMS_SQL.RETURN_RESULT (c1);
-
-            OPEN c2 FOR
-                SELECT department_name, first_name, last_name, salary
-                  FROM (
-                        SELECT first_name, last_name, salary, department_id
-                             , MEDIAN(salary) OVER () median_salary
-                          FROM hr.employees
-                       ) e
-                  JOIN hr.departments d
-                    ON d.department_id = e.department_id
-                 WHERE salary = median_salary
-              ORDER BY department_name, last_name, first_name
-            ;
-            DBMS_SQL.RETURN_RESULT (c2);
-        END;
+        SELECT Count(*) FROM hr.employees WHERE department_id = :dept_id
     ")?;
-    let expected_lowest_salary = Number::from_int(2100, &session)?;
-    let expected_median_salary = Number::from_int(6200, &session)?;
-
-    stmt.execute(())?;
-
-    let lowest_payed_employee = stmt.next_result()?.unwrap();
+    let num_employees : u32 = stmt.query_scalar(30)?;
+    assert_eq!(num_employees, 6);
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn query_scalar<T>(&'a self, args: impl ToSql) -> Result<T> where T: for<'r> FromSql<'r> {
+        let row = self.query_single(args)?.ok_or_else(|| Error::new("query returned no rows"))?;
+        row.get(0)
+    }
 
-    let rows = lowest_payed_employee.rows()?;
-    let row = rows.next()?.unwrap();
+    /**
+    Executes the prepared SELECT statement that returns at most one row and maps that row into `T`.
 
-    let department_name : &str = row.get(0)?;
-    let first_name : &str = row.get(1)?;
-    let last_name : &str = row.get(2)?;
-    let salary : Number = row.get(3)?;
+    # Parameters
 
-    assert_eq!(department_name, "Shipping");
-    assert_eq!(first_name, "TJ");
-    assert_eq!(last_name, "Olson");
-    assert_eq!(salary.compare(&expected_lowest_salary)?, Equal);
+    * `args` - SQL statement arguments - a single argument or a tuple of arguments
 
-    let row = rows.next()?;
-    assert!(row.is_none());
+    # Returns
 
-    let median_salary_employees = stmt.next_result()?.unwrap();
+    `None` if the query did not return any rows.
 
-    let rows = median_salary_employees.rows()?;
+    # Failures
 
-    let row = rows.next()?.unwrap();
-    let department_name : &str = row.get(0)?;
-    let first_name : &str = row.get(1)?;
-    let last_name : &str = row.get(2)?;
-    let salary : Number = row.get(3)?;
+    Returns an error if the query returns more than one row or if the row cannot be converted into `T`.
 
-    assert_eq!(department_name, "Sales");
-    assert_eq!(first_name, "Amit");
-    assert_eq!(last_name, "Banda");
-    assert_eq!(salary.compare(&expected_median_salary)?, Equal);
+    # Example
 
-    let row = rows.next()?.unwrap();
+    ```
+    use sibyl::{FromRow, Row, Result};
 
-    let department_name : &str = row.get(0)?;
-    let first_name : &str = row.get(1)?;
-    let last_name : &str = row.get(2)?;
-    let salary : Number = row.get(3)?;
+    struct Employee {
+        name: String,
+        salary: u32,
+    }
 
-    assert_eq!(department_name, "Sales");
-    assert_eq!(first_name, "Charles");
-    assert_eq!(last_name, "Johnson");
-    assert_eq!(salary.compare(&expected_median_salary)?, Equal);
+    impl FromRow for Employee {
+        fn from_row(row: &Row) -> Result<Self> {
+            Ok(Self { name: row.get(0)?, salary: row.get(1)? })
+        }
+    }
 
-    let row = rows.next()?;
-    assert!(row.is_none());
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT last_name, salary FROM hr.employees WHERE employee_id = :id
+    ")?;
+    let employee : Option<Employee> = stmt.query_opt(107)?;
+    let employee = employee.unwrap();
+    assert_eq!(employee.name, "Lorentz");
+    assert_eq!(employee.salary, 4200);
 
-    assert!(stmt.next_result()?.is_none());
+    let employee : Option<Employee> = stmt.query_opt(99)?;
+    assert!(employee.is_none());
     # Ok::<(),Box<dyn std::error::Error>>(())
     ```
     */
-    pub fn next_result(&'a self) -> Result<Option<Cursor>> {
-        let mut stmt = Ptr::<OCIStmt>::null();
-        let mut stmt_type = 0u32;
-        let res = unsafe {
-            OCIStmtGetNextResult(self.stmt.as_ref(), self.err.as_ref(), stmt.as_mut_ptr(), &mut stmt_type, OCI_DEFAULT)
+    pub fn query_opt<T: FromRow>(&'a self, args: impl ToSql) -> Result<Option<T>> {
+        let rows = self.query(args)?;
+        let value = match rows.next()? {
+            Some(row) => T::from_row(&row)?,
+            None => return Ok(None)
         };
-        match res {
-            OCI_NO_DATA => Ok( None ),
-            OCI_SUCCESS => Ok( Some ( Cursor::implicit(stmt, self) ) ),
-            _ => Err( Error::oci(&self.err, res) )
+        if rows.next()?.is_some() {
+            return Err( Error::new("query returned more than one row") );
         }
+        Ok(Some(value))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::*;
+    /**
+    Executes the prepared SELECT statement and maps all returned rows into `T`.
 
-    #[test]
-    fn stmt_args() -> std::result::Result<(),Box<dyn std::error::Error>> {
-        let session = crate::test_env::get_session()?;
+    # Parameters
 
-        let stmt = session.prepare("
-            INSERT INTO hr.countries
-                   ( country_id, country_name, region_id )
-            VALUES ( :country_id, :country_name
-                   , (
-                       SELECT region_id
-                         FROM hr.regions
-                        WHERE region_name = :region_name
-                     )
-                   )
-         RETURNING region_id
-              INTO :region_id
+    * `args` - SQL statement arguments - a single argument or a tuple of arguments
+
+    # Failures
+
+    Returns an error if any of the rows cannot be converted into `T`.
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT c.country_name, Median(e.salary)
+          FROM hr.employees e
+          JOIN hr.departments d ON d.department_id = e.department_id
+          JOIN hr.locations l   ON l.location_id = d.location_id
+          JOIN hr.countries c   ON c.country_id = l.country_id
+          JOIN hr.regions r     ON r.region_id = c.region_id
+         WHERE r.region_name = :REGION_NAME
+      GROUP BY c.country_name
+      ORDER BY c.country_name
+    ")?;
+    let rows : Vec<(String, u16)> = stmt.query_as("Europe")?;
+    assert_eq!(rows, [
+        ("Germany".to_string(), 10000),
+        ("United Kingdom".to_string(), 8800),
+    ]);
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn query_as<T: FromRow>(&'a self, args: impl ToSql) -> Result<Vec<T>> {
+        let rows = self.query(args)?;
+        let mut values = Vec::new();
+        while let Some(row) = rows.next()? {
+            values.push(T::from_row(&row)?);
+        }
+        Ok(values)
+    }
+
+    /**
+    Executes the prepared `SELECT ... FOR UPDATE SKIP LOCKED` statement to claim up to `max_rows`
+    rows of a work queue, and maps the claimed rows into `T`.
+
+    Rows that are locked by other sessions are skipped. Rows are locked by the query only as they
+    are fetched, thus to avoid locking more rows than will be processed the query fetches at most
+    `max_rows` rows, with the prefetch limited accordingly. The claimed rows stay locked until the
+    session commits or rolls back its transaction. This allows several consumers to process rows
+    of the same queue concurrently without claiming the same rows.
+
+    Note that the number of claimed rows should be limited via `max_rows` and not by `ROWNUM` or
+    `FETCH FIRST n ROWS` in the query as those are applied before the locked rows are skipped.
+
+    # Parameters
+
+    * `args` - SQL statement arguments - a single argument or a tuple of arguments
+    * `max_rows` - the maximum number of rows to claim
+
+    # Failures
+
+    Returns an error if the statement is not a SELECT or if any of the rows cannot be converted into `T`.
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT employee_id, last_name
+          FROM hr.employees
+         WHERE department_id = :dept
+           FOR UPDATE SKIP LOCKED
+    ")?;
+    let claimed : Vec<(u32, String)> = stmt.query_skip_locked(50, 2)?;
+    assert_eq!(claimed.len(), 2);
+    // ... process claimed rows ...
+    // Commit (or rollback) releases the locks
+    session.rollback()?;
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn query_skip_locked<T: FromRow>(&'a self, args: impl ToSql, max_rows: usize) -> Result<Vec<T>> {
+        let prefetch = self.prefetch_rows.load(Ordering::Relaxed);
+        let fetch_array = self.fetch_array.swap(0, Ordering::Relaxed);
+        self.set_prefetch_rows(max_rows.min(u32::MAX as usize) as u32)?;
+        let res = self.claim_rows(args, max_rows);
+        self.fetch_array.store(fetch_array, Ordering::Relaxed);
+        self.set_prefetch_rows(prefetch)?;
+        res
+    }
+
+    /// Fetches up to `max_rows` rows of the query result.
+    fn claim_rows<T: FromRow>(&'a self, args: impl ToSql, max_rows: usize) -> Result<Vec<T>> {
+        let rows = self.query(args)?;
+        let mut values = Vec::with_capacity(max_rows.min(1024));
+        while values.len() < max_rows {
+            match rows.next()? {
+                Some(row) => values.push(T::from_row(&row)?),
+                None => break,
+            }
+        }
+        Ok(values)
+    }
+
+    /**
+    Returns the value of the identity column of the row inserted by the last execution of this
+    INSERT statement.
+
+    The inserted row is located by its ROWID. The identity column of the table is then looked up
+    in the data dictionary and its value is read from the inserted row. Thus the INSERT does not
+    need a `RETURNING id INTO` clause and an OUT argument to retrieve the generated value.
+
+    **Note** that when the statement inserted several rows (or was executed as a batch), the
+    returned value is the identity of the last inserted row.
+
+    # Failures
+
+    Returns an error if the statement is not an INSERT, if it has not inserted any rows,
+    or if the table does not have an identity column.
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    # let stmt = session.prepare("
+    #     DECLARE
+    #         name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+    #     BEGIN
+    #         EXECUTE IMMEDIATE '
+    #             CREATE TABLE test_last_insert_id (
+    #                 id      NUMBER GENERATED ALWAYS AS IDENTITY,
+    #                 name    VARCHAR2(30)
+    #             )
+    #         ';
+    #     EXCEPTION
+    #       WHEN name_already_used THEN NULL;
+    #     END;
+    # ")?;
+    # stmt.execute(())?;
+    let stmt = session.prepare("
+        INSERT INTO test_last_insert_id (name) VALUES (:NAME)
+    ")?;
+    stmt.execute("Tenzing")?;
+    let id : u32 = stmt.last_insert_id()?;
+
+    let stmt = session.prepare("
+        SELECT name FROM test_last_insert_id WHERE id = :ID
+    ")?;
+    let row = stmt.query_single(id)?.unwrap();
+    let name : &str = row.get(0)?;
+    assert_eq!(name, "Tenzing");
+    # session.rollback()?;
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn last_insert_id<T>(&self) -> Result<T> where T: for<'r> FromSql<'r> {
+        let rowid = self.inserted_rowid()?;
+        let stmt = self.session.prepare(IDENTITY_COLUMN_SQL)?;
+        let row = stmt.query_single(&rowid)?.ok_or_else(|| Error::new("table does not have an identity column"))?;
+        let owner : &str = row.get(0)?;
+        let table : &str = row.get(1)?;
+        let column : &str = row.get(2)?;
+        let sql = format!(r#"SELECT "{}" FROM "{}"."{}" WHERE rowid = :ROW_ID"#, column, owner, table);
+        let stmt = self.session.prepare(&sql)?;
+        let row = stmt.query_single(&rowid)?.ok_or_else(|| Error::new("inserted row is not found"))?;
+        row.get(0)
+    }
+
+    /**
+    Returns the optimizer's estimate of the number of rows this query will return.
+
+    The estimate is obtained by explaining the query (see `EXPLAIN PLAN`) in a single round-trip.
+    Its accuracy thus depends on the optimizer statistics of the queried tables. It is meant to
+    be used as a capacity hint when the rows are collected into a vector or another collection,
+    rather than as a precise row count.
+
+    Note that the plan is written into and then deleted from the `PLAN_TABLE`, which is a global
+    temporary table. Thus the call starts a transaction if one is not already in progress.
+
+    # Failures
+
+    Returns an error if the statement is not a SELECT or if the query cannot be explained.
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT employee_id, last_name
+          FROM hr.employees
+    ")?;
+    let mut names = Vec::with_capacity(stmt.estimated_row_count()?);
+    let rows = stmt.query(())?;
+    while let Some(row) = rows.next()? {
+        let name : String = row.get(1)?;
+        names.push(name);
+    }
+    assert!(names.capacity() >= names.len());
+    # session.rollback()?;
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn estimated_row_count(&self) -> Result<usize> {
+        let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+        if stmt_type != OCI_STMT_SELECT {
+            return Err( Error::new("row count can only be estimated for SELECT") );
+        }
+        let sql: &str = self.get_attr(OCI_ATTR_STATEMENT)?;
+        let mut num_rows = 0u64;
+        let stmt = self.session.prepare(ROW_ESTIMATE_SQL)?;
+        stmt.execute(((":SQL", sql), (":NUM_ROWS", &mut num_rows)))?;
+        Ok( num_rows as usize )
+    }
+
+    /**
+    Describes the select-list of the prepared statement without executing it. Returns
+    the metadata of the columns that the query would return.
+
+    The statement is executed in the describe-only mode - no rows are fetched and
+    statements other than SELECT are not executed and return no columns. Arguments do not
+    have to be bound to describe the statement.
+
+    **Note** that describing a query defines its output buffers. Thus [`Statement::define_as()`]
+    must be called before `describe_result` for it to take effect.
+
+    # Example
+
+    ```
+    use sibyl::ColumnType;
+
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT employee_id, last_name, hire_date
+          FROM hr.employees
+         WHERE manager_id = :id
+    ")?;
+    let cols = stmt.describe_result()?;
+
+    assert_eq!(cols.len(), 3);
+    assert_eq!(cols[0].name()?, "EMPLOYEE_ID");
+    assert_eq!(cols[0].data_type()?, ColumnType::Number);
+    assert_eq!(cols[1].name()?, "LAST_NAME");
+    assert_eq!(cols[1].data_type()?, ColumnType::Varchar);
+    assert_eq!(cols[2].name()?, "HIRE_DATE");
+    assert_eq!(cols[2].data_type()?, ColumnType::Date);
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn describe_result(&self) -> Result<Vec<ColumnInfo<'_>>> {
+        oci::stmt_execute(self.as_ref(), &self.stmt, &self.err, 0, 0, std::ptr::null(), std::ptr::null(), OCI_DESCRIBE_ONLY)?;
+        self.described_columns()
+    }
+
+    /**
+    Retrieves a single implicit result (cursor) in the order in which they were returned
+    from the PL/SQL procedure or block. If no more results are available, then `None` is
+    returned.
+
+    PL/SQL provides a subprogram RETURN_RESULT in the DBMS_SQL package to return the result
+    of an executed statement. Only SELECT query result-sets can be implicitly returned by a
+    PL/SQL procedure or block.
+
+    `next_result` can be called iteratively by the application to retrieve each implicit
+    result from an executed PL/SQL statement. Applications retrieve each result-set sequentially
+    but can fetch rows from any result-set independently.
+
+    # Example
+
+    ```
+    use sibyl::Number;
+    use std::cmp::Ordering::Equal;
+
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        DECLARE
+            c1 SYS_REFCURSOR;
+            c2 SYS_REFCURSOR;
+        BEGIN
+            OPEN c1 FOR
+                SELECT department_name, first_name, last_name, salary
+                  FROM (
+                        SELECT first_name, last_name, salary, department_id
+                             , ROW_NUMBER() OVER (ORDER BY salary) ord
+                          FROM hr.employees
+                       ) e
+                  JOIN hr.departments d
+                    ON d.department_id = e.department_id
+                 WHERE ord = 1
+            ;
+            DBMS_SQL.RETURN_RESULT (c1);
+
+            OPEN c2 FOR
+                SELECT department_name, first_name, last_name, salary
+                  FROM (
+                        SELECT first_name, last_name, salary, department_id
+                             , MEDIAN(salary) OVER () median_salary
+                          FROM hr.employees
+                       ) e
+                  JOIN hr.departments d
+                    ON d.department_id = e.department_id
+                 WHERE salary = median_salary
+              ORDER BY department_name, last_name, first_name
+            ;
+            DBMS_SQL.RETURN_RESULT (c2);
+        END;
+    ")?;
+    let expected_lowest_salary = Number::from_int(2100, &session)?;
+    let expected_median_salary = Number::from_int(6200, &session)?;
+
+    stmt.execute(())?;
+
+    let lowest_payed_employee = stmt.next_result()?.unwrap();
+
+    let rows = lowest_payed_employee.rows()?;
+    let row = rows.next()?.unwrap();
+
+    let department_name : &str = row.get(0)?;
+    let first_name : &str = row.get(1)?;
+    let last_name : &str = row.get(2)?;
+    let salary : Number = row.get(3)?;
+
+    assert_eq!(department_name, "Shipping");
+    assert_eq!(first_name, "TJ");
+    assert_eq!(last_name, "Olson");
+    assert_eq!(salary.compare(&expected_lowest_salary)?, Equal);
+
+    let row = rows.next()?;
+    assert!(row.is_none());
+
+    let median_salary_employees = stmt.next_result()?.unwrap();
+
+    let rows = median_salary_employees.rows()?;
+
+    let row = rows.next()?.unwrap();
+    let department_name : &str = row.get(0)?;
+    let first_name : &str = row.get(1)?;
+    let last_name : &str = row.get(2)?;
+    let salary : Number = row.get(3)?;
+
+    assert_eq!(department_name, "Sales");
+    assert_eq!(first_name, "Amit");
+    assert_eq!(last_name, "Banda");
+    assert_eq!(salary.compare(&expected_median_salary)?, Equal);
+
+    let row = rows.next()?.unwrap();
+
+    let department_name : &str = row.get(0)?;
+    let first_name : &str = row.get(1)?;
+    let last_name : &str = row.get(2)?;
+    let salary : Number = row.get(3)?;
+
+    assert_eq!(department_name, "Sales");
+    assert_eq!(first_name, "Charles");
+    assert_eq!(last_name, "Johnson");
+    assert_eq!(salary.compare(&expected_median_salary)?, Equal);
+
+    let row = rows.next()?;
+    assert!(row.is_none());
+
+    assert!(stmt.next_result()?.is_none());
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn next_result(&'a self) -> Result<Option<Cursor>> {
+        let mut stmt = Ptr::<OCIStmt>::null();
+        let mut stmt_type = 0u32;
+        trace_oci_call!(OCIStmtGetNextResult);
+        let res = unsafe {
+            OCIStmtGetNextResult(self.stmt.as_ref(), self.err.as_ref(), stmt.as_mut_ptr(), &mut stmt_type, OCI_DEFAULT)
+        };
+        match res {
+            OCI_NO_DATA => Ok( None ),
+            OCI_SUCCESS => Ok( Some ( Cursor::implicit(stmt, self) ) ),
+            _ => Err( Error::oci(&self.err, res) )
+        }
+    }
+
+    /**
+    Returns an iterator over the implicit results (cursors) that were returned by the executed
+    PL/SQL procedure or block. The iterator retrieves them via [`Statement::next_result`] and
+    ends when no more results are available or after the first error.
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        DECLARE
+            c1 SYS_REFCURSOR;
+            c2 SYS_REFCURSOR;
+        BEGIN
+            OPEN c1 FOR
+                SELECT last_name FROM hr.employees WHERE employee_id = 100;
+            DBMS_SQL.RETURN_RESULT(c1);
+            OPEN c2 FOR
+                SELECT department_name FROM hr.departments WHERE department_id = 10;
+            DBMS_SQL.RETURN_RESULT(c2);
+        END;
+    ")?;
+    stmt.execute(())?;
+
+    let names = stmt.result_sets().map(|cursor| {
+        let cursor = cursor?;
+        let rows = cursor.rows()?;
+        let row = rows.next()?.unwrap();
+        let name : String = row.get(0)?;
+        Ok(name)
+    }).collect::<sibyl::Result<Vec<_>>>()?;
+
+    assert_eq!(names, ["King", "Administration"]);
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn result_sets(&'a self) -> impl Iterator<Item=Result<Cursor<'a>>> + 'a {
+        let mut done = false;
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            let res = self.next_result().transpose();
+            done = !matches!(res, Some(Ok(_)));
+            res
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn stmt_args() -> std::result::Result<(),Box<dyn std::error::Error>> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            INSERT INTO hr.countries
+                   ( country_id, country_name, region_id )
+            VALUES ( :country_id, :country_name
+                   , (
+                       SELECT region_id
+                         FROM hr.regions
+                        WHERE region_name = :region_name
+                     )
+                   )
+         RETURNING region_id
+              INTO :region_id
         ")?;
         let mut region_id : usize = 0;
         let num_rows = stmt.execute((
@@ -427,132 +1074,511 @@ mod tests {
     }
 
     #[test]
-    fn plsql_args() -> std::result::Result<(),Box<dyn std::error::Error>> {
+    fn plsql_args() -> std::result::Result<(),Box<dyn std::error::Error>> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            BEGIN
+                SELECT city, street_address
+                  INTO :city, :street_address
+                  FROM hr.locations
+                 WHERE location_id = :location_id;
+            EXCEPTION
+                WHEN NO_DATA_FOUND THEN
+                    :city := 'Unknown';
+                    :street_address := NULL;
+            END;
+        ")?;
+        let mut city = String::with_capacity(30);
+        let mut addr = String::with_capacity(40);
+        let num_rows = stmt.execute((
+            ( ":LOCATION_ID",    2500      ),
+            ( ":CITY",           &mut city ),
+            ( ":STREET_ADDRESS", &mut addr )
+        ))?;
+
+        assert_eq!(num_rows, 1);
+        assert!(!stmt.is_null(":CITY")?);
+        assert_eq!(city, "Oxford");
+        assert!(!stmt.is_null(":STREET_ADDRESS")?);
+        assert_eq!(addr, "Magdalen Centre, The Oxford Science Park");
+
+        let num_rows = stmt.execute((
+            ( ":LOCATION_ID",    2400      ),
+            ( ":CITY",           &mut city ),
+            ( ":STREET_ADDRESS", &mut addr )
+        ))?;
+
+        assert_eq!(num_rows, 1);
+        assert!(!stmt.is_null(":CITY")?);
+        assert_eq!(city, "London");
+        assert!(!stmt.is_null(":STREET_ADDRESS")?);
+        assert_eq!(addr, "8204 Arthur St");
+
+        let num_rows = stmt.execute((
+            ( ":LOCATION_ID",    2200      ),
+            ( ":CITY",           &mut city ),
+            ( ":STREET_ADDRESS", &mut addr )
+        ))?;
+
+        assert_eq!(num_rows, 1);
+        assert!(!stmt.is_null(":CITY")?);
+        assert_eq!(city, "Sydney");
+        assert!(!stmt.is_null(":STREET_ADDRESS")?);
+        assert_eq!(addr, "12-98 Victoria Street");
+
+        let num_rows = stmt.execute((
+            ( ":LOCATION_ID",    3300      ),
+            ( ":CITY",           &mut city ),
+            ( ":STREET_ADDRESS", &mut addr )
+        ))?;
+
+        assert_eq!(num_rows, 1);
+        assert!(!stmt.is_null(":CITY")?);
+        assert_eq!(city, "Unknown");
+        assert!(stmt.is_null(":STREET_ADDRESS")?);
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    #[test]
+    fn out_arg_no_rows() -> std::result::Result<(),Box<dyn std::error::Error>> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            UPDATE hr.employees
+               SET salary = Round(salary * :rate, -2)
+             WHERE employee_id = :id
+            RETURN salary INTO :new_salary
+        ")?;
+        let mut new_salary = 0u16;
+        let num_updated = stmt.execute((
+            (":ID",         107             ),
+            (":RATE",       1.07            ),
+            (":NEW_SALARY", &mut new_salary ),
+        ))?;
+
+        assert_eq!(num_updated, 1);
+        assert!(!stmt.is_null(":NEW_SALARY")?);
+        assert_eq!(new_salary, 4500);
+
+        let num_updated = stmt.execute((
+            (":ID",         99              ),
+            (":RATE",       1.03            ),
+            (":NEW_SALARY", &mut new_salary ),
+        ))?;
+
+        assert_eq!(num_updated, 0);
+        assert!(stmt.is_null(":NEW_SALARY")?);
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    /// Unless (or until) dynamic array binding is implemented this would be failing
+    #[test]
+    fn out_arg_many_rows() -> std::result::Result<(),Box<dyn std::error::Error>> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            UPDATE hr.employees
+               SET salary = Round(salary * :rate, -2)
+             WHERE manager_id = :manager_id
+            RETURN salary INTO :new_salary
+        ")?;
+        let mut new_salary = 0u16;
+        let res = stmt.execute((
+            (":MANAGER_ID", 103             ),
+            (":RATE",       1.02            ),
+            (":NEW_SALARY", &mut new_salary ),
+        ));
+        assert!(res.is_err());
+        match res.unwrap_err() {
+            Error::Oracle(code,_) => assert_eq!(code, 24369),
+            err => panic!("unexpected error {:?}", err),
+        }
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    #[test]
+    fn batch_errors() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_batch_errors (
+                        id      NUMBER PRIMARY KEY,
+                        name    VARCHAR2(30)
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_batch_errors
+                    ';
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("
+            INSERT INTO test_batch_errors (id, name) VALUES (:id, :name)
+        ")?;
+        let res = stmt.execute_batch_with_errors(&[
+            (1, "one",   ()),
+            (2, "two",   ()),
+            (3, "three", ()),
+            (2, "dup",   ()),
+            (4, "four",  ()),
+            (5, "five",  ()),
+        ])?;
+        session.commit()?;
+
+        assert_eq!(res.row_count(), 5);
+        assert!(!res.is_complete());
+        assert_eq!(res.errors().len(), 1);
+        let (row_offset, err) = &res.errors()[0];
+        assert_eq!(*row_offset, 3);
+        match err {
+            Error::Oracle(code, _) => assert_eq!(*code, 1),
+            _ => panic!("unexpected error {:?}", err),
+        }
+
+        let stmt = session.prepare("
+            SELECT id, name FROM test_batch_errors ORDER BY id
+        ")?;
+        let rows = stmt.query(())?;
+        let mut ids = Vec::new();
+        while let Some(row) = rows.next()? {
+            let id : usize = row.get(0)?;
+            let name : &str = row.get(1)?;
+            assert_ne!(name, "dup");
+            ids.push(id);
+        }
+        assert_eq!(ids, [1, 2, 3, 4, 5]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn many_binds() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let placeholders : Vec<String> = (1..=20).map(|n| format!(":P{}", n)).collect();
+        let sql = format!("SELECT {} FROM dual", placeholders.join(" + "));
+        let stmt = session.prepare(&sql)?;
+
+        let mut args = Binds::new();
+        for (idx, name) in placeholders.iter().enumerate() {
+            let val = idx as i32 + 1;
+            args = if idx % 2 == 0 { args.at(idx, val) } else { args.by_name(name, val) };
+        }
+        assert_eq!(args.len(), 20);
+
+        let row = stmt.query_single(args)?.expect("single row");
+        let sum : i32 = row.get(0)?;
+        assert_eq!(sum, 210);
+        Ok(())
+    }
+
+    #[test]
+    fn batch_returning_rowid() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_batch_rowids (
+                        id      NUMBER PRIMARY KEY,
+                        name    VARCHAR2(30)
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_batch_rowids
+                    ';
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("
+            INSERT INTO test_batch_rowids (id, name) VALUES (:id, :name)
+            RETURNING ROWID INTO :row_id
+        ")?;
+        let rows = [
+            ((":ID", 3), (":NAME", "three")),
+            ((":ID", 1), (":NAME", "one"  )),
+            ((":ID", 4), (":NAME", "four" )),
+            ((":ID", 2), (":NAME", "two"  )),
+        ];
+        let rowids = stmt.execute_batch_returning_rowid(&rows, ":ROW_ID")?;
+        assert_eq!(rowids.len(), rows.len());
+
+        let stmt = session.prepare("
+            SELECT id, name FROM test_batch_rowids WHERE rowid = :row_id
+        ")?;
+        for (rowid, ((_, id), (_, name))) in rowids.iter().zip(rows.iter()) {
+            let row = stmt.query_single(rowid)?.expect("inserted row");
+            let row_id : i32 = row.get(0)?;
+            let row_name : &str = row.get(1)?;
+            assert_eq!(row_id, *id);
+            assert_eq!(row_name, *name);
+        }
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    #[test]
+    fn insert_all() -> Result<()> {
+        use crate::{ToRow, Binds};
+
+        struct Employee {
+            id: u32,
+            name: String,
+            salary: Option<f64>,
+        }
+
+        impl ToRow for Employee {
+            fn to_row(&self) -> Binds<'_> {
+                Binds::new()
+                    .by_name(":ID", &self.id)
+                    .by_name(":NAME", self.name.as_str())
+                    .by_name(":SALARY", &self.salary)
+            }
+        }
+
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_insert_all (
+                        id          NUMBER PRIMARY KEY,
+                        name        VARCHAR2(30),
+                        salary      NUMBER
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_insert_all
+                    ';
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let employees = vec![
+            Employee { id: 1, name: "Alice".to_string(), salary: Some(5000.0) },
+            Employee { id: 2, name: "Bob".to_string(),   salary: None },
+            Employee { id: 3, name: "Carol".to_string(), salary: Some(6500.5) },
+        ];
+        let stmt = session.prepare("
+            INSERT INTO test_insert_all (id, name, salary) VALUES (:id, :name, :salary)
+        ")?;
+        let num_rows = stmt.insert_all(&employees)?;
+        assert_eq!(num_rows, 3);
+
+        let stmt = session.prepare("SELECT Count(*), Count(salary) FROM test_insert_all")?;
+        let row = stmt.query_single(())?.expect("counts");
+        let count: usize = row.get(0)?;
+        let with_salary: usize = row.get(1)?;
+        assert_eq!(count, 3);
+        assert_eq!(with_salary, 2);
+
+        let stmt = session.prepare("SELECT name FROM test_insert_all WHERE id = :id")?;
+        let row = stmt.query_single(3)?.expect("inserted row");
+        let name: &str = row.get(0)?;
+        assert_eq!(name, "Carol");
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    #[test]
+    fn estimated_row_count() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            SELECT employee_id
+              FROM hr.employees
+        ")?;
+        let mut ids = Vec::with_capacity(stmt.estimated_row_count()?);
+        let reserved = ids.capacity();
+        let rows = stmt.query(())?;
+        while let Some(row) = rows.next()? {
+            let id : u32 = row.get(0)?;
+            ids.push(id);
+        }
+        assert_eq!(ids.len(), 107);
+        assert!(reserved >= ids.len(), "reserved {} for {} rows", reserved, ids.len());
+        assert_eq!(ids.capacity(), reserved);
+
+        let stmt = session.prepare("DELETE FROM hr.employees WHERE employee_id = 0")?;
+        assert!(stmt.estimated_row_count().is_err());
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    #[test]
+    fn fetch_array_size() -> Result<()> {
         let session = crate::test_env::get_session()?;
 
+        let stat = session.prepare("
+            SELECT s.value
+              FROM v$mystat s
+              JOIN v$statname n
+                ON n.statistic# = s.statistic#
+             WHERE n.name = 'SQL*Net roundtrips to/from client'
+        ")?;
         let stmt = session.prepare("
-            BEGIN
-                SELECT city, street_address
-                  INTO :city, :street_address
-                  FROM hr.locations
-                 WHERE location_id = :location_id;
-            EXCEPTION
-                WHEN NO_DATA_FOUND THEN
-                    :city := 'Unknown';
-                    :street_address := NULL;
-            END;
+            SELECT level
+              FROM dual
+           CONNECT BY level <= 500
         ")?;
-        let mut city = String::with_capacity(30);
-        let mut addr = String::with_capacity(40);
-        let num_rows = stmt.execute((
-            ( ":LOCATION_ID",    2500      ),
-            ( ":CITY",           &mut city ),
-            ( ":STREET_ADDRESS", &mut addr )
-        ))?;
-
-        assert_eq!(num_rows, 1);
-        assert!(!stmt.is_null(":CITY")?);
-        assert_eq!(city, "Oxford");
-        assert!(!stmt.is_null(":STREET_ADDRESS")?);
-        assert_eq!(addr, "Magdalen Centre, The Oxford Science Park");
+        stmt.set_prefetch_rows(1)?;
+
+        let mut fetch_calls = Vec::new();
+        for &array_size in [1, 100].iter() {
+            stmt.set_fetch_array_size(array_size);
+            let start : u64 = stat.query_scalar(())?;
+            let rows = stmt.query(())?;
+            let mut count = 0;
+            while let Some(row) = rows.next()? {
+                let num : usize = row.get(0)?;
+                count += 1;
+                assert_eq!(num, count);
+            }
+            assert_eq!(count, 500);
+            let end : u64 = stat.query_scalar(())?;
+            fetch_calls.push(end - start);
+        }
+        assert!(fetch_calls[0] >= 500, "{} round-trips with array size 1", fetch_calls[0]);
+        assert!(fetch_calls[1] < 20, "{} round-trips with array size 100", fetch_calls[1]);
 
-        let num_rows = stmt.execute((
-            ( ":LOCATION_ID",    2400      ),
-            ( ":CITY",           &mut city ),
-            ( ":STREET_ADDRESS", &mut addr )
-        ))?;
+        Ok(())
+    }
 
-        assert_eq!(num_rows, 1);
-        assert!(!stmt.is_null(":CITY")?);
-        assert_eq!(city, "London");
-        assert!(!stmt.is_null(":STREET_ADDRESS")?);
-        assert_eq!(addr, "8204 Arthur St");
+    #[test]
+    fn execute_empty_batch() -> Result<()> {
+        let session = crate::test_env::get_session()?;
 
-        let num_rows = stmt.execute((
-            ( ":LOCATION_ID",    2200      ),
-            ( ":CITY",           &mut city ),
-            ( ":STREET_ADDRESS", &mut addr )
-        ))?;
+        let stat = session.prepare("
+            SELECT s.value
+              FROM v$mystat s
+              JOIN v$statname n
+                ON n.statistic# = s.statistic#
+             WHERE n.name = 'SQL*Net roundtrips to/from client'
+        ")?;
+        let stmt = session.prepare("
+            INSERT INTO hr.regions (region_id, region_name) VALUES (:id, :name)
+        ")?;
+        let rows : &[((&str, usize), (&str, &str))] = &[];
 
-        assert_eq!(num_rows, 1);
-        assert!(!stmt.is_null(":CITY")?);
-        assert_eq!(city, "Sydney");
-        assert!(!stmt.is_null(":STREET_ADDRESS")?);
-        assert_eq!(addr, "12-98 Victoria Street");
+        // Round-trips of the statistics query itself
+        let start : u64 = stat.query_scalar(())?;
+        let end : u64 = stat.query_scalar(())?;
+        let stat_round_trips = end - start;
 
-        let num_rows = stmt.execute((
-            ( ":LOCATION_ID",    3300      ),
-            ( ":CITY",           &mut city ),
-            ( ":STREET_ADDRESS", &mut addr )
-        ))?;
+        let start : u64 = stat.query_scalar(())?;
+        let num_rows = stmt.execute_batch(rows)?;
+        let end : u64 = stat.query_scalar(())?;
+        assert_eq!(num_rows, 0);
+        assert_eq!(end - start, stat_round_trips);
 
-        assert_eq!(num_rows, 1);
-        assert!(!stmt.is_null(":CITY")?);
-        assert_eq!(city, "Unknown");
-        assert!(stmt.is_null(":STREET_ADDRESS")?);
+        let res = stmt.execute_batch_with_errors(rows)?;
+        assert_eq!(res.row_count(), 0);
+        assert!(res.errors().is_empty());
 
-        session.rollback()?;
         Ok(())
     }
 
     #[test]
-    fn out_arg_no_rows() -> std::result::Result<(),Box<dyn std::error::Error>> {
+    fn execute_batch_rejects_descriptors() -> Result<()> {
         let session = crate::test_env::get_session()?;
 
         let stmt = session.prepare("
-            UPDATE hr.employees
-               SET salary = Round(salary * :rate, -2)
-             WHERE employee_id = :id
-            RETURN salary INTO :new_salary
+            INSERT INTO hr.job_history (employee_id, start_date, end_date, job_id)
+            VALUES (:id, :start_date, :end_date, 'IT_PROG')
         ")?;
-        let mut new_salary = 0u16;
-        let num_updated = stmt.execute((
-            (":ID",         107             ),
-            (":RATE",       1.07            ),
-            (":NEW_SALARY", &mut new_salary ),
-        ))?;
-
-        assert_eq!(num_updated, 1);
-        assert!(!stmt.is_null(":NEW_SALARY")?);
-        assert_eq!(new_salary, 4500);
-
-        let num_updated = stmt.execute((
-            (":ID",         99              ),
-            (":RATE",       1.03            ),
-            (":NEW_SALARY", &mut new_salary ),
-        ))?;
-
-        assert_eq!(num_updated, 0);
-        assert!(stmt.is_null(":NEW_SALARY")?);
+        let start = crate::Timestamp::from_string("2001-01-01 00:00:00", "YYYY-MM-DD HH24:MI:SS", &session)?;
+        let end = crate::Timestamp::from_string("2002-01-01 00:00:00", "YYYY-MM-DD HH24:MI:SS", &session)?;
+        let res = stmt.execute_batch(&[
+            (( ":ID", 107 ), ( ":START_DATE", &start ), ( ":END_DATE", &end )),
+            (( ":ID", 108 ), ( ":START_DATE", &start ), ( ":END_DATE", &end )),
+        ]);
+        match res {
+            Err(Error::Interface(msg)) => assert!(msg.contains("cannot be used in batches"), "{}", msg),
+            _ => panic!("unexpected result {:?}", res),
+        }
+        assert!(session.is_connected()?);
 
-        session.rollback()?;
         Ok(())
     }
 
-    /// Unless (or until) dynamic array binding is implemented this would be failing
     #[test]
-    fn out_arg_many_rows() -> std::result::Result<(),Box<dyn std::error::Error>> {
+    fn query_skip_locked() -> Result<()> {
         let session = crate::test_env::get_session()?;
 
         let stmt = session.prepare("
-            UPDATE hr.employees
-               SET salary = Round(salary * :rate, -2)
-             WHERE manager_id = :manager_id
-            RETURN salary INTO :new_salary
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_work_queue (
+                        id          NUMBER PRIMARY KEY,
+                        payload     VARCHAR2(30)
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_work_queue
+                    ';
+            END;
         ")?;
-        let mut new_salary = 0u16;
-        let res = stmt.execute((
-            (":MANAGER_ID", 103             ),
-            (":RATE",       1.02            ),
-            (":NEW_SALARY", &mut new_salary ),
-        ));
-        assert!(res.is_err());
-        match res.unwrap_err() {
-            Error::Oracle(code,_) => assert_eq!(code, 24369),
-            err => panic!("unexpected error {:?}", err),
-        }
+        stmt.execute(())?;
 
-        session.rollback()?;
+        let stmt = session.prepare("INSERT INTO test_work_queue (id, payload) VALUES (:id, 'job ' || :id)")?;
+        for id in 1..=10 {
+            stmt.execute(id)?;
+        }
+        session.commit()?;
+
+        let other = session.try_clone()?;
+        const CLAIM : &str = "
+            SELECT id, payload
+              FROM test_work_queue
+             ORDER BY id
+               FOR UPDATE SKIP LOCKED
+        ";
+        let stmt1 = session.prepare(CLAIM)?;
+        let stmt2 = other.prepare(CLAIM)?;
+
+        let claimed1 : Vec<(u32, String)> = stmt1.query_skip_locked((), 3)?;
+        let claimed2 : Vec<(u32, String)> = stmt2.query_skip_locked((), 3)?;
+        assert_eq!(claimed1.len(), 3);
+        assert_eq!(claimed2.len(), 3);
+        assert!(claimed1.iter().all(|(id1, _)| claimed2.iter().all(|(id2, _)| id1 != id2)));
+
+        // Commit releases the locks of the first consumer. The second one still
+        // holds its own locks, which do not block it.
+        session.commit()?;
+        let claimed : Vec<(u32, String)> = stmt2.query_skip_locked((), 10)?;
+        assert_eq!(claimed.len(), 10);
+
+        other.rollback()?;
         Ok(())
     }
 
@@ -601,4 +1627,397 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn cancel() -> Result<()> {
+        use std::{thread, time::{Duration, Instant}};
+
+        let session = crate::test_env::get_session()?;
+        let stmt = session.prepare("
+            BEGIN
+                DBMS_LOCK.SLEEP(10);
+            END;
+        ")?;
+        let started = Instant::now();
+        let res = thread::scope(|s| {
+            s.spawn(|| {
+                thread::sleep(Duration::from_secs(1));
+                stmt.cancel()
+            });
+            stmt.execute(())
+        });
+        assert!(matches!(res, Err(Error::Oracle(1013, _))), "unexpected result {:?}", res);
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        // session is still usable after the cancelled call
+        session.ping()?;
+        Ok(())
+    }
+
+    #[test]
+    fn describe_result() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            SELECT employee_id, last_name, salary
+              FROM hr.employees
+             WHERE department_id = :dept
+        ")?;
+        let cols = stmt.describe_result()?;
+        assert_eq!(cols.len(), 3);
+        assert_eq!(cols[0].name()?, "EMPLOYEE_ID");
+        assert_eq!(cols[1].name()?, "LAST_NAME");
+        assert_eq!(cols[1].data_type()?, ColumnType::Varchar);
+        assert_eq!(cols[2].name()?, "SALARY");
+        assert_eq!(cols[2].precision()?, 8);
+        assert_eq!(cols[2].scale()?, 2);
+        assert_eq!(stmt.row_count()?, 0);
+
+        // the described query can be executed later
+        let rows = stmt.query(60)?;
+        let mut num_rows = 0;
+        while let Some(_row) = rows.next()? {
+            num_rows += 1;
+        }
+        assert_eq!(num_rows, 5);
+
+        let stmt = session.prepare("
+            UPDATE hr.employees
+               SET salary = salary * 2
+             WHERE employee_id = 107
+        ")?;
+        let cols = stmt.describe_result()?;
+        assert!(cols.is_empty());
+        assert_eq!(stmt.row_count()?, 0);
+
+        let stmt = session.prepare("SELECT salary FROM hr.employees WHERE employee_id = 107")?;
+        let row = stmt.query_single(())?.unwrap();
+        let salary : u32 = row.get(0)?;
+        assert_eq!(salary, 4200);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invisible_columns() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_invisible_columns (
+                        id      NUMBER PRIMARY KEY,
+                        name    VARCHAR2(30),
+                        note    VARCHAR2(30) INVISIBLE
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_invisible_columns
+                    ';
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("INSERT INTO test_invisible_columns (id, name, note) VALUES (:ID, :NAME, :NOTE)")?;
+        stmt.execute((1, "visible", "hidden"))?;
+
+        let stmt = session.prepare("SELECT * FROM test_invisible_columns")?;
+        let cols = stmt.describe_result()?;
+        assert_eq!(cols.len(), 2);
+        assert_eq!(cols[0].name()?, "ID");
+        assert_eq!(cols[1].name()?, "NAME");
+        assert!(!cols[1].is_invisible()?);
+        assert!(cols[1].is_visible()?);
+
+        let stmt = session.prepare("SELECT id, name, note FROM test_invisible_columns")?;
+        let row = stmt.query_single(())?.unwrap();
+        assert_eq!(row.column_count(), 3);
+        let note : &str = row.get("NOTE")?;
+        assert_eq!(note, "hidden");
+        let col = stmt.column(2).expect("NOTE column info");
+        assert_eq!(col.name()?, "NOTE");
+        assert!(col.is_invisible()?);
+        assert!(!col.is_visible()?);
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    #[test]
+    fn last_insert_id() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_last_insert_id (
+                        id      NUMBER GENERATED ALWAYS AS IDENTITY,
+                        name    VARCHAR2(30)
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN NULL;
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("INSERT INTO test_last_insert_id (name) VALUES (:NAME)")?;
+        let res = stmt.last_insert_id::<u32>();
+        assert!(res.is_err());
+
+        stmt.execute("first")?;
+        let first_id : u32 = stmt.last_insert_id()?;
+        stmt.execute("second")?;
+        let second_id : u32 = stmt.last_insert_id()?;
+        assert!(second_id > first_id);
+
+        let stmt = session.prepare("
+            INSERT INTO test_last_insert_id (name) VALUES (:NAME)
+            RETURNING id INTO :ID
+        ")?;
+        let mut returned_id = 0u32;
+        stmt.execute(((":NAME", "third"), (":ID", &mut returned_id)))?;
+        let third_id : u32 = stmt.last_insert_id()?;
+        assert_eq!(third_id, returned_id);
+
+        let stmt = session.prepare("SELECT name FROM test_last_insert_id WHERE id = :ID")?;
+        let row = stmt.query_single(second_id)?.unwrap();
+        let name : &str = row.get(0)?;
+        assert_eq!(name, "second");
+
+        let stmt = session.prepare("INSERT INTO hr.regions (region_id, region_name) VALUES (:ID, :NAME)")?;
+        stmt.execute((11, "Antarctica", ()))?;
+        let res = stmt.last_insert_id::<u32>();
+        assert!(res.is_err());
+
+        let stmt = session.prepare("SELECT COUNT(*) FROM test_last_insert_id")?;
+        let res = stmt.last_insert_id::<u32>();
+        assert!(res.is_err());
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    #[test]
+    fn query_scalar() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("SELECT COUNT(*) FROM hr.employees")?;
+        let num_employees : u32 = stmt.query_scalar(())?;
+        assert_eq!(num_employees, 107);
+
+        let stmt = session.prepare("SELECT last_name FROM hr.employees WHERE employee_id = :ID")?;
+        let name : String = stmt.query_scalar(100)?;
+        assert_eq!(name, "King");
+        let res = stmt.query_scalar::<String>(99);
+        assert!(res.is_err());
+
+        let stmt = session.prepare("SELECT commission_pct FROM hr.employees WHERE employee_id = :ID")?;
+        let pct : Option<f64> = stmt.query_scalar(100)?;
+        assert!(pct.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn query_opt() -> Result<()> {
+        struct Location {
+            city: String,
+            state_province: Option<String>,
+            country_id: String,
+        }
+
+        impl FromRow for Location {
+            fn from_row(row: &Row) -> Result<Self> {
+                Ok(Self {
+                    city: row.get("CITY")?,
+                    state_province: row.get("STATE_PROVINCE")?,
+                    country_id: row.get("COUNTRY_ID")?,
+                })
+            }
+        }
+
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            SELECT city, state_province, country_id
+              FROM hr.locations
+             WHERE location_id = :ID
+        ")?;
+        let location : Location = stmt.query_opt(2400)?.expect("location 2400");
+        assert_eq!(location.city, "London");
+        assert!(location.state_province.is_none());
+        assert_eq!(location.country_id, "UK");
+
+        let location : Option<Location> = stmt.query_opt(2401)?;
+        assert!(location.is_none());
+
+        let stmt = session.prepare("
+            SELECT city, state_province, country_id
+              FROM hr.locations
+             WHERE country_id = :COUNTRY_ID
+        ")?;
+        let res = stmt.query_opt::<Location>("UK");
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn query_as() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            SELECT c.country_name, Median(e.salary)
+              FROM hr.employees e
+              JOIN hr.departments d ON d.department_id = e.department_id
+              JOIN hr.locations l   ON l.location_id = d.location_id
+              JOIN hr.countries c   ON c.country_id = l.country_id
+              JOIN hr.regions r     ON r.region_id = c.region_id
+             WHERE r.region_name = :REGION_NAME
+          GROUP BY c.country_name
+          ORDER BY c.country_name
+        ")?;
+        let rows = stmt.query_as::<(String, u16)>("Europe")?;
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0], ("Germany".to_string(), 10000));
+        assert_eq!(rows[1], ("United Kingdom".to_string(), 8800));
+
+        let rows = stmt.query_as::<(String, u16)>("Antarctica")?;
+        assert!(rows.is_empty());
+
+        let stmt = session.prepare("SELECT last_name, commission_pct FROM hr.employees WHERE employee_id = :ID")?;
+        let rows : Vec<(String, Option<f64>)> = stmt.query_as(100)?;
+        assert_eq!(rows, [("King".to_string(), None)]);
+        let res = stmt.query_as::<(String, f64)>(100);
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn call_timing() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+        session.enable_call_timing()?;
+
+        let stmt = session.prepare("
+            SELECT Count(*)
+              FROM hr.employees e1, hr.employees e2
+             WHERE e1.salary > e2.salary
+        ")?;
+        let num : u32 = stmt.query_scalar(())?;
+        assert!(num > 0);
+        let dt = stmt.last_call_time()?;
+        assert!(dt > 0);
+
+        session.disable_call_timing()?;
+        Ok(())
+    }
+
+    #[test]
+    fn last_rowid() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_last_rowid (
+                        id      NUMBER PRIMARY KEY,
+                        name    VARCHAR2(30)
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_last_rowid
+                    ';
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("INSERT INTO test_last_rowid (id, name) VALUES (:ID, :NAME)")?;
+        assert!(stmt.last_rowid().is_err());
+        stmt.execute((1, "first", ()))?;
+        let first_rowid = stmt.last_rowid()?;
+        stmt.execute((2, "second", ()))?;
+        let second_rowid = stmt.last_rowid()?;
+
+        let select = session.prepare("SELECT id, name FROM test_last_rowid WHERE rowid = :ROW_ID")?;
+        let row = select.query_single(&first_rowid)?.unwrap();
+        let id : u32 = row.get(0)?;
+        let name : &str = row.get(1)?;
+        assert_eq!(id, 1);
+        assert_eq!(name, "first");
+
+        let stmt = session.prepare("UPDATE test_last_rowid SET name = :NAME WHERE id = :ID")?;
+        stmt.execute(("updated", 2, ()))?;
+        let updated_rowid = stmt.last_rowid()?;
+        let row = select.query_single(&updated_rowid)?.unwrap();
+        let id : u32 = row.get(0)?;
+        let name : &str = row.get(1)?;
+        assert_eq!(id, 2);
+        assert_eq!(name, "updated");
+        assert_eq!(updated_rowid.to_string(&session)?, second_rowid.to_string(&session)?);
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    #[test]
+    fn snapshot() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_snapshot (
+                        id      NUMBER PRIMARY KEY
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_snapshot
+                    ';
+            END;
+        ")?;
+        stmt.execute(())?;
+        session.execute("INSERT INTO test_snapshot (id) VALUES (1)", ())?;
+        session.commit()?;
+
+        let snapshot = Snapshot::new(&session)?;
+        let mut stmt = session.prepare("SELECT Count(*) FROM test_snapshot")?;
+        stmt.capture_snapshot(&snapshot);
+        let row = stmt.query_single(())?.unwrap();
+        let count : u32 = row.get(0)?;
+        assert_eq!(count, 1);
+
+        // concurrent modification in another session
+        let other_session = crate::test_env::get_session()?;
+        other_session.execute("INSERT INTO test_snapshot (id) VALUES (2)", ())?;
+        other_session.commit()?;
+
+        let stmt = session.prepare("SELECT Count(*) FROM test_snapshot")?;
+        let row = stmt.query_single(())?.unwrap();
+        let count : u32 = row.get(0)?;
+        assert_eq!(count, 2);
+
+        let mut stmt = other_session.prepare("SELECT Count(*) FROM test_snapshot")?;
+        stmt.use_snapshot(&snapshot);
+        let row = stmt.query_single(())?.unwrap();
+        let count : u32 = row.get(0)?;
+        assert_eq!(count, 1);
+
+        Ok(())
+    }
 }
@@ -1,9 +1,12 @@
-use crate::{Result, oci::{self, *}, types::{date, number, raw, varchar}};
+use crate::{Error, Result, oci::{self, *}, types::{date, number, raw, varchar}};
 use libc::c_void;
-use std::{collections::HashMap, ptr};
+use std::{collections::HashMap, mem::size_of, ptr};
 
 pub(crate) const DEFAULT_LONG_BUFFER_SIZE: u32 = 32768;
 
+/// Minimum size of the text buffer for columns that are explicitly defined as text
+const MIN_TEXT_DEFINE_SIZE: u32 = 128;
+
 /// Column data type.
 #[derive(Debug, PartialEq)]
 pub enum ColumnType {
@@ -30,6 +33,8 @@ pub enum ColumnType {
     IntervalDayToSecond,
     RowID,
     Cursor,
+    /// User-defined named object type
+    Object,
 }
 
 impl std::fmt::Display for ColumnType {
@@ -57,6 +62,109 @@ impl std::fmt::Display for ColumnType {
             ColumnType::IntervalDayToSecond => write!(f, "INTERVAL DAY TO SECOND"),
             ColumnType::RowID => write!(f, "ROWID"),
             ColumnType::Cursor => write!(f, "SYS_REFCURSOR"),
+            ColumnType::Object => write!(f, "OBJECT"),
+        }
+    }
+}
+
+impl ColumnType {
+    /**
+    Returns the SQLT code of the Oracle internal data type that OCI reports for columns of this type.
+
+    National character set variants share codes with their database character set counterparts.
+    `Unknown` returns 0.
+
+    # Example
+
+    ```
+    use sibyl::ColumnType;
+
+    assert_eq!(ColumnType::Number.sqlt_code(), 2);
+    assert_eq!(ColumnType::NVarchar.sqlt_code(), ColumnType::Varchar.sqlt_code());
+    ```
+    */
+    pub fn sqlt_code(&self) -> u16 {
+        match self {
+            ColumnType::Unknown                     => 0,
+            ColumnType::Char | ColumnType::NChar    => SQLT_AFC,
+            ColumnType::Varchar | ColumnType::NVarchar => SQLT_CHR,
+            ColumnType::Clob | ColumnType::NClob    => SQLT_CLOB,
+            ColumnType::Long                        => SQLT_LNG,
+            ColumnType::Raw                         => SQLT_BIN,
+            ColumnType::LongRaw                     => SQLT_LBI,
+            ColumnType::Blob                        => SQLT_BLOB,
+            ColumnType::Number                      => SQLT_NUM,
+            ColumnType::BinaryFloat                 => SQLT_IBFLOAT,
+            ColumnType::BinaryDouble                => SQLT_IBDOUBLE,
+            ColumnType::Date                        => SQLT_DAT,
+            ColumnType::Timestamp                   => SQLT_TIMESTAMP,
+            ColumnType::TimestampWithTimeZone       => SQLT_TIMESTAMP_TZ,
+            ColumnType::TimestampWithLocalTimeZone  => SQLT_TIMESTAMP_LTZ,
+            ColumnType::IntervalYearToMonth         => SQLT_INTERVAL_YM,
+            ColumnType::IntervalDayToSecond         => SQLT_INTERVAL_DS,
+            ColumnType::RowID                       => SQLT_RDD,
+            ColumnType::Cursor                      => SQLT_RSET,
+            ColumnType::Object                      => SQLT_NTY,
+        }
+    }
+
+    /**
+    Returns the column type for the specified SQLT code of the Oracle internal data type.
+
+    As the code alone does not identify the character set form, text types are always
+    mapped to their database character set variants. Codes of types that Sibyl does not
+    decode are mapped to `Unknown`.
+
+    # Example
+
+    ```
+    use sibyl::ColumnType;
+
+    assert_eq!(ColumnType::from_sqlt(12), ColumnType::Date);
+    assert_eq!(ColumnType::from_sqlt(ColumnType::Timestamp.sqlt_code()), ColumnType::Timestamp);
+    ```
+    */
+    pub fn from_sqlt(code: u16) -> Self {
+        match code {
+            SQLT_RDD            => ColumnType::RowID,
+            SQLT_CHR            => ColumnType::Varchar,
+            SQLT_AFC            => ColumnType::Char,
+            SQLT_CLOB           => ColumnType::Clob,
+            SQLT_LNG            => ColumnType::Long,
+            SQLT_BIN            => ColumnType::Raw,
+            SQLT_LBI            => ColumnType::LongRaw,
+            SQLT_BLOB           => ColumnType::Blob,
+            SQLT_NUM            => ColumnType::Number,
+            SQLT_DAT            => ColumnType::Date,
+            SQLT_TIMESTAMP      => ColumnType::Timestamp,
+            SQLT_TIMESTAMP_TZ   => ColumnType::TimestampWithTimeZone,
+            SQLT_TIMESTAMP_LTZ  => ColumnType::TimestampWithLocalTimeZone,
+            SQLT_INTERVAL_YM    => ColumnType::IntervalYearToMonth,
+            SQLT_INTERVAL_DS    => ColumnType::IntervalDayToSecond,
+            SQLT_IBFLOAT        => ColumnType::BinaryFloat,
+            SQLT_IBDOUBLE       => ColumnType::BinaryDouble,
+            SQLT_RSET           => ColumnType::Cursor,
+            SQLT_NTY            => ColumnType::Object,
+            _                   => ColumnType::Unknown,
+        }
+    }
+
+    /// Returns the SQLT type of the output buffer that can be explicitly defined for this column type.
+    pub(crate) fn define_type(&self) -> Option<u16> {
+        match self {
+            ColumnType::Char | ColumnType::NChar |
+            ColumnType::Varchar | ColumnType::NVarchar => Some(SQLT_CHR),
+            ColumnType::Raw                         => Some(SQLT_BIN),
+            ColumnType::Number                      => Some(SQLT_NUM),
+            ColumnType::BinaryFloat                 => Some(SQLT_IBFLOAT),
+            ColumnType::BinaryDouble                => Some(SQLT_IBDOUBLE),
+            ColumnType::Date                        => Some(SQLT_DAT),
+            ColumnType::Timestamp                   => Some(SQLT_TIMESTAMP),
+            ColumnType::TimestampWithTimeZone       => Some(SQLT_TIMESTAMP_TZ),
+            ColumnType::TimestampWithLocalTimeZone  => Some(SQLT_TIMESTAMP_LTZ),
+            ColumnType::IntervalYearToMonth         => Some(SQLT_INTERVAL_YM),
+            ColumnType::IntervalDayToSecond         => Some(SQLT_INTERVAL_DS),
+            _ => None
         }
     }
 }
@@ -82,6 +190,15 @@ impl<'a> ColumnInfo<'a> {
         Ok(invisible == 0)
     }
 
+    /// Returns `true` if a column was declared as INVISIBLE.
+    ///
+    /// Invisible columns are not included into `SELECT *` projections. They are returned
+    /// only when they are explicitly named in the select list.
+    pub fn is_invisible(&self) -> Result<bool> {
+        let invisible: u8 = self.get_attr(OCI_ATTR_INVISIBLE_COL)?;
+        Ok(invisible != 0)
+    }
+
     /// Returns `true` if NULLs are permitted in the column.
     ///
     /// Does not return a correct value for a CUBE or ROLLUP operation.
@@ -104,7 +221,17 @@ impl<'a> ColumnInfo<'a> {
     }
 
     /// Returns true if column was declared as GENERATED BY DEFAULT ON NULL.
+    #[deprecated = "Use [`is_generated_by_default_on_null`](ColumnInfo::is_generated_by_default_on_null) instead."]
     pub fn is_generated_on_null(&self) -> Result<bool> {
+        self.is_generated_by_default_on_null()
+    }
+
+    /// Returns `true` if column was declared as GENERATED BY DEFAULT ON NULL.
+    ///
+    /// Together with [`is_identity`](ColumnInfo::is_identity) and [`is_generated_always`](ColumnInfo::is_generated_always)
+    /// this allows callers to find columns, which values are generated by the database and thus
+    /// can be omitted from INSERTs.
+    pub fn is_generated_by_default_on_null(&self) -> Result<bool> {
         let col_props: u8 = self.get_attr(OCI_ATTR_COL_PROPERTIES)?;
         Ok(col_props & OCI_ATTR_COL_PROPERTY_IS_GEN_BY_DEF_ON_NULL != 0)
     }
@@ -147,35 +274,20 @@ impl<'a> ColumnInfo<'a> {
 
     /// Returns column data type.
     pub fn data_type(&self) -> Result<ColumnType> {
-        let col_type = match self.get_attr::<u16>(OCI_ATTR_DATA_TYPE)? {
-            SQLT_RDD => ColumnType::RowID,
-            SQLT_CHR => match self.get_attr::<u8>(OCI_ATTR_CHARSET_FORM)? {
+        let col_type = match ColumnType::from_sqlt(self.get_attr::<u16>(OCI_ATTR_DATA_TYPE)?) {
+            ColumnType::Varchar => match self.get_attr::<u8>(OCI_ATTR_CHARSET_FORM)? {
                 SQLCS_NCHAR => ColumnType::NVarchar,
                 _ => ColumnType::Varchar,
             },
-            SQLT_AFC => match self.get_attr::<u8>(OCI_ATTR_CHARSET_FORM)? {
+            ColumnType::Char => match self.get_attr::<u8>(OCI_ATTR_CHARSET_FORM)? {
                 SQLCS_NCHAR => ColumnType::NChar,
                 _ => ColumnType::Char,
             },
-            SQLT_CLOB => match self.get_attr::<u8>(OCI_ATTR_CHARSET_FORM)? {
+            ColumnType::Clob => match self.get_attr::<u8>(OCI_ATTR_CHARSET_FORM)? {
                 SQLCS_NCHAR => ColumnType::NClob,
                 _ => ColumnType::Clob,
             },
-            SQLT_LNG => ColumnType::Long,
-            SQLT_BIN => ColumnType::Raw,
-            SQLT_LBI => ColumnType::LongRaw,
-            SQLT_BLOB => ColumnType::Blob,
-            SQLT_NUM => ColumnType::Number,
-            SQLT_DAT => ColumnType::Date,
-            SQLT_TIMESTAMP => ColumnType::Timestamp,
-            SQLT_TIMESTAMP_TZ => ColumnType::TimestampWithTimeZone,
-            SQLT_TIMESTAMP_LTZ => ColumnType::TimestampWithLocalTimeZone,
-            SQLT_INTERVAL_YM => ColumnType::IntervalYearToMonth,
-            SQLT_INTERVAL_DS => ColumnType::IntervalDayToSecond,
-            SQLT_IBFLOAT => ColumnType::BinaryFloat,
-            SQLT_IBDOUBLE => ColumnType::BinaryDouble,
-            SQLT_RSET => ColumnType::Cursor,
-            _ => ColumnType::Unknown,
+            col_type => col_type,
         };
         Ok(col_type)
     }
@@ -199,6 +311,77 @@ impl<'a> ColumnInfo<'a> {
 //     pub(crate) buf: ColumnBuffer
 // }
 
+/// Output buffer for LONG and LONG RAW values that are fetched piecewise
+pub(crate) struct LongBuffer {
+    data: Vec<u8>,
+    /// Number of bytes in the completed pieces
+    len: usize,
+    /// Size of the current piece. OCI updates it with the actual size of the piece data.
+    piece_len: u32,
+    ind: i16,
+    rcode: u16,
+}
+
+impl LongBuffer {
+    fn new() -> Self {
+        Self { data: Vec::new(), len: 0, piece_len: 0, ind: OCI_IND_NULL, rcode: 0 }
+    }
+
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        &self.data[..self.len + self.piece_len as usize]
+    }
+}
+
+/// Provides OCI with the next piece of the LONG buffer.
+extern "C" fn long_piece_callback(
+    octxp: *mut c_void, _defnp: *mut OCIDefine, _iter: u32,
+    bufpp: *mut *mut c_void, alenpp: *mut *mut u32, piecep: *mut u8,
+    indpp: *mut *mut c_void, rcodepp: *mut *mut u16
+) -> i32 {
+    let buf = unsafe { &mut *(octxp as *mut LongBuffer) };
+    if unsafe { *piecep } == OCI_NEXT_PIECE {
+        buf.len += buf.piece_len as usize;
+    } else {
+        buf.len = 0;
+    }
+    // Double the size of the buffer with each piece
+    let piece_size = buf.len.max(DEFAULT_LONG_BUFFER_SIZE as usize);
+    buf.data.resize(buf.len + piece_size, 0);
+    buf.piece_len = piece_size as u32;
+    unsafe {
+        *bufpp   = buf.data.as_mut_ptr().add(buf.len) as _;
+        *alenpp  = &mut buf.piece_len;
+        *indpp   = &mut buf.ind as *mut i16 as _;
+        *rcodepp = &mut buf.rcode;
+        *piecep  = OCI_NEXT_PIECE;
+    }
+    OCI_CONTINUE
+}
+
+/// Output buffer for the named object type values
+pub(crate) struct ObjectBuffer {
+    /// Type descriptor object
+    pub(crate) tdo: Ptr<OCIType>,
+    /// Object instance in the object cache. OCI allocates it during the first fetch.
+    pub(crate) obj: Ptr<c_void>,
+    /// Null indicator structure of the object instance
+    pub(crate) ind: Ptr<c_void>,
+}
+
+impl ObjectBuffer {
+    fn new(schema: &str, type_name: &str, env: &OCIEnv, err: &OCIError, svc: &OCISvcCtx) -> Result<Self> {
+        let mut tdo = Ptr::<OCIType>::null();
+        oci::type_by_name(env, err, svc, schema, type_name, tdo.as_mut_ptr())?;
+        Ok(Self { tdo, obj: Ptr::null(), ind: Ptr::null() })
+    }
+
+    /// Returns `true` if the last fetched object is atomically null
+    fn is_null(&self) -> bool {
+        // The first element of the null indicator structure is the atomic null indicator of the object
+        self.ind.is_null() || unsafe { *(self.ind.get() as *const i16) } == OCI_IND_NULL
+    }
+}
+
 /// Column output buffer
 pub(crate) enum ColumnBuffer {
     Text(Ptr<OCIString>),
@@ -217,11 +400,16 @@ pub(crate) enum ColumnBuffer {
     Double(f64),
     Rowid(Descriptor<OCIRowid>),
     Cursor(Handle<OCIStmt>),
+    LongText(Box<LongBuffer>),
+    LongBinary(Box<LongBuffer>),
+    Object(Box<ObjectBuffer>),
 }
 
 impl ColumnBuffer {
-    fn new(data_type: u16, data_size: u32, env: &impl AsRef<OCIEnv>, err: &impl AsRef<OCIError>) -> Result<Self> {
+    fn new(data_type: u16, data_size: u32, piecewise_long: bool, env: &impl AsRef<OCIEnv>, err: &impl AsRef<OCIError>) -> Result<Self> {
         let val = match data_type {
+            SQLT_LNG if piecewise_long => ColumnBuffer::LongText(Box::new(LongBuffer::new())),
+            SQLT_LBI if piecewise_long => ColumnBuffer::LongBinary(Box::new(LongBuffer::new())),
             SQLT_DAT => ColumnBuffer::Date(date::new()),
             SQLT_TIMESTAMP => ColumnBuffer::Timestamp(Descriptor::<OCITimestamp>::new(env)?),
             SQLT_TIMESTAMP_TZ => ColumnBuffer::TimestampTZ(Descriptor::<OCITimestampTZ>::new(env)?),
@@ -256,13 +444,15 @@ impl ColumnBuffer {
             ColumnBuffer::Binary(oci_raw_ptr) => {
                 raw::free(oci_raw_ptr, env, err);
             }
+            ColumnBuffer::Object(buf) if !buf.obj.is_null() => {
+                oci::object_free(env, err, buf.obj.get_mut());
+            }
             _ => {}
         }
     }
 
     // Returns (output type, pointer to the output buffer, buffer size)
     fn get_output_buffer_def(&mut self, col_size: usize) -> (u16, *mut c_void, usize) {
-        use std::mem::size_of;
         match self {
             ColumnBuffer::Text(oci_str_ptr)   => (SQLT_LVC, oci_str_ptr.get() as *mut c_void, col_size + size_of::<u32>()),
             ColumnBuffer::Binary(oci_raw_ptr) => (SQLT_LVB, oci_raw_ptr.get() as *mut c_void, col_size + size_of::<u32>()),
@@ -280,6 +470,56 @@ impl ColumnBuffer {
             ColumnBuffer::BFile(lob)          => (SQLT_BFILE, lob.as_ptr() as *mut c_void, size_of::<*mut OCILobLocator>()),
             ColumnBuffer::Rowid(rowid)        => (SQLT_RDD, rowid.as_ptr() as *mut c_void, size_of::<*mut OCIRowid>()),
             ColumnBuffer::Cursor(handle)      => (SQLT_RSET, handle.as_ptr() as *mut c_void, 0),
+            ColumnBuffer::LongText(_)         => (SQLT_CHR, ptr::null_mut(), SB4MAXVAL as usize),
+            ColumnBuffer::LongBinary(_)       => (SQLT_BIN, ptr::null_mut(), SB4MAXVAL as usize),
+            ColumnBuffer::Object(_)           => (SQLT_NTY, ptr::null_mut(), 0),
+        }
+    }
+
+    /// Returns `true` if the buffer is a descriptor, which OCI fetches into via a pointer to it.
+    fn is_descriptor(&self) -> bool {
+        matches!(self,
+            ColumnBuffer::CLOB(_) | ColumnBuffer::BLOB(_) | ColumnBuffer::BFile(_) | ColumnBuffer::Rowid(_) |
+            ColumnBuffer::Timestamp(_) | ColumnBuffer::TimestampTZ(_) | ColumnBuffer::TimestampLTZ(_) |
+            ColumnBuffer::IntervalYM(_) | ColumnBuffer::IntervalDS(_)
+        )
+    }
+
+    /// Returns `true` if values of this buffer's type cannot be fetched into arrays.
+    fn is_scalar_only(&self) -> bool {
+        matches!(self, ColumnBuffer::Cursor(_) | ColumnBuffer::LongText(_) | ColumnBuffer::LongBinary(_) | ColumnBuffer::Object(_))
+    }
+
+    /// Copies the value that an array fetch has written into `elem` into this buffer.
+    /// `elem` is the array element that was defined for this buffer by `RowArray::new`.
+    unsafe fn copy_from(&mut self, elem: *const u8, env: &OCIEnv, err: &OCIError) -> Result<()> {
+        match self {
+            ColumnBuffer::Text(oci_str_ptr) => {
+                let len = ptr::read(elem as *const u32);
+                oci::string_assign_text(env, err, elem.add(size_of::<u32>()), len, oci_str_ptr.as_mut_ptr())
+            }
+            ColumnBuffer::Binary(oci_raw_ptr) => {
+                let len = ptr::read(elem as *const u32);
+                oci::raw_assign_bytes(env, err, elem.add(size_of::<u32>()), len, oci_raw_ptr.as_mut_ptr())
+            }
+            ColumnBuffer::Number(oci_num_box) => {
+                ptr::copy_nonoverlapping(elem as *const OCINumber, oci_num_box.as_mut(), 1);
+                Ok(())
+            }
+            ColumnBuffer::Date(oci_date) => {
+                *oci_date = ptr::read(elem as *const OCIDate);
+                Ok(())
+            }
+            ColumnBuffer::Float(val) => {
+                *val = ptr::read(elem as *const f32);
+                Ok(())
+            }
+            ColumnBuffer::Double(val) => {
+                *val = ptr::read(elem as *const f64);
+                Ok(())
+            }
+            // Descriptors were fetched into directly
+            _ => Ok(())
         }
     }
 }
@@ -290,6 +530,9 @@ pub struct Column {
     buf: ColumnBuffer,
     inf: Descriptor<OCIParam>,
     def: Ptr<OCIDefine>,
+    /// Type and size of the column buffer
+    data_type: u16,
+    data_size: u32,
     /// Length of data fetched
     len: u32,
     /// Output "indicator":
@@ -300,22 +543,67 @@ pub struct Column {
     /// *  0  : Oracle Database assigned an intact value to the host variable
     /// * \>0 : The length of the item is greater than the length of the output variable; the item has been truncated.
     ///         The positive value returned in the indicator variable is the actual length before truncation.
-    ind: i16
+    ind: i16,
+    /// Column-level return code:
+    /// * 0    : The value was fetched intact
+    /// * 1405 : The selected value is null
+    /// * 1406 : The value was truncated
+    rcode: u16,
 }
 
 impl Column {
-    fn new(buf: ColumnBuffer, inf: Descriptor<OCIParam>) -> Self {
+    fn new(buf: ColumnBuffer, inf: Descriptor<OCIParam>, data_type: u16, data_size: u32) -> Self {
         Self {
             buf,
             inf,
             def: Ptr::<OCIDefine>::null(),
+            data_type,
+            data_size,
             len: 0,
-            ind: 0
+            ind: 0,
+            rcode: 0,
+        }
+    }
+
+    /// Defines the column buffer as the output buffer of the column at `pos` (1-based) of the statement.
+    /// The column must not move in memory while it remains defined.
+    fn define(&mut self, stmt: &OCIStmt, pos: usize, err: &OCIError) -> Result<()> {
+        let (output_type, output_buff_ptr, output_buff_size) = self.buf.get_output_buffer_def(self.data_size as usize);
+        let long_buf = match &mut self.buf {
+            ColumnBuffer::LongText(buf) | ColumnBuffer::LongBinary(buf) => Some(buf.as_mut() as *mut LongBuffer),
+            _ => None
+        };
+        oci::define_by_pos(
+            stmt, self.def.as_mut_ptr(), err,
+            pos as u32,
+            output_buff_ptr, output_buff_size as i64, output_type,
+            &mut self.ind,
+            &mut self.len,
+            &mut self.rcode,
+            if long_buf.is_some() { OCI_DYNAMIC_FETCH } else { OCI_DEFAULT }
+        )?;
+        if let Some(long_buf) = long_buf {
+            oci::define_dynamic(self.def.as_ref(), err, long_buf as _, long_piece_callback)?;
         }
+        if let ColumnBuffer::Object(obj_buf) = &mut self.buf {
+            oci::define_object(self.def.as_ref(), err, obj_buf.tdo.as_ref(), obj_buf.obj.as_mut_ptr(), obj_buf.ind.as_mut_ptr())?;
+        }
+        Ok(())
     }
 
     pub(crate) fn is_null(&self) -> bool {
-        self.ind == OCI_IND_NULL
+        match &self.buf {
+            ColumnBuffer::LongText(buf) | ColumnBuffer::LongBinary(buf) => buf.ind == OCI_IND_NULL,
+            ColumnBuffer::Object(buf) => buf.is_null(),
+            _ => self.ind == OCI_IND_NULL
+        }
+    }
+
+    pub(crate) fn return_code(&self) -> u16 {
+        match &self.buf {
+            ColumnBuffer::LongText(buf) | ColumnBuffer::LongBinary(buf) => buf.rcode,
+            _ => self.rcode
+        }
     }
 
     pub(crate) fn data(&mut self) -> &mut ColumnBuffer {
@@ -344,7 +632,7 @@ impl Drop for Columns {
 }
 
 impl Columns {
-    pub(crate) fn new(stmt: Ptr<OCIStmt>, env: Ptr<OCIEnv>, err: Ptr<OCIError>, max_long_fetch_size: u32) -> Result<Self> {
+    pub(crate) fn new(stmt: Ptr<OCIStmt>, svc: Ptr<OCISvcCtx>, env: Ptr<OCIEnv>, err: Ptr<OCIError>, max_long_fetch_size: u32, piecewise_long: bool, lob_prefetch_size: u32, preserve_numbers: bool, defines: &[(usize, u16)]) -> Result<Self> {
         let num_columns : u32 = attr::get(OCI_ATTR_PARAM_COUNT, OCI_HTYPE_STMT, stmt.as_ref(), err.as_ref())?;
         let num_columns = num_columns as usize;
 
@@ -359,21 +647,31 @@ impl Columns {
                 SQLT_LNG | SQLT_LBI => max_long_fetch_size,
                 _ => col_info.get_attr::<u16>(OCI_ATTR_DATA_SIZE, err.as_ref())? as u32 * utf8_factor,
             };
-            cols.push(Column::new(ColumnBuffer::new(data_type, data_size, &env, &err)?, col_info));
+            let (data_type, data_size) = match defines.iter().find(|(ix, _)| *ix == i) {
+                Some(&(_, SQLT_CHR)) => (SQLT_CHR, data_size.max(MIN_TEXT_DEFINE_SIZE)),
+                Some(&(_, define_type)) => (define_type, data_size),
+                None if preserve_numbers && data_type == SQLT_NUM => (SQLT_CHR, MIN_TEXT_DEFINE_SIZE),
+                None => (data_type, data_size),
+            };
+            let buf = if data_type == SQLT_NTY {
+                let schema : &str = col_info.get_attr(OCI_ATTR_SCHEMA_NAME, err.as_ref())?;
+                let type_name : &str = col_info.get_attr(OCI_ATTR_TYPE_NAME, err.as_ref())?;
+                ColumnBuffer::Object(Box::new(ObjectBuffer::new(schema, type_name, env.as_ref(), err.as_ref(), svc.as_ref())?))
+            } else {
+                ColumnBuffer::new(data_type, data_size, piecewise_long, &env, &err)?
+            };
+            cols.push(Column::new(buf, col_info, data_type, data_size));
 
             // Now, that columns buffers are in the vector and thus their locations in memory are fixed,
             // define the output buffers in OCI
+            cols[i].define(stmt.as_ref(), i + 1, err.as_ref())?;
 
-            let (output_type, output_buff_ptr, output_buff_size) = cols[i].buf.get_output_buffer_def(data_size as usize);
-            oci::define_by_pos(
-                stmt.as_ref(), cols[i].def.as_mut_ptr(), err.as_ref(),
-                (i + 1) as u32,
-                output_buff_ptr, output_buff_size as i64, output_type,
-                &mut cols[i].ind,
-                &mut cols[i].len,
-                ptr::null_mut::<u16>(),
-                OCI_DEFAULT
-            )?;
+            if lob_prefetch_size > 0 && matches!(cols[i].buf, ColumnBuffer::CLOB(_) | ColumnBuffer::BLOB(_)) {
+                let def : &OCIDefine = cols[i].def.as_ref();
+                attr::set(OCI_ATTR_LOBPREFETCH_SIZE, lob_prefetch_size, OCI_HTYPE_DEFINE, def, err.as_ref())?;
+                // also prefetch LOB length and chunk size
+                attr::set(OCI_ATTR_LOBPREFETCH_LENGTH, 1u32, OCI_HTYPE_DEFINE, def, err.as_ref())?;
+            }
 
             let name : &str = cols[i].inf.get_attr(OCI_ATTR_NAME, err.as_ref())?;
             names.insert(name, i);
@@ -381,10 +679,41 @@ impl Columns {
         Ok(Self { names, cols, env, err })
     }
 
+    /// Defines the column buffers as the output buffers of the statement again after they were replaced
+    /// by the column arrays of a `RowArray`.
+    pub(crate) fn redefine(&mut self, stmt: &OCIStmt) -> Result<()> {
+        let err = self.err;
+        for (i, col) in self.cols.iter_mut().enumerate() {
+            col.define(stmt, i + 1, err.as_ref())?;
+        }
+        Ok(())
+    }
+
+    /// Creates column buffers for a single row of the projection. They are not defined in OCI.
+    fn new_row(&self, stmt: &OCIStmt) -> Result<Self> {
+        let mut row = Self { names: HashMap::with_capacity(self.cols.len()), cols: Vec::with_capacity(self.cols.len()), env: self.env, err: self.err };
+        for (i, col) in self.cols.iter().enumerate() {
+            let inf = param::get((i + 1) as u32, OCI_HTYPE_STMT, stmt, self.err.as_ref())?;
+            let name : &str = inf.get_attr(OCI_ATTR_NAME, self.err.as_ref())?;
+            if col.buf.is_scalar_only() {
+                return Err(Error::msg(format!("column {} cannot be fetched in batches", name)));
+            }
+            let buf = ColumnBuffer::new(col.data_type, col.data_size, false, &self.env, &self.err)?;
+            row.cols.push(Column::new(buf, inf, col.data_type, col.data_size));
+            row.names.insert(name, i);
+        }
+        Ok(row)
+    }
+
     pub(crate) fn col_index(&self, name: &str) -> Option<usize> {
         self.names.get(name).map(|ix| *ix)
     }
 
+    /// Returns the number of columns in the projection.
+    pub(crate) fn len(&self) -> usize {
+        self.cols.len()
+    }
+
     /// Returns Column at the specified index or None if column index is out of bounds.
     pub(crate) fn col(&self, index: usize) -> Option<&Column> {
         self.cols.get(index)
@@ -404,3 +733,106 @@ impl Columns {
         self.col(index).map(|col| col.inf.get_ptr())
     }
 }
+
+/// Output arrays of a column for an array fetch
+struct ColumnArray {
+    /// Elements of the output array. They are `skip` bytes apart. Descriptor based
+    /// values are fetched via an array of pointers to the row buffers' descriptors.
+    data: Vec<u64>,
+    skip: usize,
+    ind: Vec<i16>,
+    len: Vec<u32>,
+    rcode: Vec<u16>,
+}
+
+/// Output buffers of a multi-row (array) fetch.
+///
+/// Each column of the statement is defined once with arrays of `num_rows` elements. After the
+/// fetch the values are copied from the arrays into the column buffers of the individual rows.
+pub(crate) struct RowArray {
+    rows: Vec<Columns>,
+    cols: Vec<ColumnArray>,
+}
+
+impl RowArray {
+    /**
+    Creates row buffers for `num_rows` rows and defines the statement columns with the output arrays.
+    The statement columns (`proj`) must be [redefined](Columns::redefine) after the array fetch.
+    */
+    pub(crate) fn new(proj: &mut Columns, stmt: &OCIStmt, num_rows: usize) -> Result<Self> {
+        let mut rows = Vec::with_capacity(num_rows);
+        for _ in 0..num_rows {
+            rows.push(proj.new_row(stmt)?);
+        }
+        let err = proj.err;
+        let mut cols = Vec::with_capacity(proj.cols.len());
+        for (i, col) in proj.cols.iter_mut().enumerate() {
+            let (output_type, _, value_size) = col.buf.get_output_buffer_def(col.data_size as usize);
+            // round the element size up to keep the elements aligned
+            let skip = value_size.div_ceil(size_of::<u64>()) * size_of::<u64>();
+            let mut data = vec![0u64; skip * num_rows / size_of::<u64>()];
+            if col.buf.is_descriptor() {
+                let elems = data.as_mut_ptr() as *mut *mut c_void;
+                for (r, row) in rows.iter_mut().enumerate() {
+                    let (_, desc_ptr, _) = row.cols[i].buf.get_output_buffer_def(0);
+                    unsafe { *elems.add(r) = *(desc_ptr as *const *mut c_void) };
+                }
+            }
+            let mut arr = ColumnArray { data, skip, ind: vec![0; num_rows], len: vec![0; num_rows], rcode: vec![0; num_rows] };
+            oci::define_by_pos(
+                stmt, col.def.as_mut_ptr(), err.as_ref(),
+                (i + 1) as u32,
+                arr.data.as_mut_ptr() as _, value_size as i64, output_type,
+                arr.ind.as_mut_ptr(),
+                arr.len.as_mut_ptr(),
+                arr.rcode.as_mut_ptr(),
+                OCI_DEFAULT
+            )?;
+            oci::define_array_of_struct(
+                col.def.as_ref(), err.as_ref(),
+                skip as u32, size_of::<i16>() as u32, size_of::<u32>() as u32, size_of::<u16>() as u32
+            )?;
+            cols.push(arr);
+        }
+        Ok(Self { rows, cols })
+    }
+
+    /// Moves the fetched values into the row buffers and returns the first `num_rows` rows.
+    pub(crate) fn into_rows(self, num_rows: usize) -> Result<Vec<Columns>> {
+        let Self { mut rows, cols } = self;
+        rows.truncate(num_rows);
+        for (r, row) in rows.iter_mut().enumerate() {
+            let (env, err) = (row.env, row.err);
+            for (col, arr) in row.cols.iter_mut().zip(cols.iter()) {
+                col.ind = arr.ind[r];
+                col.len = arr.len[r];
+                col.rcode = arr.rcode[r];
+                if col.ind != OCI_IND_NULL {
+                    let elem = unsafe { (arr.data.as_ptr() as *const u8).add(r * arr.skip) };
+                    unsafe { col.buf.copy_from(elem, env.as_ref(), err.as_ref())? };
+                }
+            }
+        }
+        Ok(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ColumnType;
+
+    #[test]
+    fn sqlt_codes() {
+        for col_type in [
+            ColumnType::Varchar, ColumnType::Char, ColumnType::Number, ColumnType::Date,
+            ColumnType::Timestamp, ColumnType::IntervalDayToSecond, ColumnType::Raw,
+            ColumnType::Blob, ColumnType::RowID, ColumnType::Cursor,
+        ] {
+            assert_eq!(ColumnType::from_sqlt(col_type.sqlt_code()), col_type);
+        }
+        assert_eq!(ColumnType::from_sqlt(ColumnType::NVarchar.sqlt_code()), ColumnType::Varchar);
+        assert_eq!(ColumnType::from_sqlt(ColumnType::NClob.sqlt_code()), ColumnType::Clob);
+        assert_eq!(ColumnType::Unknown.sqlt_code(), 0);
+        assert_eq!(ColumnType::from_sqlt(0), ColumnType::Unknown);
+    }
+}
@@ -36,6 +36,13 @@ impl AsRef<OCIStmt> for RefCursor {
 }
 
 impl RefCursor {
+    fn as_ptr(&self) -> *const *mut OCIStmt {
+        match self {
+            RefCursor::Handle( handle ) => handle.as_ptr(),
+            RefCursor::Ptr( ptr )       => ptr.as_ptr(),
+        }
+    }
+
     fn as_mut_ptr(&mut self) -> *mut *mut OCIStmt {
         match self {
             RefCursor::Handle( handle ) => handle.as_mut_ptr(),
@@ -140,6 +147,15 @@ impl ToSql for &mut Cursor<'_> {
     }
 }
 
+// Already open cursor passed to PL/SQL as an IN argument
+impl ToSql for &Cursor<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        let len = std::mem::size_of::<*mut OCIStmt>();
+        params.bind_in(pos, SQLT_RSET, self.cursor.as_ptr() as _, len, stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
 impl<'a> Cursor<'a> {
     pub(crate) fn read_columns(&self) -> RwLockReadGuard<Columns> {
         self.cols.get().expect("locked columns").read()
@@ -149,6 +165,11 @@ impl<'a> Cursor<'a> {
         self.cols.get().expect("locked columns").write()
     }
 
+    /// Creates and defines new output buffers for the cursor columns
+    pub(crate) fn new_columns(&self) -> Result<Columns> {
+        Columns::new(Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), self.max_long, false, 0, false, &[])
+    }
+
     pub(crate) fn session(&self) -> &Session {
         self.source.session()
     }
@@ -329,6 +350,9 @@ impl<'a> Cursor<'a> {
         # }
         ```
         See also [`Statement::next_result`] for another method to return REF CURSORs.
+
+        An open cursor can also be passed back to PL/SQL as an IN `SYS_REFCURSOR` argument.
+        To bind it as such, pass a shared reference (`&cursor`) as the argument.
     */
     pub fn new(stmt: &'a Statement) -> Result<Self> {
         let handle = Handle::<OCIStmt>::new(stmt)?;
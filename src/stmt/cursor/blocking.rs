@@ -2,7 +2,7 @@
 
 use parking_lot::RwLock;
 
-use crate::{Cursor, Result, Rows, oci::*, stmt::cols::Columns};
+use crate::{Cursor, FromRow, Result, Rows, oci::*};
 
 impl<'a> Cursor<'a> {
     /**
@@ -53,9 +53,62 @@ impl<'a> Cursor<'a> {
     */
     pub fn rows(&self) -> Result<Rows> {
         if self.cols.get().is_none() {
-            let cols = Columns::new(Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), self.max_long)?;
+            let cols = self.new_columns()?;
             self.cols.get_or_init(|| RwLock::new(cols));
         };
         Ok( Rows::from_cursor(OCI_SUCCESS, self) )
     }
-}
\ No newline at end of file
+
+    /**
+    Consumes the cursor and returns an iterator over its rows mapped into `T`.
+
+    The iterator ends when there are no more rows or after the first error.
+
+    # Example
+
+    ```
+    use sibyl::Cursor;
+
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT last_name
+             , CURSOR(
+                    SELECT department_name
+                      FROM hr.departments
+                     WHERE department_id IN (
+                                SELECT department_id
+                                  FROM hr.employees
+                                 WHERE last_name = e.last_name)
+                  ORDER BY department_name
+               ) AS departments
+          FROM (
+                SELECT DISTINCT last_name
+                  FROM hr.employees
+                 WHERE last_name = :last_name
+               ) e
+    ")?;
+    let row = stmt.query_single("King")?.unwrap();
+
+    let departments : Cursor = row.get(1)?;
+    let names = departments.into_iter::<(String,)>()
+        .map(|res| res.map(|(name,)| name))
+        .collect::<sibyl::Result<Vec<_>>>()?;
+    assert_eq!(names, ["Executive", "Sales"]);
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    #[allow(clippy::should_implement_trait)]
+    pub fn into_iter<T: FromRow + 'a>(self) -> impl Iterator<Item=Result<T>> + 'a {
+        let mut done = false;
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            let res = self.rows().and_then(|rows|
+                rows.next()?.map(|row| T::from_row(&row)).transpose()
+            ).transpose();
+            done = !matches!(res, Some(Ok(_)));
+            res
+        })
+    }
+}
@@ -1,8 +1,10 @@
 //! Nonblocking cursor
 
+use std::{future::Future, marker::PhantomData, pin::Pin, task::{Context, Poll}};
+use futures_core::Stream;
 use parking_lot::RwLock;
 
-use crate::{Cursor, Result, Rows, oci::*, stmt::cols::Columns};
+use crate::{Cursor, Error, FromRow, Result, Row, Rows, oci::*};
 
 impl<'a> Cursor<'a> {
     /**
@@ -57,10 +59,112 @@ impl<'a> Cursor<'a> {
         // Cursor::rows will be .await-ed in the same fashion as Statement::rows is
         async {
             if self.cols.get().is_none() {
-                let cols = Columns::new(Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), self.max_long)?;
+                let cols = self.new_columns()?;
                 self.cols.get_or_init(|| RwLock::new(cols));
             }
             Ok( Rows::from_cursor(OCI_SUCCESS, self) )
         }.await
     }
-}
\ No newline at end of file
+
+    /**
+    Consumes the cursor and returns a stream of its rows mapped into `T`.
+
+    The stream ends when there are no more rows or after the first error.
+
+    # Example
+
+    ```
+    use std::{future::poll_fn, pin::pin};
+    use futures_core::Stream;
+    use sibyl::Cursor;
+
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let stmt = session.prepare("
+        SELECT last_name
+             , CURSOR(
+                    SELECT department_name
+                      FROM hr.departments
+                     WHERE department_id IN (
+                                SELECT department_id
+                                  FROM hr.employees
+                                 WHERE last_name = e.last_name)
+                  ORDER BY department_name
+               ) AS departments
+          FROM (
+                SELECT DISTINCT last_name
+                  FROM hr.employees
+                 WHERE last_name = :last_name
+               ) e
+    ").await?;
+    let row = stmt.query_single("King").await?.unwrap();
+
+    let departments : Cursor = row.get(1)?;
+    let mut departments = pin!(departments.into_stream::<(String,)>());
+    let mut names = Vec::new();
+    // `StreamExt::next` from the `futures` crate would be used here in a real application
+    while let Some(res) = poll_fn(|cx| departments.as_mut().poll_next(cx)).await {
+        let (name,) = res?;
+        names.push(name);
+    }
+    assert_eq!(names, ["Executive", "Sales"]);
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub fn into_stream<T: FromRow + 'a>(self) -> impl Stream<Item=Result<T>> + 'a {
+        CursorRows { fetch: None, cursor: self, done: false, phantom_row: PhantomData }
+    }
+}
+
+/// A stream of the rows of the consumed cursor.
+struct CursorRows<'a, T> {
+    // `fetch` borrows handles of the `cursor` and thus must be dropped first
+    fetch: Option<futures::StmtFetch<'a>>,
+    cursor: Cursor<'a>,
+    done: bool,
+    phantom_row: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: FromRow> Stream for CursorRows<'a, T> {
+    type Item = Result<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+        if this.cursor.cols.get().is_none() {
+            match this.cursor.new_columns() {
+                Ok(cols) => { this.cursor.cols.get_or_init(|| RwLock::new(cols)); },
+                Err(err) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+            }
+        }
+        if this.fetch.is_none() {
+            // Statement and error handles are allocated by OCI. They do not move when the stream
+            // is moved and they outlive the fetch as it is dropped before the cursor.
+            let stmt: &'a OCIStmt  = unsafe { &*(this.cursor.as_ref() as *const OCIStmt) };
+            let err:  &'a OCIError = unsafe { &*(this.cursor.as_ref() as *const OCIError) };
+            this.fetch = Some(futures::StmtFetch::new(this.cursor.session().get_svc(), stmt, err));
+        }
+        let res = match Pin::new(this.fetch.as_mut().unwrap()).poll(cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(res) => res,
+        };
+        this.fetch = None;
+        let res = match res {
+            Ok(OCI_NO_DATA) => None,
+            Ok(res @ (OCI_SUCCESS | OCI_SUCCESS_WITH_INFO)) => {
+                let rows = Rows::from_cursor(res, &this.cursor);
+                let row = Row::new(&rows);
+                Some(T::from_row(&row))
+            },
+            Ok(res) => Some(Err(Error::oci(this.cursor.as_ref(), res))),
+            Err(err) => Some(Err(err)),
+        };
+        this.done = !matches!(res, Some(Ok(_)));
+        Poll::Ready(res)
+    }
+}
@@ -4,8 +4,8 @@ use crate::{
     IntervalDS, IntervalYM, Result, RowID, Timestamp, TimestampLTZ, TimestampTZ,
     oci::*,
     types::{
-        date, interval, number, raw, timestamp, varchar,
-        Date, Varchar, rowid
+        date, interval, number, object, raw, timestamp, varchar,
+        Date, Number, Varchar, Object, rowid
     },
     lob::{ self, LOB }, 
     Raw,
@@ -38,7 +38,7 @@ impl<'a> FromSql<'a> for String {
         match col.data() {
             ColumnBuffer::Text( oci_str_ptr )   => Ok( varchar::to_string(oci_str_ptr, row.as_ref()) ),
             ColumnBuffer::Number( oci_num_box ) => number::to_string("TM", oci_num_box.as_ref(), row.as_ref()),
-            ColumnBuffer::Date( oci_date )      => date::to_string("YYYY-MM-DD HH24::MI:SS", oci_date, row.as_ref()),
+            ColumnBuffer::Date( oci_date )      => date::to_string("YYYY-MM-DD HH24:MI:SS", oci_date, row.as_ref()),
             ColumnBuffer::Timestamp( ts )       => timestamp::to_string("YYYY-MM-DD HH24:MI:SSXFF", 3, ts.as_ref(), row),
             ColumnBuffer::TimestampTZ( ts )     => timestamp::to_string("YYYY-MM-DD HH24:MI:SSXFF TZH:TZM", 3, ts.as_ref(), row),
             ColumnBuffer::TimestampLTZ( ts )    => timestamp::to_string("YYYY-MM-DD HH24:MI:SSXFF TZH:TZM", 3, ts.as_ref(), row),
@@ -47,6 +47,7 @@ impl<'a> FromSql<'a> for String {
             ColumnBuffer::Float( val )          => Ok( val.to_string() ),
             ColumnBuffer::Double( val )         => Ok( val.to_string() ),
             ColumnBuffer::Rowid( rowid )        => rowid::to_string(rowid, row.as_ref()),
+            ColumnBuffer::LongText( buf )       => std::str::from_utf8(buf.as_bytes()).map(str::to_string).map_err(|err| Error::msg(format!("cannot return as a String: {}", err))),
             _                                   => Err( Error::new("cannot return as a String") )
         }
     }
@@ -69,6 +70,12 @@ impl<'a> FromSql<'a> for &'a str {
         assert_not_null(row, col)?;
         match col.data() {
             ColumnBuffer::Text( oci_str_ptr ) => Ok( varchar::as_str(&oci_str_ptr, row.as_ref()) ),
+            ColumnBuffer::LongText( buf ) => {
+                // inlined LongBuffer::as_bytes to deal with the buffer lifetime issue
+                let bytes = buf.as_bytes();
+                let bytes = unsafe { std::slice::from_raw_parts(bytes.as_ptr(), bytes.len()) };
+                std::str::from_utf8(bytes).map_err(|err| Error::msg(format!("cannot borrow as &str: {}", err)))
+            }
             _ => Err( Error::new("cannot borrow as &str") )
         }
     }
@@ -86,16 +93,57 @@ impl<'a> FromSql<'a> for &'a [u8] {
                     std::slice::from_raw_parts(ptr, len)
                 }
             }),
+            ColumnBuffer::LongBinary( buf ) => Ok( {
+                let bytes = buf.as_bytes();
+                unsafe {
+                    std::slice::from_raw_parts(bytes.as_ptr(), bytes.len())
+                }
+            }),
             _ => Err( Error::new("cannot borrow as &[u8]") )
         }
     }
 }
 
-impl<'a, T: number::Integer> FromSql<'a> for T {
+impl<'a> FromSql<'a> for Vec<u8> {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        assert_not_null(row, col)?;
+        match col.data() {
+            ColumnBuffer::Binary( oci_raw_ptr ) => Ok( {
+                let ptr = raw::as_ptr(oci_raw_ptr, row.as_ref());
+                let len = raw::len(oci_raw_ptr, row.as_ref());
+                unsafe {
+                    std::slice::from_raw_parts(ptr, len)
+                }.to_vec()
+            }),
+            ColumnBuffer::LongBinary( buf ) => Ok( buf.as_bytes().to_vec() ),
+            _ => Err( Error::new("cannot return as Vec<u8>") )
+        }
+    }
+}
+
+impl<'a, const N: usize> FromSql<'a> for [u8; N] {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let bytes : &[u8] = FromSql::value(row, col)?;
+        if bytes.len() != N {
+            return Err(Error::msg(format!("cannot return {} bytes as [u8; {}]", bytes.len(), N)));
+        }
+        let mut array = [0u8; N];
+        array.copy_from_slice(bytes);
+        Ok(array)
+    }
+}
+
+/// Parses a number that was fetched as text - see `Statement::preserve_number_precision`.
+fn parse_number<T: std::str::FromStr>(text: &str) -> Result<T> {
+    text.trim().parse().map_err(|_| Error::msg(format!("cannot parse {} as a number", text)))
+}
+
+impl<'a, T: number::Integer + std::str::FromStr> FromSql<'a> for T {
     fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
         assert_not_null(row, col)?;
         match col.data() {
             ColumnBuffer::Number( oci_num_box ) => <T>::from_number(oci_num_box, row.as_ref()),
+            ColumnBuffer::Text( oci_str_ptr )   => parse_number(varchar::as_str(oci_str_ptr, row.as_ref())),
             _ => Err( Error::new("cannot return as an integer") )
         }
     }
@@ -106,6 +154,7 @@ impl<'a> FromSql<'a> for f32 {
         assert_not_null(row, col)?;
         match col.data() {
             ColumnBuffer::Number( oci_num_box ) => number::to_real(oci_num_box, row.as_ref()),
+            ColumnBuffer::Text( oci_str_ptr )   => parse_number(varchar::as_str(oci_str_ptr, row.as_ref())),
             ColumnBuffer::Float( val )          => Ok( *val ),
             ColumnBuffer::Double( val )         => Ok( *val as f32 ),
             ColumnBuffer::IntervalYM( int )     => {
@@ -126,6 +175,7 @@ impl<'a> FromSql<'a> for f64 {
         assert_not_null(row, col)?;
         match col.data() {
             ColumnBuffer::Number( oci_num_box ) => number::to_real(oci_num_box, row.as_ref()),
+            ColumnBuffer::Text( oci_str_ptr )   => parse_number(varchar::as_str(oci_str_ptr, row.as_ref())),
             ColumnBuffer::Float( val )          => Ok( *val as f64 ),
             ColumnBuffer::Double( val )         => Ok( *val ),
             ColumnBuffer::IntervalYM( int )     => {
@@ -303,6 +353,41 @@ impl<'a> FromSql<'a> for Raw<'a> {
     }
 }
 
+impl<'a> FromSql<'a> for Object<'a> {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        assert_not_null(row, col)?;
+        match col.data() {
+            ColumnBuffer::Object( buf ) => Ok( Object::new(buf.obj, buf.ind, buf.tdo, row) ),
+            _ => Err( Error::new("cannot return as an Object") )
+        }
+    }
+}
+
+// VARRAYs and nested tables are described as SQLT_NTY columns, thus their
+// values are fetched into the object buffers.
+macro_rules! impl_from_collection {
+    ($($t:ty),+) => {
+        $(
+            impl<'a> FromSql<'a> for Vec<$t> {
+                fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+                    assert_not_null(row, col)?;
+                    match col.data() {
+                        ColumnBuffer::Object( buf ) => object::collection_to_vec(buf.obj, buf.tdo, row),
+                        _ => Err( Error::new("cannot return as a Vec") )
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_from_collection!(
+    Number<'a>, Date<'a>, String, &'a str, f32, f64, i8, i16, i32, i64, i128, isize, u16, u32, u64, u128, usize,
+    Option<Number<'a>>, Option<Date<'a>>, Option<String>, Option<&'a str>, Option<f32>, Option<f64>,
+    Option<i8>, Option<i16>, Option<i32>, Option<i64>, Option<i128>, Option<isize>,
+    Option<u8>, Option<u16>, Option<u32>, Option<u64>, Option<u128>, Option<usize>
+);
+
 impl<'a, T: FromSql<'a>> FromSql<'a> for Option<T> {
     fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
         if col.is_null() {
@@ -415,4 +500,28 @@ mod tests {
         let rowid : RowID = row.get(0)?;
         Ok(rowid)
     }
+
+    #[test]
+    fn from_raw_to_byte_array() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+        let stmt = session.prepare("
+            SELECT Cast(HexToRaw('00112233445566778899AABBCCDDEEFF') AS RAW(16))
+                 , Cast(HexToRaw('0011223344') AS RAW(16))
+              FROM dual
+        ")?;
+        let row = stmt.query_single(())?.unwrap();
+        let key : [u8; 16] = row.get(0)?;
+        assert_eq!(key, [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff]);
+
+        let res : Result<[u8; 16]> = row.get(1);
+        match res {
+            Ok(_) => panic!("unexpected conversion of 5 bytes into [u8; 16]"),
+            Err(Error::Interface(msg)) => assert_eq!(msg, "cannot return 5 bytes as [u8; 16]"),
+            Err(err) => panic!("unexpected error: {:?}", err)
+        }
+        let key : [u8; 5] = row.get(1)?;
+        assert_eq!(key, [0x00, 0x11, 0x22, 0x33, 0x44]);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,124 @@
+//! Conversion of JSON documents stored as text into `serde_json::Value`
+//!
+//! JSON text can be fetched from `VARCHAR2`, `LONG` and, in `blocking` mode, `CLOB` columns.
+//! CLOB locator is consumed when its content is read. In `nonblocking` mode, where the CLOB content
+//! can only be read asynchronously, fetch the JSON document via `LOB::read` or select it as
+//! `VARCHAR2`, for example, via `JSON_SERIALIZE(doc RETURNING VARCHAR2(32767))`.
+//!
+//! # Example
+//!
+//! 🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+//! to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+//!
+//! ```
+//! use serde_json::Value;
+//!
+//! # use sibyl::Result;
+//! # #[cfg(feature="blocking")]
+//! # fn main() -> Result<()> {
+//! # let session = sibyl::test_env::get_session()?;
+//! let stmt = session.prepare(r#"
+//!     SELECT '{"name": "Steven King", "phones": ["515.123.4567"]}'
+//!       FROM dual
+//! "#)?;
+//! let row = stmt.query_single(())?.unwrap();
+//! let doc : Value = row.get(0)?;
+//! assert_eq!(doc["name"], "Steven King");
+//! assert_eq!(doc["phones"][0], "515.123.4567");
+//! # Ok(())
+//! # }
+//! # #[cfg(feature="nonblocking")]
+//! # fn main() -> Result<()> {
+//! # sibyl::block_on(async {
+//! # let session = sibyl::test_env::get_session().await?;
+//! # let stmt = session.prepare(r#"
+//! #     SELECT '{"name": "Steven King", "phones": ["515.123.4567"]}'
+//! #       FROM dual
+//! # "#).await?;
+//! # let row = stmt.query_single(()).await?.unwrap();
+//! # let doc : Value = row.get(0)?;
+//! # assert_eq!(doc["name"], "Steven King");
+//! # assert_eq!(doc["phones"][0], "515.123.4567");
+//! # Ok(()) })
+//! # }
+//! ```
+
+use serde_json::Value;
+use super::{cols::{Column, ColumnBuffer}, data::FromSql, rows::Row};
+use crate::{Error, Result};
+
+fn parse(text: &str) -> Result<Value> {
+    serde_json::from_str(text).map_err(|err| Error::msg(format!("cannot parse JSON: {}", err)))
+}
+
+impl<'a> FromSql<'a> for Value {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        if let ColumnBuffer::CLOB(_) = col.data() {
+            read_clob(row, col)
+        } else {
+            let text : &str = FromSql::value(row, col)?;
+            parse(text)
+        }
+    }
+}
+
+#[cfg(feature="blocking")]
+fn read_clob<'a>(row: &'a Row<'a>, col: &mut Column) -> Result<Value> {
+    let lob : crate::CLOB = FromSql::value(row, col)?;
+    let len = lob.len()?;
+    let mut text = String::new();
+    lob.read(0, len, &mut text)?;
+    parse(&text)
+}
+
+#[cfg(feature="nonblocking")]
+fn read_clob<'a>(_row: &'a Row<'a>, _col: &mut Column) -> Result<Value> {
+    Err(Error::new("cannot read CLOB content in nonblocking mode - read it via LOB::read or fetch JSON as VARCHAR2"))
+}
+
+#[cfg(all(test,feature="blocking"))]
+mod tests {
+    use serde_json::Value;
+    use crate::*;
+
+    #[test]
+    fn json_clob() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_json_docs (
+                        id      NUMBER PRIMARY KEY,
+                        doc     CLOB
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_json_docs
+                    ';
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("INSERT INTO test_json_docs (id, doc) VALUES (:ID, :DOC)")?;
+        stmt.execute(((":ID", 1), (":DOC", r#"{"department": "IT", "employees": [{"id": 103, "name": "Alexander Hunold"}, {"id": 104, "name": "Bruce Ernst"}]}"#)))?;
+        stmt.execute(((":ID", 2), (":DOC", "not a JSON")))?;
+
+        let stmt = session.prepare("SELECT doc FROM test_json_docs WHERE id = :ID")?;
+        let row = stmt.query_single(1)?.unwrap();
+        let doc : Value = row.get(0)?;
+        assert_eq!(doc["department"], "IT");
+        assert_eq!(doc["employees"][1]["id"], 104);
+        assert_eq!(doc["employees"][1]["name"], "Bruce Ernst");
+
+        let row = stmt.query_single(2)?.unwrap();
+        let res : Result<Value> = row.get(0);
+        assert!(res.is_err());
+
+        session.rollback()?;
+        Ok(())
+    }
+}
@@ -1,17 +1,22 @@
 //! Nonblocking SQL statement methods
 
-use super::{Statement, bind::Params, cols::{DEFAULT_LONG_BUFFER_SIZE, Columns}};
-use crate::{Result, oci::*, Session, Error, Rows, Cursor, ToSql, Row};
-use parking_lot::RwLock;
+use super::{Statement, BatchResult, batch::BatchRowIds, TempLob, IDENTITY_COLUMN_SQL, ROW_ESTIMATE_SQL, bind::Params, cols::{ColumnInfo, DEFAULT_LONG_BUFFER_SIZE}};
+use crate::{Result, oci::*, Session, CLOB, BLOB, Error, Rows, Cursor, ToSql, ToRow, FromSql, FromRow, Row, RowID, env::HandleKind};
+use parking_lot::{Mutex, RwLock};
 use once_cell::sync::OnceCell;
+use std::sync::atomic::{AtomicU32, Ordering};
+use futures_core::Stream;
+use std::{future::Future, pin::Pin, task::{Context, Poll}};
 
 impl<'a> Statement<'a> {
     /// Creates a new statement
     pub(crate) async fn new(sql: &str, session: &'a Session<'a>) -> Result<Statement<'a>> {
         let err = Handle::<OCIError>::new(session)?;
         let stmt = futures::StmtPrepare::new(session.get_svc(), &err, sql).await?;
-        let params = Params::new(&stmt, &err)?.map(|params| RwLock::new(params));
-        let stmt = Self {session, svc: session.get_svc(), stmt, params, cols: OnceCell::new(), err, max_long: DEFAULT_LONG_BUFFER_SIZE};
+        let params = Params::new(&stmt, session.as_ref(), &err)?.map(|params| RwLock::new(params));
+        let svc = session.get_svc();
+        let _handle = svc.track(HandleKind::Statement);
+        let stmt = Self {session, svc, stmt, params, cols: OnceCell::new(), err, max_long: DEFAULT_LONG_BUFFER_SIZE, piecewise_long: false, lob_prefetch: 0, prefetch_rows: AtomicU32::new(0), fetch_array: AtomicU32::new(0), preserve_numbers: session.preserves_number_precision(), defines: Vec::new(), _handle, snap_in: None, snap_out: None, temp_lobs: Mutex::new(Vec::new())};
         stmt.set_prefetch_rows(10)?;
         Ok(stmt)
     }
@@ -25,10 +30,46 @@ impl<'a> Statement<'a> {
         }
     }
 
+    /// Binds `LobArg` arguments as temporary LOBs.
+    async fn bind_lob_args(&self) -> Result<()> {
+        self.temp_lobs.lock().clear();
+        let lob_args = match &self.params {
+            Some(params) => params.write().take_lob_binds(),
+            None => return Ok(()),
+        };
+        let mut temp_lobs = Vec::with_capacity(lob_args.len());
+        for arg in lob_args.iter() {
+            let lob = if arg.sql_type == SQLT_CHR {
+                let lob = CLOB::temp(self.session, arg.charset_form(), Cache::No).await?;
+                lob.append(arg.text()?).await?;
+                TempLob::Text(lob.into_bound())
+            } else {
+                let lob = BLOB::temp(self.session, Cache::No).await?;
+                lob.append(arg.data()).await?;
+                TempLob::Binary(lob.into_bound())
+            };
+            temp_lobs.push(lob);
+        }
+        if let Some(params) = &self.params {
+            let mut params = params.write();
+            for (arg, lob) in lob_args.iter().zip(temp_lobs.iter()) {
+                params.bind_lob(arg.idx, lob.sql_type(), lob.charset_form(&self.err), lob.locator_ptr(), &self.stmt, &self.err)?;
+            }
+        }
+        *self.temp_lobs.lock() = temp_lobs;
+        Ok(())
+    }
+
     /// Executes the prepared statement. Returns the OCI result code from OCIStmtExecute.
     async fn exec(&self, stmt_type: u16, args: &mut impl ToSql) -> Result<i32> {
         self.bind_args(args)?;
-        futures::StmtExecute::new(self.svc.clone(), &self.err, &self.stmt, stmt_type).await
+        self.bind_lob_args().await?;
+        self.prefetch_for_execute(stmt_type)?;
+        let res = futures::StmtExecute::new(self.svc.clone(), &self.err, &self.stmt, stmt_type)
+            .with_snapshots(self.snapshot_in(), self.snapshot_out())
+            .await?;
+        self.prefetch_for_fetch(stmt_type)?;
+        Ok(res)
     }
 
     /**
@@ -89,6 +130,215 @@ impl<'a> Statement<'a> {
         Ok(num_rows)
     }
 
+    /**
+    Executes the prepared statement once for each row in the batch using array DML.
+    Returns the number of rows affected.
+
+    The execution stops at the first row that fails. The error of that row is returned,
+    while changes made by the rows that preceded it are kept.
+
+    An empty batch is not sent to the server. The statement is not executed and 0 is returned.
+
+    # Parameters
+
+    * `rows` - arguments for each statement execution. Each row is a single argument or a tuple
+      of arguments, which are bound as IN arguments. All rows must bind the same parameters.
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let stmt = session.prepare("
+        INSERT INTO hr.regions (region_id, region_name) VALUES (:id, :name)
+    ").await?;
+    let num_rows = stmt.execute_batch(&[
+        (( ":ID", 11 ), ( ":NAME", "Antarctica" )),
+        (( ":ID", 12 ), ( ":NAME", "Oceania"    )),
+        (( ":ID", 13 ), ( ":NAME", "Arctic"     )),
+    ]).await?;
+    assert_eq!(num_rows, 3);
+    # session.rollback().await?;
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn execute_batch<T: ToSql + Clone>(&self, rows: &[T]) -> Result<usize> {
+        let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+        if stmt_type == OCI_STMT_SELECT {
+            return Err( Error::new("Use `query` to execute SELECT") );
+        }
+        if rows.is_empty() {
+            return Ok(0);
+        }
+        self.bind_batch(rows)?;
+        futures::StmtExecute::batch(self.svc.clone(), &self.err, &self.stmt, rows.len() as _, OCI_DEFAULT).await?;
+        self.row_count()
+    }
+
+    /**
+    Executes the prepared DML statement once for each row produced by the iterator using array DML.
+    Each row is converted into statement arguments by its [`ToRow`] implementation.
+    Returns the number of rows affected.
+
+    All rows are bound and then sent to the database in a single batched execution. As with
+    [`Statement::execute_batch()`], the execution stops at the first row that fails.
+
+    # Parameters
+
+    * `rows` - rows (or references to rows) to insert
+
+    # Example
+
+    ```
+    use sibyl::{ToRow, Binds};
+
+    struct Region {
+        id: u32,
+        name: &'static str,
+    }
+
+    impl ToRow for Region {
+        fn to_row(&self) -> Binds<'_> {
+            Binds::new()
+                .by_name(":ID", &self.id)
+                .by_name(":NAME", self.name)
+        }
+    }
+
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let regions = vec![
+        Region { id: 11, name: "Antarctica" },
+        Region { id: 12, name: "Oceania" },
+    ];
+    let stmt = session.prepare("
+        INSERT INTO hr.regions (region_id, region_name) VALUES (:id, :name)
+    ").await?;
+    let num_rows = stmt.insert_all(&regions).await?;
+    assert_eq!(num_rows, 2);
+    # session.rollback().await?;
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn insert_all<I, T>(&self, rows: I) -> Result<usize>
+    where I: IntoIterator<Item=T>, T: ToRow
+    {
+        let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+        if stmt_type == OCI_STMT_SELECT {
+            return Err( Error::new("Use `query` to execute SELECT") );
+        }
+        let num_rows = {
+            let rows: Vec<T> = rows.into_iter().collect();
+            if rows.is_empty() {
+                return Ok(0);
+            }
+            self.bind_rows(rows.iter().map(ToRow::to_row))?
+        };
+        futures::StmtExecute::batch(self.svc.clone(), &self.err, &self.stmt, num_rows as _, OCI_DEFAULT).await?;
+        self.row_count()
+    }
+
+    /**
+    Executes the prepared statement once for each row in the batch using array DML in the
+    batch errors mode, where rows that fail do not abort the execution of the entire batch.
+    Returns the number of successfully processed rows and errors of the failed rows.
+
+    # Parameters
+
+    * `rows` - arguments for each statement execution. Each row is a single argument or a tuple
+      of arguments, which are bound as IN arguments. All rows must bind the same parameters.
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let stmt = session.prepare("
+        INSERT INTO hr.regions (region_id, region_name) VALUES (:id, :name)
+    ").await?;
+    // Region 2 already exists
+    let res = stmt.execute_batch_with_errors(&[
+        (( ":ID", 11 ), ( ":NAME", "Antarctica" )),
+        (( ":ID",  2 ), ( ":NAME", "Americas"   )),
+        (( ":ID", 12 ), ( ":NAME", "Oceania"    )),
+    ]).await?;
+    assert_eq!(res.row_count(), 2);
+    assert_eq!(res.errors().len(), 1);
+    let (row_offset, err) = &res.errors()[0];
+    assert_eq!(*row_offset, 1);
+    match err {
+        sibyl::Error::Oracle(code, _) => assert_eq!(*code, 1), // unique constraint violated
+        _ => panic!("unexpected error {:?}", err)
+    }
+    # session.rollback().await?;
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn execute_batch_with_errors<T: ToSql + Clone>(&self, rows: &[T]) -> Result<BatchResult> {
+        let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+        if stmt_type == OCI_STMT_SELECT {
+            return Err( Error::new("Use `query` to execute SELECT") );
+        }
+        if rows.is_empty() {
+            return Ok(BatchResult::default());
+        }
+        self.bind_batch(rows)?;
+        let res = futures::StmtExecute::batch(self.svc.clone(), &self.err, &self.stmt, rows.len() as _, OCI_BATCH_ERRORS).await;
+        self.check_batch_result(res)?;
+        self.batch_result()
+    }
+
+    /**
+    Executes the prepared DML statement once for each row in the batch using array DML and
+    returns ROWIDs that the statement's `RETURNING ROWID INTO` clause reported for the affected rows.
+
+    The returned ROWIDs follow the order of the batch rows. Thus, for an INSERT, the ROWID
+    of each inserted row is at the same index as the row in `rows`.
+
+    # Parameters
+
+    * `rows` - arguments for each statement execution. Each row is a single argument or a tuple
+      of arguments, which are bound as IN arguments. All rows must bind the same parameters.
+      Rows must not bind the ROWID placeholder.
+    * `rowid_param` - name of the placeholder into which the RETURNING clause returns the ROWID
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let stmt = session.prepare("
+        INSERT INTO hr.regions (region_id, region_name) VALUES (:id, :name)
+        RETURNING ROWID INTO :row_id
+    ").await?;
+    let rowids = stmt.execute_batch_returning_rowid(&[
+        (( ":ID", 11 ), ( ":NAME", "Antarctica" )),
+        (( ":ID", 12 ), ( ":NAME", "Oceania"    )),
+    ], ":ROW_ID").await?;
+    assert_eq!(rowids.len(), 2);
+
+    let stmt = session.prepare("SELECT region_name FROM hr.regions WHERE rowid = :row_id").await?;
+    let row = stmt.query_single(&rowids[1]).await?.expect("region row");
+    let name: &str = row.get(0)?;
+    assert_eq!(name, "Oceania");
+    # session.rollback().await?;
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn execute_batch_returning_rowid<T: ToSql + Clone>(&self, rows: &[T], rowid_param: &str) -> Result<Vec<RowID>> {
+        let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+        if stmt_type == OCI_STMT_SELECT {
+            return Err( Error::new("Use `query` to execute SELECT") );
+        }
+        if rows.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut rowids = BatchRowIds::new(self);
+        self.bind_batch_returning_rowid(rows, rowid_param, &mut rowids)?;
+        futures::StmtExecute::batch(self.svc.clone(), &self.err, &self.stmt, rows.len() as _, OCI_DEFAULT).await?;
+        rowids.into_rowids()
+    }
+
     /**
     Executes the prepared statement. Returns "streaming iterator" over the returned rows.
 
@@ -151,7 +401,7 @@ impl<'a> Statement<'a> {
         let res = self.exec(stmt_type, &mut args).await?;
 
         if self.cols.get().is_none() {
-            let cols = Columns::new(Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), self.max_long)?;
+            let cols = self.new_columns()?;
             self.cols.get_or_init(|| RwLock::new(cols));
         }
 
@@ -163,6 +413,29 @@ impl<'a> Statement<'a> {
         }
     }
 
+    /// Executes the query like [`Statement::query`] does, but returns rows that own the statement.
+    /// This allows the statement to be prepared and queried in the same expression.
+    #[doc(hidden)]
+    pub async fn query_owned(self, mut args: impl ToSql) -> Result<Rows<'a>> {
+        let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+        if stmt_type != OCI_STMT_SELECT {
+            return Err( Error::new("Use `execute` to execute statements other than SELECT") );
+        }
+        let res = self.exec(stmt_type, &mut args).await?;
+
+        if self.cols.get().is_none() {
+            let cols = self.new_columns()?;
+            self.cols.get_or_init(|| RwLock::new(cols));
+        }
+
+        match res {
+            OCI_SUCCESS | OCI_SUCCESS_WITH_INFO | OCI_NO_DATA => {
+                Ok( Rows::from_owned_query(res, self) )
+            }
+            _ => Err( Error::oci(&self.err, res) )
+        }
+    }
+
     /**
     Convenience method to execute a query that returns a single rows.
 
@@ -222,7 +495,7 @@ impl<'a> Statement<'a> {
         let res = self.exec(stmt_type, &mut args).await?;
 
         if self.cols.get().is_none() {
-            let cols = Columns::new(Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), self.max_long)?;
+            let cols = self.new_columns()?;
             self.cols.get_or_init(|| RwLock::new(cols));
         }
 
@@ -233,6 +506,355 @@ impl<'a> Statement<'a> {
         }
     }
 
+    /**
+    Executes the prepared SELECT statement that returns a single value - for example, `SELECT Count(*)` -
+    and returns the value of the first column of the first row.
+
+    # Parameters
+
+    * `args` - SQL statement arguments - a single argument or a tuple of arguments
+
+    # Failures
+
+    Returns an error if the query does not return any rows or if the value of the first column
+    cannot be converted into `T`, which includes the value being NULL for types other than `Option`.
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let stmt = session.prepare("
+        SELECT Count(*) FROM hr.employees WHERE department_id = :dept_id
+    ").await?;
+    let num_employees : u32 = stmt.query_scalar(30).await?;
+    assert_eq!(num_employees, 6);
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn query_scalar<T>(&'a self, args: impl ToSql) -> Result<T> where T: for<'r> FromSql<'r> {
+        let row = self.query_single(args).await?.ok_or_else(|| Error::new("query returned no rows"))?;
+        row.get(0)
+    }
+
+    /**
+    Executes the prepared SELECT statement that returns at most one row and maps that row into `T`.
+
+    # Parameters
+
+    * `args` - SQL statement arguments - a single argument or a tuple of arguments
+
+    # Returns
+
+    `None` if the query did not return any rows.
+
+    # Failures
+
+    Returns an error if the query returns more than one row or if the row cannot be converted into `T`.
+
+    # Example
+
+    ```
+    use sibyl::{FromRow, Row, Result};
+
+    struct Employee {
+        name: String,
+        salary: u32,
+    }
+
+    impl FromRow for Employee {
+        fn from_row(row: &Row) -> Result<Self> {
+            Ok(Self { name: row.get(0)?, salary: row.get(1)? })
+        }
+    }
+
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let stmt = session.prepare("
+        SELECT last_name, salary FROM hr.employees WHERE employee_id = :id
+    ").await?;
+    let employee : Option<Employee> = stmt.query_opt(107).await?;
+    let employee = employee.unwrap();
+    assert_eq!(employee.name, "Lorentz");
+    assert_eq!(employee.salary, 4200);
+
+    let employee : Option<Employee> = stmt.query_opt(99).await?;
+    assert!(employee.is_none());
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn query_opt<T: FromRow>(&'a self, args: impl ToSql) -> Result<Option<T>> {
+        let rows = self.query(args).await?;
+        let value = match rows.next().await? {
+            Some(row) => T::from_row(&row)?,
+            None => return Ok(None)
+        };
+        if rows.next().await?.is_some() {
+            return Err( Error::new("query returned more than one row") );
+        }
+        Ok(Some(value))
+    }
+
+    /**
+    Executes the prepared SELECT statement and maps all returned rows into `T`.
+
+    # Parameters
+
+    * `args` - SQL statement arguments - a single argument or a tuple of arguments
+
+    # Failures
+
+    Returns an error if any of the rows cannot be converted into `T`.
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let stmt = session.prepare("
+        SELECT c.country_name, Median(e.salary)
+          FROM hr.employees e
+          JOIN hr.departments d ON d.department_id = e.department_id
+          JOIN hr.locations l   ON l.location_id = d.location_id
+          JOIN hr.countries c   ON c.country_id = l.country_id
+          JOIN hr.regions r     ON r.region_id = c.region_id
+         WHERE r.region_name = :REGION_NAME
+      GROUP BY c.country_name
+      ORDER BY c.country_name
+    ").await?;
+    let rows : Vec<(String, u16)> = stmt.query_as("Europe").await?;
+    assert_eq!(rows, [
+        ("Germany".to_string(), 10000),
+        ("United Kingdom".to_string(), 8800),
+    ]);
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn query_as<T: FromRow>(&'a self, args: impl ToSql) -> Result<Vec<T>> {
+        let rows = self.query(args).await?;
+        let mut values = Vec::new();
+        while let Some(row) = rows.next().await? {
+            values.push(T::from_row(&row)?);
+        }
+        Ok(values)
+    }
+
+    /**
+    Executes the prepared `SELECT ... FOR UPDATE SKIP LOCKED` statement to claim up to `max_rows`
+    rows of a work queue, and maps the claimed rows into `T`.
+
+    Rows that are locked by other sessions are skipped. Rows are locked by the query only as they
+    are fetched, thus to avoid locking more rows than will be processed the query fetches at most
+    `max_rows` rows, with the prefetch limited accordingly. The claimed rows stay locked until the
+    session commits or rolls back its transaction. This allows several consumers to process rows
+    of the same queue concurrently without claiming the same rows.
+
+    Note that the number of claimed rows should be limited via `max_rows` and not by `ROWNUM` or
+    `FETCH FIRST n ROWS` in the query as those are applied before the locked rows are skipped.
+
+    # Parameters
+
+    * `args` - SQL statement arguments - a single argument or a tuple of arguments
+    * `max_rows` - the maximum number of rows to claim
+
+    # Failures
+
+    Returns an error if the statement is not a SELECT or if any of the rows cannot be converted into `T`.
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let stmt = session.prepare("
+        SELECT employee_id, last_name
+          FROM hr.employees
+         WHERE department_id = :dept
+           FOR UPDATE SKIP LOCKED
+    ").await?;
+    let claimed : Vec<(u32, String)> = stmt.query_skip_locked(50, 2).await?;
+    assert_eq!(claimed.len(), 2);
+    // ... process claimed rows ...
+    // Commit (or rollback) releases the locks
+    session.rollback().await?;
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn query_skip_locked<T: FromRow>(&'a self, args: impl ToSql, max_rows: usize) -> Result<Vec<T>> {
+        let prefetch = self.prefetch_rows.load(Ordering::Relaxed);
+        let fetch_array = self.fetch_array.swap(0, Ordering::Relaxed);
+        self.set_prefetch_rows(max_rows.min(u32::MAX as usize) as u32)?;
+        let res = self.claim_rows(args, max_rows).await;
+        self.fetch_array.store(fetch_array, Ordering::Relaxed);
+        self.set_prefetch_rows(prefetch)?;
+        res
+    }
+
+    /// Fetches up to `max_rows` rows of the query result.
+    async fn claim_rows<T: FromRow>(&'a self, args: impl ToSql, max_rows: usize) -> Result<Vec<T>> {
+        let rows = self.query(args).await?;
+        let mut values = Vec::with_capacity(max_rows.min(1024));
+        while values.len() < max_rows {
+            match rows.next().await? {
+                Some(row) => values.push(T::from_row(&row)?),
+                None => break,
+            }
+        }
+        Ok(values)
+    }
+
+    /**
+    Returns the value of the identity column of the row inserted by the last execution of this
+    INSERT statement.
+
+    The inserted row is located by its ROWID. The identity column of the table is then looked up
+    in the data dictionary and its value is read from the inserted row. Thus the INSERT does not
+    need a `RETURNING id INTO` clause and an OUT argument to retrieve the generated value.
+
+    **Note** that when the statement inserted several rows (or was executed as a batch), the
+    returned value is the identity of the last inserted row.
+
+    # Failures
+
+    Returns an error if the statement is not an INSERT, if it has not inserted any rows,
+    or if the table does not have an identity column.
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     DECLARE
+    #         name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+    #     BEGIN
+    #         EXECUTE IMMEDIATE '
+    #             CREATE TABLE test_last_insert_id (
+    #                 id      NUMBER GENERATED ALWAYS AS IDENTITY,
+    #                 name    VARCHAR2(30)
+    #             )
+    #         ';
+    #     EXCEPTION
+    #       WHEN name_already_used THEN NULL;
+    #     END;
+    # ").await?;
+    # stmt.execute(()).await?;
+    let stmt = session.prepare("
+        INSERT INTO test_last_insert_id (name) VALUES (:NAME)
+    ").await?;
+    stmt.execute("Tenzing").await?;
+    let id : u32 = stmt.last_insert_id().await?;
+
+    let stmt = session.prepare("
+        SELECT name FROM test_last_insert_id WHERE id = :ID
+    ").await?;
+    let row = stmt.query_single(id).await?.unwrap();
+    let name : &str = row.get(0)?;
+    assert_eq!(name, "Tenzing");
+    # session.rollback().await?;
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn last_insert_id<T>(&self) -> Result<T> where T: for<'r> FromSql<'r> {
+        let rowid = self.inserted_rowid()?;
+        let stmt = self.session.prepare(IDENTITY_COLUMN_SQL).await?;
+        let row = stmt.query_single(&rowid).await?.ok_or_else(|| Error::new("table does not have an identity column"))?;
+        let owner : &str = row.get(0)?;
+        let table : &str = row.get(1)?;
+        let column : &str = row.get(2)?;
+        let sql = format!(r#"SELECT "{}" FROM "{}"."{}" WHERE rowid = :ROW_ID"#, column, owner, table);
+        let stmt = self.session.prepare(&sql).await?;
+        let row = stmt.query_single(&rowid).await?.ok_or_else(|| Error::new("inserted row is not found"))?;
+        row.get(0)
+    }
+
+    /**
+    Returns the optimizer's estimate of the number of rows this query will return.
+
+    The estimate is obtained by explaining the query (see `EXPLAIN PLAN`) in a single round-trip.
+    Its accuracy thus depends on the optimizer statistics of the queried tables. It is meant to
+    be used as a capacity hint when the rows are collected into a vector or another collection,
+    rather than as a precise row count.
+
+    Note that the plan is written into and then deleted from the `PLAN_TABLE`, which is a global
+    temporary table. Thus the call starts a transaction if one is not already in progress.
+
+    # Failures
+
+    Returns an error if the statement is not a SELECT or if the query cannot be explained.
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let stmt = session.prepare("
+        SELECT employee_id, last_name
+          FROM hr.employees
+    ").await?;
+    let mut names = Vec::with_capacity(stmt.estimated_row_count().await?);
+    let rows = stmt.query(()).await?;
+    while let Some(row) = rows.next().await? {
+        let name : String = row.get(1)?;
+        names.push(name);
+    }
+    assert!(names.capacity() >= names.len());
+    # session.rollback().await?;
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn estimated_row_count(&self) -> Result<usize> {
+        let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+        if stmt_type != OCI_STMT_SELECT {
+            return Err( Error::new("row count can only be estimated for SELECT") );
+        }
+        let sql: &str = self.get_attr(OCI_ATTR_STATEMENT)?;
+        let mut num_rows = 0u64;
+        let stmt = self.session.prepare(ROW_ESTIMATE_SQL).await?;
+        stmt.execute(((":SQL", sql), (":NUM_ROWS", &mut num_rows))).await?;
+        Ok( num_rows as usize )
+    }
+
+    /**
+    Describes the select-list of the prepared statement without executing it. Returns
+    the metadata of the columns that the query would return.
+
+    The statement is executed in the describe-only mode - no rows are fetched and
+    statements other than SELECT are not executed and return no columns. Arguments do not
+    have to be bound to describe the statement.
+
+    **Note** that describing a query defines its output buffers. Thus [`Statement::define_as()`]
+    must be called before `describe_result` for it to take effect.
+
+    # Example
+
+    ```
+    use sibyl::ColumnType;
+
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let stmt = session.prepare("
+        SELECT employee_id, last_name, hire_date
+          FROM hr.employees
+         WHERE manager_id = :id
+    ").await?;
+    let cols = stmt.describe_result().await?;
+
+    assert_eq!(cols.len(), 3);
+    assert_eq!(cols[0].name()?, "EMPLOYEE_ID");
+    assert_eq!(cols[0].data_type()?, ColumnType::Number);
+    assert_eq!(cols[1].name()?, "LAST_NAME");
+    assert_eq!(cols[1].data_type()?, ColumnType::Varchar);
+    assert_eq!(cols[2].name()?, "HIRE_DATE");
+    assert_eq!(cols[2].data_type()?, ColumnType::Date);
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn describe_result(&self) -> Result<Vec<ColumnInfo<'_>>> {
+        futures::StmtExecute::batch(self.svc.clone(), &self.err, &self.stmt, 0, OCI_DESCRIBE_ONLY).await?;
+        self.described_columns()
+    }
+
     /**
     Retrieves a single implicit result (cursor) in the order in which they were returned
     from the PL/SQL procedure or block. If no more results are available, then `None` is
@@ -352,6 +974,88 @@ impl<'a> Statement<'a> {
             Ok(None)
         }
     }
+
+    /**
+    Returns a stream of the implicit results (cursors) that were returned by the executed
+    PL/SQL procedure or block. The stream retrieves them via [`Statement::next_result`] and
+    ends when no more results are available or after the first error.
+
+    # Example
+
+    ```
+    use std::{future::poll_fn, pin::pin};
+    use futures_core::Stream;
+
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let stmt = session.prepare("
+        DECLARE
+            c1 SYS_REFCURSOR;
+            c2 SYS_REFCURSOR;
+        BEGIN
+            OPEN c1 FOR
+                SELECT last_name FROM hr.employees WHERE employee_id = 100;
+            DBMS_SQL.RETURN_RESULT(c1);
+            OPEN c2 FOR
+                SELECT department_name FROM hr.departments WHERE department_id = 10;
+            DBMS_SQL.RETURN_RESULT(c2);
+        END;
+    ").await?;
+    stmt.execute(()).await?;
+
+    let mut results = pin!(stmt.result_sets());
+    let mut names = Vec::new();
+    // `StreamExt::next` from the `futures` crate would be used here in a real application
+    while let Some(cursor) = poll_fn(|cx| results.as_mut().poll_next(cx)).await {
+        let cursor = cursor?;
+        let rows = cursor.rows().await?;
+        let row = rows.next().await?.unwrap();
+        let name : String = row.get(0)?;
+        names.push(name);
+    }
+    assert_eq!(names, ["King", "Administration"]);
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub fn result_sets(&'a self) -> impl Stream<Item=Result<Cursor<'a>>> + 'a {
+        ResultSets { stmt: self, next: None, done: false }
+    }
+}
+
+/// A stream of the implicit results of the executed statement.
+struct ResultSets<'a> {
+    stmt: &'a Statement<'a>,
+    next: Option<futures::StmtGetNextResult<'a>>,
+    done: bool,
+}
+
+impl<'a> Stream for ResultSets<'a> {
+    type Item = Result<Cursor<'a>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+        let stmt = this.stmt;
+        let next = this.next.get_or_insert_with(|| futures::StmtGetNextResult::new(stmt.svc.clone(), &stmt.stmt, &stmt.err));
+        let res = match Pin::new(next).poll(cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(res) => res,
+        };
+        this.next = None;
+        match res {
+            Ok(Some(cursor)) => Poll::Ready(Some(Ok(Cursor::implicit(cursor, stmt)))),
+            Ok(None) => {
+                this.done = true;
+                Poll::Ready(None)
+            },
+            Err(err) => {
+                this.done = true;
+                Poll::Ready(Some(Err(err)))
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -599,4 +1303,85 @@ mod tests {
             Ok(())
         })
     }
+
+    #[test]
+    fn cancel() -> Result<()> {
+        use std::{sync::Arc, time::{Duration, Instant}};
+
+        block_on(async {
+            // The spawned task shares the statement, and thus the session it borrows must be 'static
+            let session : &'static Session<'static> = Box::leak(Box::new(crate::test_env::get_session().await?));
+            let stmt = Arc::new(session.prepare("
+                BEGIN
+                    DBMS_LOCK.SLEEP(10);
+                END;
+            ").await?);
+            let started = Instant::now();
+            let handle = spawn({
+                let stmt = stmt.clone();
+                async move {
+                    crate::task::sleep(Duration::from_secs(1)).await;
+                    stmt.cancel()
+                }
+            });
+            let res = stmt.execute(()).await;
+            assert!(matches!(res, Err(Error::Oracle(1013, _))), "unexpected result {:?}", res);
+            assert!(started.elapsed() < Duration::from_secs(5));
+
+            let res = handle.await;
+            #[cfg(any(feature="tokio", feature="actix"))]
+            let res = res.expect("task result");
+            res?;
+
+            // session is still usable after the cancelled call
+            session.ping().await?;
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn describe_result() -> Result<()> {
+        block_on(async {
+            let session = crate::test_env::get_session().await?;
+
+            let stmt = session.prepare("
+                SELECT employee_id, last_name, salary
+                  FROM hr.employees
+                 WHERE department_id = :dept
+            ").await?;
+            let cols = stmt.describe_result().await?;
+            assert_eq!(cols.len(), 3);
+            assert_eq!(cols[0].name()?, "EMPLOYEE_ID");
+            assert_eq!(cols[1].name()?, "LAST_NAME");
+            assert_eq!(cols[1].data_type()?, ColumnType::Varchar);
+            assert_eq!(cols[2].name()?, "SALARY");
+            assert_eq!(cols[2].precision()?, 8);
+            assert_eq!(cols[2].scale()?, 2);
+            assert_eq!(stmt.row_count()?, 0);
+
+            // the described query can be executed later
+            let rows = stmt.query(60).await?;
+            let mut num_rows = 0;
+            while let Some(_row) = rows.next().await? {
+                num_rows += 1;
+            }
+            assert_eq!(num_rows, 5);
+
+            let stmt = session.prepare("
+                UPDATE hr.employees
+                   SET salary = salary * 2
+                 WHERE employee_id = 107
+            ").await?;
+            let cols = stmt.describe_result().await?;
+            assert!(cols.is_empty());
+            assert_eq!(stmt.row_count()?, 0);
+
+            let stmt = session.prepare("SELECT salary FROM hr.employees WHERE employee_id = 107").await?;
+            let row = stmt.query_single(()).await?.unwrap();
+            let salary : u32 = row.get(0)?;
+            assert_eq!(salary, 4200);
+
+            Ok(())
+        })
+    }
 }
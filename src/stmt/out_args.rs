@@ -0,0 +1,165 @@
+//! Binding of struct fields as OUT arguments
+
+/**
+Implements [`ToSql`](crate::ToSql) for a mutable reference to a struct, which fields are bound
+as OUT arguments to the named parameter placeholders.
+
+This allows a single `RETURNING ... INTO` clause (or a PL/SQL block with several OUT parameters)
+to populate all the fields of the struct in one call. The struct is described by its name and
+the list of its fields, each mapped to the name of the placeholder the field is bound to.
+
+The struct can be passed to `execute` as an argument by itself or as a part of the arguments tuple.
+
+**Note** that like any other OUT `String` (or `Vec<u8>`) argument, the corresponding fields must have
+enough capacity to receive the returned values.
+
+# Example
+
+🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+```
+use sibyl::impl_out_args;
+
+struct Department {
+    id: u32,
+    name: String,
+    location_id: u32,
+}
+
+impl_out_args! {
+    Department {
+        id          => ":DEPARTMENT_ID",
+        name        => ":DEPARTMENT_NAME",
+        location_id => ":LOCATION_ID",
+    }
+}
+
+# use sibyl::Result;
+# #[cfg(feature="blocking")]
+# fn main() -> Result<()> {
+# let session = sibyl::test_env::get_session()?;
+let stmt = session.prepare("
+    INSERT INTO hr.departments (department_id, department_name, location_id)
+    VALUES (hr.departments_seq.nextval, :NAME, (SELECT location_id FROM hr.locations WHERE city = :CITY))
+    RETURNING department_id, department_name, location_id
+         INTO :DEPARTMENT_ID, :DEPARTMENT_NAME, :LOCATION_ID
+")?;
+let mut dept = Department { id: 0, name: String::with_capacity(30), location_id: 0 };
+let count = stmt.execute(((":NAME", "Security"), (":CITY", "Seattle"), &mut dept))?;
+assert_eq!(count, 1);
+assert!(dept.id > 0);
+assert_eq!(dept.name, "Security");
+assert_eq!(dept.location_id, 1700);
+# session.rollback()?;
+# Ok(())
+# }
+# #[cfg(feature="nonblocking")]
+# fn main() -> Result<()> {
+# sibyl::block_on(async {
+# let session = sibyl::test_env::get_session().await?;
+# let stmt = session.prepare("
+#     INSERT INTO hr.departments (department_id, department_name, location_id)
+#     VALUES (hr.departments_seq.nextval, :NAME, (SELECT location_id FROM hr.locations WHERE city = :CITY))
+#     RETURNING department_id, department_name, location_id
+#          INTO :DEPARTMENT_ID, :DEPARTMENT_NAME, :LOCATION_ID
+# ").await?;
+# let mut dept = Department { id: 0, name: String::with_capacity(30), location_id: 0 };
+# let count = stmt.execute(((":NAME", "Security"), (":CITY", "Seattle"), &mut dept)).await?;
+# assert_eq!(count, 1);
+# assert!(dept.id > 0);
+# assert_eq!(dept.name, "Security");
+# assert_eq!(dept.location_id, 1700);
+# session.rollback().await?;
+# Ok(()) })
+# }
+```
+*/
+#[macro_export]
+macro_rules! impl_out_args {
+    ($name:ident { $($field:ident => $placeholder:literal),+ $(,)? }) => {
+        impl $crate::ToSql for &mut $name {
+            fn bind_to(&mut self, mut pos: usize, params: &mut $crate::__private::Params, stmt: &$crate::__private::OCIStmt, err: &$crate::__private::OCIError) -> $crate::Result<usize> {
+                $(
+                    pos = $crate::ToSql::bind_to(&mut ($placeholder, &mut self.$field), pos, params, stmt, err)?;
+                )+
+                Ok(pos)
+            }
+
+            fn update_from_bind(&mut self, mut pos: usize, params: &$crate::__private::Params) -> $crate::Result<usize> {
+                $(
+                    pos = $crate::ToSql::update_from_bind(&mut ($placeholder, &mut self.$field), pos, params)?;
+                )+
+                Ok(pos)
+            }
+        }
+    };
+}
+
+#[cfg(all(test,feature="blocking"))]
+mod tests {
+    use crate::Result;
+
+    struct CharacterData {
+        id: u32,
+        text: String,
+        ntext: String,
+    }
+
+    crate::impl_out_args! {
+        CharacterData {
+            id    => ":ID",
+            text  => ":TEXT_OUT",
+            ntext => ":NTXT_OUT",
+        }
+    }
+
+    #[test]
+    fn returning_into_struct() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_character_data (
+                        id      NUMBER GENERATED ALWAYS AS IDENTITY,
+                        text    VARCHAR2(97),
+                        ntext   NVARCHAR2(99)
+                    )
+                ';
+            EXCEPTION
+              WHEN name_already_used THEN NULL;
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("
+            INSERT INTO test_character_data (text, ntext) VALUES (:TEXT, '> ' || :TEXT)
+            RETURNING id, text, ntext INTO :ID, :TEXT_OUT, :NTXT_OUT
+        ")?;
+        let mut data = CharacterData { id: 0, text: String::with_capacity(97), ntext: String::with_capacity(99) };
+        let count = stmt.execute(((":TEXT", "The woods are lovely, dark and deep,"), &mut data, ()))?;
+        assert_eq!(count, 1);
+        assert!(data.id > 0);
+        assert_eq!(data.text, "The woods are lovely, dark and deep,");
+        assert_eq!(data.ntext, "> The woods are lovely, dark and deep,");
+
+        let first_id = data.id;
+        let count = stmt.execute(((":TEXT", "But I have promises to keep,"), &mut data, ()))?;
+        assert_eq!(count, 1);
+        assert!(data.id > first_id);
+        assert_eq!(data.text, "But I have promises to keep,");
+        assert_eq!(data.ntext, "> But I have promises to keep,");
+
+        let stmt = session.prepare("SELECT text, ntext FROM test_character_data WHERE id = :ID")?;
+        let row = stmt.query_single(data.id)?.unwrap();
+        let text : &str = row.get(0)?;
+        let ntext : &str = row.get(1)?;
+        assert_eq!(text, data.text);
+        assert_eq!(ntext, data.ntext);
+
+        session.rollback()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+//! Prepare and query in a single step
+
+use super::Statement;
+use crate::{Error, Result};
+
+/// Checks that the arguments of the `query!` provide values for all the parameter placeholders.
+pub fn check_arg_names(stmt: &Statement, names: &[&str]) -> Result<()> {
+    for placeholder in stmt.bind_names() {
+        if !names.iter().any(|name| name.eq_ignore_ascii_case(&placeholder)) {
+            return Err(Error::msg(format!("no argument for parameter placeholder :{}", placeholder)));
+        }
+    }
+    Ok(())
+}
+
+/**
+Prepares the SELECT statement and executes it with the named arguments.
+
+The macro accepts the session, the SQL text and a list of `NAME = value` arguments, where each
+`NAME` is the name of the parameter placeholder (without the colon). It returns the [`Rows`](crate::Rows)
+of the executed query, which own the prepared statement.
+
+The SQL is not checked at compile time. However, the statement is not executed if it has
+parameter placeholders that were not given an argument.
+
+# Example
+
+```
+use sibyl::query;
+
+# let session = sibyl::test_env::get_session()?;
+let rows = query!(session, "
+    SELECT first_name, last_name
+      FROM hr.employees
+     WHERE employee_id = :ID
+", ID = 107)?;
+
+let row = rows.next()?.expect("one row");
+let first_name : &str = row.get(0)?;
+let last_name : &str = row.get(1)?;
+assert_eq!(first_name, "Diana");
+assert_eq!(last_name, "Lorentz");
+assert!(rows.next()?.is_none());
+# Ok::<(),Box<dyn std::error::Error>>(())
+```
+*/
+#[cfg(feature="blocking")]
+#[macro_export]
+macro_rules! query {
+    ($session:expr, $sql:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        $session.prepare($sql).and_then(|stmt| {
+            $crate::__private::check_arg_names(&stmt, &[$(stringify!($name)),*])?;
+            stmt.query_owned(( $( (concat!(":", stringify!($name)), $value) ),* ))
+        })
+    };
+}
+
+/**
+Prepares the SELECT statement and executes it with the named arguments.
+
+The macro accepts the session, the SQL text and a list of `NAME = value` arguments, where each
+`NAME` is the name of the parameter placeholder (without the colon). It returns a future that
+resolves into the [`Rows`](crate::Rows) of the executed query, which own the prepared statement.
+
+The SQL is not checked at compile time. However, the statement is not executed if it has
+parameter placeholders that were not given an argument.
+
+# Example
+
+```
+use sibyl::query;
+
+# sibyl::block_on(async {
+# let session = sibyl::test_env::get_session().await?;
+let rows = query!(session, "
+    SELECT first_name, last_name
+      FROM hr.employees
+     WHERE employee_id = :ID
+", ID = 107).await?;
+
+let row = rows.next().await?.expect("one row");
+let first_name : &str = row.get(0)?;
+let last_name : &str = row.get(1)?;
+assert_eq!(first_name, "Diana");
+assert_eq!(last_name, "Lorentz");
+assert!(rows.next().await?.is_none());
+# Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+```
+*/
+#[cfg(feature="nonblocking")]
+#[macro_export]
+macro_rules! query {
+    ($session:expr, $sql:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        async {
+            let stmt = $session.prepare($sql).await?;
+            $crate::__private::check_arg_names(&stmt, &[$(stringify!($name)),*])?;
+            stmt.query_owned(( $( (concat!(":", stringify!($name)), $value) ),* )).await
+        }
+    };
+}
+
+#[cfg(all(test,feature="blocking"))]
+mod tests {
+    use crate::Result;
+
+    #[test]
+    fn query_macro() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let rows = crate::query!(session, "
+            SELECT last_name
+              FROM hr.employees
+             WHERE department_id = :DEPT AND salary > :MIN_SALARY
+             ORDER BY last_name
+        ", DEPT = 30, MIN_SALARY = 3000)?;
+        let mut names = Vec::new();
+        while let Some(row) = rows.next()? {
+            let name : String = row.get(0)?;
+            names.push(name);
+        }
+        assert_eq!(names, ["Khoo", "Raphaely"]);
+
+        let rows = crate::query!(session, "SELECT last_name FROM hr.employees WHERE employee_id = 100")?;
+        let row = rows.next()?.expect("one row");
+        let name : &str = row.get(0)?;
+        assert_eq!(name, "King");
+
+        let rows = crate::query!(session, "
+            SELECT last_name
+              FROM hr.employees
+             WHERE department_id = :DEPT AND salary > :MIN_SALARY AND salary < :MAX_SALARY
+        ", DEPT = 30, MIN_SALARY = 3000, MAX_SALARY = 5000)?;
+        let row = rows.next()?.expect("one row");
+        let name : &str = row.get(0)?;
+        assert_eq!(name, "Khoo");
+        assert!(rows.next()?.is_none());
+
+        let res = crate::query!(session, "
+            SELECT last_name
+              FROM hr.employees
+             WHERE department_id = :DEPT AND salary > :MIN_SALARY
+        ", DEPT = 30);
+        assert!(res.is_err());
+
+        Ok(())
+    }
+}
@@ -8,14 +8,25 @@ mod blocking;
 #[cfg_attr(docsrs, doc(cfg(feature="nonblocking")))]
 mod nonblocking;
 
-use std::sync::atomic::AtomicI32;
+#[cfg(feature="blocking")]
+use blocking::Deadline;
+
+#[cfg(feature="nonblocking")]
+use nonblocking::Deadline;
+
+use std::{sync::atomic::AtomicI32, time::Duration};
 
-use super::{cols::Columns, data::FromSql, Position};
-use crate::{Cursor, Error, Result, RowID, Statement, oci::{*, attr}, types::Ctx, Session};
-use parking_lot::{RwLockReadGuard, RwLockWriteGuard};
+use super::{cols::{Columns, ColumnBuffer, RowArray}, data::FromSql, Position};
+use crate::{Cursor, Error, Result, RowID, Statement, oci::{*, attr}, types::Ctx, Session, LOB, lob::InternalLob, Number, Integer};
+use std::cmp::Ordering;
+use parking_lot::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 pub(crate) enum DataSource<'a> {
     Statement(&'a Statement<'a>),
+    /// Statement that was prepared only to execute the query and that is owned by its rows.
+    /// Its lifetime is erased, so the owned statement does not make the rows outlive `'a` when
+    /// they are dropped. The rows themselves are still bound by `'a`, which keeps the session alive.
+    OwnedStatement(Box<Statement<'static>>),
     Cursor(&'a Cursor<'a>)
 }
 
@@ -25,8 +36,9 @@ macro_rules! impl_as_ref_for_data_source {
             impl AsRef<$tname> for DataSource<'_> {
                 fn as_ref(&self) -> &$tname {
                     match self {
-                        &Self::Statement(stmt) => stmt.as_ref(),
-                        &Self::Cursor(cursor)  => cursor.as_ref(),
+                        &Self::Statement(stmt)     => stmt.as_ref(),
+                        Self::OwnedStatement(stmt) => (**stmt).as_ref(),
+                        &Self::Cursor(cursor)      => cursor.as_ref(),
                     }
                 }
             }
@@ -39,31 +51,45 @@ impl_as_ref_for_data_source!(OCIEnv, OCIError, OCISvcCtx, OCIStmt);
 impl Ctx for DataSource<'_> {
     fn try_as_session(&self) -> Option<&OCISession> {
         match self {
-            &Self::Statement(stmt) => stmt.try_as_session(),
-            &Self::Cursor(cursor)  => cursor.try_as_session(),
+            &Self::Statement(stmt)     => stmt.try_as_session(),
+            Self::OwnedStatement(stmt) => stmt.try_as_session(),
+            &Self::Cursor(cursor)      => cursor.try_as_session(),
         }
     }
 }
 
-impl DataSource<'_> {
+impl<'a> DataSource<'a> {
     pub(crate) fn read_columns(&self) -> RwLockReadGuard<Columns> {
         match self {
-            &Self::Statement(stmt) => stmt.read_columns(),
-            &Self::Cursor(cursor)  => cursor.read_columns(),
+            &Self::Statement(stmt)     => stmt.read_columns(),
+            Self::OwnedStatement(stmt) => stmt.read_columns(),
+            &Self::Cursor(cursor)      => cursor.read_columns(),
         }
     }
 
     pub(crate) fn write_columns(&self) -> RwLockWriteGuard<Columns> {
         match self {
-            &Self::Statement(stmt) => stmt.write_columns(),
-            &Self::Cursor(cursor)  => cursor.write_columns(),
+            &Self::Statement(stmt)     => stmt.write_columns(),
+            Self::OwnedStatement(stmt) => stmt.write_columns(),
+            &Self::Cursor(cursor)      => cursor.write_columns(),
         }
     }
 
     pub(crate) fn session(&self) -> &Session {
         match self {
-            &Self::Statement(stmt) => stmt.session(),
-            &Self::Cursor(cursor)  => cursor.session(),
+            &Self::Statement(stmt)     => stmt.session(),
+            Self::OwnedStatement(stmt) => stmt.session(),
+            &Self::Cursor(cursor)      => cursor.session(),
+        }
+    }
+
+    /// Returns the session with the lifetime of the data source rather than the lifetime of the borrow.
+    /// This allows objects that refer to the session to outlive the row they were taken from.
+    fn owning_session(&self) -> &'a Session<'a> {
+        match self {
+            &Self::Statement(stmt)     => stmt.session,
+            Self::OwnedStatement(stmt) => stmt.session,
+            &Self::Cursor(cursor)      => cursor.session(),
         }
     }
 }
@@ -72,32 +98,202 @@ impl DataSource<'_> {
 pub struct Rows<'a> {
     rset: DataSource<'a>,
     last_result: AtomicI32,
+    deadline: Mutex<Option<Deadline>>,
 }
 
 impl<'a> Rows<'a> {
+    fn new(query_result: i32, rset: DataSource<'a>) -> Self {
+        Self { rset, last_result: AtomicI32::new(query_result), deadline: Mutex::new(None) }
+    }
+
     pub(crate) fn from_query(query_result: i32, stmt: &'a Statement<'a>) -> Self {
-        Self { rset: DataSource::Statement(stmt), last_result: AtomicI32::new(query_result) }
+        Self::new(query_result, DataSource::Statement(stmt))
+    }
+
+    pub(crate) fn from_owned_query(query_result: i32, stmt: Statement<'a>) -> Self {
+        // SAFETY: Only the lifetime parameter changes, so the layout of the statement is the same.
+        // The erased lifetime is never observed: the statement is owned by these rows and is only
+        // reached through `DataSource`, which hands out borrows that are no longer than `&self`, or
+        // (the session) that are bound by `'a`. The statement is dropped when the rows are dropped,
+        // and `Rows<'a>` cannot outlive `'a`. Thus the session the statement refers to is still alive
+        // when the statement is closed. The deadline, which is dropped after the statement, does not
+        // refer to it - it uses the session's service context and its own error handle.
+        let stmt = unsafe { std::mem::transmute::<Statement<'a>, Statement<'static>>(stmt) };
+        Self::new(query_result, DataSource::OwnedStatement(Box::new(stmt)))
     }
 
     pub(crate) fn from_cursor(query_result: i32, cursor: &'a Cursor<'a>) -> Self {
-        Self { rset: DataSource::Cursor(cursor), last_result: AtomicI32::new(query_result) }
+        Self::new(query_result, DataSource::Cursor(cursor))
     }
 
     fn src(self) -> DataSource<'a> {
         self.rset
     }
+
+    /// Defines the output arrays for the array fetch of the next `num_rows` rows
+    fn define_batch(&self, num_rows: usize) -> Result<RowArray> {
+        let stmt: &OCIStmt = self.rset.as_ref();
+        let mut cols = self.rset.write_columns();
+        RowArray::new(&mut cols, stmt, num_rows).inspect_err(|_| {
+            let _ = cols.redefine(stmt);
+        })
+    }
+
+    /// Restores the single row defines after the array fetch and returns the fetched rows
+    fn fetched_batch(&self, batch: RowArray, fetch_result: Result<i32>) -> Result<Vec<Row<'_>>> {
+        let stmt: &OCIStmt = self.rset.as_ref();
+        let num_rows = fetch_result.and_then(|res| {
+            self.last_result.store(res, std::sync::atomic::Ordering::Release);
+            match res {
+                OCI_SUCCESS | OCI_SUCCESS_WITH_INFO | OCI_NO_DATA => {
+                    let num_rows : u32 = attr::get(OCI_ATTR_ROWS_FETCHED, OCI_HTYPE_STMT, stmt, self.rset.as_ref())?;
+                    Ok(num_rows as usize)
+                }
+                _ => Err( Error::oci(self.rset.as_ref(), res) )
+            }
+        });
+        self.rset.write_columns().redefine(stmt)?;
+        let rows = batch.into_rows(num_rows?)?;
+        Ok( rows.into_iter().map(|cols| Row::batch(self, cols)).collect() )
+    }
+
+    /**
+    Limits the total time that the remaining rows of this result set can be fetched in.
+
+    Unlike the session's [`call_timeout`](crate::Session::set_call_timeout), which bounds each individual
+    round-trip, the deadline applies to all subsequent fetches together. Thus it also aborts result sets
+    that deliver rows slowly, one round-trip at a time. The fetch that is still in progress when the
+    deadline expires is cancelled via `OCIBreak`.
+
+    Once the deadline has expired, fetching the next row fails with the "deadline exceeded" error.
+
+    # Parameters
+
+    * `timeout` - total time allowed for fetching, counted from this call
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    use std::time::Duration;
+
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT employee_id
+          FROM hr.employees
+      ORDER BY employee_id
+    ")?;
+    let rows = stmt.query(())?;
+    rows.set_deadline(Duration::from_secs(5))?;
+
+    let mut ids = Vec::new();
+    while let Some(row) = rows.next()? {
+        let id : u32 = row.get(0)?;
+        ids.push(id);
+    }
+    assert_eq!(ids.len(), 107);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT employee_id
+    #       FROM hr.employees
+    #   ORDER BY employee_id
+    # ").await?;
+    # let rows = stmt.query(()).await?;
+    # rows.set_deadline(Duration::from_secs(5))?;
+    # let mut ids = Vec::new();
+    # while let Some(row) = rows.next().await? {
+    #     let id : u32 = row.get(0)?;
+    #     ids.push(id);
+    # }
+    # assert_eq!(ids.len(), 107);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn set_deadline(&self, timeout: Duration) -> Result<()> {
+        let deadline = Deadline::new(timeout, &self.rset)?;
+        *self.deadline.lock() = Some(deadline);
+        Ok(())
+    }
+
+    /// Returns the names of the result set columns
+    fn column_names(&self) -> Result<Vec<String>> {
+        let cols = self.rset.read_columns();
+        let err: &OCIError = self.rset.as_ref();
+        (0..cols.len()).map(|index|
+            cols.col(index)
+                .ok_or_else(|| Error::msg(format!("Column {} does not exist", index)))
+                .and_then(|col| col.name(err))
+                .map(String::from)
+        ).collect()
+    }
+}
+
+/// Writes a single CSV record. Fields are quoted, as RFC 4180 describes, when they contain
+/// a comma, a double quote, or a line break. Records are terminated by CRLF.
+fn write_csv_record(out: &mut impl std::io::Write, fields: &[String]) -> Result<()> {
+    let mut line = String::new();
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            line.push(',');
+        }
+        if field.contains([',', '"', '\r', '\n']) {
+            line.push('"');
+            line.push_str(&field.replace('"', "\"\""));
+            line.push('"');
+        } else {
+            line.push_str(field);
+        }
+    }
+    line.push_str("\r\n");
+    out.write_all(line.as_bytes()).map_err(|err| Error::msg(format!("cannot write CSV: {}", err)))
 }
 
 enum RowSource<'a> {
     Single(DataSource<'a>),
-    Multi(&'a DataSource<'a>)
+    Multi(&'a DataSource<'a>),
+    /// A row from a batch fetched by `fetch_many` that owns its column buffers
+    Batch(&'a DataSource<'a>, RwLock<Columns>),
 }
 
-impl RowSource<'_> {
+impl<'a> RowSource<'a> {
     fn rset(&self) -> &DataSource {
         match self {
             Self::Single(ds) => ds,
             &Self::Multi(ds) => ds,
+            &Self::Batch(ds, _) => ds,
+        }
+    }
+
+    fn owning_session(&self) -> &'a Session<'a> {
+        match self {
+            Self::Single(ds) => ds.owning_session(),
+            &Self::Multi(ds) => ds.owning_session(),
+            &Self::Batch(ds, _) => ds.owning_session(),
+        }
+    }
+
+    fn read_columns(&self) -> RwLockReadGuard<'_, Columns> {
+        match self {
+            Self::Batch(_, cols) => cols.read(),
+            _ => self.rset().read_columns(),
+        }
+    }
+
+    fn write_columns(&self) -> RwLockWriteGuard<'_, Columns> {
+        match self {
+            Self::Batch(_, cols) => cols.write(),
+            _ => self.rset().write_columns(),
         }
     }
 }
@@ -110,6 +306,7 @@ macro_rules! impl_as_ref_for_row_source {
                     match self {
                         Self::Single(ds) => ds.as_ref(),
                         &Self::Multi(ds) => ds.as_ref(),
+                        &Self::Batch(ds, _) => ds.as_ref(),
                     }
                 }
             }
@@ -146,7 +343,7 @@ impl Ctx for Row<'_> {
 }
 
 impl<'a> Row<'a> {
-    fn new(rows: &'a Rows) -> Self {
+    pub(crate) fn new(rows: &'a Rows) -> Self {
         Self { src: RowSource::Multi(&rows.rset) }
     }
 
@@ -154,13 +351,17 @@ impl<'a> Row<'a> {
         Self { src: RowSource::Single(rows.src()) }
     }
 
+    fn batch(rows: &'a Rows, cols: Columns) -> Self {
+        Self { src: RowSource::Batch(&rows.rset, RwLock::new(cols)) }
+    }
+
     pub(crate) fn session(&self) -> &Session {
         self.src.rset().session()
     }
 
     // `get` helper to ensure that the read lock is released when we have the index
     fn col_index(&self, pos: &impl Position) -> Option<usize> {
-        let cols = self.src.rset().read_columns();
+        let cols = self.src.read_columns();
         pos.name().and_then(|name| cols.col_index(name)).or(pos.index())
     }
 
@@ -213,12 +414,259 @@ impl<'a> Row<'a> {
     This method considers the out of bounds or unknown/misnamed "columns" to be NULL.
     */
     pub fn is_null(&self, pos: impl Position) -> bool {
-        let cols = self.src.rset().read_columns();
+        let cols = self.src.read_columns();
         pos.name().and_then(|name| cols.col_index(name)).or(pos.index())
             .map(|ix| cols.is_null(ix))
             .unwrap_or(true)
     }
 
+    /**
+    Returns the column-level return code of the last fetch of the specified column.
+
+    The returned code is:
+    * `0` - the value was fetched intact,
+    * `1405` - the fetched value is NULL,
+    * `1406` - the fetched value was truncated to fit the column buffer.
+
+    The latter allows detection of truncated reads that otherwise would have been silent.
+
+    # Parameters
+
+    * `pos` - column name or a zero-based column index
+
+    # Failures
+
+    * `Column does not exist` - the column as specified was not found
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    // TEXT is a LONG column
+    let mut stmt = session.prepare("
+        SELECT text
+          FROM all_views
+         WHERE owner = 'HR' AND view_name = 'EMP_DETAILS_VIEW'
+    ")?;
+    stmt.set_max_long_size(16);
+    let row = stmt.query_single(())?.unwrap();
+
+    let rc = row.column_return_code(0)?;
+    assert_eq!(rc, 1406);
+
+    let text : &str = row.get(0)?;
+    assert_eq!(text.len(), 16);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let mut stmt = session.prepare("
+    #     SELECT text
+    #       FROM all_views
+    #      WHERE owner = 'HR' AND view_name = 'EMP_DETAILS_VIEW'
+    # ").await?;
+    # stmt.set_max_long_size(16);
+    # let row = stmt.query_single(()).await?.unwrap();
+    # let rc = row.column_return_code(0)?;
+    # assert_eq!(rc, 1406);
+    # let text : &str = row.get(0)?;
+    # assert_eq!(text.len(), 16);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn column_return_code(&self, pos: impl Position) -> Result<u16> {
+        let cols = self.src.read_columns();
+        pos.name().and_then(|name| cols.col_index(name)).or(pos.index())
+            .and_then(|ix| cols.col(ix))
+            .map(|col| col.return_code())
+            .ok_or_else(|| Error::msg(format!("Column {} does not exist", pos)))
+    }
+
+    /**
+    Returns the number of columns in the row.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT city, state_province, country_id
+          FROM hr.locations
+         WHERE location_id = :id
+    ")?;
+    let row = stmt.query_single(2400)?.unwrap();
+
+    assert_eq!(row.column_count(), 3);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT city, state_province, country_id
+    #       FROM hr.locations
+    #      WHERE location_id = :id
+    # ").await?;
+    # let row = stmt.query_single(2400).await?.unwrap();
+    # assert_eq!(row.column_count(), 3);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn column_count(&self) -> usize {
+        self.src.read_columns().len()
+    }
+
+    /**
+    Returns the name of the column at the specified zero-based index.
+
+    Together with [`Row::column_count`] this allows processing of rows, which shape is not
+    known upfront.
+
+    # Failures
+
+    * `Column does not exist` - the index is out of bounds
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT city, state_province, country_id
+          FROM hr.locations
+         WHERE location_id = :id
+    ")?;
+    let row = stmt.query_single(2400)?.unwrap();
+
+    let mut names = Vec::new();
+    for i in 0..row.column_count() {
+        names.push(row.column_name(i)?);
+    }
+    assert_eq!(names, ["CITY", "STATE_PROVINCE", "COUNTRY_ID"]);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT city, state_province, country_id
+    #       FROM hr.locations
+    #      WHERE location_id = :id
+    # ").await?;
+    # let row = stmt.query_single(2400).await?.unwrap();
+    # let mut names = Vec::new();
+    # for i in 0..row.column_count() {
+    #     names.push(row.column_name(i)?);
+    # }
+    # assert_eq!(names, ["CITY", "STATE_PROVINCE", "COUNTRY_ID"]);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn column_name(&self, pos: usize) -> Result<&str> {
+        let param = self.src.read_columns().column_param(pos)
+            .ok_or_else(|| Error::msg(format!("Column {} does not exist", pos)))?;
+        attr::get(OCI_ATTR_NAME, OCI_DTYPE_PARAM, param.as_ref(), self.as_ref())
+    }
+
+    /**
+    Returns values of all columns of the row formatted as text.
+
+    Values are formatted the same way `get::<String>` does it - numbers use the "text minimum"
+    format, dates and timestamps are formatted as `YYYY-MM-DD HH24:MI:SS`, etc. In addition to that
+    RAW and LONG RAW values are returned as upper-case hexadecimal strings. NULL values are returned
+    as empty strings. This is mostly useful for exporting or dumping rows, which shape is not known
+    upfront.
+
+    # Failures
+
+    Returns an error if any of the columns has a type that cannot be represented as text, such as
+    a LOB, a cursor, or an object.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT location_id, postal_code, city, country_id
+          FROM hr.locations
+         WHERE location_id = :id
+    ")?;
+    let row = stmt.query_single(2400)?.unwrap();
+
+    let values = row.to_string_vec()?;
+    assert_eq!(values, ["2400", "", "London", "UK"]);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT location_id, postal_code, city, country_id
+    #       FROM hr.locations
+    #      WHERE location_id = :id
+    # ").await?;
+    # let row = stmt.query_single(2400).await?.unwrap();
+    # let values = row.to_string_vec()?;
+    # assert_eq!(values, ["2400", "", "London", "UK"]);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn to_string_vec(&'a self) -> Result<Vec<String>> {
+        let num_cols = self.column_count();
+        let mut values = Vec::with_capacity(num_cols);
+        for index in 0..num_cols {
+            let text = if self.is_null(index) {
+                String::new()
+            } else if self.is_binary(index) {
+                let bytes : &[u8] = self.get(index)?;
+                bytes.iter().map(|b| format!("{:02X}", b)).collect()
+            } else {
+                self.get(index)?
+            };
+            values.push(text);
+        }
+        Ok(values)
+    }
+
+    /// Returns `true` if the column at the specified index is a RAW or a LONG RAW
+    fn is_binary(&self, index: usize) -> bool {
+        self.src.write_columns().col_mut(index).is_some_and(|col|
+            matches!(col.data(), ColumnBuffer::Binary(_) | ColumnBuffer::LongBinary(_))
+        )
+    }
+
     /**
     Returns value of the specified column in the row.
 
@@ -301,7 +749,7 @@ impl<'a> Row<'a> {
         match self.col_index(&pos) {
             None => Err(Error::msg(format!("Column {} does not exist", pos))),
             Some(index) => {
-                if let Some(result) = self.src.rset().write_columns().col_mut(index).map(|col| FromSql::value(self, col)) {
+                if let Some(result) = self.src.write_columns().col_mut(index).map(|col| FromSql::value(self, col)) {
                     result
                 } else {
                     Err(Error::msg(format!("Column {} cannot be found", pos)))
@@ -311,20 +759,18 @@ impl<'a> Row<'a> {
     }
 
     /**
-    Returns value of the specified column in the current row.
+    Returns value of the specified column in the row or the provided default value if the column is NULL.
 
-    This method used to provides a friendlier alternative to [`get`](Row::get) to fetch data
-    from `NOT NULL` columns at the time when `get` was always returning an `Option`.
+    This is a shortcut for fetching the value as an `Option` and then unwrapping it with a default.
 
     # Parameters
 
     * `pos` - column name or a zero-based column index
+    * `default` - value to return when the column is NULL
 
     # Failures
 
     * `Column does not exist` - the column as specified was not found
-    * `Column is null` - method was used to fetch data from a NULL-able column **and**
-        the column's value was NULL.
 
     # Example
 
@@ -337,25 +783,157 @@ impl<'a> Row<'a> {
     # fn main() -> Result<()> {
     # let session = sibyl::test_env::get_session()?;
     let stmt = session.prepare("
-        SELECT postal_code, city, state_province, country_id
+        SELECT postal_code, country_id
           FROM hr.locations
          WHERE location_id = :id
     ")?;
     let row = stmt.query_single(2400)?.unwrap();
 
-    // CITY is NOT NULL
-    let city : &str = row.get("CITY")?;
-
-    assert_eq!(city, "London");
-
-    // POSTAL_CODE, STATE_PROVINCE and COUNTRY_ID are all NULL-able
-    let postal_code    : Option<&str> = row.get("POSTAL_CODE")?;
-    let state_province : Option<&str> = row.get("STATE_PROVINCE")?;
-    let country_id     : Option<&str> = row.get("COUNTRY_ID")?;
-
-    assert!(postal_code.is_none());     // this one is NULL
-    assert!(state_province.is_none());  // also NULL
-    assert!(country_id.is_some());      // not NULL then
+    let postal_code : &str = row.get_or(0, "N/A")?;
+    assert_eq!(postal_code, "N/A");
+    let country_id : &str = row.get_or("COUNTRY_ID", "N/A")?;
+    assert_eq!(country_id, "UK");
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT postal_code, country_id
+    #       FROM hr.locations
+    #      WHERE location_id = :id
+    # ").await?;
+    # let row = stmt.query_single(2400).await?.unwrap();
+    # let postal_code : &str = row.get_or(0, "N/A")?;
+    # assert_eq!(postal_code, "N/A");
+    # let country_id : &str = row.get_or("COUNTRY_ID", "N/A")?;
+    # assert_eq!(country_id, "UK");
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn get_or<T: FromSql<'a>, P: Position>(&'a self, pos: P, default: T) -> Result<T> {
+        match self.col_index(&pos) {
+            None => Err(Error::msg(format!("Column {} does not exist", pos))),
+            Some(index) if self.is_null(index) => Ok(default),
+            Some(_) => self.get(pos),
+        }
+    }
+
+    /**
+    Returns an owned copy of the text in the specified column of the current row.
+
+    Text fetched as `&str` borrows the column buffer of the row. That buffer is reused for the
+    next row when [`Rows::next`] is called, and thus `&str` values cannot be collected across rows.
+    This method copies the text out of the buffer, and the returned string remains valid after
+    the next row is fetched. `row.get::<String>()` does the same, but this method returns the
+    more compact `Box<str>` and makes the intent to outlive the row explicit.
+
+    # Parameters
+
+    * `pos` - column name or a zero-based column index
+
+    # Failures
+
+    * `Column does not exist` - the column as specified was not found
+    * The column value is NULL or it cannot be converted into text
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest)
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT last_name
+          FROM hr.employees
+         WHERE manager_id = :id
+      ORDER BY employee_id
+    ")?;
+    let rows = stmt.query(103)?;
+    let mut names = Vec::new();
+    while let Some(row) = rows.next()? {
+        names.push(row.get_str_owned(0)?);
+    }
+    assert_eq!(names, ["Ernst".into(), "Austin".into(), "Pataballa".into(), "Lorentz".into()]);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT last_name
+    #       FROM hr.employees
+    #      WHERE manager_id = :id
+    #   ORDER BY employee_id
+    # ").await?;
+    # let rows = stmt.query(103).await?;
+    # let mut names = Vec::new();
+    # while let Some(row) = rows.next().await? {
+    #     names.push(row.get_str_owned(0)?);
+    # }
+    # assert_eq!(names, ["Ernst".into(), "Austin".into(), "Pataballa".into(), "Lorentz".into()]);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn get_str_owned<P: Position>(&'a self, pos: P) -> Result<Box<str>> {
+        let text : &str = self.get(pos)?;
+        Ok( Box::from(text) )
+    }
+
+    /**
+    Returns value of the specified column in the current row.
+
+    This method used to provides a friendlier alternative to [`get`](Row::get) to fetch data
+    from `NOT NULL` columns at the time when `get` was always returning an `Option`.
+
+    # Parameters
+
+    * `pos` - column name or a zero-based column index
+
+    # Failures
+
+    * `Column does not exist` - the column as specified was not found
+    * `Column is null` - method was used to fetch data from a NULL-able column **and**
+        the column's value was NULL.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest)
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT postal_code, city, state_province, country_id
+          FROM hr.locations
+         WHERE location_id = :id
+    ")?;
+    let row = stmt.query_single(2400)?.unwrap();
+
+    // CITY is NOT NULL
+    let city : &str = row.get("CITY")?;
+
+    assert_eq!(city, "London");
+
+    // POSTAL_CODE, STATE_PROVINCE and COUNTRY_ID are all NULL-able
+    let postal_code    : Option<&str> = row.get("POSTAL_CODE")?;
+    let state_province : Option<&str> = row.get("STATE_PROVINCE")?;
+    let country_id     : Option<&str> = row.get("COUNTRY_ID")?;
+
+    assert!(postal_code.is_none());     // this one is NULL
+    assert!(state_province.is_none());  // also NULL
+    assert!(country_id.is_some());      // not NULL then
     let country_id = country_id.unwrap();
     assert_eq!(country_id, "UK");
 
@@ -394,6 +972,64 @@ impl<'a> Row<'a> {
     # }
     ```
     */
+    /**
+    Returns values of the leading columns of the row as a tuple.
+
+    The first element of the tuple is read from the first column of the row, the second
+    one - from the second, etc. Tuples of up to 12 elements are supported. Each element
+    can be of any type that [`get`](Row::get) can return, including `Option`s for NULL-able
+    columns.
+
+    # Failures
+
+    * `Column does not exist` - the row has fewer columns than the tuple has elements
+    * `Column is null` - the column's value was NULL and the type of the tuple element is not an `Option`
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT city, state_province, country_id
+          FROM hr.locations
+         WHERE location_id = :id
+    ")?;
+    let row = stmt.query_single(2400)?.unwrap();
+
+    let (city, state_province, country_id) : (&str, Option<&str>, &str) = row.get_tuple()?;
+    assert_eq!(city, "London");
+    assert!(state_province.is_none());
+    assert_eq!(country_id, "UK");
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT city, state_province, country_id
+    #       FROM hr.locations
+    #      WHERE location_id = :id
+    # ").await?;
+    # let row = stmt.query_single(2400).await?.unwrap();
+    # let (city, state_province, country_id) : (&str, Option<&str>, &str) = row.get_tuple()?;
+    # assert_eq!(city, "London");
+    # assert!(state_province.is_none());
+    # assert_eq!(country_id, "UK");
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn get_tuple<T: FromSqlTuple<'a>>(&'a self) -> Result<T> {
+        T::from_row(self)
+    }
+
     #[deprecated = "Use [`get`](Row::get) instead."]
     pub fn get_not_null<T: FromSql<'a>, P: Position>(&'a self, pos: P) -> Result<T> {
         self.get(pos)
@@ -478,12 +1114,514 @@ impl<'a> Row<'a> {
         attr::get_into(OCI_ATTR_ROWID, &mut rowid, OCI_HTYPE_STMT, stmt, self.as_ref())?;
         Ok( rowid )
     }
+
+    /// Returns the value of the numeric column clamped to the range of `T` and
+    /// whether the original value was within that range.
+    fn get_int_in_range<T: Integer, P: Position>(&'a self, pos: P) -> Result<(T, bool)> {
+        let num : Number = self.get(pos)?;
+        if num.compare(&Number::from_int(T::MAX, self)?)? == Ordering::Greater {
+            Ok((T::MAX, false))
+        } else if num.compare(&Number::from_int(T::MIN, self)?)? == Ordering::Less {
+            Ok((T::MIN, false))
+        } else {
+            Ok((num.to_int()?, true))
+        }
+    }
+
+    /**
+    Returns the value of the specified numeric column as an integer of type `T`, clamped to
+    the range of that type. The returned flag is `true` if the value had to be clamped.
+
+    # Parameters
+
+    * `pos` - column name or a zero-based column index
+
+    # Failures
+
+    * `Column does not exist` - the column as specified was not found
+    * `Column is null` - the column's value was NULL
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT 1000000, 1000 FROM dual
+    ")?;
+    let row = stmt.query_single(())?.unwrap();
+
+    let (val, clamped) = row.get_clamped::<u16,_>(0)?;
+    assert_eq!(val, u16::MAX);
+    assert!(clamped);
+
+    let (val, clamped) = row.get_clamped::<u16,_>(1)?;
+    assert_eq!(val, 1000);
+    assert!(!clamped);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT 1000000, 1000 FROM dual
+    # ").await?;
+    # let row = stmt.query_single(()).await?.unwrap();
+    # let (val, clamped) = row.get_clamped::<u16,_>(0)?;
+    # assert_eq!(val, u16::MAX);
+    # assert!(clamped);
+    # let (val, clamped) = row.get_clamped::<u16,_>(1)?;
+    # assert_eq!(val, 1000);
+    # assert!(!clamped);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn get_clamped<T: Integer, P: Position>(&'a self, pos: P) -> Result<(T, bool)> {
+        let (val, in_range) = self.get_int_in_range(pos)?;
+        Ok((val, !in_range))
+    }
+
+    /**
+    Returns the value of the specified numeric column as an integer of type `T`.
+    Fails if the value is out of the range of that type.
+
+    # Parameters
+
+    * `pos` - column name or a zero-based column index
+
+    # Failures
+
+    * `Column does not exist` - the column as specified was not found
+    * `Column is null` - the column's value was NULL
+    * `Column value is out of range` - the value does not fit into `T`
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT 1000000, 1000 FROM dual
+    ")?;
+    let row = stmt.query_single(())?.unwrap();
+
+    let res = row.get_checked::<u16,_>(0);
+    assert!(res.is_err());
+
+    let val = row.get_checked::<u16,_>(1)?;
+    assert_eq!(val, 1000);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT 1000000, 1000 FROM dual
+    # ").await?;
+    # let row = stmt.query_single(()).await?.unwrap();
+    # let res = row.get_checked::<u16,_>(0);
+    # assert!(res.is_err());
+    # let val = row.get_checked::<u16,_>(1)?;
+    # assert_eq!(val, 1000);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn get_checked<T: Integer, P: Position>(&'a self, pos: P) -> Result<T> {
+        let col = pos.to_string();
+        let (val, in_range) = self.get_int_in_range(pos)?;
+        if in_range {
+            Ok(val)
+        } else {
+            Err(Error::msg(format!("Column {} value is out of range of {}", col, std::any::type_name::<T>())))
+        }
+    }
+
+    /**
+    Returns the LOB locator from the specified column for writing.
+
+    LOBs can only be modified when their rows are locked, i.e. when they were selected with
+    `SELECT ... FOR UPDATE`. Locators of LOBs from rows that are not locked can still be used
+    to read LOB content, but writes into those LOBs fail. This method verifies that the row
+    was locked and returns an error right away, rather than letting a later write fail.
+
+    # Parameters
+
+    * `pos` - column name or a zero-based column index
+
+    # Failures
+
+    * `Column does not exist` - the column as specified was not found
+    * `Column is null` - the column's value was NULL
+    * `LOB is not writable` - the row was not selected `FOR UPDATE`
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest)
+
+    ```
+    # use sibyl::{Result, CLOB, RowID};
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    # let stmt = session.prepare("
+    #     declare
+    #         name_already_used exception; pragma exception_init(name_already_used, -955);
+    #     begin
+    #         execute immediate '
+    #             create table test_lobs (
+    #                 id       number generated always as identity,
+    #                 text     clob,
+    #                 data     blob,
+    #                 ext_file bfile
+    #             )
+    #         ';
+    #     exception
+    #         when name_already_used then null;
+    #     end;
+    # ")?;
+    # stmt.execute(())?;
+    let stmt = session.prepare("
+        INSERT INTO test_lobs (text) VALUES (Empty_Clob()) RETURNING rowid INTO :row_id
+    ")?;
+    let mut rowid = RowID::new(&session)?;
+    stmt.execute(&mut rowid)?;
+
+    let stmt = session.prepare("
+        SELECT text FROM test_lobs WHERE rowid = :row_id
+    ")?;
+    let row = stmt.query_single(&rowid)?.unwrap();
+    let res : Result<CLOB> = row.get_lob_for_update(0);
+    assert!(res.is_err());
+
+    let stmt = session.prepare("
+        SELECT text FROM test_lobs WHERE rowid = :row_id FOR UPDATE
+    ")?;
+    let row = stmt.query_single(&rowid)?.unwrap();
+    let lob : CLOB = row.get_lob_for_update(0)?;
+    lob.append("Hello, World!")?;
+    assert_eq!(lob.len()?, 13);
+    # session.rollback()?;
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     declare
+    #         name_already_used exception; pragma exception_init(name_already_used, -955);
+    #     begin
+    #         execute immediate '
+    #             create table test_lobs (
+    #                 id       number generated always as identity,
+    #                 text     clob,
+    #                 data     blob,
+    #                 ext_file bfile
+    #             )
+    #         ';
+    #     exception
+    #         when name_already_used then null;
+    #     end;
+    # ").await?;
+    # stmt.execute(()).await?;
+    # let stmt = session.prepare("
+    #     INSERT INTO test_lobs (text) VALUES (Empty_Clob()) RETURNING rowid INTO :row_id
+    # ").await?;
+    # let mut rowid = RowID::new(&session)?;
+    # stmt.execute(&mut rowid).await?;
+    # let stmt = session.prepare("
+    #     SELECT text FROM test_lobs WHERE rowid = :row_id
+    # ").await?;
+    # let row = stmt.query_single(&rowid).await?.unwrap();
+    # let res : Result<CLOB> = row.get_lob_for_update(0);
+    # assert!(res.is_err());
+    # let stmt = session.prepare("
+    #     SELECT text FROM test_lobs WHERE rowid = :row_id FOR UPDATE
+    # ").await?;
+    # let row = stmt.query_single(&rowid).await?.unwrap();
+    # let lob : CLOB = row.get_lob_for_update(0)?;
+    # lob.append("Hello, World!").await?;
+    # assert_eq!(lob.len().await?, 13);
+    # session.rollback().await?;
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn get_lob_for_update<T, P>(&'a self, pos: P) -> Result<LOB<'a,T>>
+    where T: DescriptorType<OCIType=OCILobLocator> + InternalLob, LOB<'a,T>: FromSql<'a>, P: Position
+    {
+        // Only rows that were selected FOR UPDATE return their ROWIDs implicitly
+        let rowid = self.rowid()?;
+        if !rowid.is_initialized() {
+            return Err(Error::msg(format!("LOB in column {} is not writable as its row was not selected FOR UPDATE", pos)));
+        }
+        self.get(pos)
+    }
+
+    /**
+    Takes the LOB locator from the specified column.
+
+    Unlike [`get`](Row::get), which returns a LOB that borrows the row, the returned LOB is bound only
+    to the result set. Thus it can be kept after the row has been dropped and the next row has been
+    fetched. The locator is moved out of the row - subsequent attempts to get the LOB from the same
+    column of this row fail.
+
+    # Parameters
+
+    * `pos` - column name or a zero-based column index
+
+    # Failures
+
+    * `Column does not exist` - the column as specified was not found
+    * `Column is null` - the column value is NULL
+    * The column is not a LOB of the requested type or its locator has been already taken.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    use sibyl::CLOB;
+
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT To_Clob('first') FROM dual
+        UNION ALL
+        SELECT To_Clob('second') FROM dual
+    ")?;
+    let rows = stmt.query(())?;
+    let mut lobs : Vec<CLOB> = Vec::new();
+    while let Some(row) = rows.next()? {
+        lobs.push(row.take_lob(0)?);
+    }
+    assert_eq!(lobs.len(), 2);
+
+    let mut text = String::new();
+    lobs[1].read(0, 10, &mut text)?;
+    assert_eq!(text, "second");
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT To_Clob('first') FROM dual
+    #     UNION ALL
+    #     SELECT To_Clob('second') FROM dual
+    # ").await?;
+    # let rows = stmt.query(()).await?;
+    # let mut lobs : Vec<CLOB> = Vec::new();
+    # while let Some(row) = rows.next().await? {
+    #     lobs.push(row.take_lob(0)?);
+    # }
+    # assert_eq!(lobs.len(), 2);
+    # let mut text = String::new();
+    # lobs[1].read(0, 10, &mut text).await?;
+    # assert_eq!(text, "second");
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn take_lob<T, P>(&self, pos: P) -> Result<LOB<'a,T>>
+    where T: DescriptorType<OCIType=OCILobLocator>, P: Position
+    {
+        let index = self.col_index(&pos).ok_or_else(|| Error::msg(format!("Column {} does not exist", pos)))?;
+        let mut cols = self.src.write_columns();
+        let col = cols.col_mut(index).ok_or_else(|| Error::msg(format!("Column {} cannot be found", pos)))?;
+        if col.is_null() {
+            return Err(Error::msg(format!("Column {} is null", pos)));
+        }
+        let mut loc = Descriptor::<T>::new(self)?;
+        let taken = match col.data() {
+            ColumnBuffer::CLOB(row_loc)  if T::sql_type() == SQLT_CLOB  => swap_lob_locators(row_loc, &mut loc, self)?,
+            ColumnBuffer::BLOB(row_loc)  if T::sql_type() == SQLT_BLOB  => swap_lob_locators(row_loc, &mut loc, self)?,
+            ColumnBuffer::BFile(row_loc) if T::sql_type() == SQLT_BFILE => swap_lob_locators(row_loc, &mut loc, self)?,
+            _ => return Err(Error::new("cannot return as a LOB locator")),
+        };
+        if !taken {
+            return Err(Error::new("already consumed"));
+        }
+        Ok(LOB::make_new(loc, self.src.owning_session()))
+    }
+}
+
+/// Moves the initialized LOB locator from the column buffer into the provided descriptor.
+/// Returns `false` if the column locator has been already taken.
+fn swap_lob_locators<S, T>(row_loc: &mut Descriptor<S>, loc: &mut Descriptor<T>, row: &Row) -> Result<bool>
+where S: DescriptorType<OCIType=OCILobLocator>, T: DescriptorType<OCIType=OCILobLocator>
+{
+    if !crate::lob::is_initialized(row_loc, row.as_ref(), row.as_ref())? {
+        return Ok(false);
+    }
+    let row_ptr = row_loc.get_ptr();
+    row_loc.replace(loc.get_ptr());
+    loc.replace(row_ptr);
+    Ok(true)
+}
+
+/**
+A type that can be created from a row of query results.
+
+Applications implement it for their own types to be able to map rows to them - for example,
+by [`Statement::query_opt()`](crate::Statement::query_opt) or [`Statement::query_as()`](crate::Statement::query_as).
+
+`FromRow` is also implemented for tuples (up to 12 elements) of owned values. The tuple elements
+are read from the leading columns of the row.
+
+# Example
+
+```
+use sibyl::{FromRow, Row, Result};
+
+struct Employee {
+    name: String,
+    salary: u32,
+}
+
+impl FromRow for Employee {
+    fn from_row(row: &Row) -> Result<Self> {
+        Ok(Self {
+            name: row.get("LAST_NAME")?,
+            salary: row.get("SALARY")?,
+        })
+    }
+}
+```
+*/
+pub trait FromRow : Sized {
+    /// Creates a value from the columns of the row.
+    fn from_row(row: &Row) -> Result<Self>;
+}
+
+/// Tuple of values that can be read from the leading columns of a row.
+///
+/// See [`Row::get_tuple`].
+pub trait FromSqlTuple<'a> : Sized {
+    /// Reads tuple elements from the corresponding columns of the row.
+    fn from_row(row: &'a Row<'a>) -> Result<Self>;
+}
+
+macro_rules! impl_from_sql_tuple {
+    ($($t:ident $i:tt),+) => {
+        impl<'a, $($t: FromSql<'a>),+> FromSqlTuple<'a> for ($($t,)+) {
+            fn from_row(row: &'a Row<'a>) -> Result<Self> {
+                Ok( ( $( row.get::<$t,usize>($i)?, )+ ) )
+            }
+        }
+    };
+}
+
+impl_from_sql_tuple!(A 0);
+impl_from_sql_tuple!(A 0, B 1);
+impl_from_sql_tuple!(A 0, B 1, C 2);
+impl_from_sql_tuple!(A 0, B 1, C 2, D 3);
+impl_from_sql_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_from_sql_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_sql_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_sql_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_from_sql_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_from_sql_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_from_sql_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_from_sql_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+macro_rules! impl_from_row_tuple {
+    ($($t:ident $i:tt),+) => {
+        impl<$($t: for<'r> FromSql<'r>),+> FromRow for ($($t,)+) {
+            fn from_row(row: &Row) -> Result<Self> {
+                Ok( ( $( row.get::<$t,usize>($i)?, )+ ) )
+            }
+        }
+    };
 }
 
+impl_from_row_tuple!(A 0);
+impl_from_row_tuple!(A 0, B 1);
+impl_from_row_tuple!(A 0, B 1, C 2);
+impl_from_row_tuple!(A 0, B 1, C 2, D 3);
+impl_from_row_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_from_row_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_row_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_row_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_from_row_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_from_row_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_from_row_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_from_row_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
 #[cfg(all(test,feature="blocking"))]
 mod tests {
     use crate::*;
 
+    #[test]
+    fn write_csv() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            SELECT location_id, city, street_address || ', ' || city AS address
+                 , 'He said \"hi\"' AS note, state_province
+              FROM hr.locations
+             WHERE country_id = :id
+          ORDER BY location_id
+        ")?;
+        let rows = stmt.query("CA")?;
+        let mut csv = Vec::new();
+        let num_rows = rows.write_csv(&mut csv)?;
+        assert_eq!(num_rows, 2);
+        assert_eq!(String::from_utf8(csv).expect("UTF-8 CSV"),
+            "LOCATION_ID,CITY,ADDRESS,NOTE,STATE_PROVINCE\r\n\
+             1800,Toronto,\"147 Spadina Ave, Toronto\",\"He said \"\"hi\"\"\",Ontario\r\n\
+             1900,Whitehorse,\"6092 Boxwood St, Whitehorse\",\"He said \"\"hi\"\"\",Yukon\r\n"
+        );
+
+        let rows = stmt.query("XX")?;
+        let mut csv = Vec::new();
+        let num_rows = rows.write_csv(&mut csv)?;
+        assert_eq!(num_rows, 0);
+        assert_eq!(String::from_utf8(csv).expect("UTF-8 CSV"), "LOCATION_ID,CITY,ADDRESS,NOTE,STATE_PROVINCE\r\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_string_vec() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            SELECT employee_id, first_name, last_name, email, job_id, salary, commission_pct
+                 , manager_id, department_id, Hextoraw('C0FFEE'), Date '2006-01-03'
+              FROM hr.employees
+             WHERE employee_id = :id
+        ")?;
+        let row = stmt.query_single(103)?.expect("employee 103");
+
+        let values = row.to_string_vec()?;
+        assert_eq!(values, [
+            "103", "Alexander", "Hunold", "AHUNOLD", "IT_PROG", "9000", "",
+            "102", "60", "C0FFEE", "2006-01-03 00:00:00"
+        ]);
+
+        Ok(())
+    }
+
     #[test]
     fn get_null() -> Result<()> {
         let session = crate::test_env::get_session()?;
@@ -572,4 +1710,215 @@ mod tests {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn fetch_many() -> Result<()> {
+        use crate::oci::OCI_ATTR_ROWS_FETCHED;
+
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            SELECT level, To_Char(level, 'FM000'), Decode(Mod(level, 2), 0, Date '2020-01-01' + level)
+                 , Timestamp '2020-01-01 00:00:00' + NumToDSInterval(level, 'SECOND')
+              FROM dual
+           CONNECT BY level <= 500
+        ")?;
+        let rows = stmt.query(())?;
+
+        let batch = rows.fetch_many(200)?;
+        assert_eq!(batch.len(), 200);
+        // the whole batch was returned by a single fetch call
+        let num_fetched : u32 = stmt.get_attr(OCI_ATTR_ROWS_FETCHED)?;
+        assert_eq!(num_fetched, 200);
+        for (i, row) in batch.iter().enumerate() {
+            let num : usize = row.get(0)?;
+            assert_eq!(num, i + 1);
+            let txt : &str = row.get(1)?;
+            assert_eq!(txt, format!("{:03}", i + 1));
+            let date : Option<Date> = row.get(2)?;
+            if num % 2 == 0 {
+                let date = date.expect("date");
+                let days = date.days_from(&Date::with_date(2020, 1, 1, &session))?;
+                assert_eq!(days, num as i32);
+            } else {
+                assert!(date.is_none());
+            }
+            let ts : Timestamp = row.get(3)?;
+            let (hour, min, sec, _) = ts.time()?;
+            assert_eq!(hour as usize * 3600 + min as usize * 60 + sec as usize, num);
+        }
+
+        // single row fetches continue after the batch
+        let row = rows.next()?.expect("row 201");
+        let num : usize = row.get(0)?;
+        assert_eq!(num, 201);
+        // batch rows are not affected by the subsequent fetches
+        let num : usize = batch[199].get(0)?;
+        assert_eq!(num, 200);
+
+        let batch = rows.fetch_many(500)?;
+        assert_eq!(batch.len(), 299);
+        let num : usize = batch[298].get(0)?;
+        assert_eq!(num, 500);
+
+        assert!(rows.fetch_many(500)?.is_empty());
+        assert!(rows.next()?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_tuple() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            SELECT first_name, last_name, salary
+              FROM hr.employees
+             WHERE employee_id = :id
+        ")?;
+        let row = stmt.query_single(107)?.unwrap();
+
+        let (first_name, last_name, salary) : (String, String, Number) = row.get_tuple()?;
+
+        let col_first_name : String = row.get(0)?;
+        let col_last_name : String = row.get(1)?;
+        let col_salary : Number = row.get(2)?;
+        assert_eq!(first_name, col_first_name);
+        assert_eq!(last_name, col_last_name);
+        assert_eq!(salary.compare(&col_salary)?, std::cmp::Ordering::Equal);
+        assert_eq!(first_name, "Diana");
+        assert_eq!(last_name, "Lorentz");
+        assert_eq!(salary.to_int::<u32>()?, 4200);
+
+        let res : Result<(String, String, Number, String)> = row.get_tuple();
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn column_names() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            SELECT employee_id, last_name, commission_pct
+              FROM hr.employees
+             WHERE employee_id = :id
+        ")?;
+        let row = stmt.query_single(107)?.unwrap();
+
+        let mut cols = Vec::new();
+        for i in 0..row.column_count() {
+            let name = row.column_name(i)?;
+            let value : Option<String> = row.get(i)?;
+            cols.push((name, value));
+        }
+        assert_eq!(cols, [
+            ("EMPLOYEE_ID", Some("107".to_string())),
+            ("LAST_NAME", Some("Lorentz".to_string())),
+            ("COMMISSION_PCT", None),
+        ]);
+
+        assert!(row.column_name(3).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_or() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            SELECT commission_pct, manager_id
+              FROM hr.employees
+             WHERE employee_id = :id
+        ")?;
+        let row = stmt.query_single(107)?.unwrap();
+
+        let commission : u32 = row.get_or(0, 0u32)?;
+        assert_eq!(commission, 0);
+        let manager_id : u32 = row.get_or("MANAGER_ID", 0u32)?;
+        assert_eq!(manager_id, 103);
+
+        let res = row.get_or("NO_SUCH_COLUMN", 0u32);
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_str_owned() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+        let stmt = session.prepare("
+            SELECT last_name, first_name
+              FROM hr.employees
+             WHERE department_id = :dept
+          ORDER BY employee_id
+        ")?;
+        stmt.set_prefetch_rows(1)?;
+        let rows = stmt.query(30)?;
+        let mut names = Vec::new();
+        while let Some(row) = rows.next()? {
+            let last_name = row.get_str_owned(0)?;
+            let first_name = row.get_str_owned("FIRST_NAME")?;
+            names.push((first_name, last_name));
+        }
+        let names : Vec<(&str,&str)> = names.iter().map(|(first, last)| (first.as_ref(), last.as_ref())).collect();
+        assert_eq!(names, [
+            ("Den", "Raphaely"), ("Alexander", "Khoo"), ("Shelli", "Baida"),
+            ("Sigal", "Tobias"), ("Guy", "Himuro"), ("Karen", "Colmenares")
+        ]);
+
+        let stmt = session.prepare("SELECT commission_pct, last_name FROM hr.employees WHERE employee_id = :id")?;
+        let row = stmt.query_single(107)?.unwrap();
+        assert!(row.get_str_owned(0).is_err());
+        assert!(row.get_str_owned("NO_SUCH_COLUMN").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn deadline() -> Result<()> {
+        use std::time::{Duration, Instant};
+
+        let session = crate::test_env::get_session()?;
+        // every row takes a second to produce
+        let stmt = session.prepare("
+            WITH
+                FUNCTION slow_row(n NUMBER) RETURN NUMBER IS
+                BEGIN
+                    DBMS_LOCK.SLEEP(1);
+                    RETURN n;
+                END;
+            SELECT slow_row(level) FROM dual CONNECT BY level <= 10
+        ")?;
+        stmt.set_prefetch_rows(1)?;
+        let rows = stmt.query(())?;
+
+        let started = Instant::now();
+        rows.set_deadline(Duration::from_millis(2500))?;
+        let mut num_rows = 0;
+        let res = loop {
+            match rows.next() {
+                Ok(Some(_)) => num_rows += 1,
+                Ok(None) => break Ok(()),
+                Err(err) => break Err(err),
+            }
+        };
+        let elapsed = started.elapsed();
+        match res {
+            Err(Error::Interface(msg)) => assert_eq!(msg, "deadline exceeded"),
+            _ => panic!("unexpected result {:?}", res),
+        }
+        assert!(num_rows >= 2 && num_rows < 10, "fetched {} rows", num_rows);
+        assert!(elapsed >= Duration::from_millis(2500), "aborted after {:?}", elapsed);
+        assert!(elapsed < Duration::from_millis(4000), "aborted after {:?}", elapsed);
+
+        let res = rows.next();
+        assert!(res.is_err());
+
+        // session is still usable after the cancelled fetch
+        session.ping()?;
+        Ok(())
+    }
+}
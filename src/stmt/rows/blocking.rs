@@ -1,8 +1,109 @@
 //! Blocking mode row fetch
 
-use std::sync::atomic::Ordering;
+use std::{io::Write, sync::{Arc, atomic::Ordering}, thread, time::{Duration, Instant}};
+use once_cell::sync::OnceCell;
+use parking_lot::{Condvar, Mutex};
 
-use crate::{Result, Error, Rows, Row, oci::*};
+use super::{DataSource, write_csv_record};
+use crate::{Result, Error, Rows, Row, oci::{self, *}};
+
+/// State of the fetching that is shared with the deadline watchdog
+#[derive(Default)]
+struct FetchState {
+    fetching: bool,
+    expired: bool,
+}
+
+/// Result set deadline that is watched by the watchdog
+struct Watched {
+    expires: Instant,
+    svc: Ptr<OCISvcCtx>,
+    // The statement's error handle might be in use by the fetch that is being cancelled
+    err: Handle<OCIError>,
+    state: Mutex<FetchState>,
+}
+
+/// Cancels fetches that are still in progress when their deadlines expire.
+/// A single watchdog thread serves the deadlines of all result sets.
+struct Watchdog {
+    deadlines: Mutex<Vec<Arc<Watched>>>,
+    cvar: Condvar,
+}
+
+impl Watchdog {
+    /// Returns the watchdog. Its thread is started when the first deadline is set.
+    fn get() -> &'static Watchdog {
+        static WATCHDOG: OnceCell<Watchdog> = OnceCell::new();
+        WATCHDOG.get_or_init(|| {
+            thread::spawn(|| WATCHDOG.wait().run());
+            Self { deadlines: Mutex::new(Vec::new()), cvar: Condvar::new() }
+        })
+    }
+
+    fn run(&self) {
+        let mut deadlines = self.deadlines.lock();
+        loop {
+            let now = Instant::now();
+            deadlines.retain(|deadline| {
+                if deadline.expires > now {
+                    return true;
+                }
+                let mut state = deadline.state.lock();
+                state.expired = true;
+                if state.fetching {
+                    // the fetch will fail and report the expired deadline
+                    let _ = oci::break_call(&deadline.svc, &deadline.err);
+                }
+                false
+            });
+            match deadlines.iter().map(|deadline| deadline.expires).min() {
+                Some(expires) => { self.cvar.wait_until(&mut deadlines, expires); }
+                None => self.cvar.wait(&mut deadlines),
+            }
+        }
+    }
+}
+
+/// Result set deadline. The watchdog cancels the fetch that is still in progress
+/// when the deadline expires.
+pub(crate) struct Deadline {
+    watched: Arc<Watched>,
+}
+
+impl Deadline {
+    pub(super) fn new(timeout: Duration, rset: &DataSource) -> Result<Self> {
+        let watched = Arc::new(Watched {
+            expires: Instant::now() + timeout,
+            svc: Ptr::from(rset.as_ref()),
+            err: Handle::<OCIError>::new(rset)?,
+            state: Mutex::new(FetchState::default()),
+        });
+        let watchdog = Watchdog::get();
+        watchdog.deadlines.lock().push(watched.clone());
+        watchdog.cvar.notify_one();
+        Ok(Self { watched })
+    }
+
+    /// Marks the start of a fetch. Returns `false` if the deadline has already expired.
+    fn start_fetch(&self) -> bool {
+        let mut state = self.watched.state.lock();
+        state.fetching = !state.expired;
+        state.fetching
+    }
+
+    /// Marks the end of a fetch. Returns `true` if the deadline expired during the fetch.
+    fn end_fetch(&self) -> bool {
+        let mut state = self.watched.state.lock();
+        state.fetching = false;
+        state.expired
+    }
+}
+
+impl Drop for Deadline {
+    fn drop(&mut self) {
+        Watchdog::get().deadlines.lock().retain(|deadline| !Arc::ptr_eq(deadline, &self.watched));
+    }
+}
 
 impl<'a> Rows<'a> {
     /**
@@ -46,9 +147,7 @@ impl<'a> Rows<'a> {
         if self.last_result.load(Ordering::Acquire) == OCI_NO_DATA {
             Ok( None )
         } else {
-            let res = unsafe {
-                OCIStmtFetch2(self.rset.as_ref(), self.rset.as_ref(), 1, OCI_FETCH_NEXT, 0, OCI_DEFAULT)
-            };
+            let res = self.fetch(1)?;
             self.last_result.store(res, Ordering::Release);
             match res {
                 OCI_NO_DATA => Ok( None ),
@@ -58,11 +157,138 @@ impl<'a> Rows<'a> {
         }
     }
 
+    /**
+    Fetches up to `num_rows` next rows of the SELECT's result set.
+
+    The rows are fetched by a single array fetch, i.e. the whole batch is transferred from the server
+    in a single round-trip. The returned rows own their data and thus remain valid while the subsequent
+    rows are fetched.
+
+    An empty vector is returned when there are no more rows in the result set.
+
+    # Parameters
+
+    * `num_rows` - maximum number of rows to fetch
+
+    # Failures
+
+    Returns an error if the query returns REF CURSOR, object or LONG columns that are fetched piecewise.
+    Values of those types cannot be fetched in batches.
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT employee_id
+          FROM hr.employees
+      ORDER BY employee_id
+    ")?;
+    let rows = stmt.query(())?;
+
+    let batch = rows.fetch_many(50)?;
+    assert_eq!(batch.len(), 50);
+    let first_id : u32 = batch[0].get(0)?;
+    assert_eq!(first_id, 100);
+
+    let batch = rows.fetch_many(100)?;
+    assert_eq!(batch.len(), 57);
+    let last_id : u32 = batch[56].get(0)?;
+    assert_eq!(last_id, 206);
+
+    let batch = rows.fetch_many(100)?;
+    assert!(batch.is_empty());
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn fetch_many(&self, num_rows: usize) -> Result<Vec<Row<'_>>> {
+        if num_rows == 0 || self.last_result.load(Ordering::Acquire) == OCI_NO_DATA {
+            return Ok( Vec::new() );
+        }
+        let batch = self.define_batch(num_rows)?;
+        let res = self.fetch(num_rows as u32);
+        self.fetched_batch(batch, res)
+    }
+
+    /**
+    Writes the remaining rows of the result set to `out` as CSV.
+
+    The first written record is the header with the column names. Each row is then written as
+    a record of its column values formatted by [`Row::to_string_vec`]. Fields that contain commas,
+    double quotes, or line breaks are quoted and double quotes in them are escaped as RFC 4180
+    describes. Records are terminated by CRLF.
+
+    Returns the number of data rows written.
+
+    # Parameters
+
+    * `out` - the writer the CSV is written to
+
+    # Failures
+
+    Returns an error if any of the columns cannot be formatted as text or if the CSV cannot be written.
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT location_id, city, state_province
+          FROM hr.locations
+         WHERE country_id = :id
+      ORDER BY location_id
+    ")?;
+    let rows = stmt.query("CA")?;
+
+    let mut csv = Vec::new();
+    let num_rows = rows.write_csv(&mut csv)?;
+
+    assert_eq!(num_rows, 2);
+    assert_eq!(String::from_utf8(csv)?,
+        "LOCATION_ID,CITY,STATE_PROVINCE\r\n\
+         1800,Toronto,Ontario\r\n\
+         1900,Whitehorse,Yukon\r\n"
+    );
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn write_csv<W: Write>(&self, mut out: W) -> Result<usize> {
+        write_csv_record(&mut out, &self.column_names()?)?;
+        let mut num_rows = 0;
+        while let Some(row) = self.next()? {
+            write_csv_record(&mut out, &row.to_string_vec()?)?;
+            num_rows += 1;
+        }
+        Ok(num_rows)
+    }
+
+    /// Fetches the next `num_rows` rows from the server or from the prefetch buffer
+    fn fetch(&self, num_rows: u32) -> Result<i32> {
+        let deadline = self.deadline.lock();
+        let deadline = deadline.as_ref();
+        if let Some(deadline) = deadline {
+            if !deadline.start_fetch() {
+                return Err( Error::new("deadline exceeded") );
+            }
+        }
+        trace_oci_call!(OCIStmtFetch2);
+        let res = unsafe {
+            OCIStmtFetch2(self.rset.as_ref(), self.rset.as_ref(), num_rows, OCI_FETCH_NEXT, 0, OCI_DEFAULT)
+        };
+        if let Some(deadline) = deadline {
+            if deadline.end_fetch() && res < 0 {
+                return Err( Error::new("deadline exceeded") );
+            }
+        }
+        Ok(res)
+    }
+
     /// Variant of [`Row::next`] for a single row query
     pub(in crate::stmt) fn single(self) -> Result<Option<Row<'a>>> {
         if self.last_result.load(Ordering::Relaxed) == OCI_NO_DATA {
             Ok( None )
         } else {
+            trace_oci_call!(OCIStmtFetch2);
             let res = unsafe {
                 OCIStmtFetch2(self.rset.as_ref(), self.rset.as_ref(), 1, OCI_FETCH_NEXT, 0, OCI_DEFAULT)
             };
@@ -1,8 +1,20 @@
 //! Nonblocking mode row fetch
 
-use std::sync::atomic::Ordering;
+use std::{future::{Future, poll_fn}, io::Write, pin::pin, sync::atomic::Ordering, time::{Duration, Instant}};
 
-use crate::{Result, Error, Rows, Row, oci::*};
+use super::{DataSource, write_csv_record};
+use crate::{Result, Error, Rows, Row, oci::{self, *}};
+
+/// Result set deadline. The fetch that is still in progress when the deadline expires is cancelled.
+pub(crate) struct Deadline {
+    expires: Instant,
+}
+
+impl Deadline {
+    pub(super) fn new(timeout: Duration, _rset: &DataSource) -> Result<Self> {
+        Ok(Self { expires: Instant::now() + timeout })
+    }
+}
 
 impl<'a> Rows<'a> {
     /**
@@ -47,9 +59,7 @@ impl<'a> Rows<'a> {
         if self.last_result.load(Ordering::Acquire) == OCI_NO_DATA {
             Ok( None )
         } else {
-            let stmt: &OCIStmt  = self.rset.as_ref();
-            let err:  &OCIError = self.rset.as_ref();
-            let res = futures::StmtFetch::new(self.rset.session().get_svc(), stmt, err).await?;
+            let res = self.fetch(1).await?;
             self.last_result.store(res, Ordering::Release);
             match res {
                 OCI_NO_DATA => Ok( None ),
@@ -59,6 +69,149 @@ impl<'a> Rows<'a> {
         }
     }
 
+    /**
+    Fetches up to `num_rows` next rows of the SELECT's result set.
+
+    The rows are fetched by a single array fetch, i.e. the whole batch is transferred from the server
+    in a single round-trip. The returned rows own their data and thus remain valid while the subsequent
+    rows are fetched.
+
+    An empty vector is returned when there are no more rows in the result set.
+
+    # Parameters
+
+    * `num_rows` - maximum number of rows to fetch
+
+    # Failures
+
+    Returns an error if the query returns REF CURSOR, object or LONG columns that are fetched piecewise.
+    Values of those types cannot be fetched in batches.
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let stmt = session.prepare("
+        SELECT employee_id
+          FROM hr.employees
+      ORDER BY employee_id
+    ").await?;
+    let rows = stmt.query(()).await?;
+
+    let batch = rows.fetch_many(50).await?;
+    assert_eq!(batch.len(), 50);
+    let first_id : u32 = batch[0].get(0)?;
+    assert_eq!(first_id, 100);
+
+    let batch = rows.fetch_many(100).await?;
+    assert_eq!(batch.len(), 57);
+    let last_id : u32 = batch[56].get(0)?;
+    assert_eq!(last_id, 206);
+
+    let batch = rows.fetch_many(100).await?;
+    assert!(batch.is_empty());
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn fetch_many(&'a self, num_rows: usize) -> Result<Vec<Row<'a>>> {
+        if num_rows == 0 || self.last_result.load(Ordering::Acquire) == OCI_NO_DATA {
+            return Ok( Vec::new() );
+        }
+        let batch = self.define_batch(num_rows)?;
+        let res = self.fetch(num_rows as u32).await;
+        self.fetched_batch(batch, res)
+    }
+
+    /**
+    Writes the remaining rows of the result set to `out` as CSV.
+
+    The first written record is the header with the column names. Each row is then written as
+    a record of its column values formatted by [`Row::to_string_vec`]. Fields that contain commas,
+    double quotes, or line breaks are quoted and double quotes in them are escaped as RFC 4180
+    describes. Records are terminated by CRLF.
+
+    Returns the number of data rows written.
+
+    # Parameters
+
+    * `out` - the writer the CSV is written to
+
+    # Failures
+
+    Returns an error if any of the columns cannot be formatted as text or if the CSV cannot be written.
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let stmt = session.prepare("
+        SELECT location_id, city, state_province
+          FROM hr.locations
+         WHERE country_id = :id
+      ORDER BY location_id
+    ").await?;
+    let rows = stmt.query("CA").await?;
+
+    let mut csv = Vec::new();
+    let num_rows = rows.write_csv(&mut csv).await?;
+
+    assert_eq!(num_rows, 2);
+    assert_eq!(String::from_utf8(csv).unwrap(),
+        "LOCATION_ID,CITY,STATE_PROVINCE\r\n\
+         1800,Toronto,Ontario\r\n\
+         1900,Whitehorse,Yukon\r\n"
+    );
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn write_csv<W: Write>(&'a self, mut out: W) -> Result<usize> {
+        write_csv_record(&mut out, &self.column_names()?)?;
+        let mut num_rows = 0;
+        while let Some(row) = self.next().await? {
+            write_csv_record(&mut out, &row.to_string_vec()?)?;
+            num_rows += 1;
+        }
+        Ok(num_rows)
+    }
+
+    /// Fetches the next `num_rows` rows from the server or from the prefetch buffer
+    async fn fetch(&self, num_rows: u32) -> Result<i32> {
+        let stmt: &OCIStmt  = self.rset.as_ref();
+        let err:  &OCIError = self.rset.as_ref();
+        let fetch = futures::StmtFetch::rows(self.rset.session().get_svc(), stmt, err, num_rows);
+        let expires = self.deadline.lock().as_ref().map(|deadline| deadline.expires);
+        let expires = match expires {
+            Some(expires) => expires,
+            None => return fetch.await,
+        };
+        if Instant::now() >= expires {
+            return Err( Error::new("deadline exceeded") );
+        }
+        let mut fetch = pin!(fetch);
+        let mut cancelled = false;
+        let res = poll_fn(|cx| {
+            if !cancelled && Instant::now() >= expires {
+                cancelled = true;
+                // The statement's error handle might be in use by the fetch that is being cancelled
+                if let Ok(err) = Handle::<OCIError>::new(&self.rset) {
+                    if oci::break_call(self.rset.as_ref(), &err).is_err() {
+                        // Abort the call instead. The fetch still has to complete (with an error)
+                        // to release the session.
+                        oci::reset(self.rset.as_ref(), &err);
+                    }
+                }
+            }
+            fetch.as_mut().poll(cx)
+        }).await;
+        if cancelled && res.is_err() {
+            Err( Error::new("deadline exceeded") )
+        } else {
+            res
+        }
+    }
+
     pub(in crate::stmt) async fn single(self) -> Result<Option<Row<'a>>> {
         if self.last_result.load(Ordering::Relaxed) == OCI_NO_DATA {
             Ok( None )
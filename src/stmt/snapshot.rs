@@ -0,0 +1,59 @@
+//! Read consistency snapshots
+
+use std::{sync::Arc, marker::PhantomData};
+use crate::{Result, Environment, Session, oci::{Descriptor, OCISnapshot}};
+
+/**
+A snapshot of the database's committed data.
+
+A snapshot is captured by a query that was executed by a statement to which the snapshot
+was attached via [`Statement::capture_snapshot()`](crate::Statement::capture_snapshot). Queries
+that are executed by statements that use the captured snapshot - see
+[`Statement::use_snapshot()`](crate::Statement::use_snapshot) - see the data as of the snapshot.
+Statements can be prepared in different sessions. Thus several sessions might read the same
+consistent point-in-time view of the committed data.
+
+Note that uncommitted changes of one session are not visible in another session even when
+both use the same snapshot.
+
+Snapshots are cheap to clone. Clones refer to the same snapshot.
+*/
+#[derive(Clone)]
+pub struct Snapshot<'a> {
+    desc: Arc<Descriptor<OCISnapshot>>,
+    phantom_env: PhantomData<&'a Environment>,
+}
+
+impl<'a> Snapshot<'a> {
+    /**
+    Creates a new empty snapshot. The snapshot content is set by the first query that captures it.
+
+    # Example
+
+    ```
+    use sibyl::Snapshot;
+
+    # #[cfg(feature="blocking")]
+    # fn main() -> sibyl::Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let snapshot = Snapshot::new(&session)?;
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> sibyl::Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let snapshot = Snapshot::new(&session)?;
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn new(session: &Session<'a>) -> Result<Self> {
+        let desc = Descriptor::<OCISnapshot>::new(session)?;
+        Ok(Self { desc: Arc::new(desc), phantom_env: PhantomData })
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const OCISnapshot {
+        self.desc.get_ptr().get()
+    }
+}
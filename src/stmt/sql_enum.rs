@@ -0,0 +1,206 @@
+//! Mapping of Rust enums to and from code columns
+
+/**
+Implements [`ToSql`](crate::ToSql) and [`FromSql`](crate::FromSql) for a field-less enum,
+which variants are stored in the database as single character or numeric codes.
+
+The enum is described by its name, the type of the codes - `char` for `CHAR(1)` (or `VARCHAR2`)
+columns, or one of the integer types for `NUMBER` columns - and the codes of its variants.
+
+Fetching a code that is not mapped to any of the variants fails with an error.
+
+# Example
+
+🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+```
+use sibyl::impl_sql_enum;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status { Active, Inactive }
+
+impl_sql_enum! {
+    Status : char {
+        Active   = 'A',
+        Inactive = 'I',
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Priority { Low, Normal, High }
+
+impl_sql_enum! {
+    Priority : i32 {
+        Low    = 1,
+        Normal = 5,
+        High   = 9,
+    }
+}
+
+# use sibyl::Result;
+# #[cfg(feature="blocking")]
+# fn main() -> Result<()> {
+# let session = sibyl::test_env::get_session()?;
+let stmt = session.prepare("
+    SELECT Cast(:STATUS AS CHAR(1)), :PRIORITY + 4
+      FROM dual
+")?;
+let row = stmt.query_single((Status::Active, Priority::Low, ()))?.unwrap();
+let status : Status = row.get(0)?;
+let priority : Priority = row.get(1)?;
+assert_eq!(status, Status::Active);
+assert_eq!(priority, Priority::Normal);
+# Ok(())
+# }
+# #[cfg(feature="nonblocking")]
+# fn main() -> Result<()> {
+# sibyl::block_on(async {
+# let session = sibyl::test_env::get_session().await?;
+# let stmt = session.prepare("
+#     SELECT Cast(:STATUS AS CHAR(1)), :PRIORITY + 4
+#       FROM dual
+# ").await?;
+# let row = stmt.query_single((Status::Active, Priority::Low, ())).await?.unwrap();
+# let status : Status = row.get(0)?;
+# let priority : Priority = row.get(1)?;
+# assert_eq!(status, Status::Active);
+# assert_eq!(priority, Priority::Normal);
+# Ok(()) })
+# }
+```
+*/
+#[macro_export]
+macro_rules! impl_sql_enum {
+    ($name:ident : char { $($variant:ident = $code:literal),+ $(,)? }) => {
+        impl $crate::ToSql for $name {
+            fn bind_to(&mut self, pos: usize, params: &mut $crate::__private::Params, stmt: &$crate::__private::OCIStmt, err: &$crate::__private::OCIError) -> $crate::Result<usize> {
+                let mut code : &'static str = match self {
+                    $( $name::$variant => concat!($code), )+
+                };
+                $crate::ToSql::bind_to(&mut code, pos, params, stmt, err)
+            }
+        }
+
+        impl $crate::ToSql for &$name {
+            fn bind_to(&mut self, pos: usize, params: &mut $crate::__private::Params, stmt: &$crate::__private::OCIStmt, err: &$crate::__private::OCIError) -> $crate::Result<usize> {
+                let mut code : &'static str = match self {
+                    $( $name::$variant => concat!($code), )+
+                };
+                $crate::ToSql::bind_to(&mut code, pos, params, stmt, err)
+            }
+        }
+
+        impl<'a> $crate::FromSql<'a> for $name {
+            fn value(row: &'a $crate::Row<'a>, col: &mut $crate::__private::Column) -> $crate::Result<Self> {
+                let code : &str = $crate::FromSql::value(row, col)?;
+                // CHAR columns might be wider than 1 character
+                let code = code.trim_end();
+                $(
+                    if code == concat!($code) {
+                        return Ok($name::$variant);
+                    }
+                )+
+                Err($crate::Error::Interface(format!("'{}' is not a code of {}", code, stringify!($name))))
+            }
+        }
+    };
+    ($name:ident : $repr:ty { $($variant:ident = $code:literal),+ $(,)? }) => {
+        impl $crate::ToSql for $name {
+            fn bind_to(&mut self, pos: usize, params: &mut $crate::__private::Params, stmt: &$crate::__private::OCIStmt, err: &$crate::__private::OCIError) -> $crate::Result<usize> {
+                let mut code : &'static $repr = match self {
+                    $( $name::$variant => &$code, )+
+                };
+                $crate::ToSql::bind_to(&mut code, pos, params, stmt, err)
+            }
+        }
+
+        impl $crate::ToSql for &$name {
+            fn bind_to(&mut self, pos: usize, params: &mut $crate::__private::Params, stmt: &$crate::__private::OCIStmt, err: &$crate::__private::OCIError) -> $crate::Result<usize> {
+                let mut code : &'static $repr = match self {
+                    $( $name::$variant => &$code, )+
+                };
+                $crate::ToSql::bind_to(&mut code, pos, params, stmt, err)
+            }
+        }
+
+        impl<'a> $crate::FromSql<'a> for $name {
+            fn value(row: &'a $crate::Row<'a>, col: &mut $crate::__private::Column) -> $crate::Result<Self> {
+                let code : $repr = $crate::FromSql::value(row, col)?;
+                $(
+                    if code == $code {
+                        return Ok($name::$variant);
+                    }
+                )+
+                Err($crate::Error::Interface(format!("{} is not a code of {}", code, stringify!($name))))
+            }
+        }
+    };
+}
+
+#[cfg(all(test,feature="blocking"))]
+mod tests {
+    use crate::Result;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Status { Active, Inactive }
+
+    crate::impl_sql_enum! {
+        Status : char {
+            Active   = 'A',
+            Inactive = 'I',
+        }
+    }
+
+    #[test]
+    fn char_enum() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_sql_enums (
+                        id      NUMBER PRIMARY KEY,
+                        status  CHAR(1) NOT NULL
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_sql_enums
+                    ';
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("INSERT INTO test_sql_enums (id, status) VALUES (:id, :status)")?;
+        stmt.execute((1, Status::Active, ()))?;
+        stmt.execute((2, &Status::Inactive, ()))?;
+
+        let stmt = session.prepare("SELECT status FROM test_sql_enums WHERE id = :id")?;
+        let row = stmt.query_single(1)?.unwrap();
+        let code : &str = row.get(0)?;
+        assert_eq!(code, "A");
+        let status : Status = row.get(0)?;
+        assert_eq!(status, Status::Active);
+
+        let row = stmt.query_single(2)?.unwrap();
+        let status : Status = row.get(0)?;
+        assert_eq!(status, Status::Inactive);
+
+        let stmt = session.prepare("SELECT id FROM test_sql_enums WHERE status = :status")?;
+        let row = stmt.query_single(Status::Inactive)?.unwrap();
+        let id : u32 = row.get(0)?;
+        assert_eq!(id, 2);
+
+        let stmt = session.prepare("SELECT 'X' FROM dual")?;
+        let row = stmt.query_single(())?.unwrap();
+        let res : Result<Status> = row.get(0);
+        assert!(res.is_err());
+
+        session.rollback()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,253 @@
+//! An IN argument which data is streamed from a reader during the statement execution.
+//!
+//! Large values that are bound as regular arguments have to be materialized in memory before
+//! the statement is executed. `Streamed` instead binds the parameter placeholder dynamically and
+//! reads the value from the provided reader in pieces as OCI requests them during the execution.
+//! Thus, the entire value is never held in memory at once.
+//!
+//! `Streamed` values can be inserted into LONG and LONG RAW columns, and into CLOB and BLOB columns.
+//! They cannot be used in batches.
+//!
+//! # Example
+//!
+//! ```
+//! use std::io::Read;
+//! # use sibyl::*;
+//! # #[cfg(feature="blocking")]
+//! # fn main() -> Result<()> {
+//! # let session = sibyl::test_env::get_session()?;
+//! # let stmt = session.prepare("
+//! #     DECLARE
+//! #         name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+//! #     BEGIN
+//! #         EXECUTE IMMEDIATE '
+//! #             CREATE TABLE test_streamed_clob (
+//! #                 id      NUMBER GENERATED ALWAYS AS IDENTITY,
+//! #                 text    CLOB
+//! #             )
+//! #         ';
+//! #     EXCEPTION
+//! #       WHEN name_already_used THEN NULL;
+//! #     END;
+//! # ")?;
+//! # stmt.execute(())?;
+//! let stmt = session.prepare("
+//!     INSERT INTO test_streamed_clob (text) VALUES (:TEXT)
+//! ")?;
+//! // 1MB of text that is never materialized
+//! let text = std::io::repeat(b'A').take(1024 * 1024);
+//! let num_rows = stmt.execute(Streamed::text(text))?;
+//! assert_eq!(num_rows, 1);
+//! # session.rollback()?;
+//! # Ok(())
+//! # }
+//! # #[cfg(feature="nonblocking")]
+//! # fn main() -> Result<()> {
+//! # sibyl::block_on(async {
+//! # let session = sibyl::test_env::get_session().await?;
+//! # let stmt = session.prepare("
+//! #     DECLARE
+//! #         name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+//! #     BEGIN
+//! #         EXECUTE IMMEDIATE '
+//! #             CREATE TABLE test_streamed_clob (
+//! #                 id      NUMBER GENERATED ALWAYS AS IDENTITY,
+//! #                 text    CLOB
+//! #             )
+//! #         ';
+//! #     EXCEPTION
+//! #       WHEN name_already_used THEN NULL;
+//! #     END;
+//! # ").await?;
+//! # stmt.execute(()).await?;
+//! # let stmt = session.prepare("
+//! #     INSERT INTO test_streamed_clob (text) VALUES (:TEXT)
+//! # ").await?;
+//! # let text = std::io::repeat(b'A').take(1024 * 1024);
+//! # let num_rows = stmt.execute(Streamed::text(text)).await?;
+//! # assert_eq!(num_rows, 1);
+//! # session.rollback().await?;
+//! # Ok(()) })
+//! # }
+//! ```
+
+use crate::{Result, ToSql};
+use crate::oci::*;
+use crate::stmt::Params;
+use libc::c_void;
+use std::io::Read;
+
+/// Size of the pieces in which the streamed data is provided to OCI.
+const PIECE_SIZE : usize = 32768;
+
+/// An IN argument which data is read from a reader while the statement is executed.
+pub struct Streamed<R> {
+    reader: R,
+    sql_type: u16,
+    piece: Vec<u8>,
+    is_first_piece: bool,
+    error: Option<std::io::Error>,
+}
+
+impl<R: Read + Send + Sync> Streamed<R> {
+    fn new(reader: R, sql_type: u16) -> Self {
+        Self { reader, sql_type, piece: Vec::new(), is_first_piece: true, error: None }
+    }
+
+    /// Creates an argument that streams text, which is read from the `reader`,
+    /// into a LONG or a CLOB.
+    pub fn text(reader: R) -> Self {
+        Self::new(reader, SQLT_LNG)
+    }
+
+    /// Creates an argument that streams binary data, which is read from the `reader`,
+    /// into a LONG RAW or a BLOB.
+    pub fn binary(reader: R) -> Self {
+        Self::new(reader, SQLT_LBI)
+    }
+
+    /// Returns the error that the reader reported while the data was streamed, if any.
+    ///
+    /// The read error aborts the execution, which then fails with an OCI error.
+    pub fn read_error(&self) -> Option<&std::io::Error> {
+        self.error.as_ref()
+    }
+
+    /// Reads the next piece of data. Returns `true` if the end of the data has been reached.
+    fn read_piece(&mut self) -> std::io::Result<bool> {
+        self.piece.resize(PIECE_SIZE, 0);
+        let mut len = 0;
+        while len < PIECE_SIZE {
+            match self.reader.read(&mut self.piece[len..]) {
+                Ok(0) => break,
+                Ok(n) => len += n,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            }
+        }
+        self.piece.truncate(len);
+        Ok(len < PIECE_SIZE)
+    }
+}
+
+/// Provides OCI with the next piece of the streamed data.
+extern "C" fn piece_in_callback<R: Read + Send + Sync>(
+    ictxp: *mut c_void, _bindp: *mut OCIBind, _iter: u32, _index: u32,
+    bufpp: *mut *mut c_void, alenp: *mut u32, piecep: *mut u8, indpp: *mut *mut c_void
+) -> i32 {
+    static NOT_NULL_IND : i16 = OCI_IND_NOTNULL;
+    let arg = unsafe { &mut *(ictxp as *mut Streamed<R>) };
+    match arg.read_piece() {
+        Ok(is_last) => {
+            let piece = match (arg.is_first_piece, is_last) {
+                (true, true)   => OCI_ONE_PIECE,
+                (true, false)  => OCI_FIRST_PIECE,
+                (false, false) => OCI_NEXT_PIECE,
+                (false, true)  => OCI_LAST_PIECE,
+            };
+            arg.is_first_piece = false;
+            unsafe {
+                *bufpp  = arg.piece.as_mut_ptr() as _;
+                *alenp  = arg.piece.len() as _;
+                *indpp  = &NOT_NULL_IND as *const i16 as _;
+                *piecep = piece;
+            }
+            OCI_CONTINUE
+        }
+        Err(err) => {
+            arg.error = Some(err);
+            OCI_ERROR
+        }
+    }
+}
+
+impl<R: Read + Send + Sync> ToSql for Streamed<R> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        self.is_first_piece = true;
+        self.error = None;
+        let ctx = self as *mut Self as *mut c_void;
+        params.bind_piecewise(pos, self.sql_type, ctx, piece_in_callback::<R>, stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature="blocking")]
+    #[test]
+    fn stream_long() -> crate::Result<()> {
+        use crate::*;
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_streamed_long (
+                        id      NUMBER PRIMARY KEY,
+                        text    LONG
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_streamed_long
+                    ';
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        // The text is generated lazily, one line at a time
+        let mut line_num = 0;
+        let lines = std::iter::from_fn(move || {
+            line_num += 1;
+            Some(format!("line {:05}\n", line_num))
+        });
+        let reader = LinesReader { lines, buf: Vec::new(), remaining: 300_000 };
+
+        let stmt = session.prepare("INSERT INTO test_streamed_long (id, text) VALUES (:ID, :TEXT)")?;
+        let num_rows = stmt.execute(((":ID", 1), (":TEXT", Streamed::text(reader))))?;
+        assert_eq!(num_rows, 1);
+
+        let mut stmt = session.prepare("SELECT text FROM test_streamed_long WHERE id = :ID")?;
+        stmt.set_max_long_size(400_000);
+        let row = stmt.query_single(1)?.expect("inserted row");
+        let text : &str = row.get(0)?;
+        assert_eq!(text.len(), 300_000);
+        let expected : String = (1..)
+            .flat_map(|line_num: u32| format!("line {:05}\n", line_num).into_bytes())
+            .take(300_000)
+            .map(char::from)
+            .collect();
+        assert_eq!(text, expected);
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    /// Reader that takes its data from an iterator of lines
+    #[cfg(feature="blocking")]
+    struct LinesReader<I> {
+        lines: I,
+        buf: Vec<u8>,
+        remaining: usize,
+    }
+
+    #[cfg(feature="blocking")]
+    impl<I: Iterator<Item = String>> std::io::Read for LinesReader<I> {
+        fn read(&mut self, out: &mut [u8]) -> std::io::Result<usize> {
+            if self.buf.is_empty() {
+                match self.lines.next() {
+                    Some(line) => self.buf = line.into_bytes(),
+                    None => return Ok(0),
+                }
+            }
+            let len = out.len().min(self.buf.len()).min(self.remaining);
+            out[..len].copy_from_slice(&self.buf[..len]);
+            self.buf.drain(..len);
+            self.remaining -= len;
+            Ok(len)
+        }
+    }
+}
@@ -1,5 +1,8 @@
 //! Abstraction over async task functions
 
+use std::{future::Future, pin::Pin, task::{Context, Poll}, sync::atomic::Ordering};
+use crate::oci::futures::NUM_ACTIVE_ASYNC_DROPS;
+
 #[cfg(not(any(feature="tokio", feature="actix", feature="async-std", feature="async-global")))]
 compile_error!("'nonblocking' requires an async runtime. Select 'tokio', 'actix', 'async-std', or 'async-global'");
 
@@ -20,7 +23,7 @@ mod tokio;
 pub use self::tokio::{spawn, block_on};
 
 #[cfg(feature="tokio")]
-pub(crate) use self::tokio::{execute_blocking, spawn_detached};
+pub(crate) use self::tokio::{execute_blocking, spawn_detached, sleep};
 
 #[cfg(feature="actix")]
 mod actix;
@@ -29,7 +32,7 @@ mod actix;
 pub use self::actix::{spawn, block_on};
 
 #[cfg(feature="actix")]
-pub(crate) use self::actix::{execute_blocking, spawn_detached};
+pub(crate) use self::actix::{execute_blocking, spawn_detached, sleep};
 
 #[cfg(feature="async-std")]
 mod async_std;
@@ -38,7 +41,7 @@ mod async_std;
 pub use self::async_std::{spawn, block_on};
 
 #[cfg(feature="async-std")]
-pub(crate) use self::async_std::{execute_blocking, spawn_detached};
+pub(crate) use self::async_std::{execute_blocking, spawn_detached, sleep};
 
 #[cfg(feature="async-global")]
 mod async_global;
@@ -47,4 +50,48 @@ mod async_global;
 pub use self::async_global::{spawn, block_on};
 
 #[cfg(feature="async-global")]
-pub(crate) use self::async_global::{execute_blocking, spawn_detached};
+pub(crate) use self::async_global::{execute_blocking, spawn_detached, sleep};
+
+/// Future that yields to the executor once
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+/**
+Waits until all OCI handles that are being released asynchronously are freed.
+
+Sessions, statements, cursors and LOBs in `nonblocking` mode release their OCI resources in
+tasks that are spawned when they are dropped. If the async runtime is shut down before these
+tasks complete, OCI handles would be left unreleased or released after the runtime is gone.
+Applications should call this function before they shut down the runtime.
+
+# Example
+
+```
+# sibyl::block_on(async {
+{
+    let session = sibyl::test_env::get_session().await?;
+    session.ping().await?;
+}
+// the session is being released in the background
+sibyl::flush_async_drops().await;
+# Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+```
+*/
+pub async fn flush_async_drops() {
+    while NUM_ACTIVE_ASYNC_DROPS.load(Ordering::Acquire) > 0 {
+        YieldNow(false).await;
+    }
+}
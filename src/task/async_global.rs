@@ -1,10 +1,11 @@
 //! Abstraction over async-global-executor task functions
 
-use std::future::Future;
+use std::{future::Future, time::Duration};
 
 pub use async_global_executor::spawn;
 
 use async_global_executor::spawn_blocking;
+use async_io::Timer;
 use crate::Result;
 
 pub(crate) async fn execute_blocking<F, R>(f: F) -> Result<R> 
@@ -23,6 +24,10 @@ where
     spawn(f).detach()
 }
 
+pub(crate) async fn sleep(duration: Duration) {
+    Timer::after(duration).await;
+}
+
 /// Runs a future on async-global-executor.
 /// 
 /// This function is included to run Sibyl's tests and examples.
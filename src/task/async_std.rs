@@ -1,6 +1,6 @@
 //! Abstraction over async-std task functions
 
-use std::{future::Future, sync::atomic::Ordering};
+use std::{future::Future, sync::atomic::Ordering, time::Duration};
 
 pub use async_rt::task::spawn;
 
@@ -24,6 +24,10 @@ where
     let _ = spawn(f);
 }
 
+pub(crate) async fn sleep(duration: Duration) {
+    task::sleep(duration).await
+}
+
 /// Runs a future on async-std executor.
 /// 
 /// This function ensures that all async drops have run to completion.
@@ -1,10 +1,10 @@
 //! Abstraction over tokio task functions
 
-use std::{future::Future, sync::atomic::Ordering};
+use std::{future::Future, sync::atomic::Ordering, time::Duration};
 
 pub use tokio_rt::task::spawn;
 
-use tokio_rt::{task, runtime};
+use tokio_rt::{task, runtime, time};
 use crate::{Result, Error, oci::futures::NUM_ACTIVE_ASYNC_DROPS};
 
 pub(crate) async fn execute_blocking<F, R>(f: F) -> Result<R>
@@ -26,6 +26,10 @@ where
     let _ = spawn(f);
 }
 
+pub(crate) async fn sleep(duration: Duration) {
+    time::sleep(duration).await
+}
+
 /// Builds a new multi-thread Tokio runtime and runs a future to completion on it.
 /// 
 /// This function ensures that all async drops have run to completion.
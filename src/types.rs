@@ -7,6 +7,13 @@ pub(crate) mod varchar;
 pub(crate) mod timestamp;
 pub(crate) mod interval;
 pub(crate) mod rowid;
+pub(crate) mod object;
+#[cfg(feature="uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature="uuid")))]
+mod uuid;
+#[cfg(feature="chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature="chrono")))]
+mod chrono;
 
 pub use date::Date;
 pub use raw::Raw;
@@ -15,6 +22,7 @@ pub use varchar::Varchar;
 pub use rowid::RowID;
 pub use timestamp::DateTime;
 pub use interval::Interval;
+pub use object::{Object, FromAttr};
 
 use libc::c_void;
 use crate::ToSql;
@@ -0,0 +1,281 @@
+//! Mapping of `chrono` date and time types to and from Oracle date and time data types
+//!
+//! | chrono           | Oracle                   |
+//! |------------------|--------------------------|
+//! | `NaiveDate`      | DATE                     |
+//! | `NaiveDateTime`  | TIMESTAMP                |
+//! | `DateTime<Utc>`  | TIMESTAMP WITH TIME ZONE |
+//! | `Duration`       | INTERVAL DAY TO SECOND   |
+//!
+//! `NaiveDate` is bound as DATE with zero time. When a DATE with non-zero time is read as
+//! a `NaiveDate` the time is truncated. `DateTime<Utc>` values are bound with the `+00:00`
+//! time zone, and timestamps with any time zone are converted to UTC when they are read.
+//!
+//! # Example
+//!
+//! ```
+//! use chrono::{DateTime, TimeZone, Utc};
+//!
+//! # use sibyl::Result;
+//! # #[cfg(feature="blocking")]
+//! # fn main() -> Result<()> {
+//! # let session = sibyl::test_env::get_session()?;
+//! let ts = Utc.with_ymd_and_hms(1969, 7, 20, 20, 17, 40).unwrap();
+//! let stmt = session.prepare("
+//!     SELECT To_Char(:TS, 'YYYY-MM-DD HH24:MI:SS TZH:TZM'), :TS FROM dual
+//! ")?;
+//! let row = stmt.query_single(&ts)?.unwrap();
+//! let txt : &str = row.get(0)?;
+//! assert_eq!(txt, "1969-07-20 20:17:40 +00:00");
+//! let val : DateTime<Utc> = row.get(1)?;
+//! assert_eq!(val, ts);
+//! # Ok(())
+//! # }
+//! # #[cfg(feature="nonblocking")]
+//! # fn main() -> Result<()> {
+//! # sibyl::block_on(async {
+//! # let session = sibyl::test_env::get_session().await?;
+//! # let ts = Utc.with_ymd_and_hms(1969, 7, 20, 20, 17, 40).unwrap();
+//! # let stmt = session.prepare("
+//! #     SELECT To_Char(:TS, 'YYYY-MM-DD HH24:MI:SS TZH:TZM'), :TS FROM dual
+//! # ").await?;
+//! # let row = stmt.query_single(&ts).await?.unwrap();
+//! # let txt : &str = row.get(0)?;
+//! # assert_eq!(txt, "1969-07-20 20:17:40 +00:00");
+//! # let val : DateTime<Utc> = row.get(1)?;
+//! # assert_eq!(val, ts);
+//! # Ok(()) })
+//! # }
+//! ```
+
+use ::chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Timelike, Utc};
+use super::OracleDataType;
+use crate::{oci::{self, *}, Error, Result, ToSql, FromSql, Row, stmt::{Params, Column}};
+use crate::{Date, Timestamp, TimestampTZ, IntervalDS};
+use std::mem::size_of;
+
+const SECS_PER_DAY : i64 = 86_400;
+
+fn oracle_year(year: i32) -> Result<i16> {
+    if (-4712..=9999).contains(&year) {
+        Ok(year as i16)
+    } else {
+        Err(Error::msg(format!("year {} is out of Oracle date range", year)))
+    }
+}
+
+fn to_oci_date(date: &NaiveDate) -> Result<OCIDate> {
+    Ok(OCIDate {
+        year: oracle_year(date.year())?, month: date.month() as u8, day: date.day() as u8,
+        hour: 0, min: 0, sec: 0,
+    })
+}
+
+/// Creates an OCI timestamp descriptor for the date and time in the specified time zone.
+fn to_timestamp<T>(dt: &NaiveDateTime, tz: &str, params: &Params, err: &OCIError) -> Result<Descriptor<T>>
+where T: DescriptorType<OCIType=OCIDateTime>
+{
+    let env = params.env();
+    let mut ts = Descriptor::<T>::new(env)?;
+    oci::date_time_construct(
+        env.get() as _, err, &mut ts,
+        oracle_year(dt.year())?, dt.month() as u8, dt.day() as u8,
+        dt.hour() as u8, dt.minute() as u8, dt.second() as u8,
+        // leap second is represented by nanoseconds over 1e9
+        dt.nanosecond().min(999_999_999),
+        tz.as_ptr(), tz.len()
+    )?;
+    Ok(ts)
+}
+
+/// Creates an OCI INTERVAL DAY TO SECOND descriptor for the duration.
+fn to_interval(duration: &Duration, params: &Params, err: &OCIError) -> Result<Descriptor<OCIIntervalDayToSecond>> {
+    let secs = duration.num_seconds();
+    let nanos = duration.subsec_nanos();
+    let days = secs / SECS_PER_DAY;
+    if days.abs() > 999_999_999 {
+        return Err(Error::msg(format!("{} days are out of INTERVAL DAY TO SECOND range", days)));
+    }
+    // All components have the same sign as the duration
+    let secs = secs % SECS_PER_DAY;
+    let env = params.env();
+    let mut int = Descriptor::<OCIIntervalDayToSecond>::new(env)?;
+    oci::interval_set_day_second(
+        env.get() as _, err,
+        days as i32, (secs / 3600) as i32, (secs % 3600 / 60) as i32, (secs % 60) as i32, nanos,
+        &mut int
+    )?;
+    Ok(int)
+}
+
+/// Binds `chrono` values via their Oracle representations
+trait BindAs {
+    fn bind_as(&self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<()>;
+}
+
+impl BindAs for NaiveDate {
+    fn bind_as(&self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<()> {
+        let date = to_oci_date(self)?;
+        params.bind_in(pos, SQLT_ODT, &date as *const OCIDate as _, size_of::<OCIDate>(), stmt, err)
+    }
+}
+
+impl BindAs for NaiveDateTime {
+    fn bind_as(&self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<()> {
+        let ts = to_timestamp::<OCITimestamp>(self, "", params, err)?;
+        params.bind_descriptor(pos, ts, stmt, err)
+    }
+}
+
+impl BindAs for DateTime<Utc> {
+    fn bind_as(&self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<()> {
+        let ts = to_timestamp::<OCITimestampTZ>(&self.naive_utc(), "+00:00", params, err)?;
+        params.bind_descriptor(pos, ts, stmt, err)
+    }
+}
+
+impl BindAs for Duration {
+    fn bind_as(&self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<()> {
+        let int = to_interval(self, params, err)?;
+        params.bind_descriptor(pos, int, stmt, err)
+    }
+}
+
+macro_rules! impl_chrono_to_sql {
+    ($($t:ty => $sqlt:ident),+) => {
+        $(
+            impl ToSql for $t {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    self.bind_as(pos, params, stmt, err)?;
+                    Ok(pos + 1)
+                }
+            }
+            impl ToSql for &$t {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    (*self).bind_as(pos, params, stmt, err)?;
+                    Ok(pos + 1)
+                }
+            }
+            impl_sql_type!{ $t, &$t => $sqlt }
+            impl OracleDataType for $t {}
+            impl OracleDataType for &$t {}
+        )+
+    };
+}
+
+impl_chrono_to_sql! {
+    NaiveDate       => SQLT_ODT,
+    NaiveDateTime   => SQLT_TIMESTAMP,
+    DateTime<Utc>   => SQLT_TIMESTAMP_TZ,
+    Duration        => SQLT_INTERVAL_DS
+}
+
+fn to_naive_date(year: i16, month: u8, day: u8) -> Result<NaiveDate> {
+    NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)
+        .ok_or_else(|| Error::msg(format!("cannot return {}-{}-{} as NaiveDate", year, month, day)))
+}
+
+impl<'a> FromSql<'a> for NaiveDate {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let date : Date = FromSql::value(row, col)?;
+        let (year, month, day) = date.date();
+        to_naive_date(year, month, day)
+    }
+}
+
+impl<'a> FromSql<'a> for NaiveDateTime {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let ts : Timestamp = FromSql::value(row, col)?;
+        let (year, month, day, hour, min, sec, nanos) = ts.date_and_time()?;
+        to_naive_date(year, month, day)?
+            .and_hms_nano_opt(hour as u32, min as u32, sec as u32, nanos)
+            .ok_or_else(|| Error::new("cannot return as NaiveDateTime"))
+    }
+}
+
+impl<'a> FromSql<'a> for DateTime<Utc> {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let ts : TimestampTZ = FromSql::value(row, col)?;
+        let nanos = ts.to_unix_nanos()?;
+        let secs = nanos.div_euclid(1_000_000_000) as i64;
+        let nanos = nanos.rem_euclid(1_000_000_000) as u32;
+        DateTime::from_timestamp(secs, nanos).ok_or_else(|| Error::new("cannot return as DateTime<Utc>"))
+    }
+}
+
+impl<'a> FromSql<'a> for Duration {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let int : IntervalDS = FromSql::value(row, col)?;
+        let (days, hours, mins, secs, nanos) = int.duration()?;
+        let secs = ((days as i64 * 24 + hours as i64) * 60 + mins as i64) * 60 + secs as i64;
+        Ok(Duration::seconds(secs) + Duration::nanoseconds(nanos as i64))
+    }
+}
+
+#[cfg(all(test,feature="blocking"))]
+mod tests {
+    use ::chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+    use crate::*;
+
+    #[test]
+    fn chrono_binds() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_chrono_values (
+                        id      NUMBER PRIMARY KEY,
+                        ts_tz   TIMESTAMP(9) WITH TIME ZONE,
+                        ts      TIMESTAMP(9),
+                        dt      DATE,
+                        dur     INTERVAL DAY(3) TO SECOND(9)
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_chrono_values
+                    ';
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let ts_tz = Utc.with_ymd_and_hms(1969, 7, 20, 20, 17, 40).unwrap() + Duration::nanoseconds(123_456_789);
+        let ts = NaiveDate::from_ymd_opt(1969, 7, 21).unwrap().and_hms_nano_opt(2, 56, 15, 987_654_321).unwrap();
+        let dt = NaiveDate::from_ymd_opt(1969, 7, 24).unwrap();
+        let dur = -(Duration::days(8) + Duration::seconds(11_915) + Duration::nanoseconds(5));
+
+        let stmt = session.prepare("
+            INSERT INTO test_chrono_values (id, ts_tz, ts, dt, dur) VALUES (:ID, :TS_TZ, :TS, :DT, :DUR)
+        ")?;
+        stmt.execute(((":ID", 1), (":TS_TZ", &ts_tz), (":TS", &ts), (":DT", &dt), (":DUR", &dur)))?;
+        let no_ts_tz : Option<DateTime<Utc>> = None;
+        stmt.execute(((":ID", 2), (":TS_TZ", no_ts_tz), (":TS", ts), (":DT", dt), (":DUR", dur)))?;
+
+        let stmt = session.prepare("
+            SELECT ts_tz, To_Char(ts_tz, 'TZH:TZM'), ts, dt, dur FROM test_chrono_values WHERE id = :ID
+        ")?;
+        let row = stmt.query_single(1)?.unwrap();
+        let val : DateTime<Utc> = row.get(0)?;
+        assert_eq!(val, ts_tz);
+        let tz : &str = row.get(1)?;
+        assert_eq!(tz, "+00:00");
+        let val : NaiveDateTime = row.get(2)?;
+        assert_eq!(val, ts);
+        let val : NaiveDate = row.get(3)?;
+        assert_eq!(val, dt);
+        let val : Duration = row.get(4)?;
+        assert_eq!(val, dur);
+
+        let row = stmt.query_single(2)?.unwrap();
+        let val : Option<DateTime<Utc>> = row.get(0)?;
+        assert!(val.is_none());
+        let val : NaiveDateTime = row.get(2)?;
+        assert_eq!(val, ts);
+
+        session.rollback()?;
+        Ok(())
+    }
+}
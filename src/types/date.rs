@@ -3,7 +3,7 @@
 mod tosql;
 
 use crate::{ Result, oci::{self, *} };
-use std::{ mem, cmp::Ordering };
+use std::{ mem, cmp::Ordering, hash::{Hash, Hasher} };
 
 /// Returns a stub date to be used as a row's column buffer or an output variable
 pub(crate) fn new() -> OCIDate {
@@ -53,7 +53,15 @@ pub(crate) fn from_date<'a>(from: &OCIDate, err: &'a OCIError) -> Result<Date<'a
 //     }
 // }
 
-/// Represents Oracle DATE
+/**
+    Represents Oracle DATE
+
+    Dates can be compared with the comparison operators and sorted. These use [`Date::compare()`]
+    internally and thus panic if OCI reports an error. Use `compare()` directly when this is
+    not acceptable.
+
+    Dates also implement `Hash`, and thus can be used as `HashMap` and `HashSet` keys.
+*/
 pub struct Date<'a> {
     date: OCIDate,
     err:  &'a OCIError,
@@ -482,6 +490,33 @@ impl<'a> Date<'a> {
     }
 }
 
+impl PartialEq for Date<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Date<'_> {}
+
+impl PartialOrd for Date<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Date<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare(other).expect("comparable dates")
+    }
+}
+
+impl Hash for Date<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let OCIDate { year, month, day, hour, min, sec } = self.date;
+        (year, month, day, hour, min, sec).hash(state);
+    }
+}
+
 impl std::fmt::Debug for Date<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.date.fmt(f)
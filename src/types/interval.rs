@@ -3,9 +3,9 @@
 mod tosql;
 
 use super::{Ctx, Number};
-use crate::{Result, oci::{self, *}};
+use crate::{Error, Result, oci::{self, *}};
 use libc::size_t;
-use std::{mem, cmp::Ordering, ops::{Deref, DerefMut}};
+use std::{mem, cmp::Ordering, convert::TryFrom, ops::{Deref, DerefMut}};
 
 pub(crate) fn to_string(int: &OCIInterval, lfprec: u8, fsprec: u8, ctx: &dyn Ctx) -> Result<String> {
     let name = mem::MaybeUninit::<[u8;32]>::uninit();
@@ -31,7 +31,97 @@ pub(crate) fn from_interval<'a,T>(int: &Descriptor<T>, ctx: &'a dyn Ctx) -> Resu
     Ok( Interval { ctx, interval } )
 }
 
-/// Represents interval data types
+/// Components of an ISO-8601 duration
+#[derive(Default)]
+struct IsoDuration {
+    negative: bool,
+    years:    i64,
+    months:   i64,
+    weeks:    i64,
+    days:     i64,
+    hours:    i64,
+    minutes:  i64,
+    seconds:  i64,
+    nanos:    i64,
+}
+
+/**
+Parses ISO-8601 duration - `[-]PnYnMnWnDTnHnMn.nS` - into its components.
+Designators that are not needed can be omitted, but those that are present must follow in the order above.
+Only seconds can have a fraction.
+*/
+fn parse_iso8601(txt: &str) -> Result<IsoDuration> {
+    let invalid = || Error::msg(format!("invalid ISO-8601 duration '{}'", txt));
+    let mut dur = IsoDuration::default();
+    let rest = match txt.strip_prefix('-') {
+        Some(rest) => { dur.negative = true; rest },
+        None => txt.strip_prefix('+').unwrap_or(txt)
+    };
+    let mut rest = rest.strip_prefix('P').ok_or_else(invalid)?;
+    let mut in_time = false;
+    let mut last_field = 0;
+    while !rest.is_empty() {
+        if let Some(time) = rest.strip_prefix('T') {
+            if in_time || time.is_empty() {
+                return Err(invalid());
+            }
+            in_time = true;
+            rest = time;
+            continue;
+        }
+        let num_len = rest.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ',')).ok_or_else(invalid)?;
+        let (num, tail) = rest.split_at(num_len);
+        let field = match (in_time, tail.as_bytes()[0]) {
+            (false, b'Y') => 1,
+            (false, b'M') => 2,
+            (false, b'W') => 3,
+            (false, b'D') => 4,
+            (true,  b'H') => 5,
+            (true,  b'M') => 6,
+            (true,  b'S') => 7,
+            _ => return Err(invalid())
+        };
+        if field <= last_field || num.is_empty() {
+            return Err(invalid());
+        }
+        last_field = field;
+        rest = &tail[1..];
+
+        let (int, frac) = match num.find(['.', ',']) {
+            Some(pos) => (&num[..pos], Some(&num[pos + 1..])),
+            None => (num, None)
+        };
+        let value : i64 = int.parse().map_err(|_| invalid())?;
+        match field {
+            1 => dur.years   = value,
+            2 => dur.months  = value,
+            3 => dur.weeks   = value,
+            4 => dur.days    = value,
+            5 => dur.hours   = value,
+            6 => dur.minutes = value,
+            _ => dur.seconds = value,
+        }
+        if let Some(frac) = frac {
+            if field != 7 || frac.is_empty() || frac.len() > 9 {
+                return Err(invalid());
+            }
+            let frac_value : i64 = frac.parse().map_err(|_| invalid())?;
+            dur.nanos = frac_value * 10i64.pow(9 - frac.len() as u32);
+        }
+    }
+    if last_field == 0 {
+        return Err(invalid());
+    }
+    Ok(dur)
+}
+
+/**
+    Represents interval data types
+
+    Intervals of the same type can be compared with the comparison operators and sorted.
+    These use [`Interval::compare()`] internally and thus panic if OCI reports an error.
+    Use `compare()` directly when this is not acceptable.
+*/
 pub struct Interval<'a, T> where T: DescriptorType<OCIType=OCIInterval> {
     interval: Descriptor<T>,
     ctx: &'a dyn Ctx,
@@ -358,6 +448,45 @@ impl<'a> Interval<'a, OCIIntervalDayToSecond> {
         Ok( Self { ctx, interval } )
     }
 
+    /**
+        Returns new interval with the duration specified by the ISO-8601 duration string.
+
+        The duration can have weeks, days, hours, minutes and seconds (with an optional fraction).
+        The duration may be preceded by a minus sign to represent a negative interval.
+
+        # Failures
+
+        - If the string is not a valid ISO-8601 duration.
+        - If the duration has years or months, which cannot be represented by an `INTERVAL DAY TO SECOND`.
+
+        # Example
+        ```
+        use sibyl::{ self as oracle, IntervalDS };
+        let env = oracle::env()?;
+
+        let int = IntervalDS::from_iso8601("PT1H30M", &env)?;
+        assert_eq!(int.duration()?, (0, 1, 30, 0, 0));
+
+        let int = IntervalDS::from_iso8601("-P1DT36H0.25S", &env)?;
+        assert_eq!(int.duration()?, (-2, -12, 0, 0, -250_000_000));
+        # Ok::<(),oracle::Error>(())
+        ```
+    */
+    pub fn from_iso8601(txt: &str, ctx: &'a dyn Ctx) -> Result<Self> {
+        let dur = parse_iso8601(txt)?;
+        if dur.years != 0 || dur.months != 0 {
+            return Err(Error::msg(format!("'{}' cannot be represented as INTERVAL DAY TO SECOND", txt)));
+        }
+        let secs = ((((dur.weeks as i128 * 7 + dur.days as i128) * 24 + dur.hours as i128) * 60) + dur.minutes as i128) * 60 + dur.seconds as i128;
+        let days = i32::try_from(secs / 86_400).map_err(|_| Error::msg(format!("'{}' is out of range", txt)))?;
+        let secs = (secs % 86_400) as i32;
+        let sign = if dur.negative { -1 } else { 1 };
+        Self::with_duration(
+            sign * days, sign * (secs / 3600), sign * (secs / 60 % 60), sign * (secs % 60), sign * dur.nanos as i32,
+            ctx
+        )
+    }
+
     /**
         Gets values of day, hour, minute, second, and nanoseconds from an interval.
 
@@ -429,6 +558,42 @@ impl<'a> Interval<'a, OCIIntervalYearToMonth> {
         Ok( Self { ctx, interval } )
     }
 
+    /**
+        Returns new interval with the duration specified by the ISO-8601 duration string.
+
+        The duration can have years and months. The duration may be preceded by a minus sign
+        to represent a negative interval.
+
+        # Failures
+
+        - If the string is not a valid ISO-8601 duration.
+        - If the duration has weeks, days or time, which cannot be represented by an `INTERVAL YEAR TO MONTH`.
+
+        # Example
+        ```
+        use sibyl::{ self as oracle, IntervalYM };
+        let env = oracle::env()?;
+
+        let int = IntervalYM::from_iso8601("P1Y2M", &env)?;
+        assert_eq!(int.duration()?, (1, 2));
+
+        let int = IntervalYM::from_iso8601("-P18M", &env)?;
+        assert_eq!(int.duration()?, (-1, -6));
+        # Ok::<(),oracle::Error>(())
+        ```
+    */
+    pub fn from_iso8601(txt: &str, ctx: &'a dyn Ctx) -> Result<Self> {
+        let dur = parse_iso8601(txt)?;
+        if dur.weeks != 0 || dur.days != 0 || dur.hours != 0 || dur.minutes != 0 || dur.seconds != 0 || dur.nanos != 0 {
+            return Err(Error::msg(format!("'{}' cannot be represented as INTERVAL YEAR TO MONTH", txt)));
+        }
+        let months = dur.years as i128 * 12 + dur.months as i128;
+        let years = i32::try_from(months / 12).map_err(|_| Error::msg(format!("'{}' is out of range", txt)))?;
+        let months = (months % 12) as i32;
+        let sign = if dur.negative { -1 } else { 1 };
+        Self::with_duration(sign * years, sign * months, ctx)
+    }
+
     /**
         Gets values of year and month from an interval.
 
@@ -472,6 +637,26 @@ impl<'a> Interval<'a, OCIIntervalYearToMonth> {
     }
 }
 
+impl<T> PartialEq for Interval<'_, T> where T: DescriptorType<OCIType=OCIInterval> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Interval<'_, T> where T: DescriptorType<OCIType=OCIInterval> {}
+
+impl<T> PartialOrd for Interval<'_, T> where T: DescriptorType<OCIType=OCIInterval> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Interval<'_, T> where T: DescriptorType<OCIType=OCIInterval> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare(other).expect("comparable intervals")
+    }
+}
+
 impl std::fmt::Debug for Interval<'_, OCIIntervalDayToSecond> {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.duration() {
@@ -5,11 +5,11 @@ mod convert;
 mod tosql;
 
 pub use self::convert::Integer;
-pub(crate) use self::convert::{Real, from_number, to_string, to_real};
+pub(crate) use self::convert::{Real, IntoNumber, from_number, to_string, to_real, to_i64, to_u64, to_i128};
 
 use super::{Ctx, interval::Interval};
 use crate::{Result, oci::{self, *}};
-use std::{cmp::Ordering, mem, ops::{Deref, DerefMut}};
+use std::{cmp::Ordering, hash::{Hash, Hasher}, mem, ops::{Deref, DerefMut}};
 
 /**
     Creates an uninitialized OCI number. This simplified version of `u128_into_number`
@@ -85,7 +85,17 @@ macro_rules! impl_opi {
     }};
 }
 
-/// Represents OTS types NUMBER, NUMERIC, INT, SHORTINT, REAL, DOUBLE PRECISION, FLOAT and DECIMAL.
+/**
+    Represents OTS types NUMBER, NUMERIC, INT, SHORTINT, REAL, DOUBLE PRECISION, FLOAT and DECIMAL.
+
+    Numbers can be compared with the comparison operators and sorted. These use [`Number::compare()`]
+    internally and thus panic if OCI reports an error. Use `compare()` directly when this is
+    not acceptable.
+
+    Numbers also implement `Hash`, and thus can be used as `HashMap` and `HashSet` keys.
+    The hash is computed from the Oracle's internal NUMBER representation, which is canonical -
+    equal numbers have the same bytes.
+*/
 pub struct Number<'a> {
     ctx: &'a dyn Ctx,
     num: OCINumber,
@@ -237,6 +247,11 @@ impl<'a> Number<'a> {
     /**
         Creates a new Number from a floating point number.
 
+        # Failures
+
+        Returns an error if `val` is NaN or infinite as NUMBER cannot represent those.
+        Use `BINARY_FLOAT` and `BINARY_DOUBLE` columns to store them.
+
         # Example
         ```
         use sibyl::{ self as oracle, Number };
@@ -245,6 +260,8 @@ impl<'a> Number<'a> {
         let num = Number::from_real(2.7182818284590452353602874713527, &env)?;
 
         assert_eq!(num.to_string("TM")?, "2.71828182845905");
+
+        assert!(Number::from_real(f64::NAN, &env).is_err());
         # Ok::<(),oracle::Error>(())
         ```
     */
@@ -332,6 +349,73 @@ impl<'a> Number<'a> {
         <T>::from_number(&self.num, self.ctx.as_ref())
     }
 
+    /**
+        Returns the value of self as `i64`. Unlike `to_int` this method does not round
+        the value. Instead it fails if the number is not an integer or if it is out of
+        the `i64` range.
+
+        # Example
+        ```
+        use sibyl::{ self as oracle, Number };
+        let env = oracle::env()?;
+
+        let num = Number::from_int(-4_611_686_018_427_387_904i64, &env)?;
+        assert_eq!(num.to_i64()?, -4_611_686_018_427_387_904);
+
+        let num = Number::pi(&env);
+        assert!(num.to_i64().is_err());
+
+        let num = Number::from_int(u64::MAX, &env)?;
+        assert!(num.to_i64().is_err());
+        # Ok::<(),oracle::Error>(())
+        ```
+    */
+    pub fn to_i64(&self) -> Result<i64> {
+        to_i64(&self.num, self.ctx.as_ref())
+    }
+
+    /**
+        Returns the value of self as `u64`. Fails if the number is not an integer
+        or if it is out of the `u64` range.
+
+        # Example
+        ```
+        use sibyl::{ self as oracle, Number };
+        let env = oracle::env()?;
+
+        let num = Number::from_int(u64::MAX, &env)?;
+        assert_eq!(num.to_u64()?, u64::MAX);
+
+        let num = Number::from_int(-1, &env)?;
+        assert!(num.to_u64().is_err());
+        # Ok::<(),oracle::Error>(())
+        ```
+    */
+    pub fn to_u64(&self) -> Result<u64> {
+        to_u64(&self.num, self.ctx.as_ref())
+    }
+
+    /**
+        Returns the value of self as `i128`. Fails if the number is not an integer
+        or if it is out of the `i128` range.
+
+        # Example
+        ```
+        use sibyl::{ self as oracle, Number };
+        let env = oracle::env()?;
+
+        let num = Number::from_int(i128::MIN, &env)?;
+        assert_eq!(num.to_i128()?, i128::MIN);
+
+        let num = Number::from_int(u128::MAX, &env)?;
+        assert!(num.to_i128().is_err());
+        # Ok::<(),oracle::Error>(())
+        ```
+    */
+    pub fn to_i128(&self) -> Result<i128> {
+        to_i128(&self.num, self.ctx.as_ref())
+    }
+
     /**
         Returns floating point representation of self
 
@@ -1100,6 +1184,34 @@ impl<'a> Number<'a> {
     }
 }
 
+impl PartialEq for Number<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Number<'_> {}
+
+impl PartialOrd for Number<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Number<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare(other).expect("comparable numbers")
+    }
+}
+
+impl Hash for Number<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // the first byte is the length of the number
+        let len = (self.num.bytes[0] as usize).min(self.num.bytes.len() - 1);
+        self.num.bytes[..=len].hash(state);
+    }
+}
+
 impl std::fmt::Debug for Number<'_> {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.to_string("TM") {
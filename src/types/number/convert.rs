@@ -151,20 +151,23 @@ impl IntoNumber for u128 {
 }
 
 macro_rules! impl_int_into_num {
-    ($($t:ty),+ => $dt:ty) => {
+    ($($t:ty),+ => $sign:ident) => {
         $(
             impl IntoNumber for $t {
                 fn into_number(self, err: &OCIError) -> Result<OCINumber> {
-                    let val = self as $dt;
-                    val.into_number(err)
+                    let mut num = mem::MaybeUninit::<OCINumber>::uninit();
+                    oci::number_from_int(err, &self as *const $t as *const c_void, mem::size_of::<$t>() as u32, $sign, num.as_mut_ptr())?;
+                    Ok(unsafe { num.assume_init() })
                 }
             }
         )+
     };
 }
 
-impl_int_into_num!(i8, i16, i32, i64, isize => i128);
-impl_int_into_num!(u8, u16, u32, u64, usize => u128);
+// OCI converts integers up to 64 bits. 128-bit integers are converted by `i128_into_number`
+// and `u128_into_number`.
+impl_int_into_num!(i8, i16, i32, i64, isize => OCI_NUMBER_SIGNED);
+impl_int_into_num!(u8, u16, u32, u64, usize => OCI_NUMBER_UNSIGNED);
 
 /// Trait for types that can be created from `OCINumber`
 pub trait FromNumber : Sized + Copy {
@@ -207,12 +210,18 @@ impl_int_from_num!(u8, u16, u32, u64, usize => u128, u128_from_number);
 
 impl IntoNumber for f64 {
     fn into_number(self, err: &OCIError) -> Result<OCINumber> {
+        if !self.is_finite() {
+            return Err( Error::msg(format!("{} cannot be represented as NUMBER", self)) );
+        }
         real_into_number(self, err)
     }
 }
 
 impl IntoNumber for f32 {
     fn into_number(self, err: &OCIError) -> Result<OCINumber> {
+        if !self.is_finite() {
+            return Err( Error::msg(format!("{} cannot be represented as NUMBER", self)) );
+        }
         real_into_number(self, err)
     }
 }
@@ -230,10 +239,17 @@ impl FromNumber for f32 {
 }
 
 /// Marker trait for integer numbers
-pub trait Integer: IntoNumber + FromNumber {}
+pub trait Integer: IntoNumber + FromNumber {
+    /// The smallest value that can be represented by this integer type
+    #[doc(hidden)]
+    const MIN: Self;
+    /// The largest value that can be represented by this integer type
+    #[doc(hidden)]
+    const MAX: Self;
+}
 
 macro_rules! impl_int {
-    ($($t:ty),+) => { $( impl Integer for $t {} )+ };
+    ($($t:ty),+) => { $( impl Integer for $t { const MIN: Self = <$t>::MIN; const MAX: Self = <$t>::MAX; } )+ };
 }
 
 impl_int!(i8, i16, i32, i64, i128, isize);
@@ -256,6 +272,49 @@ pub(crate) fn to_real<T: Real>(num: &OCINumber, err: &OCIError) -> Result<T> {
     Ok(unsafe { res.assume_init() })
 }
 
+/// Returns an error if the number has a fractional part.
+fn ensure_int(num: &OCINumber, err: &OCIError) -> Result<()> {
+    let mut is_int = 0i32;
+    oci::number_is_int(err, num, &mut is_int)?;
+    if is_int == 0 {
+        Err( Error::new("number is not an integer") )
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn to_i64(num: &OCINumber, err: &OCIError) -> Result<i64> {
+    ensure_int(num, err)?;
+    let mut val = 0i64;
+    oci::number_to_int(err, num, mem::size_of::<i64>() as u32, OCI_NUMBER_SIGNED, &mut val as *mut i64 as *mut c_void)?;
+    Ok(val)
+}
+
+pub(crate) fn to_u64(num: &OCINumber, err: &OCIError) -> Result<u64> {
+    ensure_int(num, err)?;
+    let mut val = 0u64;
+    oci::number_to_int(err, num, mem::size_of::<u64>() as u32, OCI_NUMBER_UNSIGNED, &mut val as *mut u64 as *mut c_void)?;
+    Ok(val)
+}
+
+pub(crate) fn to_i128(num: &OCINumber, err: &OCIError) -> Result<i128> {
+    ensure_int(num, err)?;
+    let mut cmp = 0i32;
+    oci::number_cmp(err, num, &i128_into_number(i128::MAX), &mut cmp)?;
+    if cmp > 0 {
+        return Err( Error::new("overflow") );
+    }
+    oci::number_cmp(err, num, &i128_into_number(i128::MIN), &mut cmp)?;
+    if cmp < 0 {
+        Err( Error::new("overflow") )
+    } else if cmp == 0 {
+        // its magnitude does not fit into i128 before it is negated
+        Ok( i128::MIN )
+    } else {
+        i128_from_number(num)
+    }
+}
+
 pub(crate) fn to_string(fmt: &str, num: &OCINumber, err: &OCIError) -> Result<String> {
     let txt = mem::MaybeUninit::<[u8;64]>::uninit();
     let mut txt = unsafe { txt.assume_init() };
@@ -337,4 +396,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn num_to_exact_int() -> Result<()> {
+        let env = env()?;
+
+        let num = Number::from_int(i64::MAX, &env)?;
+        assert_eq!(num.to_i64()?, i64::MAX);
+        let num = Number::from_int(i64::MIN, &env)?;
+        assert_eq!(num.to_i64()?, i64::MIN);
+        let num = Number::from_int(u64::MAX, &env)?;
+        assert_eq!(num.to_u64()?, u64::MAX);
+        assert!(num.to_i64().is_err());
+        let num = Number::from_int(-1, &env)?;
+        assert_eq!(num.to_i64()?, -1);
+        assert!(num.to_u64().is_err());
+
+        let num = Number::from_int(i128::MAX, &env)?;
+        assert_eq!(num.to_i128()?, i128::MAX);
+        assert!(num.to_u64().is_err());
+        let num = Number::from_int(i128::MIN, &env)?;
+        assert_eq!(num.to_i128()?, i128::MIN);
+        let num = Number::from_int(u128::MAX, &env)?;
+        assert!(num.to_i128().is_err());
+        let one = Number::from_int(1, &env)?;
+        let num = Number::from_int(i128::MIN, &env)?.sub(&one)?;
+        assert!(num.to_i128().is_err());
+
+        let num = Number::pi(&env);
+        assert!(num.to_i64().is_err());
+        assert!(num.to_u64().is_err());
+        assert!(num.to_i128().is_err());
+        let num = Number::from_real(2.5, &env)?;
+        assert!(num.to_i64().is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,475 @@
+//! Access to the attributes of the fetched named object type (`CREATE TYPE ... AS OBJECT`) values
+
+use libc::c_void;
+use crate::{Error, Result, oci::{self, *}, types::{Ctx, Date, Number, date, number, varchar}};
+
+/**
+Attribute of a fetched object.
+
+This is an internal type. It is only visible because it is a part of the [`FromAttr`] trait.
+*/
+pub struct Attr<'a, 'n> {
+    name: &'n str,
+    typecode: u16,
+    value: *const c_void,
+    is_null: bool,
+    ctx: &'a dyn Ctx,
+}
+
+impl<'a, 'n> Attr<'a, 'n> {
+    fn assert_not_null(&self) -> Result<()> {
+        if self.is_null {
+            Err(Error::msg(format!("attribute {} is null", self.name)))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn number(&self) -> Result<&'a OCINumber> {
+        self.assert_not_null()?;
+        match self.typecode {
+            OCI_TYPECODE_NUMBER | OCI_TYPECODE_INTEGER | OCI_TYPECODE_SMALLINT | OCI_TYPECODE_DECIMAL |
+            OCI_TYPECODE_FLOAT | OCI_TYPECODE_REAL | OCI_TYPECODE_DOUBLE => {
+                Ok(unsafe { &*(self.value as *const OCINumber) })
+            }
+            _ => Err(Error::msg(format!("attribute {} is not a number", self.name)))
+        }
+    }
+
+    fn text(&self) -> Result<Option<&'a OCIString>> {
+        self.assert_not_null()?;
+        match self.typecode {
+            OCI_TYPECODE_VARCHAR | OCI_TYPECODE_VARCHAR2 | OCI_TYPECODE_CHAR |
+            OCI_TYPECODE_NCHAR | OCI_TYPECODE_NVARCHAR2 => {
+                // Text attributes are stored in the object as pointers to OCIString
+                Ok(unsafe { (*(self.value as *const *const OCIString)).as_ref() })
+            }
+            _ => Ok(None)
+        }
+    }
+
+    fn date(&self) -> Result<&'a OCIDate> {
+        self.assert_not_null()?;
+        if self.typecode == OCI_TYPECODE_DATE {
+            Ok(unsafe { &*(self.value as *const OCIDate) })
+        } else {
+            Err(Error::msg(format!("attribute {} is not a date", self.name)))
+        }
+    }
+}
+
+/// A trait for types which values can be created from the attributes of the fetched objects.
+pub trait FromAttr<'a> : Sized {
+    /// Converts, if possible, the attribute value into the requested type.
+    #[doc(hidden)]
+    fn value(attr: &Attr<'a, '_>) -> Result<Self>;
+}
+
+impl<'a> FromAttr<'a> for Number<'a> {
+    fn value(attr: &Attr<'a, '_>) -> Result<Self> {
+        Number::from(attr.number()?, attr.ctx)
+    }
+}
+
+impl<'a, T: number::Integer> FromAttr<'a> for T {
+    fn value(attr: &Attr<'a, '_>) -> Result<Self> {
+        <T>::from_number(attr.number()?, attr.ctx.as_ref())
+    }
+}
+
+impl<'a> FromAttr<'a> for f64 {
+    fn value(attr: &Attr<'a, '_>) -> Result<Self> {
+        number::to_real(attr.number()?, attr.ctx.as_ref())
+    }
+}
+
+impl<'a> FromAttr<'a> for f32 {
+    fn value(attr: &Attr<'a, '_>) -> Result<Self> {
+        number::to_real(attr.number()?, attr.ctx.as_ref())
+    }
+}
+
+impl<'a> FromAttr<'a> for &'a str {
+    fn value(attr: &Attr<'a, '_>) -> Result<Self> {
+        match attr.text()? {
+            Some(txt) => Ok(varchar::as_str(txt, attr.ctx.as_ref())),
+            None => Err(Error::msg(format!("cannot borrow attribute {} as &str", attr.name)))
+        }
+    }
+}
+
+impl<'a> FromAttr<'a> for String {
+    fn value(attr: &Attr<'a, '_>) -> Result<Self> {
+        if let Some(txt) = attr.text()? {
+            return Ok(varchar::to_string(txt, attr.ctx.as_ref()));
+        }
+        match attr.typecode {
+            OCI_TYPECODE_DATE => date::to_string("YYYY-MM-DD HH24:MI:SS", attr.date()?, attr.ctx.as_ref()),
+            _ => number::to_string("TM", attr.number()?, attr.ctx.as_ref()),
+        }
+    }
+}
+
+impl<'a> FromAttr<'a> for Date<'a> {
+    fn value(attr: &Attr<'a, '_>) -> Result<Self> {
+        date::from_date(attr.date()?, attr.ctx.as_ref())
+    }
+}
+
+impl<'a, T: FromAttr<'a>> FromAttr<'a> for Option<T> {
+    fn value(attr: &Attr<'a, '_>) -> Result<Self> {
+        if attr.is_null {
+            Ok(None)
+        } else {
+            let val : T = FromAttr::value(attr)?;
+            Ok(Some(val))
+        }
+    }
+}
+
+/**
+Value of a user-defined object type (`CREATE TYPE ... AS OBJECT`) that was fetched from
+a table or a view column.
+
+Only flat objects - the ones with scalar `NUMBER`, `VARCHAR2`/`CHAR` and `DATE` attributes -
+are supported. `Object` is a view into the object that OCI keeps in the object cache. It
+borrows from the row it was fetched with.
+
+# Example
+
+🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+```
+use sibyl::{Object, Number};
+
+# use sibyl::Result;
+# #[cfg(feature="blocking")]
+# fn main() -> Result<()> {
+# let session = sibyl::test_env::get_session()?;
+let stmt = session.prepare("
+    DECLARE
+        name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+    BEGIN
+        EXECUTE IMMEDIATE 'CREATE TYPE test_doc_point AS OBJECT (x NUMBER, y NUMBER, label VARCHAR2(30))';
+    EXCEPTION
+        WHEN name_already_used THEN NULL;
+    END;
+")?;
+stmt.execute(())?;
+
+let stmt = session.prepare("SELECT test_doc_point(3, 4, 'Pt') FROM dual")?;
+let row = stmt.query_single(())?.expect("a single row");
+let point : Object = row.get(0)?;
+let x : Number = point.get("X")?;
+let y : i32 = point.get("Y")?;
+let label : &str = point.get("LABEL")?;
+assert_eq!(x.to_int::<i32>()?, 3);
+assert_eq!(y, 4);
+assert_eq!(label, "Pt");
+# Ok(())
+# }
+# #[cfg(feature="nonblocking")]
+# fn main() -> Result<()> {
+# sibyl::block_on(async {
+# let session = sibyl::test_env::get_session().await?;
+# let stmt = session.prepare("
+#     DECLARE
+#         name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+#     BEGIN
+#         EXECUTE IMMEDIATE 'CREATE TYPE test_doc_point AS OBJECT (x NUMBER, y NUMBER, label VARCHAR2(30))';
+#     EXCEPTION
+#         WHEN name_already_used THEN NULL;
+#     END;
+# ").await?;
+# stmt.execute(()).await?;
+# let stmt = session.prepare("SELECT test_doc_point(3, 4, 'Pt') FROM dual").await?;
+# let row = stmt.query_single(()).await?.expect("a single row");
+# let point : Object = row.get(0)?;
+# let x : Number = point.get("X")?;
+# let y : i32 = point.get("Y")?;
+# let label : &str = point.get("LABEL")?;
+# assert_eq!(x.to_int::<i32>()?, 3);
+# assert_eq!(y, 4);
+# assert_eq!(label, "Pt");
+# Ok(()) })
+# }
+```
+*/
+pub struct Object<'a> {
+    obj: Ptr<c_void>,
+    ind: Ptr<c_void>,
+    tdo: Ptr<OCIType>,
+    ctx: &'a dyn Ctx,
+}
+
+impl<'a> Object<'a> {
+    pub(crate) fn new(obj: Ptr<c_void>, ind: Ptr<c_void>, tdo: Ptr<OCIType>, ctx: &'a dyn Ctx) -> Self {
+        Self { obj, ind, tdo, ctx }
+    }
+
+    fn attr<'n>(&self, name: &'n str) -> Result<Attr<'a, 'n>> {
+        let mut null_status = OCI_IND_NOTNULL;
+        let mut value = std::ptr::null_mut::<c_void>();
+        let mut attr_tdo = Ptr::<OCIType>::null();
+        oci::object_get_attr(
+            self.ctx.as_ref(), self.ctx.as_ref(), self.obj.get(), self.ind.get(), self.tdo.as_ref(), name,
+            &mut null_status, &mut value, attr_tdo.as_mut_ptr()
+        )?;
+        let typecode = if attr_tdo.is_null() { 0 } else { oci::type_code(self.ctx.as_ref(), self.ctx.as_ref(), attr_tdo.as_ref()) };
+        Ok(Attr { name, typecode, value, is_null: null_status == OCI_IND_NULL || value.is_null(), ctx: self.ctx })
+    }
+
+    /**
+    Returns the value of the named attribute.
+
+    # Parameters
+
+    * `name` - Attribute name as it is stored in the data dictionary, which is usually
+      in uppercase unless the attribute was declared as a quoted identifier.
+
+    # Failures
+
+    - The object type does not have the attribute
+    - The attribute is null and the requested type is not an `Option`
+    - The attribute value cannot be converted into the requested type
+
+    # Example
+
+    See [`Object`]
+    */
+    pub fn get<T: FromAttr<'a>>(&self, name: &str) -> Result<T> {
+        let attr = self.attr(name)?;
+        FromAttr::value(&attr)
+    }
+
+    /**
+    Returns `true` if the named attribute is null.
+
+    # Failures
+
+    - The object type does not have the attribute
+    */
+    pub fn is_attr_null(&self, name: &str) -> Result<bool> {
+        let attr = self.attr(name)?;
+        Ok(attr.is_null)
+    }
+}
+
+/// Collects elements of the fetched VARRAY or nested table into a vector.
+pub(crate) fn collection_to_vec<'a, T: FromAttr<'a>>(coll: Ptr<c_void>, tdo: Ptr<OCIType>, ctx: &'a dyn Ctx) -> Result<Vec<T>> {
+    let env : &OCIEnv = ctx.as_ref();
+    let err : &OCIError = ctx.as_ref();
+    if oci::type_code(env, err, tdo.as_ref()) != OCI_TYPECODE_NAMEDCOLLECTION {
+        return Err(Error::new("not a collection"));
+    }
+    let typecode = oci::type_coll_elem_code(env, err, tdo.as_ref())?;
+    let coll = coll.get() as *const OCIColl;
+    let size = oci::coll_size(env, err, coll)?;
+    let mut elems = Vec::with_capacity(size);
+    for i in 0..size {
+        let mut exists = 0i32;
+        let mut value = std::ptr::null_mut::<c_void>();
+        let mut ind = std::ptr::null_mut::<c_void>();
+        oci::coll_get_elem(env, err, coll, i, &mut exists, &mut value, &mut ind)?;
+        if exists == 0 {
+            // deleted element of a nested table
+            continue;
+        }
+        let is_null = value.is_null() || (!ind.is_null() && unsafe { *(ind as *const i16) } == OCI_IND_NULL);
+        let elem = Attr { name: "collection element", typecode, value, is_null, ctx };
+        elems.push(FromAttr::value(&elem)?);
+    }
+    Ok(elems)
+}
+
+#[cfg(all(test,feature="blocking"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn read_flat_object() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE 'CREATE TYPE test_pair_obj AS OBJECT (a NUMBER, b VARCHAR2(30))';
+            EXCEPTION
+                WHEN name_already_used THEN NULL;
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_object_columns (
+                        id      NUMBER PRIMARY KEY,
+                        pair    test_pair_obj
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_object_columns
+                    ';
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("
+            INSERT INTO test_object_columns (id, pair)
+            VALUES (1, test_pair_obj(42, 'forty-two'))
+        ")?;
+        stmt.execute(())?;
+        let stmt = session.prepare("
+            INSERT INTO test_object_columns (id, pair)
+            VALUES (2, test_pair_obj(NULL, 'none'))
+        ")?;
+        stmt.execute(())?;
+        let stmt = session.prepare("
+            INSERT INTO test_object_columns (id) VALUES (3)
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("SELECT pair FROM test_object_columns ORDER BY id")?;
+        let cols = stmt.describe_result()?;
+        assert_eq!(cols[0].data_type()?, ColumnType::Object);
+
+        let rows = stmt.query(())?;
+
+        let row = rows.next()?.expect("first row");
+        let pair : Object = row.get(0)?;
+        let (a, b) : (Number, String) = (pair.get("A")?, pair.get("B")?);
+        assert_eq!(a.to_int::<i32>()?, 42);
+        assert_eq!(b, "forty-two");
+
+        let row = rows.next()?.expect("second row");
+        let pair : Object = row.get(0)?;
+        assert!(pair.is_attr_null("A")?);
+        let a : Option<Number> = pair.get("A")?;
+        assert!(a.is_none());
+        let res : Result<Number> = pair.get("A");
+        assert!(res.is_err());
+        let b : &str = pair.get("B")?;
+        assert_eq!(b, "none");
+        let res : Result<i32> = pair.get("C");
+        assert!(res.is_err());
+
+        let row = rows.next()?.expect("third row");
+        assert!(row.is_null(0));
+        let pair : Option<Object> = row.get(0)?;
+        assert!(pair.is_none());
+
+        assert!(rows.next()?.is_none());
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    #[test]
+    fn read_date_attribute() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE 'CREATE TYPE test_event_obj AS OBJECT (name VARCHAR2(30), at DATE)';
+            EXCEPTION
+                WHEN name_already_used THEN NULL;
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("
+            SELECT test_event_obj('launch', TO_DATE('2021-03-04 05:06:07', 'YYYY-MM-DD HH24:MI:SS')) FROM dual
+        ")?;
+        let row = stmt.query_single(())?.expect("one row");
+        let event : Object = row.get(0)?;
+        let at : String = event.get("AT")?;
+        assert_eq!(at, "2021-03-04 05:06:07");
+        let at : Date = event.get("AT")?;
+        assert_eq!(at.date_and_time(), (2021, 3, 4, 5, 6, 7));
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_varray() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE 'CREATE TYPE test_num_varray AS VARRAY(10) OF NUMBER';
+            EXCEPTION
+                WHEN name_already_used THEN NULL;
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_varray_columns (
+                        id      NUMBER PRIMARY KEY,
+                        nums    test_num_varray
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_varray_columns
+                    ';
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("
+            INSERT INTO test_varray_columns (id, nums)
+            VALUES (1, test_num_varray(3, 1, 4, 1, 5))
+        ")?;
+        stmt.execute(())?;
+        let stmt = session.prepare("
+            INSERT INTO test_varray_columns (id, nums)
+            VALUES (2, test_num_varray(2, NULL, 7))
+        ")?;
+        stmt.execute(())?;
+        let stmt = session.prepare("
+            INSERT INTO test_varray_columns (id, nums)
+            VALUES (3, test_num_varray())
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("SELECT nums FROM test_varray_columns ORDER BY id")?;
+        let rows = stmt.query(())?;
+
+        let row = rows.next()?.expect("first row");
+        let nums : Vec<Number> = row.get(0)?;
+        let nums = nums.iter().map(|n| n.to_int::<i32>()).collect::<Result<Vec<_>>>()?;
+        assert_eq!(nums, [3, 1, 4, 1, 5]);
+
+        let row = rows.next()?.expect("second row");
+        let res : Result<Vec<i32>> = row.get(0);
+        assert!(res.is_err());
+        let nums : Vec<Option<i32>> = row.get(0)?;
+        assert_eq!(nums, [Some(2), None, Some(7)]);
+
+        let row = rows.next()?.expect("third row");
+        let nums : Vec<Number> = row.get(0)?;
+        assert!(nums.is_empty());
+
+        assert!(rows.next()?.is_none());
+
+        session.rollback()?;
+        Ok(())
+    }
+}
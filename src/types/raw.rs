@@ -36,6 +36,8 @@ pub(crate) fn len(raw: &OCIRaw, env: &OCIEnv) -> usize {
     The RAW datatype is used for binary data or byte strings that are not to be interpreted by Oracle.
     The maximum length of a RAW column is 2000 bytes.
     The LONG RAW datatype is similar to the RAW datatype, except that it stores raw data with a length up to two gigabytes.
+
+    Raw values are compared and hashed by their content, and thus can be used as `HashMap` and `HashSet` keys.
 */
 pub struct Raw<'a> {
     raw: Ptr<OCIRaw>,
@@ -221,6 +223,20 @@ impl<'a> Raw<'a> {
     }
 }
 
+impl PartialEq for Raw<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl Eq for Raw<'_> {}
+
+impl std::hash::Hash for Raw<'_> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
+    }
+}
+
 impl std::fmt::Debug for Raw<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         const MAX_LEN : usize = 50;
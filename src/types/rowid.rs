@@ -44,6 +44,10 @@ impl RowID  {
         Self(rowid)
     }
 
+    pub(crate) fn is_initialized(&self) -> bool {
+        is_initialized(&self.0)
+    }
+
     /**
         Returns character representation of a ROWID.
 
@@ -3,8 +3,11 @@
 mod tosql;
 
 use super::{ Ctx, interval::Interval };
-use crate::{ Result, oci::{self, *} };
-use std::{ mem, ptr, cmp::Ordering, ops::{Deref, DerefMut} };
+use crate::{ Error, Result, oci::{self, *} };
+use std::{ mem, ptr, cmp::Ordering, convert::TryFrom, hash::{Hash, Hasher}, ops::{Deref, DerefMut} };
+
+const NANOS_PER_SEC : i128 = 1_000_000_000;
+const NANOS_PER_DAY : i128 = 86_400 * NANOS_PER_SEC;
 
 pub(crate) fn to_string(fmt: &str, fsprec: u8, ts: &OCIDateTime, ctx: &dyn Ctx) -> Result<String> {
     let name = mem::MaybeUninit::<[u8;128]>::uninit();
@@ -36,7 +39,48 @@ where T: DescriptorType<OCIType=OCIDateTime>
     Ok( DateTime { ctx, datetime } )
 }
 
-/// Represents datetime data types.
+/// Returns the number of days since 1970-01-01 for the given (proleptic Gregorian) date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Returns the (proleptic Gregorian) date that is the given number of days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = era * 400 + yoe + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Converts time zone offset to nanoseconds
+fn offset_nanos((hours, minutes): (i8, i8)) -> i128 {
+    (hours as i128 * 60 + minutes as i128) * 60 * NANOS_PER_SEC
+}
+
+/**
+    Represents datetime data types.
+
+    Timestamps of the same type can be compared with the comparison operators and sorted.
+    These use [`DateTime::compare()`] internally and thus panic if OCI reports an error.
+    Use `compare()` directly when this is not acceptable.
+
+    Timestamps also implement `Hash`, and thus can be used as `HashMap` and `HashSet` keys.
+    The hash is computed from the point in time the timestamp represents - see
+    [`DateTime::to_unix_nanos()`] - and thus timestamps with time zones that compare as equal
+    also hash the same. Like comparison operators hashing panics if OCI reports an error.
+*/
 pub struct DateTime<'a, T> where T: DescriptorType<OCIType=OCIDateTime> {
     datetime: Descriptor<T>,
     ctx: &'a dyn Ctx,
@@ -139,6 +183,61 @@ impl<'a, T> DateTime<'a, T> where T: DescriptorType<OCIType=OCIDateTime> {
         Ok( Self { ctx, datetime } )
     }
 
+    /**
+        Creates a timestamp from the number of nanoseconds since the Unix epoch (1970-01-01 00:00:00 UTC).
+
+        Time zone, as a string, is either a time zone region name or an offset in the format "\[+|-\]\[HH:MM\]".
+        If the time zone is not specified, then the session default time zone is assumed. The created timestamp
+        represents the given instant in that time zone.
+
+        Timestamps that do not have a time zone are created with the UTC date and time of the instant.
+
+        # Example
+        ```
+        use sibyl::{ self as oracle, TimestampTZ };
+        let env = oracle::env()?;
+
+        let ts = TimestampTZ::from_unix_nanos(-14_182_916_000_000_000, "UTC", &env)?;
+        assert_eq!(ts.date_and_time()?, (1969, 7, 20, 20, 18, 4, 0));
+
+        let ts = TimestampTZ::from_unix_nanos(-14_182_916_000_000_000, "-04:00", &env)?;
+        assert_eq!(ts.date_and_time()?, (1969, 7, 20, 16, 18, 4, 0));
+        # Ok::<(),oracle::Error>(())
+        ```
+    */
+    pub fn from_unix_nanos(nanos: i128, tz: &str, ctx: &'a dyn Ctx) -> Result<Self> {
+        let ts = Self::with_local_nanos(nanos, tz, ctx)?;
+        if T::get_type() == OCI_DTYPE_TIMESTAMP {
+            return Ok(ts);
+        }
+        // The offset at the UTC date and time is a first approximation of the offset
+        // at the instant. They might differ if there is a DST transition between them.
+        let offset = offset_nanos(ts.tz_offset()?);
+        let ts = Self::with_local_nanos(nanos + offset, tz, ctx)?;
+        let actual_offset = offset_nanos(ts.tz_offset()?);
+        if actual_offset == offset {
+            Ok(ts)
+        } else {
+            Self::with_local_nanos(nanos + actual_offset, tz, ctx)
+        }
+    }
+
+    /// Creates a timestamp with the date and time fields set to the date and time that is `nanos`
+    /// nanoseconds after 1970-01-01 00:00:00.
+    fn with_local_nanos(nanos: i128, tz: &str, ctx: &'a dyn Ctx) -> Result<Self> {
+        let days = i64::try_from(nanos.div_euclid(NANOS_PER_DAY)).map_err(|_| Error::new("timestamp is out of range"))?;
+        let time = nanos.rem_euclid(NANOS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+        let year = i16::try_from(year).map_err(|_| Error::new("timestamp is out of range"))?;
+        let secs = time / NANOS_PER_SEC;
+        let fsec = time % NANOS_PER_SEC;
+        Self::with_date_and_time(
+            year, month as u8, day as u8,
+            (secs / 3600) as u8, (secs / 60 % 60) as u8, (secs % 60) as u8, fsec as u32,
+            tz, ctx
+        )
+    }
+
     /**
         Creates new timestamp from the given string according to the specified format.
 
@@ -387,6 +486,36 @@ impl<'a, T> DateTime<'a, T> where T: DescriptorType<OCIType=OCIDateTime> {
         Ok((year, month, day, hour, min, sec, nanos))
     }
 
+    /**
+        Returns the number of nanoseconds since the Unix epoch (1970-01-01 00:00:00 UTC).
+
+        The date and time of timestamps that do not have a time zone are assumed to be in UTC.
+
+        # Example
+        ```
+        use sibyl::{ self as oracle, Timestamp, TimestampTZ };
+        let env = oracle::env()?;
+
+        let ts = TimestampTZ::with_date_and_time(1969,7,20,16,18,4,0,"-04:00", &env)?;
+        assert_eq!(ts.to_unix_nanos()?, -14_182_916_000_000_000);
+
+        let ts = Timestamp::with_date_and_time(1969,7,20,20,18,4,0,"", &env)?;
+        assert_eq!(ts.to_unix_nanos()?, -14_182_916_000_000_000);
+        # Ok::<(),oracle::Error>(())
+        ```
+    */
+    pub fn to_unix_nanos(&self) -> Result<i128> {
+        let (year, month, day, hour, min, sec, fsec) = self.date_and_time()?;
+        let days = days_from_civil(year as i64, month as u32, day as u32);
+        let secs = hour as i128 * 3600 + min as i128 * 60 + sec as i128;
+        let nanos = days as i128 * NANOS_PER_DAY + secs * NANOS_PER_SEC + fsec as i128;
+        if T::get_type() == OCI_DTYPE_TIMESTAMP {
+            Ok(nanos)
+        } else {
+            Ok(nanos - offset_nanos(self.tz_offset()?))
+        }
+    }
+
     /**
         Returns the time zone name portion of a timestamp
 
@@ -500,6 +629,32 @@ impl<'a> DateTime<'a, OCITimestampTZ> {
     }
 }
 
+impl<T> PartialEq for DateTime<'_, T> where T: DescriptorType<OCIType=OCIDateTime> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for DateTime<'_, T> where T: DescriptorType<OCIType=OCIDateTime> {}
+
+impl<T> PartialOrd for DateTime<'_, T> where T: DescriptorType<OCIType=OCIDateTime> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for DateTime<'_, T> where T: DescriptorType<OCIType=OCIDateTime> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare(other).expect("comparable timestamps")
+    }
+}
+
+impl<T> Hash for DateTime<'_, T> where T: DescriptorType<OCIType=OCIDateTime> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_unix_nanos().expect("timestamp").hash(state);
+    }
+}
+
 impl std::fmt::Debug for DateTime<'_, OCITimestampTZ> {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.to_string("YYYY-DD-MM HH24:MI:SSXFF TZR", 3) {
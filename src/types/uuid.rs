@@ -0,0 +1,143 @@
+//! Mapping of `uuid::Uuid` to and from `RAW(16)` columns
+//!
+//! UUIDs are stored as 16 bytes in the order defined by RFC 4122 - the same order in which
+//! hexadecimal digits appear in the UUID text. Thus `RAWTOHEX` of the stored value returns
+//! the same digits as the hyphenated UUID text (without hyphens and in upper case).
+//!
+//! Note that .NET `Guid.ToByteArray` and Windows `GUID` structures use the mixed-endian layout,
+//! where the first 3 groups of bytes are little-endian. GUIDs that were stored as raw bytes by
+//! such clients will be read as UUIDs with these groups reversed. Use `Uuid::from_bytes_le`
+//! and `Uuid::to_bytes_le` to convert between the layouts.
+//!
+//! # Example
+//!
+//! ```
+//! use uuid::Uuid;
+//!
+//! # use sibyl::Result;
+//! # #[cfg(feature="blocking")]
+//! # fn main() -> Result<()> {
+//! # let session = sibyl::test_env::get_session()?;
+//! let id = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+//! let stmt = session.prepare("
+//!     SELECT RawToHex(:ID), :ID FROM dual
+//! ")?;
+//! let row = stmt.query_single(&id)?.unwrap();
+//! let hex : &str = row.get(0)?;
+//! assert_eq!(hex, "67E5504410B1426F9247BB680E5FE0C8");
+//! let val : Uuid = row.get(1)?;
+//! assert_eq!(val, id);
+//! # Ok(())
+//! # }
+//! # #[cfg(feature="nonblocking")]
+//! # fn main() -> Result<()> {
+//! # sibyl::block_on(async {
+//! # let session = sibyl::test_env::get_session().await?;
+//! # let id = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+//! # let stmt = session.prepare("
+//! #     SELECT RawToHex(:ID), :ID FROM dual
+//! # ").await?;
+//! # let row = stmt.query_single(&id).await?.unwrap();
+//! # let hex : &str = row.get(0)?;
+//! # assert_eq!(hex, "67E5504410B1426F9247BB680E5FE0C8");
+//! # let val : Uuid = row.get(1)?;
+//! # assert_eq!(val, id);
+//! # Ok(()) })
+//! # }
+//! ```
+
+use ::uuid::Uuid;
+use super::OracleDataType;
+use crate::{oci::*, Error, Result, ToSql, FromSql, Row, stmt::{Params, Column}};
+
+impl ToSql for Uuid {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        let bytes = self.as_bytes();
+        params.bind_in(pos, SQLT_BIN, bytes.as_ptr() as _, bytes.len(), stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl ToSql for &Uuid {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        let bytes = self.as_bytes();
+        params.bind_in(pos, SQLT_BIN, bytes.as_ptr() as _, bytes.len(), stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl_sql_type!{ Uuid, &Uuid => SQLT_BIN }
+
+impl OracleDataType for Uuid {}
+impl OracleDataType for &Uuid {}
+
+impl<'a> FromSql<'a> for Uuid {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let bytes : &[u8] = FromSql::value(row, col)?;
+        Uuid::from_slice(bytes).map_err(|_| Error::msg(format!("cannot return {} bytes as Uuid", bytes.len())))
+    }
+}
+
+#[cfg(all(test,feature="blocking"))]
+mod tests {
+    use ::uuid::Uuid;
+    use crate::*;
+
+    #[test]
+    fn uuid_raw16() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_uuids (
+                        id      RAW(16) PRIMARY KEY,
+                        name    VARCHAR2(100)
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_uuids
+                    ';
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let id = Uuid::from_u128(0x936da01f_9abd_4d9d_80c7_02af85c822a8);
+        let stmt = session.prepare("INSERT INTO test_uuids (id, name) VALUES (:ID, :NAME)")?;
+        stmt.execute(((":ID", id), (":NAME", "rust")))?;
+        let stmt = session.prepare("INSERT INTO test_uuids (id, name) VALUES (SYS_GUID(), :NAME)")?;
+        stmt.execute("oracle")?;
+
+        let stmt = session.prepare("SELECT id, RawToHex(id) FROM test_uuids WHERE name = :NAME")?;
+        let row = stmt.query_single("rust")?.unwrap();
+        let val : Uuid = row.get(0)?;
+        assert_eq!(val, id);
+        let hex : &str = row.get(1)?;
+        assert_eq!(hex, id.simple().to_string().to_uppercase());
+
+        let stmt = session.prepare("SELECT name FROM test_uuids WHERE id = :ID")?;
+        let row = stmt.query_single(&id)?.unwrap();
+        let name : &str = row.get(0)?;
+        assert_eq!(name, "rust");
+
+        let stmt = session.prepare("SELECT id, RawToHex(id) FROM test_uuids WHERE name = :NAME")?;
+        let row = stmt.query_single("oracle")?.unwrap();
+        let val : Uuid = row.get(0)?;
+        let hex : &str = row.get(1)?;
+        assert_eq!(hex, val.simple().to_string().to_uppercase());
+
+        let stmt = session.prepare("SELECT :ID, HexToRaw('0011') FROM dual")?;
+        let no_id : Option<Uuid> = None;
+        let row = stmt.query_single(no_id)?.unwrap();
+        let val : Option<Uuid> = row.get(0)?;
+        assert!(val.is_none());
+        let res : Result<Uuid> = row.get(1);
+        assert!(res.is_err());
+
+        session.rollback()?;
+        Ok(())
+    }
+}
@@ -56,10 +56,15 @@ pub(crate) fn as_str<'a>(txt: &OCIString, env: &OCIEnv) -> &'a str {
     }
 }
 
+/// Maximum number of bytes a single character might need in the AL32UTF8 client character set
+const MAX_BYTES_PER_CHAR : usize = 4;
+
 /// Represents Oracle character types - VARCHAR, LONG, etc.
 pub struct Varchar<'a> {
     txt: Ptr<OCIString>,
     ctx: &'a dyn Ctx,
+    /// Capacity in characters when the Varchar uses character length semantics, 0 otherwise.
+    char_cap: u32,
 }
 
 impl Drop for Varchar<'_> {
@@ -88,7 +93,7 @@ impl<'a> Varchar<'a> {
     pub fn from(text: &str, ctx: &'a dyn Ctx) -> Result<Self> {
         let mut txt = Ptr::<OCIString>::null();
         oci::string_assign_text(ctx.as_ref(), ctx.as_ref(), text.as_ptr(), text.len() as u32, txt.as_mut_ptr())?;
-        Ok( Self { ctx, txt } )
+        Ok( Self { ctx, txt, char_cap: 0 } )
     }
 
     /**
@@ -111,13 +116,13 @@ impl<'a> Varchar<'a> {
         let ctx = other.ctx;
         let mut txt = Ptr::<OCIString>::null();
         oci::string_assign(ctx.as_ref(), ctx.as_ref(), &other.txt, txt.as_mut_ptr())?;
-        Ok( Self { ctx, txt } )
+        Ok( Self { ctx, txt, char_cap: 0 } )
     }
 
     pub(crate) fn from_ocistring(oci_str: &OCIString, ctx: &'a dyn Ctx) -> Result<Self> {
         let mut txt = Ptr::<OCIString>::null();
         oci::string_assign(ctx.as_ref(), ctx.as_ref(), oci_str, txt.as_mut_ptr())?;
-        Ok( Self { ctx, txt } )
+        Ok( Self { ctx, txt, char_cap: 0 } )
     }
 
     /**
@@ -137,7 +142,61 @@ impl<'a> Varchar<'a> {
     */
     pub fn with_capacity(size: usize, ctx: &'a dyn Ctx) -> Result<Self> {
         let txt = new(size as u32, ctx.as_ref(), ctx.as_ref())?;
-        Ok( Self { ctx, txt } )
+        Ok( Self { ctx, txt, char_cap: 0 } )
+    }
+
+    /**
+        Returns a new Varchar with the memory allocated for `num_chars` characters.
+
+        Unlike [`with_capacity`](Varchar::with_capacity), which allocates memory in bytes, this
+        method reserves enough space for any `num_chars` characters regardless of how many bytes
+        each of them takes in the client character set. When bound, the Varchar uses character
+        length semantics, which makes it a natural fit for `NCHAR`/`NVARCHAR2` columns and
+        for `VARCHAR2(n CHAR)` columns that store multibyte data.
+
+        # Example
+        ```
+        use sibyl::{ self as oracle, Varchar };
+        let env = oracle::env()?;
+
+        let mut txt = Varchar::with_char_capacity(4, &env)?;
+        assert_eq!(txt.char_capacity(), Some(4));
+        assert!(txt.capacity()? >= 16);
+
+        txt.set("🚲🛠📬🎓")?;
+        assert_eq!(txt.len(), 16);
+        # Ok::<(),oracle::Error>(())
+        ```
+    */
+    pub fn with_char_capacity(num_chars: usize, ctx: &'a dyn Ctx) -> Result<Self> {
+        let txt = new((num_chars * MAX_BYTES_PER_CHAR) as u32, ctx.as_ref(), ctx.as_ref())?;
+        Ok( Self { ctx, txt, char_cap: num_chars as u32 } )
+    }
+
+    /**
+        Returns the capacity in characters if this Varchar was created with
+        [`with_char_capacity`](Varchar::with_char_capacity), or `None` if its
+        capacity is measured in bytes.
+
+        # Example
+        ```
+        use sibyl::{ self as oracle, Varchar };
+        let env = oracle::env()?;
+
+        let txt = Varchar::with_capacity(16, &env)?;
+        assert_eq!(txt.char_capacity(), None);
+
+        let txt = Varchar::with_char_capacity(16, &env)?;
+        assert_eq!(txt.char_capacity(), Some(16));
+        # Ok::<(),oracle::Error>(())
+        ```
+    */
+    pub fn char_capacity(&self) -> Option<usize> {
+        if self.char_cap > 0 { Some(self.char_cap as usize) } else { None }
+    }
+
+    pub(crate) fn max_char_size(&self) -> u32 {
+        self.char_cap
     }
 
     /**
@@ -198,7 +257,8 @@ impl<'a> Varchar<'a> {
 
     /**
         Changes the size of the memory of a string in the object cache.
-        Content of the string is not preserved.
+        Content of the string is not preserved. The new size is in bytes, thus
+        the resized Varchar reverts to byte length semantics.
 
         # Example
         ```
@@ -228,6 +288,7 @@ impl<'a> Varchar<'a> {
         ```
     */
     pub fn resize(&mut self, new_size: usize) -> Result<()> {
+        self.char_cap = 0;
         oci::string_resize(self.ctx.as_ref(), self.ctx.as_ref(), new_size as u32, self.txt.as_mut_ptr())
     }
 
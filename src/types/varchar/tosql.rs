@@ -26,6 +26,9 @@ impl ToSql for Varchar<'_> {
         let len = self.len();
         let cap = self.capacity()? + size_of::<u32>();
         params.bind(pos, SQLT_LVC, self.txt.get() as _, len + size_of::<u32>(), cap, stmt, err)?;
+        if self.max_char_size() > 0 {
+            params.set_max_char_size(pos, self.max_char_size(), err)?;
+        }
         if len == 0 {
             params.mark_as_null(pos);
         }
@@ -38,6 +41,9 @@ impl ToSql for &Varchar<'_> {
         let len = self.len();
         let cap = self.capacity()? + size_of::<u32>();
         params.bind(pos, SQLT_LVC, self.txt.get() as _, len + size_of::<u32>(), cap, stmt, err)?;
+        if self.max_char_size() > 0 {
+            params.set_max_char_size(pos, self.max_char_size(), err)?;
+        }
         if len == 0 {
             params.mark_as_null(pos);
         }
@@ -50,6 +56,9 @@ impl ToSql for &mut Varchar<'_> {
         let len = self.len();
         let cap = self.capacity()? + size_of::<u32>();
         params.bind(pos, SQLT_LVC, self.txt.get() as _, len + size_of::<u32>(), cap, stmt, err)?;
+        if self.max_char_size() > 0 {
+            params.set_max_char_size(pos, self.max_char_size(), err)?;
+        }
         if len == 0 {
             params.mark_as_null(pos);
         }
@@ -63,6 +72,9 @@ impl ToSql for &[Varchar<'_>] {
             let len = item.len();
             let cap = item.capacity()? + size_of::<u32>();
             params.bind(pos, SQLT_LVC, item.txt.get() as _, len + size_of::<u32>(), cap, stmt, err)?;
+            if item.max_char_size() > 0 {
+                params.set_max_char_size(pos, item.max_char_size(), err)?;
+            }
             if len == 0 {
                 params.mark_as_null(pos);
             }
@@ -82,6 +94,9 @@ impl ToSql for &[&Varchar<'_>] {
             let len = item.len();
             let cap = item.capacity()? + size_of::<u32>();
             params.bind(pos, SQLT_LVC, item.txt.get() as _, len + size_of::<u32>(), cap, stmt, err)?;
+            if item.max_char_size() > 0 {
+                params.set_max_char_size(pos, item.max_char_size(), err)?;
+            }
             if len == 0 {
                 params.mark_as_null(pos);
             }
@@ -101,6 +116,9 @@ impl ToSql for &mut [&mut Varchar<'_>] {
             let len = item.len();
             let cap = item.capacity()? + size_of::<u32>();
             params.bind(pos, SQLT_LVC, item.txt.get() as _, len + size_of::<u32>(), cap, stmt, err)?;
+            if item.max_char_size() > 0 {
+                params.set_max_char_size(pos, item.max_char_size(), err)?;
+            }
             if len == 0 {
                 params.mark_as_null(pos);
             }
@@ -103,6 +103,106 @@ mod blocking {
         Ok(())
     }
 
+    #[test]
+    fn identity_columns() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_character_data (
+                        id      NUMBER GENERATED ALWAYS AS IDENTITY,
+                        text    VARCHAR2(97),
+                        ntext   NVARCHAR2(99)
+                    )
+                ';
+            EXCEPTION
+              WHEN name_already_used THEN NULL;
+            END;
+        ")?;
+        stmt.execute(())?;
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_identity_on_null (
+                        id      NUMBER GENERATED BY DEFAULT ON NULL AS IDENTITY,
+                        name    VARCHAR2(30)
+                    )
+                ';
+            EXCEPTION
+              WHEN name_already_used THEN NULL;
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("SELECT id, text FROM test_character_data")?;
+        let cols = stmt.describe_result()?;
+        assert!(cols[0].is_identity()?);
+        assert!(cols[0].is_generated_always()?);
+        assert!(!cols[0].is_generated_by_default_on_null()?);
+        assert!(!cols[1].is_identity()?);
+
+        let stmt = session.prepare("SELECT id, name FROM test_identity_on_null")?;
+        let cols = stmt.describe_result()?;
+        assert!(cols[0].is_identity()?);
+        assert!(!cols[0].is_generated_always()?);
+        assert!(cols[0].is_generated_by_default_on_null()?);
+        assert!(!cols[1].is_identity()?);
+        assert!(!cols[1].is_generated_by_default_on_null()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn nchar_char_semantics() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_nchar_data (
+                        id      NUMBER GENERATED ALWAYS AS IDENTITY,
+                        ntext   NVARCHAR2(6)
+                    )
+                ';
+            EXCEPTION
+              WHEN name_already_used THEN NULL;
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("
+            INSERT INTO test_nchar_data (ntext) VALUES (:NTEXT)
+            RETURNING id, ntext INTO :ID, :NTXT_OUT
+        ")?;
+        // 6 characters, but 12 bytes in UTF-8
+        let mut ntext = Varchar::with_char_capacity(6, &session)?;
+        ntext.set("Привет")?;
+        let mut ntxt_out = Varchar::with_char_capacity(6, &session)?;
+        let mut id = 0;
+        let count = stmt.execute(
+            (
+                (":NTEXT",    &ntext),
+                (":ID",       &mut id),
+                (":NTXT_OUT", &mut ntxt_out),
+            )
+        )?;
+        assert_eq!(count, 1);
+        assert!(id > 0);
+        assert_eq!(ntxt_out.as_str(), "Привет");
+
+        let stmt = session.prepare("SELECT ntext FROM test_nchar_data WHERE id = :ID")?;
+        let row = stmt.query_single(id)?.unwrap();
+        let text : &str = row.get(0)?;
+        assert_eq!(text, "Привет");
+
+        Ok(())
+    }
+
     #[test]
     fn datetime_datatypes() -> Result<()> {
         use std::cmp::Ordering::Equal;
@@ -415,7 +515,7 @@ mod blocking {
         ")?;
         let data = [0xfeu8, 0xff, 0x00, 0x48, 0x00, 0x65, 0x00, 0x6c, 0x00, 0x6c, 0x00, 0x6f, 0x00, 0x2c, 0x00, 0x20, 0x00, 0x57, 0x00, 0x6f, 0x00, 0x72, 0x00, 0x6c, 0x00, 0x64, 0x00, 0x21];
         let mut id = 0;
-        let count = stmt.execute((&data[..], &mut id, ()))?;
+        let count = stmt.execute((&data[..], &mut id))?;
         assert_eq!(count, 1);
         assert!(id > 0);
 
@@ -428,6 +528,54 @@ mod blocking {
         Ok(())
     }
 
+    #[test]
+    fn raw_pair_args() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_raw_pairs (
+                        id      NUMBER GENERATED ALWAYS AS IDENTITY,
+                        bin     R(16)
+                    )
+                ';
+            EXCEPTION
+              WHEN name_already_used THEN NULL;
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("
+            INSERT INTO test_raw_pairs (bin) VALUES (:BIN)
+            RETURNING id INTO :ID
+        ")?;
+        let data = [0x12u8, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x0f, 0xed, 0xcb, 0xa9, 0x87, 0x65, 0x43, 0x21];
+        let mut id = 0;
+        let count = stmt.execute((&data[..], &mut id))?;
+        assert_eq!(count, 1);
+        assert!(id > 0);
+
+        let mut vec_id = 0;
+        let count = stmt.execute((data.to_vec(), &mut vec_id))?;
+        assert_eq!(count, 1);
+        assert!(vec_id > id);
+
+        let stmt = session.prepare("SELECT bin FROM test_raw_pairs WHERE id IN (:ID, :VEC_ID)")?;
+        let rows = stmt.query((id, vec_id, ()))?;
+        let mut num_rows = 0;
+        while let Some(row) = rows.next()? {
+            let bin : &[u8] = row.get(0)?;
+            assert_eq!(bin, &data[..]);
+            num_rows += 1;
+        }
+        assert_eq!(num_rows, 2);
+
+        session.rollback()?;
+        Ok(())
+    }
+
     #[test]
     fn numeric_datatypes() -> Result<()> {
         use std::cmp::Ordering::Equal;
@@ -488,6 +636,309 @@ mod blocking {
         Ok(())
     }
 
+    #[test]
+    fn number_keys() -> Result<()> {
+        use std::collections::HashSet;
+
+        let oracle = sibyl::env()?;
+
+        let mut keys = HashSet::new();
+        assert!(keys.insert(Number::from_int(42, &oracle)?));
+        assert!(keys.insert(Number::from_string("3.14", "9D99", &oracle)?));
+        assert!(keys.insert(Number::zero(&oracle)));
+        assert!(!keys.insert(Number::from_string("42.00", "99D99", &oracle)?));
+        assert!(!keys.insert(Number::from_real(42.0, &oracle)?));
+        assert!(!keys.insert(Number::from_int(0, &oracle)?));
+        assert_eq!(keys.len(), 3);
+
+        assert!(keys.contains(&Number::from_string("3.140", "9D999", &oracle)?));
+        assert!(keys.contains(&Number::from_int(42u8, &oracle)?));
+        assert!(!keys.contains(&Number::from_int(-42, &oracle)?));
+        assert!(!keys.contains(&Number::pi(&oracle)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn preserve_number_precision() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        let sql = "SELECT 1234567890123456789.0123456789012345678, 42, -0.5 FROM dual";
+
+        let stmt = session.prepare(sql)?;
+        let row = stmt.query_single(())?.expect("one row");
+        let num : f64 = row.get(0)?;
+        assert_ne!(num.to_string(), "1234567890123456789.0123456789012345678");
+
+        let mut stmt = session.prepare(sql)?;
+        stmt.preserve_number_precision(true);
+        let row = stmt.query_single(())?.expect("one row");
+        let num : &str = row.get(0)?;
+        assert_eq!(num, "1234567890123456789.0123456789012345678");
+        let num : String = row.get(0)?;
+        assert_eq!(num.chars().filter(char::is_ascii_digit).count(), 38);
+        let num : i32 = row.get(1)?;
+        assert_eq!(num, 42);
+        let num : f64 = row.get(2)?;
+        assert_eq!(num, -0.5);
+
+        // session default
+        session.preserve_number_precision(true);
+        let stmt = session.prepare(sql)?;
+        session.preserve_number_precision(false);
+        let row = stmt.query_single(())?.expect("one row");
+        let num : &str = row.get(0)?;
+        assert_eq!(num, "1234567890123456789.0123456789012345678");
+
+        Ok(())
+    }
+
+    #[test]
+    fn binary_float_special_values() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_binary_float_data (
+                        id      NUMBER PRIMARY KEY,
+                        flt     BINARY_FLOAT,
+                        dbl     BINARY_DOUBLE
+                    )
+                ';
+            EXCEPTION
+              WHEN name_already_used THEN
+                EXECUTE IMMEDIATE '
+                    TRUNCATE TABLE test_binary_float_data
+                ';
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("INSERT INTO test_binary_float_data (id, flt, dbl) VALUES (:ID, :FLT, :DBL)")?;
+        stmt.execute((1, f32::NAN,          f64::NAN))?;
+        stmt.execute((2, f32::INFINITY,     f64::INFINITY))?;
+        stmt.execute((3, f32::NEG_INFINITY, f64::NEG_INFINITY))?;
+        stmt.execute((4, -0.0f32,           f64::MIN_POSITIVE))?;
+
+        // the server sees the IEEE values
+        let stmt = session.prepare("
+            SELECT id, flt, dbl
+                 , CASE WHEN dbl IS NAN THEN 'NaN' WHEN dbl IS INFINITE THEN 'Inf' ELSE 'Num' END
+              FROM test_binary_float_data
+             ORDER BY id
+        ")?;
+        let rows = stmt.query(())?;
+        let mut classes = Vec::new();
+        while let Some(row) = rows.next()? {
+            let id : u32 = row.get(0)?;
+            let flt : f32 = row.get(1)?;
+            let dbl : f64 = row.get(2)?;
+            let class : String = row.get(3)?;
+            match id {
+                1 => { assert!(flt.is_nan()); assert!(dbl.is_nan()); },
+                2 => { assert_eq!(flt, f32::INFINITY); assert_eq!(dbl, f64::INFINITY); },
+                3 => { assert_eq!(flt, f32::NEG_INFINITY); assert_eq!(dbl, f64::NEG_INFINITY); },
+                _ => { assert_eq!(flt, 0.0); assert_eq!(dbl, f64::MIN_POSITIVE); },
+            }
+            classes.push(class);
+        }
+        assert_eq!(classes, ["NaN", "Inf", "Inf", "Num"]);
+
+        // NaN read as NUMBER
+        let stmt = session.prepare("SELECT dbl FROM test_binary_float_data WHERE id = 1")?;
+        let row = stmt.query_single(())?.expect("one row");
+        let res : Result<Number> = row.get(0);
+        assert!(res.is_err());
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    #[test]
+    fn integer_binds() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_integer_data (
+                        id      NUMBER PRIMARY KEY,
+                        num     NUMBER
+                    )
+                ';
+            EXCEPTION
+              WHEN name_already_used THEN
+                EXECUTE IMMEDIATE '
+                    TRUNCATE TABLE test_integer_data
+                ';
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("INSERT INTO test_integer_data (id, num) VALUES (:ID, :NUM)")?;
+        stmt.execute((1, u64::MAX, ()))?;
+        stmt.execute((2, &(i64::MAX as u64 + 1), ()))?;
+        stmt.execute((3, i128::MAX, ()))?;
+        stmt.execute((4, i128::MIN, ()))?;
+        stmt.execute((5, Some(u128::MAX), ()))?;
+        stmt.execute((6, i64::MIN, ()))?;
+        stmt.execute((7, None::<u64>, ()))?;
+
+        let stmt = session.prepare("SELECT num, To_Char(num, 'TM') FROM test_integer_data WHERE id = :ID")?;
+
+        let row = stmt.query_single(1)?.unwrap();
+        let val : u64 = row.get(0)?;
+        assert_eq!(val, u64::MAX);
+        let txt : &str = row.get(1)?;
+        assert_eq!(txt, "18446744073709551615");
+
+        let row = stmt.query_single(2)?.unwrap();
+        let val : u64 = row.get(0)?;
+        assert_eq!(val, i64::MAX as u64 + 1);
+
+        let row = stmt.query_single(3)?.unwrap();
+        let val : i128 = row.get(0)?;
+        assert_eq!(val, i128::MAX);
+        let txt : &str = row.get(1)?;
+        assert_eq!(txt, "170141183460469231731687303715884105727");
+
+        let row = stmt.query_single(4)?.unwrap();
+        let val : i128 = row.get(0)?;
+        assert_eq!(val, i128::MIN);
+
+        let row = stmt.query_single(5)?.unwrap();
+        let val : u128 = row.get(0)?;
+        assert_eq!(val, u128::MAX);
+
+        let row = stmt.query_single(6)?.unwrap();
+        let val : i64 = row.get(0)?;
+        assert_eq!(val, i64::MIN);
+
+        let row = stmt.query_single(7)?.unwrap();
+        assert!(row.is_null(0));
+
+        let stmt = session.prepare("SELECT Count(*) FROM test_integer_data WHERE num IN (:A, :B)")?;
+        let num_rows : u32 = stmt.query_scalar(&[u64::MAX, i64::MAX as u64 + 1][..])?;
+        assert_eq!(num_rows, 2);
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    #[test]
+    fn unix_nanos() -> Result<()> {
+        let oracle = sibyl::env()?;
+
+        // 2023-11-14 22:13:20.123456789 UTC
+        let nanos = 1_700_000_000_123_456_789i128;
+
+        let ts = TimestampTZ::from_unix_nanos(nanos, "UTC", &oracle)?;
+        assert_eq!(ts.date_and_time()?, (2023, 11, 14, 22, 13, 20, 123_456_789));
+        assert_eq!(ts.to_unix_nanos()?, nanos);
+
+        let ts = TimestampTZ::from_unix_nanos(nanos, "+05:30", &oracle)?;
+        assert_eq!(ts.date_and_time()?, (2023, 11, 15, 3, 43, 20, 123_456_789));
+        assert_eq!(ts.tz_offset()?, (5, 30));
+        assert_eq!(ts.to_unix_nanos()?, nanos);
+
+        let ts = TimestampTZ::from_unix_nanos(nanos, "America/New_York", &oracle)?;
+        assert_eq!(ts.date_and_time()?, (2023, 11, 14, 17, 13, 20, 123_456_789));
+        assert_eq!(ts.to_unix_nanos()?, nanos);
+
+        // 2023-07-14 22:13:20 UTC is in EDT
+        let summer = nanos - 123 * 86_400_000_000_000;
+        let ts = TimestampTZ::from_unix_nanos(summer, "America/New_York", &oracle)?;
+        assert_eq!(ts.date_and_time()?, (2023, 7, 14, 18, 13, 20, 123_456_789));
+        assert_eq!(ts.to_unix_nanos()?, summer);
+
+        let ts = Timestamp::from_unix_nanos(-1, "", &oracle)?;
+        assert_eq!(ts.date_and_time()?, (1969, 12, 31, 23, 59, 59, 999_999_999));
+        assert_eq!(ts.to_unix_nanos()?, -1);
+
+        let ts = Timestamp::with_date_and_time(1, 1, 1, 0, 0, 0, 0, "", &oracle)?;
+        let nanos = ts.to_unix_nanos()?;
+        let ts = Timestamp::from_unix_nanos(nanos, "", &oracle)?;
+        assert_eq!(ts.date_and_time()?, (1, 1, 1, 0, 0, 0, 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn sort_temporal_values() -> Result<()> {
+        let oracle = sibyl::env()?;
+
+        let mut stamps = [
+            Timestamp::with_date_and_time(1969, 7, 20, 20, 18, 4, 0, "", &oracle)?,
+            Timestamp::with_date_and_time(1961, 4, 12, 6, 7, 0, 0, "", &oracle)?,
+            Timestamp::with_date_and_time(1969, 7, 20, 20, 17, 40, 0, "", &oracle)?,
+            Timestamp::with_date_and_time(1957, 10, 4, 19, 28, 34, 0, "", &oracle)?,
+        ];
+        stamps.sort();
+        let dates = stamps.iter().map(|ts| ts.date_and_time()).collect::<Result<Vec<_>>>()?;
+        assert_eq!(dates, [
+            (1957, 10, 4, 19, 28, 34, 0),
+            (1961, 4, 12, 6, 7, 0, 0),
+            (1969, 7, 20, 20, 17, 40, 0),
+            (1969, 7, 20, 20, 18, 4, 0),
+        ]);
+        assert!(stamps[0] < stamps[1]);
+        assert!(stamps[3] > stamps[2]);
+        assert!(stamps[1] == Timestamp::with_date_and_time(1961, 4, 12, 6, 7, 0, 0, "", &oracle)?);
+
+        let mut dates = [
+            Date::with_date(1969, 7, 20, &oracle),
+            Date::with_date(1957, 10, 4, &oracle),
+        ];
+        dates.sort();
+        assert_eq!(dates[0].date(), (1957, 10, 4));
+        assert!(dates[0] != dates[1]);
+
+        let short = IntervalDS::from_iso8601("PT1H30M", &oracle)?;
+        let long = IntervalDS::from_iso8601("P1D", &oracle)?;
+        assert!(short < long);
+        assert_eq!(short.max(long).duration()?, (1, 0, 0, 0, 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn iso8601_intervals() -> Result<()> {
+        let oracle = sibyl::env()?;
+
+        let int = IntervalDS::from_iso8601("PT1H30M", &oracle)?;
+        assert_eq!(int.duration()?, (0, 1, 30, 0, 0));
+        let int = IntervalDS::from_iso8601("P3DT4H5M6.789S", &oracle)?;
+        assert_eq!(int.duration()?, (3, 4, 5, 6, 789_000_000));
+        let int = IntervalDS::from_iso8601("P2W", &oracle)?;
+        assert_eq!(int.duration()?, (14, 0, 0, 0, 0));
+        let int = IntervalDS::from_iso8601("PT90M", &oracle)?;
+        assert_eq!(int.duration()?, (0, 1, 30, 0, 0));
+        let int = IntervalDS::from_iso8601("PT0,5S", &oracle)?;
+        assert_eq!(int.duration()?, (0, 0, 0, 0, 500_000_000));
+        let int = IntervalDS::from_iso8601("-PT25H", &oracle)?;
+        assert_eq!(int.duration()?, (-1, -1, 0, 0, 0));
+        let int = IntervalDS::from_iso8601("P0D", &oracle)?;
+        assert_eq!(int.duration()?, (0, 0, 0, 0, 0));
+
+        let int = IntervalYM::from_iso8601("P1Y2M", &oracle)?;
+        assert_eq!(int.duration()?, (1, 2));
+        let int = IntervalYM::from_iso8601("P14M", &oracle)?;
+        assert_eq!(int.duration()?, (1, 2));
+        let int = IntervalYM::from_iso8601("-P3Y", &oracle)?;
+        assert_eq!(int.duration()?, (-3, 0));
+
+        assert!(IntervalDS::from_iso8601("P1Y", &oracle).is_err());
+        assert!(IntervalYM::from_iso8601("P1D", &oracle).is_err());
+        assert!(IntervalYM::from_iso8601("P1YT1H", &oracle).is_err());
+        for txt in ["", "P", "PT", "1H", "PT1H30", "P1H", "PT1D", "PT30M1H", "P1.5D", "PT1.S", "PT1.0000000001S", "P1DT"].iter() {
+            assert!(IntervalDS::from_iso8601(txt, &oracle).is_err(), "{}", txt);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn rowid_datatype() -> Result<()> {
         let session = sibyl::test_env::get_session()?;
@@ -608,6 +1059,46 @@ mod blocking {
         Ok(())
     }
 
+    #[test]
+    fn ref_cursor_in_arg() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        let stmt = session.prepare("
+            BEGIN
+                OPEN :employees FOR
+                    SELECT employee_id
+                      FROM hr.employees
+                     WHERE department_id = :department_id
+                ;
+            END;
+        ")?;
+        let mut employees = Cursor::new(&stmt)?;
+        stmt.execute(((":EMPLOYEES", &mut employees), (":DEPARTMENT_ID", 60)))?;
+
+        let stmt = session.prepare("
+            DECLARE
+                FUNCTION count_rows(c IN SYS_REFCURSOR) RETURN NUMBER IS
+                    id  NUMBER;
+                    cnt NUMBER := 0;
+                BEGIN
+                    LOOP
+                        FETCH c INTO id;
+                        EXIT WHEN c%NOTFOUND;
+                        cnt := cnt + 1;
+                    END LOOP;
+                    CLOSE c;
+                    RETURN cnt;
+                END;
+            BEGIN
+                :num_rows := count_rows(:employees);
+            END;
+        ")?;
+        let mut num_rows = 0u32;
+        stmt.execute(((":NUM_ROWS", &mut num_rows), (":EMPLOYEES", &employees)))?;
+        assert_eq!(num_rows, 5);
+
+        Ok(())
+    }
+
     #[test]
     fn ref_cursor_result() -> Result<()> {
         use std::cmp::Ordering::Equal;
@@ -704,6 +1195,65 @@ mod blocking {
         Ok(())
     }
 
+    #[test]
+    fn ref_cursor_result_sets() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        let stmt = session.prepare("
+            DECLARE
+                c1 SYS_REFCURSOR;
+                c2 SYS_REFCURSOR;
+            BEGIN
+                OPEN c1 FOR
+                    SELECT department_name, first_name, last_name, salary
+                      FROM (
+                            SELECT first_name, last_name, salary, department_id
+                                 , ROW_NUMBER() OVER (ORDER BY salary) ord
+                              FROM hr.employees
+                           ) e
+                      JOIN hr.departments d
+                        ON d.department_id = e.department_id
+                     WHERE ord = 1
+                ;
+                OPEN c2 FOR
+                    SELECT department_name, first_name, last_name, salary
+                      FROM (
+                            SELECT first_name, last_name, salary, department_id
+                                 , MEDIAN(salary) OVER () median_salary
+                              FROM hr.employees
+                           ) e
+                      JOIN hr.departments d
+                        ON d.department_id = e.department_id
+                     WHERE salary = median_salary
+                  ORDER BY department_name, last_name, first_name
+                ;
+                DBMS_SQL.RETURN_RESULT(c1);
+                DBMS_SQL.RETURN_RESULT(c2);
+            END;
+        ")?;
+        stmt.execute(())?;
+        assert_eq!(stmt.implicit_result_count()?, 2);
+
+        let employees = stmt.result_sets().map(|cursor| {
+            let cursor = cursor?;
+            let rows = cursor.rows()?;
+            let mut names = Vec::new();
+            while let Some(row) = rows.next()? {
+                let first_name : &str = row.get(1)?;
+                let last_name : &str = row.get(2)?;
+                names.push(format!("{} {}", first_name, last_name));
+            }
+            Ok(names)
+        }).collect::<Result<Vec<_>>>()?;
+
+        assert_eq!(employees.len(), 2);
+        assert_eq!(employees[0], ["TJ Olson"]);
+        assert_eq!(employees[1], ["Amit Banda", "Charles Johnson"]);
+
+        assert!(stmt.result_sets().next().is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn ref_cursor_column() -> Result<()> {
         let session = sibyl::test_env::get_session()?;
@@ -742,6 +1292,45 @@ mod blocking {
 
         assert!(dept_rows.next()?.is_none());
 
+        Ok(())
+    }
+    #[test]
+    fn ref_cursor_column_iter() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            SELECT last_name
+                 , CURSOR(
+                        SELECT department_name
+                          FROM hr.departments
+                         WHERE department_id IN (
+                                    SELECT department_id
+                                      FROM hr.employees
+                                     WHERE last_name = e.last_name)
+                      ORDER BY department_name
+                   ) AS departments
+              FROM (
+                    SELECT distinct last_name
+                      FROM hr.employees
+                     WHERE last_name IN ('Grant', 'King')
+                   ) e
+          ORDER BY last_name
+        ")?;
+        let rows = stmt.query(())?;
+        let mut res = Vec::new();
+        while let Some(row) = rows.next()? {
+            let last_name : String = row.get(0)?;
+            let departments : Cursor = row.get(1)?;
+            let department_names = departments.into_iter::<(String,)>()
+                .map(|dept| dept.map(|(name,)| name))
+                .collect::<Result<Vec<_>>>()?;
+            res.push((last_name, department_names));
+        }
+        assert_eq!(res, [
+            ("Grant".to_string(), vec!["Shipping".to_string()]),
+            ("King".to_string(), vec!["Executive".to_string(), "Sales".to_string()]),
+        ]);
+
         Ok(())
     }
 }
@@ -857,6 +1446,55 @@ mod nonblocking {
         })
     }
 
+    #[test]
+    fn nchar_char_semantics() -> Result<()> {
+        block_on(async {
+            let session = sibyl::test_env::get_session().await?;
+            let stmt = session.prepare("
+                DECLARE
+                    name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+                BEGIN
+                    EXECUTE IMMEDIATE '
+                        CREATE TABLE test_nchar_data (
+                            id      NUMBER GENERATED ALWAYS AS IDENTITY,
+                            ntext   NVARCHAR2(6)
+                        )
+                    ';
+                EXCEPTION
+                  WHEN name_already_used THEN NULL;
+                END;
+            ").await?;
+            stmt.execute(()).await?;
+
+            let stmt = session.prepare("
+                INSERT INTO test_nchar_data (ntext) VALUES (:NTEXT)
+                RETURNING id, ntext INTO :ID, :NTXT_OUT
+            ").await?;
+            // 6 characters, but 12 bytes in UTF-8
+            let mut ntext = Varchar::with_char_capacity(6, &session)?;
+            ntext.set("Привет")?;
+            let mut ntxt_out = Varchar::with_char_capacity(6, &session)?;
+            let mut id = 0;
+            let count = stmt.execute(
+                (
+                    (":NTEXT",    &ntext),
+                    (":ID",       &mut id),
+                    (":NTXT_OUT", &mut ntxt_out),
+                )
+            ).await?;
+            assert_eq!(count, 1);
+            assert!(id > 0);
+            assert_eq!(ntxt_out.as_str(), "Привет");
+
+            let stmt = session.prepare("SELECT ntext FROM test_nchar_data WHERE id = :ID").await?;
+            let row = stmt.query_single(id).await?.unwrap();
+            let text : &str = row.get(0)?;
+            assert_eq!(text, "Привет");
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn datetime_datatypes() -> Result<()> {
         block_on(async {
@@ -1082,6 +1720,56 @@ mod nonblocking {
         })
     }
 
+    #[test]
+    fn raw_pair_args() -> Result<()> {
+        block_on(async {
+            let session = sibyl::test_env::get_session().await?;
+            let stmt = session.prepare("
+                DECLARE
+                    name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+                BEGIN
+                    EXECUTE IMMEDIATE '
+                        CREATE TABLE test_raw_pairs (
+                            id      NUMBER GENERATED ALWAYS AS IDENTITY,
+                            bin     R.await(16)
+                        )
+                    ';
+                EXCEPTION
+                  WHEN name_already_used THEN NULL;
+                END;
+            ").await?;
+            stmt.execute(()).await?;
+
+            let stmt = session.prepare("
+                INSERT INTO test_raw_pairs (bin) VALUES (:BIN)
+                RETURNING id INTO :ID
+            ").await?;
+            let data = [0x12u8, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x0f, 0xed, 0xcb, 0xa9, 0x87, 0x65, 0x43, 0x21];
+            let mut id = 0;
+            let count = stmt.execute((&data[..], &mut id)).await?;
+            assert_eq!(count, 1);
+            assert!(id > 0);
+
+            let mut vec_id = 0;
+            let count = stmt.execute((data.to_vec(), &mut vec_id)).await?;
+            assert_eq!(count, 1);
+            assert!(vec_id > id);
+
+            let stmt = session.prepare("SELECT bin FROM test_raw_pairs WHERE id IN (:ID, :VEC_ID)").await?;
+            let rows = stmt.query((id, vec_id, ())).await?;
+            let mut num_rows = 0;
+            while let Some(row) = rows.next().await? {
+                let bin : &[u8] = row.get(0)?;
+                assert_eq!(bin, &data[..]);
+                num_rows += 1;
+            }
+            assert_eq!(num_rows, 2);
+
+            session.rollback().await?;
+            Ok(())
+        })
+    }
+
     #[test]
     fn numeric_datatypes() -> Result<()> {
         block_on(async {
@@ -1275,6 +1963,48 @@ mod nonblocking {
         })
     }
 
+    #[test]
+    fn ref_cursor_in_arg() -> Result<()> {
+        block_on(async {
+            let session = sibyl::test_env::get_session().await?;
+            let stmt = session.prepare("
+                BEGIN
+                    OPEN :employees FOR
+                        SELECT employee_id
+                          FROM hr.employees
+                         WHERE department_id = :department_id
+                    ;
+                END;
+            ").await?;
+            let mut employees = Cursor::new(&stmt)?;
+            stmt.execute(((":EMPLOYEES", &mut employees), (":DEPARTMENT_ID", 60))).await?;
+
+            let stmt = session.prepare("
+                DECLARE
+                    FUNCTION count_rows(c IN SYS_REFCURSOR) RETURN NUMBER IS
+                        id  NUMBER;
+                        cnt NUMBER := 0;
+                    BEGIN
+                        LOOP
+                            FETCH c INTO id;
+                            EXIT WHEN c%NOTFOUND;
+                            cnt := cnt + 1;
+                        END LOOP;
+                        CLOSE c;
+                        RETURN cnt;
+                    END;
+                BEGIN
+                    :num_rows := count_rows(:employees);
+                END;
+            ").await?;
+            let mut num_rows = 0u32;
+            stmt.execute(((":NUM_ROWS", &mut num_rows), (":EMPLOYEES", &employees))).await?;
+            assert_eq!(num_rows, 5);
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn ref_cursor_result() -> Result<()> {
         block_on(async {
@@ -1372,6 +2102,72 @@ mod nonblocking {
         })
     }
 
+    #[test]
+    fn ref_cursor_result_sets() -> Result<()> {
+        block_on(async {
+            use std::{future::poll_fn, pin::pin};
+            use futures_core::Stream;
+
+            let session = sibyl::test_env::get_session().await?;
+            let stmt = session.prepare("
+                DECLARE
+                    c1 SYS_REFCURSOR;
+                    c2 SYS_REFCURSOR;
+                BEGIN
+                    OPEN c1 FOR
+                        SELECT department_name, first_name, last_name, salary
+                          FROM (
+                                SELECT first_name, last_name, salary, department_id
+                                     , ROW_NUMBER() OVER (ORDER BY salary) ord
+                                  FROM hr.employees
+                               ) e
+                          JOIN hr.departments d
+                            ON d.department_id = e.department_id
+                         WHERE ord = 1
+                    ;
+                    OPEN c2 FOR
+                        SELECT department_name, first_name, last_name, salary
+                          FROM (
+                                SELECT first_name, last_name, salary, department_id
+                                     , MEDIAN(salary) OVER () median_salary
+                                  FROM hr.employees
+                               ) e
+                          JOIN hr.departments d
+                            ON d.department_id = e.department_id
+                         WHERE salary = median_salary
+                      ORDER BY department_name, last_name, first_name
+                    ;
+                    DBMS_SQL.RETURN_RESULT(c1);
+                    DBMS_SQL.RETURN_RESULT(c2);
+                END;
+            ").await?;
+            stmt.execute(()).await?;
+            assert_eq!(stmt.implicit_result_count()?, 2);
+
+            let mut results = pin!(stmt.result_sets());
+            let mut employees = Vec::new();
+            while let Some(cursor) = poll_fn(|cx| results.as_mut().poll_next(cx)).await {
+                let cursor = cursor?;
+                let rows = cursor.rows().await?;
+                let mut names = Vec::new();
+                while let Some(row) = rows.next().await? {
+                    let first_name : &str = row.get(1)?;
+                    let last_name : &str = row.get(2)?;
+                    names.push(format!("{} {}", first_name, last_name));
+                }
+                employees.push(names);
+            }
+
+            assert_eq!(employees.len(), 2);
+            assert_eq!(employees[0], ["TJ Olson"]);
+            assert_eq!(employees[1], ["Amit Banda", "Charles Johnson"]);
+
+            assert!(stmt.next_result().await?.is_none());
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn ref_cursor_column() -> Result<()> {
         block_on(async {
@@ -1414,6 +2210,53 @@ mod nonblocking {
         })
     }
 
+    #[test]
+    fn ref_cursor_column_stream() -> Result<()> {
+        use std::{future::poll_fn, pin::pin};
+        use futures_core::Stream;
+
+        block_on(async {
+            let session = sibyl::test_env::get_session().await?;
+            let stmt = session.prepare("
+                SELECT last_name
+                     , CURSOR(
+                            SELECT department_name
+                              FROM hr.departments
+                             WHERE department_id IN (
+                                        SELECT department_id
+                                          FROM hr.employees
+                                         WHERE last_name = e.last_name)
+                          ORDER BY department_name
+                       ) AS departments
+                  FROM (
+                        SELECT distinct last_name
+                          FROM hr.employees
+                         WHERE last_name IN ('Grant', 'King')
+                       ) e
+              ORDER BY last_name
+            ").await?;
+            let rows = stmt.query(()).await?;
+            let mut res = Vec::new();
+            while let Some(row) = rows.next().await? {
+                let last_name : String = row.get(0)?;
+                let departments : Cursor = row.get(1)?;
+                let mut departments = pin!(departments.into_stream::<(String,)>());
+                let mut department_names = Vec::new();
+                while let Some(dept) = poll_fn(|cx| departments.as_mut().poll_next(cx)).await {
+                    let (name,) = dept?;
+                    department_names.push(name);
+                }
+                res.push((last_name, department_names));
+            }
+            assert_eq!(res, [
+                ("Grant".to_string(), vec!["Shipping".to_string()]),
+                ("King".to_string(), vec!["Executive".to_string(), "Sales".to_string()]),
+            ]);
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn large_object_datatypes() -> Result<()> {
         block_on(async {
@@ -55,4 +55,52 @@ mod tests {
         
         Ok(())
     }
+
+    #[test]
+    fn bind_map() -> Result<()> {
+        use std::collections::HashMap;
+
+        let session = get_session()?;
+
+        let dept = 50;
+        let job = String::from("ST_CLERK");
+        let hired_after = Date::from_string("January 1, 2006", "MONTH DD, YYYY", &session)?;
+
+        let mut filters : HashMap<&str, &dyn ToSqlDyn> = HashMap::new();
+        filters.insert("department_id", &dept);
+        filters.insert("job_id", &job);
+        filters.insert("hire_date", &hired_after);
+
+        // Each run adds one more filter to the ones that are already applied
+        let columns = ["department_id", "job_id", "hire_date"];
+        let mut counts = Vec::new();
+        for num_filters in 1..=columns.len() {
+            let mut sql = String::from("SELECT Count(*) FROM hr.employees WHERE 1 = 1");
+            let mut args = BindMap::new();
+            for &name in columns[..num_filters].iter() {
+                let op = if name == "hire_date" { ">=" } else { "=" };
+                sql.push_str(&format!(" AND {} {} :{}", name, op, name));
+                args.insert(name, filters[name]);
+            }
+            assert_eq!(args.len(), num_filters);
+            let stmt = session.prepare(&sql)?;
+            let count : u32 = stmt.query_scalar(&args)?;
+            counts.push(count);
+        }
+        assert_eq!(counts[0], 45);
+        assert_eq!(counts[1], 20);
+        assert!(counts[2] <= counts[1]);
+
+        let stmt = session.prepare("
+            SELECT Count(*)
+              FROM hr.employees
+             WHERE department_id = :dept
+               AND job_id = :job
+        ")?;
+        let args : &[(&str, &dyn ToSqlDyn)] = &[(":JOB", &job), (":DEPT", &dept)];
+        let count : u32 = stmt.query_scalar(args)?;
+        assert_eq!(count, 20);
+
+        Ok(())
+    }
 }
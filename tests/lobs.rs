@@ -392,6 +392,307 @@ mod blocking {
         }
         Ok(())
     }
+
+    #[test]
+    fn read_clob_chunks() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+
+        // ~3MB of text with 2- and 3-byte UTF-8 characters and a supplementary character
+        let text = "Ecchoing Green, tête-à-tête, “quoted”, 🦀\n".repeat(64 * 1024);
+
+        let lob = CLOB::temp(&session, CharSetForm::Implicit, Cache::No)?;
+        lob.write(0, &text)?;
+        let lob_len = lob.len()?;
+
+        let mut lob_content = String::new();
+        let mut num_pieces = 0;
+        let mut num_piece_chars = 0;
+        let num_chars = lob.read_chunks(0, |piece, num_chars| {
+            num_pieces += 1;
+            num_piece_chars += num_chars;
+            lob_content.push_str(piece);
+        })?;
+        assert!(num_pieces > 1);
+        assert_eq!(num_chars, lob_len);
+        assert_eq!(num_piece_chars, lob_len);
+        assert_eq!(lob_content, text);
+
+        Ok(())
+    }
+    fn round_trips(session: &Session) -> Result<u64> {
+        let stmt = session.prepare("
+            SELECT m.value
+              FROM v$mystat m
+              JOIN v$statname n ON n.statistic# = m.statistic#
+             WHERE n.name = 'SQL*Net roundtrips to/from client'
+        ")?;
+        let row = stmt.query_single(())?.expect("one row");
+        row.get(0)
+    }
+
+    fn read_small_clobs(session: &Session, lob_prefetch_size: u32) -> Result<u64> {
+        let mut stmt = session.prepare("SELECT text FROM test_lob_prefetch ORDER BY id")?;
+        stmt.set_prefetch_rows(100)?;
+        stmt.set_lob_prefetch_size(lob_prefetch_size);
+
+        let start = round_trips(session)?;
+        let rows = stmt.query(())?;
+        let mut num_rows = 0;
+        while let Some(row) = rows.next()? {
+            let lob : CLOB = row.get(0)?;
+            let mut text = String::new();
+            lob.read(0, 100, &mut text)?;
+            num_rows += 1;
+            assert_eq!(text, format!("small text {}", num_rows));
+        }
+        assert_eq!(num_rows, 50);
+        let end = round_trips(session)?;
+        Ok(end - start)
+    }
+
+    #[test]
+    fn lob_prefetch() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_lob_prefetch (
+                        id      NUMBER PRIMARY KEY,
+                        text    CLOB
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_lob_prefetch
+                    ';
+            END;
+        ")?;
+        stmt.execute(())?;
+        let stmt = session.prepare("
+            INSERT INTO test_lob_prefetch (id, text)
+            SELECT level, To_Clob('small text ' || level)
+              FROM dual
+           CONNECT BY level <= 50
+        ")?;
+        stmt.execute(())?;
+        session.commit()?;
+
+        let without_prefetch = read_small_clobs(&session, 0)?;
+        let with_prefetch = read_small_clobs(&session, 1024)?;
+        assert!(with_prefetch < without_prefetch, "{} round-trips with LOB prefetch vs {} without", with_prefetch, without_prefetch);
+
+        Ok(())
+    }
+    #[test]
+    fn read_only_lob() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+
+        let lob = CLOB::temp(&session, CharSetForm::Implicit, Cache::No)?;
+        lob.write(0, "Ecchoing Green")?;
+        assert!(!lob.is_readonly());
+
+        lob.open_readonly()?;
+        assert!(lob.is_readonly());
+
+        let res = lob.write(0, "Echoing");
+        assert!(matches!(res, Err(Error::ReadOnlyLob)), "unexpected result {:?}", res);
+        let res = lob.append(" Valley");
+        assert!(matches!(res, Err(Error::ReadOnlyLob)), "unexpected result {:?}", res);
+        let res = lob.trim(0);
+        assert!(matches!(res, Err(Error::ReadOnlyLob)), "unexpected result {:?}", res);
+
+        let mut text = String::new();
+        lob.read(0, 100, &mut text)?;
+        assert_eq!(text, "Ecchoing Green");
+
+        lob.close()?;
+        assert!(!lob.is_readonly());
+        lob.append(" Valley")?;
+        assert_eq!(lob.len()?, 21);
+
+        Ok(())
+    }
+
+    #[test]
+    fn take_lob() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        check_or_create_test_table(&session)?;
+
+        let stmt = session.prepare("INSERT INTO test_large_object_data (bin) VALUES (:BIN) RETURNING id INTO :ID")?;
+        let mut ids = [0usize; 2];
+        stmt.execute((&[1u8, 2, 3][..], &mut ids[0], ()))?;
+        stmt.execute((&[4u8, 5, 6, 7][..], &mut ids[1], ()))?;
+
+        let stmt = session.prepare("SELECT bin FROM test_large_object_data WHERE id IN (:ID1, :ID2) ORDER BY id")?;
+        let rows = stmt.query((ids[0], ids[1], ()))?;
+        let mut lobs : Vec<BLOB> = Vec::new();
+        while let Some(row) = rows.next()? {
+            let lob = row.take_lob(0)?;
+            // the locator has been moved out of the row
+            let res : Result<BLOB> = row.take_lob(0);
+            assert!(res.is_err());
+            lobs.push(lob);
+        }
+        assert_eq!(lobs.len(), 2);
+
+        let mut data = Vec::new();
+        lobs[0].read(0, 100, &mut data)?;
+        assert_eq!(data, [1, 2, 3]);
+        data.clear();
+        lobs[1].read(0, 100, &mut data)?;
+        assert_eq!(data, [4, 5, 6, 7]);
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    #[test]
+    fn buffered_writes() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        check_or_create_test_table(&session)?;
+
+        let stmt = session.prepare("INSERT INTO test_large_object_data (bin) VALUES (Empty_Blob()) RETURNING id INTO :ID")?;
+        let mut id = 0usize;
+        stmt.execute(&mut id)?;
+
+        let stmt = session.prepare("SELECT bin FROM test_large_object_data WHERE id = :ID FOR UPDATE")?;
+        let row = stmt.query_single(&id)?.expect("one row");
+        let lob : BLOB = row.get(0)?;
+
+        lob.enable_buffering()?;
+        let piece = [0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut offset = 0;
+        for _ in 0..1000 {
+            offset += lob.write(offset, &piece)?;
+        }
+        assert_eq!(offset, 10_000);
+        lob.flush_buffer()?;
+        lob.disable_buffering()?;
+
+        let stmt = session.prepare("SELECT bin FROM test_large_object_data WHERE id = :ID")?;
+        let row = stmt.query_single(&id)?.expect("one row");
+        let lob : BLOB = row.get(0)?;
+        assert_eq!(lob.len()?, 10_000);
+        let mut data = Vec::new();
+        lob.read(9_990, 10, &mut data)?;
+        assert_eq!(data, piece);
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    #[test]
+    fn bind_large_text() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        check_or_create_test_table(&session)?;
+
+        // larger than the VARCHAR2 bind limit
+        let text = "Lorem ipsum dolor sit amet, consectetur adipiscing elit. ".repeat(1800);
+        assert!(text.len() > 100_000);
+
+        let data = text.as_bytes().to_vec();
+
+        let stmt = session.prepare("INSERT INTO test_large_object_data (text, bin) VALUES (:TEXT, :BIN) RETURNING id INTO :ID")?;
+        let mut id = 0usize;
+        let count = stmt.execute((LobArg::text(&text), LobArg::binary(&data), &mut id, ()))?;
+        assert_eq!(count, 1);
+
+        let stmt = session.prepare("SELECT text, bin FROM test_large_object_data WHERE id = :ID")?;
+        let row = stmt.query_single(&id)?.expect("one row");
+        let lob : CLOB = row.get(0)?;
+        assert_eq!(lob.len()?, text.len());
+        let mut content = String::new();
+        lob.read(0, text.len(), &mut content)?;
+        assert_eq!(content, text);
+        let lob : BLOB = row.get(1)?;
+        let mut content = Vec::new();
+        lob.read(0, data.len(), &mut content)?;
+        assert_eq!(content, data);
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    #[test]
+    fn bind_nclob() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        check_or_create_test_table(&session)?;
+
+        let text = "Привет, 世界! ∑ ≠ ∞ ".to_string();
+        let stmt = session.prepare("INSERT INTO test_large_object_data (ntxt) VALUES (:NTXT) RETURNING id INTO :ID")?;
+
+        let lob = CLOB::temp(&session, CharSetForm::NChar, Cache::No)?;
+        lob.append(&text)?;
+        let mut id1 = 0usize;
+        stmt.execute((&lob, &mut id1, ()))?;
+
+        // bound as a temporary NCLOB
+        let large_text = text.repeat(2000);
+        let mut id2 = 0usize;
+        stmt.execute((LobArg::ntext(&large_text), &mut id2, ()))?;
+
+        let stmt = session.prepare("SELECT ntxt FROM test_large_object_data WHERE id = :ID")?;
+        for (id, expected) in [(id1, &text), (id2, &large_text)] {
+            let row = stmt.query_single(id)?.expect("one row");
+            let lob : CLOB = row.get(0)?;
+            assert!(matches!(lob.charset_form()?, CharSetForm::NChar));
+            let mut content = String::new();
+            lob.read(0, lob.len()?, &mut content)?;
+            assert_eq!(&content, expected);
+        }
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    fn temp_lob_count(session: &Session) -> Result<u64> {
+        let stmt = session.prepare("
+            SELECT Nvl(Sum(cache_lobs + nocache_lobs + abstract_lobs), 0)
+              FROM v$temporary_lobs
+             WHERE sid = Sys_Context('USERENV', 'SID')
+        ")?;
+        let row = stmt.query_single(())?.expect("one row");
+        row.get(0)
+    }
+
+    #[test]
+    fn temp_lobs_are_freed() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        let baseline = temp_lob_count(&session)?;
+
+        for i in 0..2000 {
+            let lob = CLOB::temp(&session, CharSetForm::Implicit, Cache::No)?;
+            lob.append("Lorem ipsum dolor sit amet")?;
+            if i % 2 == 0 {
+                lob.open()?;
+            }
+        }
+        assert_eq!(temp_lob_count(&session)?, baseline);
+
+        // temporary LOBs that are created by the server
+        let stmt = session.prepare("SELECT To_Clob(:TXT) FROM dual")?;
+        for i in 0..1000 {
+            let row = stmt.query_single(format!("text {}", i))?.expect("one row");
+            let lob : CLOB = row.get(0)?;
+            assert_eq!(lob.len()?, format!("text {}", i).len());
+        }
+        assert_eq!(temp_lob_count(&session)?, baseline);
+
+        // temporary LOBs that are returned via OUT parameters
+        let stmt = session.prepare("BEGIN :LOB := To_Clob('text'); END;")?;
+        for _ in 0..1000 {
+            let mut lob = CLOB::new(&session)?;
+            stmt.execute(&mut lob)?;
+            assert_eq!(lob.len()?, 4);
+        }
+        assert_eq!(temp_lob_count(&session)?, baseline);
+
+        Ok(())
+    }
 }
 
 #[cfg(feature="nonblocking")]
@@ -645,6 +946,138 @@ mod nonblocking {
         })
     }
 
+    #[test]
+    fn read_clob_chunks() -> Result<()> {
+        block_on(async {
+            let session = sibyl::test_env::get_session().await?;
+
+            // ~3MB of text with 2- and 3-byte UTF-8 characters and a supplementary character
+            let text = "Ecchoing Green, tête-à-tête, “quoted”, 🦀\n".repeat(64 * 1024);
+
+            let lob = CLOB::temp(&session, CharSetForm::Implicit, Cache::No).await?;
+            lob.write(0, &text).await?;
+            let lob_len = lob.len().await?;
+
+            let mut lob_content = String::new();
+            let mut num_pieces = 0;
+            let mut num_piece_chars = 0;
+            let num_chars = lob.read_chunks(0, |piece, num_chars| {
+                num_pieces += 1;
+                num_piece_chars += num_chars;
+                lob_content.push_str(piece);
+            }).await?;
+            assert!(num_pieces > 1);
+            assert_eq!(num_chars, lob_len);
+            assert_eq!(num_piece_chars, lob_len);
+            assert_eq!(lob_content, text);
+
+            Ok(())
+        })
+    }
+
+    async fn round_trips(session: &Session<'_>) -> Result<u64> {
+        let stmt = session.prepare("
+            SELECT m.value
+              FROM v$mystat m
+              JOIN v$statname n ON n.statistic# = m.statistic#
+             WHERE n.name = 'SQL*Net roundtrips to/from client'
+        ").await?;
+        let row = stmt.query_single(()).await?.expect("one row");
+        row.get(0)
+    }
+
+    async fn read_small_clobs(session: &Session<'_>, lob_prefetch_size: u32) -> Result<u64> {
+        let mut stmt = session.prepare("SELECT text FROM test_lob_prefetch ORDER BY id").await?;
+        stmt.set_prefetch_rows(100)?;
+        stmt.set_lob_prefetch_size(lob_prefetch_size);
+
+        let start = round_trips(session).await?;
+        let rows = stmt.query(()).await?;
+        let mut num_rows = 0;
+        while let Some(row) = rows.next().await? {
+            let lob : CLOB = row.get(0)?;
+            let mut text = String::new();
+            lob.read(0, 100, &mut text).await?;
+            num_rows += 1;
+            assert_eq!(text, format!("small text {}", num_rows));
+        }
+        assert_eq!(num_rows, 50);
+        let end = round_trips(session).await?;
+        Ok(end - start)
+    }
+
+    #[test]
+    fn lob_prefetch() -> Result<()> {
+        block_on(async {
+            let session = sibyl::test_env::get_session().await?;
+
+            let stmt = session.prepare("
+                DECLARE
+                    name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+                BEGIN
+                    EXECUTE IMMEDIATE '
+                        CREATE TABLE test_lob_prefetch (
+                            id      NUMBER PRIMARY KEY,
+                            text    CLOB
+                        )
+                    ';
+                EXCEPTION
+                    WHEN name_already_used THEN
+                        EXECUTE IMMEDIATE '
+                            TRUNCATE TABLE test_lob_prefetch
+                        ';
+                END;
+            ").await?;
+            stmt.execute(()).await?;
+            let stmt = session.prepare("
+                INSERT INTO test_lob_prefetch (id, text)
+                SELECT level, To_Clob('small text ' || level)
+                  FROM dual
+               CONNECT BY level <= 50
+            ").await?;
+            stmt.execute(()).await?;
+            session.commit().await?;
+
+            let without_prefetch = read_small_clobs(&session, 0).await?;
+            let with_prefetch = read_small_clobs(&session, 1024).await?;
+            assert!(with_prefetch < without_prefetch, "{} round-trips with LOB prefetch vs {} without", with_prefetch, without_prefetch);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn read_only_lob() -> Result<()> {
+        block_on(async {
+            let session = sibyl::test_env::get_session().await?;
+
+            let lob = CLOB::temp(&session, CharSetForm::Implicit, Cache::No).await?;
+            lob.write(0, "Ecchoing Green").await?;
+            assert!(!lob.is_readonly());
+
+            lob.open_readonly().await?;
+            assert!(lob.is_readonly());
+
+            let res = lob.write(0, "Echoing").await;
+            assert!(matches!(res, Err(Error::ReadOnlyLob)), "unexpected result {:?}", res);
+            let res = lob.append(" Valley").await;
+            assert!(matches!(res, Err(Error::ReadOnlyLob)), "unexpected result {:?}", res);
+            let res = lob.trim(0).await;
+            assert!(matches!(res, Err(Error::ReadOnlyLob)), "unexpected result {:?}", res);
+
+            let mut text = String::new();
+            lob.read(0, 100, &mut text).await?;
+            assert_eq!(text, "Ecchoing Green");
+
+            lob.close().await?;
+            assert!(!lob.is_readonly());
+            lob.append(" Valley").await?;
+            assert_eq!(lob.len().await?, 21);
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn temp_blob_api() -> Result<()> {
         block_on(async {
@@ -202,6 +202,49 @@ mod tests {
 
         Ok(())
     }
+
+    /**
+        Creates a session in one thread, moves it into another thread
+        and then uses it again after it was returned back.
+    */
+    #[test]
+    fn session_moved_to_thread() -> Result<()> {
+        use once_cell::sync::OnceCell;
+
+        static ORACLE : OnceCell<Environment> = OnceCell::new();
+        let oracle = ORACLE.get_or_try_init(|| {
+            env()
+        })?;
+
+        let dbname = env::var("DBNAME").expect("database name");
+        let dbuser = env::var("DBUSER").expect("user name");
+        let dbpass = env::var("DBPASS").expect("password");
+
+        let session = oracle.connect(&dbname, &dbuser, &dbpass)?;
+        let handle = thread::spawn(move || -> (Session<'static>, String) {
+            let name = {
+                let stmt = session.prepare("
+                    SELECT first_name, last_name, hire_date
+                      FROM (
+                            SELECT first_name, last_name, hire_date
+                                 , Row_Number() OVER (ORDER BY hire_date DESC, last_name) AS hire_date_rank
+                              FROM hr.employees
+                           )
+                     WHERE hire_date_rank = 1
+                ").expect("prepared select");
+                fetch_latest_hire(stmt).expect("selected employee name")
+            };
+            (session, name)
+        });
+        let (session, name) = handle.join().expect("select result");
+        assert_eq!(name, "Amit Banda was hired on April 21, 2008");
+
+        let stmt = session.prepare("SELECT Count(*) FROM hr.employees")?;
+        let num_employees : u32 = stmt.query_scalar(())?;
+        assert_eq!(num_employees, 107);
+
+        Ok(())
+    }
 }
 
 #[cfg(feature="nonblocking")]
@@ -257,6 +300,50 @@ mod tests {
         })
     }
 
+    /**
+        Moves a session into a spawned task, which holds it (and statements
+        that borrow it) across `.await` points.
+    */
+    #[test]
+    fn session_in_spawned_task() -> Result<()> {
+        block_on(async {
+            use once_cell::sync::OnceCell;
+
+            static ORACLE : OnceCell<Environment> = OnceCell::new();
+            let oracle = ORACLE.get_or_try_init(|| {
+                sibyl::env()
+            })?;
+
+            let dbname = env::var("DBNAME").expect("database name");
+            let dbuser = env::var("DBUSER").expect("user name");
+            let dbpass = env::var("DBPASS").expect("password");
+
+            let session = oracle.connect(&dbname, &dbuser, &dbpass).await?;
+            let handle = spawn(async move {
+                let stmt = session.prepare("
+                    SELECT first_name, last_name, hire_date
+                      FROM (
+                            SELECT first_name, last_name, hire_date
+                                 , Row_Number() OVER (ORDER BY hire_date DESC, last_name) AS hire_date_rank
+                              FROM hr.employees
+                           )
+                     WHERE hire_date_rank = 1
+                ").await?;
+                let name = fetch_latest_hire(stmt).await?;
+                session.ping().await?;
+                Ok::<_,Error>(name)
+            });
+            let worker_result = handle.await;
+            #[cfg(any(feature="tokio", feature="actix"))]
+            let worker_result = worker_result.expect("completed task result");
+
+            let name = worker_result?;
+            assert_eq!(name, "Amit Banda was hired on April 21, 2008");
+
+            Ok(())
+        })
+    }
+
     async fn fetch_latest_hire(stmt: Statement<'_>) -> Result<String> {
         if let Some( row ) = stmt.query_single(()).await? {
             let first_name : Option<&str> = row.get(0)?;
@@ -0,0 +1,592 @@
+#[cfg(feature="blocking")]
+mod blocking {
+    use sibyl::*;
+
+    #[test]
+    fn close() -> Result<()> {
+        let oracle = sibyl::env()?;
+        let dbname = std::env::var("DBNAME").expect("database name");
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        let dbpass = std::env::var("DBPASS").expect("password");
+
+        let session = oracle.connect(&dbname, &dbuser, &dbpass)?;
+        session.ping()?;
+        session.close()?;
+
+        let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 1)?;
+        let session = pool.get_session()?;
+        session.ping()?;
+        session.close()?;
+        assert_eq!(pool.busy_count()?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn close_killed_session() -> Result<()> {
+        let oracle = sibyl::env()?;
+        let dbname = std::env::var("DBNAME").expect("database name");
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        let dbpass = std::env::var("DBPASS").expect("password");
+
+        let session = oracle.connect(&dbname, &dbuser, &dbpass)?;
+        let stmt = session.prepare("
+            SELECT sid, serial#
+              FROM v$session
+             WHERE sid = Sys_Context('USERENV', 'SID')
+        ")?;
+        let row = stmt.query_single(())?.unwrap();
+        let sid : u32 = row.get(0)?;
+        let serial : u32 = row.get(1)?;
+        drop(row);
+        drop(stmt);
+
+        let admin = oracle.connect(&dbname, &dbuser, &dbpass)?;
+        let stmt = admin.prepare(&format!("ALTER SYSTEM KILL SESSION '{},{}' IMMEDIATE", sid, serial))?;
+        stmt.execute(())?;
+
+        let res = session.close();
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn transaction() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_transactions (
+                        id      NUMBER PRIMARY KEY,
+                        name    VARCHAR2(100) NOT NULL
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_transactions
+                    ';
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let id = session.transaction(|session| {
+            let stmt = session.prepare("INSERT INTO test_transactions (id, name) VALUES (:id, :name)")?;
+            stmt.execute((1, "committed", ()))?;
+            Ok(1)
+        })?;
+        assert_eq!(id, 1);
+
+        let res : Result<()> = session.transaction(|session| {
+            let stmt = session.prepare("INSERT INTO test_transactions (id, name) VALUES (:id, :name)")?;
+            stmt.execute((2, "rolled back", ()))?;
+            Err(Error::Interface("abort".to_string()))
+        });
+        assert!(matches!(res, Err(Error::Interface(msg)) if msg == "abort"));
+
+        let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            session.transaction(|session| -> Result<()> {
+                let stmt = session.prepare("INSERT INTO test_transactions (id, name) VALUES (:id, :name)")?;
+                stmt.execute((3, "panicked", ()))?;
+                panic!("abort");
+            })
+        }));
+        assert!(res.is_err());
+
+        // verify the outcome from another session
+        let other = sibyl::test_env::get_session()?;
+        let stmt = other.prepare("SELECT id, name FROM test_transactions ORDER BY id")?;
+        let rows = stmt.query(())?;
+        let row = rows.next()?.expect("committed row");
+        let id : u32 = row.get(0)?;
+        let name : &str = row.get(1)?;
+        assert_eq!(id, 1);
+        assert_eq!(name, "committed");
+        assert!(rows.next()?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn one_shot_statements() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        session.execute("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_one_shot_statements (
+                        id      NUMBER PRIMARY KEY,
+                        name    VARCHAR2(100) NOT NULL
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN
+                    EXECUTE IMMEDIATE '
+                        TRUNCATE TABLE test_one_shot_statements
+                    ';
+            END;
+        ", ())?;
+
+        let num_rows = session.execute("INSERT INTO test_one_shot_statements (id, name) VALUES (:id, :name)", (1, "one", ()))?;
+        assert_eq!(num_rows, 1);
+
+        let rows = session.query("SELECT id, name FROM test_one_shot_statements WHERE id = :id", 1)?;
+        let row = rows.next()?.expect("inserted row");
+        let id : u32 = row.get(0)?;
+        let name : &str = row.get(1)?;
+        assert_eq!(id, 1);
+        assert_eq!(name, "one");
+        assert!(rows.next()?.is_none());
+
+        let res = session.execute("SELECT id FROM test_one_shot_statements", ());
+        assert!(res.is_err());
+
+        session.rollback()?;
+        Ok(())
+    }
+
+    #[test]
+    fn active_handle_counts() -> Result<()> {
+        let oracle = sibyl::env()?;
+        let dbname = std::env::var("DBNAME").expect("database name");
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        let dbpass = std::env::var("DBPASS").expect("password");
+
+        let baseline = oracle.active_handle_counts();
+        assert_eq!(baseline, HandleCounts::default());
+        {
+            let session = oracle.connect(&dbname, &dbuser, &dbpass)?;
+            let stmt = session.prepare("SELECT Empty_Blob(), Empty_Clob() FROM dual")?;
+            let row = stmt.query_single(())?.unwrap();
+            let blob : BLOB = row.get(0)?;
+            let clob : CLOB = row.get(1)?;
+            let temp = CLOB::temp(&session, CharSetForm::Implicit, Cache::No)?;
+
+            let counts = oracle.active_handle_counts();
+            assert_eq!(counts.sessions, 1);
+            assert_eq!(counts.statements, 1);
+            assert_eq!(counts.lobs, 3);
+
+            drop(temp);
+            drop(clob);
+            drop(blob);
+            drop(row);
+            drop(stmt);
+            let counts = oracle.active_handle_counts();
+            assert_eq!(counts.sessions, 1);
+            assert_eq!(counts.statements, 0);
+            assert_eq!(counts.lobs, 0);
+        }
+        assert_eq!(oracle.active_handle_counts(), baseline);
+
+        let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 1)?;
+        {
+            let session = pool.get_session()?;
+            let _stmt = session.prepare("SELECT * FROM dual")?;
+            let counts = oracle.active_handle_counts();
+            assert_eq!(counts.sessions, 1);
+            assert_eq!(counts.statements, 1);
+        }
+        assert_eq!(oracle.active_handle_counts(), baseline);
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_clone() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        session.set_nls_date_format("YYYY-MM-DD")?;
+        let other = session.try_clone()?;
+        assert_eq!(other.nls("NLS_DATE_FORMAT").as_deref(), Some("YYYY-MM-DD"));
+
+        let sid_sql = "SELECT Sys_Context('USERENV', 'SID') FROM dual";
+        let (sid, other_sid) = std::thread::scope(|s| -> Result<(u32,u32)> {
+            let handle = s.spawn(|| -> Result<u32> {
+                let stmt = other.prepare(sid_sql)?;
+                let row = stmt.query_single(())?.expect("one row");
+                row.get(0)
+            });
+            let stmt = session.prepare(sid_sql)?;
+            let row = stmt.query_single(())?.expect("one row");
+            let sid : u32 = row.get(0)?;
+            let other_sid = handle.join().expect("thread result")?;
+            Ok((sid, other_sid))
+        })?;
+        assert_ne!(sid, other_sid);
+
+        let stmt = other.prepare("SELECT To_Char(To_Date('2024-03-15','YYYY-MM-DD')) FROM dual")?;
+        let row = stmt.query_single(())?.expect("one row");
+        let date : &str = row.get(0)?;
+        assert_eq!(date, "2024-03-15");
+        Ok(())
+    }
+
+    #[test]
+    fn oci_handles() -> Result<()> {
+        use std::ffi::c_void;
+
+        extern "C" {
+            fn OCIPing(svchp: *mut c_void, errhp: *mut c_void, mode: u32) -> i32;
+        }
+
+        let session = sibyl::test_env::get_session()?;
+        let res = unsafe {
+            session.with_oci_handles(|svc, err, env| {
+                assert!(!svc.is_null());
+                assert!(!err.is_null());
+                assert!(!env.is_null());
+                OCIPing(svc, err, 0)
+            })
+        };
+        assert_eq!(res, 0);
+
+        // the session is still usable after the OCI call that was made outside the crate
+        session.ping()?;
+        Ok(())
+    }
+    #[test]
+    fn edition() -> Result<()> {
+        // Tests a custom edition if one is provided. Otherwise uses the base edition that always exists.
+        let edition = std::env::var("DBEDITION").unwrap_or_else(|_| "ORA$BASE".to_string());
+
+        let session = sibyl::test_env::get_session()?;
+        session.set_edition(&edition)?;
+
+        let stmt = session.prepare("SELECT Sys_Context('USERENV', 'CURRENT_EDITION_NAME') FROM dual")?;
+        let row = stmt.query_single(())?.expect("one row");
+        let current_edition : &str = row.get(0)?;
+        assert_eq!(current_edition, edition.to_uppercase());
+
+        assert!(session.set_edition("ORA$BASE; DROP TABLE x").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn connect_from_env() -> Result<()> {
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        let oracle = sibyl::env()?;
+
+        let session = oracle.connect_from_env()?;
+        let stmt = session.prepare("SELECT Sys_Context('USERENV', 'SESSION_USER') FROM dual")?;
+        let user : String = stmt.query_scalar(())?;
+        assert_eq!(user, dbuser.to_uppercase());
+
+        let pool = oracle.create_session_pool_from_env(0, 1, 1)?;
+        let session = pool.get_session()?;
+        session.ping()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature="nonblocking")]
+mod nonblocking {
+    use sibyl::*;
+
+    #[test]
+    fn transaction() -> Result<()> {
+        block_on(async {
+            let session = sibyl::test_env::get_session().await?;
+            let stmt = session.prepare("
+                DECLARE
+                    name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+                BEGIN
+                    EXECUTE IMMEDIATE '
+                        CREATE TABLE test_async_transactions (
+                            id      NUMBER PRIMARY KEY,
+                            name    VARCHAR2(100) NOT NULL
+                        )
+                    ';
+                EXCEPTION
+                    WHEN name_already_used THEN
+                        EXECUTE IMMEDIATE '
+                            TRUNCATE TABLE test_async_transactions
+                        ';
+                END;
+            ").await?;
+            stmt.execute(()).await?;
+
+            let id = session.transaction(|session| async move {
+                let stmt = session.prepare("INSERT INTO test_async_transactions (id, name) VALUES (:id, :name)").await?;
+                stmt.execute((1, "committed", ())).await?;
+                Ok(1)
+            }).await?;
+            assert_eq!(id, 1);
+
+            let res : Result<()> = session.transaction(|session| async move {
+                let stmt = session.prepare("INSERT INTO test_async_transactions (id, name) VALUES (:id, :name)").await?;
+                stmt.execute((2, "rolled back", ())).await?;
+                Err(Error::Interface("abort".to_string()))
+            }).await;
+            assert!(matches!(res, Err(Error::Interface(msg)) if msg == "abort"));
+
+            // verify the outcome from another session
+            let other = sibyl::test_env::get_session().await?;
+            let stmt = other.prepare("SELECT id, name FROM test_async_transactions ORDER BY id").await?;
+            let rows = stmt.query(()).await?;
+            let row = rows.next().await?.expect("committed row");
+            let id : u32 = row.get(0)?;
+            let name : &str = row.get(1)?;
+            assert_eq!(id, 1);
+            assert_eq!(name, "committed");
+            assert!(rows.next().await?.is_none());
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn abandoned_transaction() -> Result<()> {
+        use std::{future::Future, sync::atomic::{AtomicBool, Ordering}, task::Poll};
+
+        block_on(async {
+            let session = sibyl::test_env::get_session().await?;
+            let stmt = session.prepare("
+                DECLARE
+                    name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+                BEGIN
+                    EXECUTE IMMEDIATE '
+                        CREATE TABLE test_async_abandoned_transactions (
+                            id      NUMBER PRIMARY KEY,
+                            name    VARCHAR2(100) NOT NULL
+                        )
+                    ';
+                EXCEPTION
+                    WHEN name_already_used THEN
+                        EXECUTE IMMEDIATE '
+                            TRUNCATE TABLE test_async_abandoned_transactions
+                        ';
+                END;
+            ").await?;
+            stmt.execute(()).await?;
+
+            let inserted = AtomicBool::new(false);
+            let mut txn = Box::pin(session.transaction(|session| {
+                let inserted = &inserted;
+                async move {
+                    let stmt = session.prepare("INSERT INTO test_async_abandoned_transactions (id, name) VALUES (:id, :name)").await?;
+                    stmt.execute((1, "abandoned", ())).await?;
+                    inserted.store(true, Ordering::Release);
+                    std::future::pending::<Result<()>>().await
+                }
+            }));
+            // drive the transaction until it has inserted the row and then abandon it
+            std::future::poll_fn(|cx| {
+                assert!(txn.as_mut().poll(cx).is_pending());
+                if inserted.load(Ordering::Acquire) { Poll::Ready(()) } else { Poll::Pending }
+            }).await;
+            drop(txn);
+
+            // The abandoned transaction is rolled back before this insert is executed.
+            // Thus the rollback does not undo it.
+            let stmt = session.prepare("INSERT INTO test_async_abandoned_transactions (id, name) VALUES (:id, :name)").await?;
+            stmt.execute((2, "committed", ())).await?;
+            session.commit().await?;
+
+            let other = sibyl::test_env::get_session().await?;
+            let stmt = other.prepare("SELECT id FROM test_async_abandoned_transactions ORDER BY id").await?;
+            let rows = stmt.query(()).await?;
+            let row = rows.next().await?.expect("committed row");
+            let id : u32 = row.get(0)?;
+            assert_eq!(id, 2);
+            assert!(rows.next().await?.is_none());
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn one_shot_statements() -> Result<()> {
+        block_on(async {
+            let session = sibyl::test_env::get_session().await?;
+            session.execute("
+                DECLARE
+                    name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+                BEGIN
+                    EXECUTE IMMEDIATE '
+                        CREATE TABLE test_async_one_shot_statements (
+                            id      NUMBER PRIMARY KEY,
+                            name    VARCHAR2(100) NOT NULL
+                        )
+                    ';
+                EXCEPTION
+                    WHEN name_already_used THEN
+                        EXECUTE IMMEDIATE '
+                            TRUNCATE TABLE test_async_one_shot_statements
+                        ';
+                END;
+            ", ()).await?;
+
+            let num_rows = session.execute("INSERT INTO test_async_one_shot_statements (id, name) VALUES (:id, :name)", (1, "one", ())).await?;
+            assert_eq!(num_rows, 1);
+
+            let rows = session.query("SELECT id, name FROM test_async_one_shot_statements WHERE id = :id", 1).await?;
+            let row = rows.next().await?.expect("inserted row");
+            let id : u32 = row.get(0)?;
+            let name : &str = row.get(1)?;
+            assert_eq!(id, 1);
+            assert_eq!(name, "one");
+            assert!(rows.next().await?.is_none());
+
+            session.rollback().await?;
+            Ok(())
+        })
+    }
+
+    /// Suspends the test task without blocking the runtime thread that the keepalive task might need
+    async fn sleep(duration: std::time::Duration) {
+        #[cfg(feature="tokio")]
+        tokio_rt::time::sleep(duration).await;
+        #[cfg(feature="actix")]
+        actix_rt::time::sleep(duration).await;
+        #[cfg(feature="async-std")]
+        async_rt::task::sleep(duration).await;
+        #[cfg(feature="async-global")]
+        async_io::Timer::after(duration).await;
+    }
+
+    #[test]
+    fn keepalive() -> Result<()> {
+        use std::time::Duration;
+
+        block_on(async {
+            let session = sibyl::test_env::get_session().await?;
+            let stat = session.prepare("
+                SELECT s.value
+                  FROM v$mystat s
+                  JOIN v$statname n
+                    ON n.statistic# = s.statistic#
+                 WHERE n.name = 'SQL*Net roundtrips to/from client'
+            ").await?;
+
+            // Round-trips of the statistics query itself
+            let start : u64 = stat.query_scalar(()).await?;
+            let end : u64 = stat.query_scalar(()).await?;
+            let stat_round_trips = end - start;
+
+            // idle the session past several keepalive intervals
+            let start : u64 = stat.query_scalar(()).await?;
+            session.enable_keepalive(Duration::from_millis(100));
+            sleep(Duration::from_millis(550)).await;
+            let end : u64 = stat.query_scalar(()).await?;
+            let pings = end - start - stat_round_trips;
+            assert!((3..=6).contains(&pings), "{} pings in 550ms at 100ms interval", pings);
+
+            // restarting replaces the running task
+            let start : u64 = stat.query_scalar(()).await?;
+            session.enable_keepalive(Duration::from_millis(50));
+            sleep(Duration::from_millis(275)).await;
+            let end : u64 = stat.query_scalar(()).await?;
+            let pings = end - start - stat_round_trips;
+            assert!((3..=6).contains(&pings), "{} pings in 275ms at 50ms interval", pings);
+
+            // let the task that might be already pinging finish
+            session.disable_keepalive();
+            sleep(Duration::from_millis(100)).await;
+
+            let start : u64 = stat.query_scalar(()).await?;
+            sleep(Duration::from_millis(300)).await;
+            let end : u64 = stat.query_scalar(()).await?;
+            assert_eq!(end - start, stat_round_trips, "pings after keepalive was disabled");
+
+            session.ping().await?;
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn try_clone() -> Result<()> {
+        block_on(async {
+            let session = sibyl::test_env::get_session().await?;
+            let other = session.try_clone().await?;
+
+            let sid_sql = "SELECT Sys_Context('USERENV', 'SID') FROM dual";
+            let handle = spawn(async move {
+                let stmt = other.prepare(sid_sql).await?;
+                let row = stmt.query_single(()).await?.expect("one row");
+                let sid : u32 = row.get(0)?;
+                Ok::<_,Error>(sid)
+            });
+            let stmt = session.prepare(sid_sql).await?;
+            let row = stmt.query_single(()).await?.expect("one row");
+            let sid : u32 = row.get(0)?;
+
+            let other_sid = handle.await;
+            #[cfg(any(feature="tokio", feature="actix"))]
+            let other_sid = other_sid.expect("completed task result");
+            let other_sid = other_sid?;
+            assert_ne!(sid, other_sid);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn oci_handles() -> Result<()> {
+        use std::ffi::c_void;
+
+        extern "C" {
+            fn OCIPing(svchp: *mut c_void, errhp: *mut c_void, mode: u32) -> i32;
+        }
+        const OCI_STILL_EXECUTING : i32 = -3123;
+
+        block_on(async {
+            let session = sibyl::test_env::get_session().await?;
+            let res = unsafe {
+                session.with_oci_handles(|svc, err, _env| {
+                    loop {
+                        let res = OCIPing(svc, err, 0);
+                        if res != OCI_STILL_EXECUTING {
+                            break res;
+                        }
+                        std::thread::yield_now();
+                    }
+                })
+            };
+            assert_eq!(res, 0);
+
+            session.ping().await?;
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn edition() -> Result<()> {
+        let edition = std::env::var("DBEDITION").unwrap_or_else(|_| "ORA$BASE".to_string());
+
+        block_on(async {
+            let session = sibyl::test_env::get_session().await?;
+            session.set_edition(&edition).await?;
+
+            let stmt = session.prepare("SELECT Sys_Context('USERENV', 'CURRENT_EDITION_NAME') FROM dual").await?;
+            let row = stmt.query_single(()).await?.expect("one row");
+            let current_edition : &str = row.get(0)?;
+            assert_eq!(current_edition, edition.to_uppercase());
+
+            assert!(session.set_edition("ORA$BASE; DROP TABLE x").await.is_err());
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn connect_from_env() -> Result<()> {
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        block_on(async {
+            let oracle = sibyl::env()?;
+
+            let session = oracle.connect_from_env().await?;
+            let stmt = session.prepare("SELECT Sys_Context('USERENV', 'SESSION_USER') FROM dual").await?;
+            let user : String = stmt.query_scalar(()).await?;
+            assert_eq!(user, dbuser.to_uppercase());
+
+            let pool = oracle.create_session_pool_from_env(0, 1, 1).await?;
+            let session = pool.get_session().await?;
+            session.ping().await?;
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,634 @@
+#[cfg(feature="blocking")]
+mod blocking {
+    use sibyl::*;
+
+    #[test]
+    fn validated_session() -> Result<()> {
+        let oracle = sibyl::env()?;
+        let dbname = std::env::var("DBNAME").expect("database name");
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        let dbpass = std::env::var("DBPASS").expect("password");
+
+        let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 1, 1, 2)?;
+
+        let (sid, serial) = {
+            let session = pool.get_session()?;
+            let stmt = session.prepare("
+                SELECT sid, serial#
+                  FROM v$session
+                 WHERE sid = Sys_Context('USERENV', 'SID')
+            ")?;
+            let row = stmt.query_single(())?.unwrap();
+            let sid : u32 = row.get(0)?;
+            let serial : u32 = row.get(1)?;
+            (sid, serial)
+        };
+        assert_eq!(pool.open_count()?, 1);
+
+        // Kill the pooled session from another (non-pooled) session
+        let admin = oracle.connect(&dbname, &dbuser, &dbpass)?;
+        let stmt = admin.prepare(&format!("ALTER SYSTEM KILL SESSION '{},{}' IMMEDIATE", sid, serial))?;
+        stmt.execute(())?;
+
+        let session = pool.get_session_validated()?;
+        session.ping()?;
+        let stmt = session.prepare("
+            SELECT sid, serial#
+              FROM v$session
+             WHERE sid = Sys_Context('USERENV', 'SID')
+        ")?;
+        let row = stmt.query_single(())?.unwrap();
+        let new_sid : u32 = row.get(0)?;
+        let new_serial : u32 = row.get(1)?;
+        // Oracle might reuse the SID of the killed session, but not with the same serial number
+        assert_ne!((new_sid, new_serial), (sid, serial));
+
+        Ok(())
+    }
+
+    #[test]
+    fn reconnect_lost_session() -> Result<()> {
+        let oracle = sibyl::env()?;
+        let dbname = std::env::var("DBNAME").expect("database name");
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        let dbpass = std::env::var("DBPASS").expect("password");
+
+        let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 1, 1, 1)?;
+
+        let (sid, serial) = {
+            let session = pool.get_session()?;
+            let stmt = session.prepare("
+                SELECT sid, serial#
+                  FROM v$session
+                 WHERE sid = Sys_Context('USERENV', 'SID')
+            ")?;
+            let row = stmt.query_single(())?.unwrap();
+            let sid : u32 = row.get(0)?;
+            let serial : u32 = row.get(1)?;
+            (sid, serial)
+        };
+
+        let admin = oracle.connect(&dbname, &dbuser, &dbpass)?;
+        let stmt = admin.prepare(&format!("ALTER SYSTEM KILL SESSION '{},{}' IMMEDIATE", sid, serial))?;
+        stmt.execute(())?;
+
+        // The only pooled session is dead. The pool must replace it.
+        let session = pool.get_session()?;
+        session.ping()?;
+        let stmt = session.prepare("
+            SELECT sid, serial#
+              FROM v$session
+             WHERE sid = Sys_Context('USERENV', 'SID')
+        ")?;
+        let row = stmt.query_single(())?.unwrap();
+        let new_sid : u32 = row.get(0)?;
+        let new_serial : u32 = row.get(1)?;
+        assert_ne!((new_sid, new_serial), (sid, serial));
+
+        Ok(())
+    }
+
+    #[test]
+    fn tagged_session() -> Result<()> {
+        let oracle = sibyl::env()?;
+        let dbname = std::env::var("DBNAME").expect("database name");
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        let dbpass = std::env::var("DBPASS").expect("password");
+
+        let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 2)?;
+
+        let session = pool.get_tagged_session("NLS_DATE_FORMAT=YYYY-MM-DD")?;
+        assert!(!session.is_tag_match());
+        session.set_nls_date_format("YYYY-MM-DD")?;
+        session.release_with_tag("NLS_DATE_FORMAT=YYYY-MM-DD")?;
+
+        let session = pool.get_tagged_session("NLS_DATE_FORMAT=YYYY-MM-DD")?;
+        assert!(session.is_tag_match());
+        let stmt = session.prepare("SELECT value FROM nls_session_parameters WHERE parameter = 'NLS_DATE_FORMAT'")?;
+        let row = stmt.query_single(())?.unwrap();
+        let fmt : &str = row.get(0)?;
+        assert_eq!(fmt, "YYYY-MM-DD");
+
+        Ok(())
+    }
+
+    #[test]
+    fn session_init() -> Result<()> {
+        use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+
+        let oracle = sibyl::env()?;
+        let dbname = std::env::var("DBNAME").expect("database name");
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        let dbpass = std::env::var("DBPASS").expect("password");
+
+        let init_count = Arc::new(AtomicUsize::new(0));
+        let counter = init_count.clone();
+        let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 3)?
+            .with_session_init(move |session| {
+                counter.fetch_add(1, Ordering::Relaxed);
+                session.set_nls_date_format("YYYY-MM-DD")
+            });
+
+        for _ in 0..10 {
+            let session = pool.get_session()?;
+            let stmt = session.prepare("SELECT To_Char(To_Date('2022-12-15')) FROM dual")?;
+            let row = stmt.query_single(())?.unwrap();
+            let date : &str = row.get(0)?;
+            assert_eq!(date, "2022-12-15");
+        }
+        assert_eq!(pool.open_count()?, 1);
+        assert_eq!(init_count.load(Ordering::Relaxed), 1);
+
+        for _ in 0..5 {
+            let s1 = pool.get_session()?;
+            let s2 = pool.get_session()?;
+            let s3 = pool.get_session()?;
+            for session in [s1, s2, s3] {
+                session.ping()?;
+            }
+        }
+        assert_eq!(pool.open_count()?, 3);
+        assert_eq!(init_count.load(Ordering::Relaxed), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_clone_initialized() -> Result<()> {
+        use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+
+        let oracle = sibyl::env()?;
+        let dbname = std::env::var("DBNAME").expect("database name");
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        let dbpass = std::env::var("DBPASS").expect("password");
+
+        let init_count = Arc::new(AtomicUsize::new(0));
+        let counter = init_count.clone();
+        let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 2)?
+            .with_session_init(move |session| {
+                counter.fetch_add(1, Ordering::Relaxed);
+                session.set_nls_date_format("YYYY-MM-DD")
+            });
+
+        {
+            let session = pool.get_session()?;
+            let other = session.try_clone()?;
+            assert_eq!(pool.busy_count()?, 2);
+            assert_eq!(init_count.load(Ordering::Relaxed), 2);
+
+            let stmt = other.prepare("SELECT To_Char(To_Date('2022-12-15')) FROM dual")?;
+            let row = stmt.query_single(())?.unwrap();
+            let date : &str = row.get(0)?;
+            assert_eq!(date, "2022-12-15");
+        }
+
+        // Both sessions were tagged as initialized when they were released
+        let session = pool.get_session()?;
+        let _other = session.try_clone()?;
+        assert_eq!(pool.open_count()?, 2);
+        assert_eq!(init_count.load(Ordering::Relaxed), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn default_stmt_cache_size() -> Result<()> {
+        let oracle = sibyl::env()?;
+        let dbname = std::env::var("DBNAME").expect("database name");
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        let dbpass = std::env::var("DBPASS").expect("password");
+
+        oracle.set_default_stmt_cache_size(50);
+        let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 2)?;
+        assert_eq!(pool.statement_cache_size()?, 50);
+
+        let s1 = pool.get_session()?;
+        let s2 = pool.get_session()?;
+        assert_eq!(s1.stmt_cache_size()?, 50);
+        assert_eq!(s2.stmt_cache_size()?, 50);
+
+        Ok(())
+    }
+
+    #[test]
+    fn warmup() -> Result<()> {
+        let oracle = sibyl::env()?;
+        let dbname = std::env::var("DBNAME").expect("database name");
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        let dbpass = std::env::var("DBPASS").expect("password");
+
+        let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 3, 1, 5)?;
+
+        pool.warmup()?;
+        assert_eq!(pool.open_count()?, 3);
+        assert_eq!(pool.busy_count()?, 0);
+
+        Ok(())
+    }
+    #[test]
+    fn reset_on_release() -> Result<()> {
+        let oracle = sibyl::env()?;
+        let dbname = std::env::var("DBNAME").expect("database name");
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        let dbpass = std::env::var("DBPASS").expect("password");
+
+        let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 1)?
+            .reset_on_release(true);
+
+        let session = pool.get_session()?;
+        session.execute("
+            CREATE OR REPLACE PACKAGE test_session_state AS
+                counter NUMBER := 0;
+            END;
+        ", ())?;
+        session.execute("BEGIN test_session_state.counter := 42; END;", ())?;
+        let mut counter = 0;
+        session.execute("BEGIN :COUNTER := test_session_state.counter; END;", &mut counter)?;
+        assert_eq!(counter, 42);
+        drop(session);
+
+        let session = pool.get_session()?;
+        let mut counter = 0;
+        session.execute("BEGIN :COUNTER := test_session_state.counter; END;", &mut counter)?;
+        assert_eq!(counter, 0);
+
+        Ok(())
+    }
+    #[test]
+    fn drcp_purity() -> Result<()> {
+        // DRCP tests need a connect string that requests a pooled server, for example, "//host/service:pooled"
+        let dbname = match std::env::var("DRCP_DBNAME") {
+            Ok(dbname) => dbname,
+            Err(_) => return Ok(()),
+        };
+        let oracle = sibyl::env()?;
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        let dbpass = std::env::var("DBPASS").expect("password");
+
+        let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 2)?;
+
+        let session = pool.get_session_with_purity(Purity::New)?;
+        session.ping()?;
+        drop(session);
+
+        let session = pool.get_session_with_purity(Purity::Reuse)?;
+        session.ping()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature="nonblocking")]
+mod nonblocking {
+    use sibyl::*;
+
+    #[test]
+    fn validated_session() -> Result<()> {
+        block_on(async {
+            use once_cell::sync::OnceCell;
+
+            static ORACLE : OnceCell<Environment> = OnceCell::new();
+            let oracle = ORACLE.get_or_try_init(|| {
+                sibyl::env()
+            })?;
+            let dbname = std::env::var("DBNAME").expect("database name");
+            let dbuser = std::env::var("DBUSER").expect("user name");
+            let dbpass = std::env::var("DBPASS").expect("password");
+
+            let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 1, 1, 2).await?;
+
+            let (sid, serial) = {
+                let session = pool.get_session().await?;
+                let stmt = session.prepare("
+                    SELECT sid, serial#
+                      FROM v$session
+                     WHERE sid = Sys_Context('USERENV', 'SID')
+                ").await?;
+                let row = stmt.query_single(()).await?.unwrap();
+                let sid : u32 = row.get(0)?;
+                let serial : u32 = row.get(1)?;
+                (sid, serial)
+            };
+
+            let admin = oracle.connect(&dbname, &dbuser, &dbpass).await?;
+            let stmt = admin.prepare(&format!("ALTER SYSTEM KILL SESSION '{},{}' IMMEDIATE", sid, serial)).await?;
+            stmt.execute(()).await?;
+
+            let session = pool.get_session_validated().await?;
+            session.ping().await?;
+            let stmt = session.prepare("
+                SELECT sid, serial#
+                  FROM v$session
+                 WHERE sid = Sys_Context('USERENV', 'SID')
+            ").await?;
+            let row = stmt.query_single(()).await?.unwrap();
+            let new_sid : u32 = row.get(0)?;
+            let new_serial : u32 = row.get(1)?;
+            // Oracle might reuse the SID of the killed session, but not with the same serial number
+            assert_ne!((new_sid, new_serial), (sid, serial));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn reconnect_lost_session() -> Result<()> {
+        block_on(async {
+            use once_cell::sync::OnceCell;
+
+            static ORACLE : OnceCell<Environment> = OnceCell::new();
+            let oracle = ORACLE.get_or_try_init(|| {
+                sibyl::env()
+            })?;
+            let dbname = std::env::var("DBNAME").expect("database name");
+            let dbuser = std::env::var("DBUSER").expect("user name");
+            let dbpass = std::env::var("DBPASS").expect("password");
+
+            let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 1, 1, 1).await?;
+
+            let (sid, serial) = {
+                let session = pool.get_session().await?;
+                let stmt = session.prepare("
+                    SELECT sid, serial#
+                      FROM v$session
+                     WHERE sid = Sys_Context('USERENV', 'SID')
+                ").await?;
+                let row = stmt.query_single(()).await?.unwrap();
+                let sid : u32 = row.get(0)?;
+                let serial : u32 = row.get(1)?;
+                (sid, serial)
+            };
+
+            let admin = oracle.connect(&dbname, &dbuser, &dbpass).await?;
+            let stmt = admin.prepare(&format!("ALTER SYSTEM KILL SESSION '{},{}' IMMEDIATE", sid, serial)).await?;
+            stmt.execute(()).await?;
+
+            // The only pooled session is dead. The pool must replace it.
+            let session = pool.get_session().await?;
+            session.ping().await?;
+            let stmt = session.prepare("
+                SELECT sid, serial#
+                  FROM v$session
+                 WHERE sid = Sys_Context('USERENV', 'SID')
+            ").await?;
+            let row = stmt.query_single(()).await?.unwrap();
+            let new_sid : u32 = row.get(0)?;
+            let new_serial : u32 = row.get(1)?;
+            assert_ne!((new_sid, new_serial), (sid, serial));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn tagged_session() -> Result<()> {
+        use once_cell::sync::OnceCell;
+
+        static ORACLE : OnceCell<Environment> = OnceCell::new();
+        let oracle = ORACLE.get_or_try_init(|| {
+            sibyl::env()
+        })?;
+        let dbname = std::env::var("DBNAME").expect("database name");
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        let dbpass = std::env::var("DBPASS").expect("password");
+
+        let pool = block_on(oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 2))?;
+
+        // `block_on` returns only after the session is released back into the pool
+        block_on(async {
+            let session = pool.get_tagged_session("NLS_DATE_FORMAT=YYYY-MM-DD").await?;
+            assert!(!session.is_tag_match());
+            session.set_nls_date_format("YYYY-MM-DD").await?;
+            session.release_with_tag("NLS_DATE_FORMAT=YYYY-MM-DD")
+        })?;
+
+        block_on(async {
+            let session = pool.get_tagged_session("NLS_DATE_FORMAT=YYYY-MM-DD").await?;
+            assert!(session.is_tag_match());
+            let stmt = session.prepare("SELECT value FROM nls_session_parameters WHERE parameter = 'NLS_DATE_FORMAT'").await?;
+            let row = stmt.query_single(()).await?.unwrap();
+            let fmt : &str = row.get(0)?;
+            assert_eq!(fmt, "YYYY-MM-DD");
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn session_init() -> Result<()> {
+        use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+        use once_cell::sync::OnceCell;
+
+        static ORACLE : OnceCell<Environment> = OnceCell::new();
+        let oracle = ORACLE.get_or_try_init(|| {
+            sibyl::env()
+        })?;
+        let dbname = std::env::var("DBNAME").expect("database name");
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        let dbpass = std::env::var("DBPASS").expect("password");
+
+        let init_count = Arc::new(AtomicUsize::new(0));
+        let counter = init_count.clone();
+        let pool = block_on(oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 3))?
+            .with_session_init(move |session| {
+                counter.fetch_add(1, Ordering::Relaxed);
+                Box::pin(async move {
+                    session.set_nls_date_format("YYYY-MM-DD").await
+                })
+            });
+
+        for _ in 0..10 {
+            // `block_on` returns only after the session is released back into the pool
+            block_on(async {
+                let session = pool.get_session().await?;
+                let stmt = session.prepare("SELECT To_Char(To_Date('2022-12-15')) FROM dual").await?;
+                let row = stmt.query_single(()).await?.unwrap();
+                let date : &str = row.get(0)?;
+                assert_eq!(date, "2022-12-15");
+                Ok::<_,Error>(())
+            })?;
+        }
+        assert_eq!(pool.open_count()?, 1);
+        assert_eq!(init_count.load(Ordering::Relaxed), 1);
+
+        for _ in 0..5 {
+            block_on(async {
+                let s1 = pool.get_session().await?;
+                let s2 = pool.get_session().await?;
+                let s3 = pool.get_session().await?;
+                for session in [s1, s2, s3] {
+                    session.ping().await?;
+                }
+                Ok::<_,Error>(())
+            })?;
+        }
+        assert_eq!(pool.open_count()?, 3);
+        assert_eq!(init_count.load(Ordering::Relaxed), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_clone_initialized() -> Result<()> {
+        use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+        use once_cell::sync::OnceCell;
+
+        static ORACLE : OnceCell<Environment> = OnceCell::new();
+        let oracle = ORACLE.get_or_try_init(|| {
+            sibyl::env()
+        })?;
+        let dbname = std::env::var("DBNAME").expect("database name");
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        let dbpass = std::env::var("DBPASS").expect("password");
+
+        let init_count = Arc::new(AtomicUsize::new(0));
+        let counter = init_count.clone();
+        let pool = block_on(oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 2))?
+            .with_session_init(move |session| {
+                counter.fetch_add(1, Ordering::Relaxed);
+                Box::pin(async move {
+                    session.set_nls_date_format("YYYY-MM-DD").await
+                })
+            });
+
+        // `block_on` returns only after the sessions are released back into the pool
+        block_on(async {
+            let session = pool.get_session().await?;
+            let other = session.try_clone().await?;
+            assert_eq!(pool.busy_count()?, 2);
+            assert_eq!(init_count.load(Ordering::Relaxed), 2);
+
+            let stmt = other.prepare("SELECT To_Char(To_Date('2022-12-15')) FROM dual").await?;
+            let row = stmt.query_single(()).await?.unwrap();
+            let date : &str = row.get(0)?;
+            assert_eq!(date, "2022-12-15");
+            Ok::<_,Error>(())
+        })?;
+
+        // Both sessions were tagged as initialized when they were released
+        block_on(async {
+            let session = pool.get_session().await?;
+            let _other = session.try_clone().await?;
+            assert_eq!(pool.open_count()?, 2);
+            assert_eq!(init_count.load(Ordering::Relaxed), 2);
+            Ok::<_,Error>(())
+        })?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn default_stmt_cache_size() -> Result<()> {
+        use once_cell::sync::OnceCell;
+
+        static ORACLE : OnceCell<Environment> = OnceCell::new();
+        let oracle = ORACLE.get_or_try_init(|| {
+            sibyl::env()
+        })?;
+        let dbname = std::env::var("DBNAME").expect("database name");
+        let dbuser = std::env::var("DBUSER").expect("user name");
+        let dbpass = std::env::var("DBPASS").expect("password");
+
+        oracle.set_default_stmt_cache_size(50);
+        block_on(async {
+            let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 2).await?;
+            assert_eq!(pool.statement_cache_size()?, 50);
+
+            let s1 = pool.get_session().await?;
+            let s2 = pool.get_session().await?;
+            assert_eq!(s1.stmt_cache_size()?, 50);
+            assert_eq!(s2.stmt_cache_size()?, 50);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn warmup() -> Result<()> {
+        block_on(async {
+            use once_cell::sync::OnceCell;
+
+            static ORACLE : OnceCell<Environment> = OnceCell::new();
+            let oracle = ORACLE.get_or_try_init(|| {
+                sibyl::env()
+            })?;
+            let dbname = std::env::var("DBNAME").expect("database name");
+            let dbuser = std::env::var("DBUSER").expect("user name");
+            let dbpass = std::env::var("DBPASS").expect("password");
+
+            let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 3, 1, 5).await?;
+
+            pool.warmup().await?;
+            assert_eq!(pool.open_count()?, 3);
+            assert_eq!(pool.busy_count()?, 0);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn reset_on_release() -> Result<()> {
+        block_on(async {
+            use once_cell::sync::OnceCell;
+
+            static ORACLE : OnceCell<Environment> = OnceCell::new();
+            let oracle = ORACLE.get_or_try_init(|| {
+                sibyl::env()
+            })?;
+            let dbname = std::env::var("DBNAME").expect("database name");
+            let dbuser = std::env::var("DBUSER").expect("user name");
+            let dbpass = std::env::var("DBPASS").expect("password");
+
+            let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 1).await?
+                .reset_on_release(true);
+
+            let session = pool.get_session().await?;
+            session.execute("
+                CREATE OR REPLACE PACKAGE test_session_state AS
+                    counter NUMBER := 0;
+                END;
+            ", ()).await?;
+            session.execute("BEGIN test_session_state.counter := 42; END;", ()).await?;
+            let mut counter = 0;
+            session.execute("BEGIN :COUNTER := test_session_state.counter; END;", &mut counter).await?;
+            assert_eq!(counter, 42);
+            drop(session);
+
+            let session = pool.get_session().await?;
+            let mut counter = 0;
+            session.execute("BEGIN :COUNTER := test_session_state.counter; END;", &mut counter).await?;
+            assert_eq!(counter, 0);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn drcp_purity() -> Result<()> {
+        // DRCP tests need a connect string that requests a pooled server, for example, "//host/service:pooled"
+        let dbname = match std::env::var("DRCP_DBNAME") {
+            Ok(dbname) => dbname,
+            Err(_) => return Ok(()),
+        };
+        block_on(async {
+            use once_cell::sync::OnceCell;
+
+            static ORACLE : OnceCell<Environment> = OnceCell::new();
+            let oracle = ORACLE.get_or_try_init(|| {
+                sibyl::env()
+            })?;
+            let dbuser = std::env::var("DBUSER").expect("user name");
+            let dbpass = std::env::var("DBPASS").expect("password");
+
+            let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 2).await?;
+
+            let session = pool.get_session_with_purity(Purity::New).await?;
+            session.ping().await?;
+            drop(session);
+
+            let session = pool.get_session_with_purity(Purity::Reuse).await?;
+            session.ping().await?;
+
+            Ok(())
+        })
+    }
+}
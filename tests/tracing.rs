@@ -0,0 +1,52 @@
+#[cfg(all(feature="blocking",feature="tracing"))]
+mod blocking {
+    use sibyl::*;
+    use std::sync::{Arc, Mutex, atomic::{AtomicU64, Ordering}};
+    use tracing::{span, Event, Metadata, Subscriber};
+
+    /// Records names of the created spans
+    struct SpanNames {
+        names:   Arc<Mutex<Vec<&'static str>>>,
+        next_id: AtomicU64,
+    }
+
+    impl Subscriber for SpanNames {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+            self.names.lock().unwrap().push(span.metadata().name());
+            span::Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed))
+        }
+
+        fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+        fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+        fn event(&self, _event: &Event<'_>) {}
+        fn enter(&self, _span: &span::Id) {}
+        fn exit(&self, _span: &span::Id) {}
+    }
+
+    #[test]
+    fn oci_call_spans() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+
+        let names = Arc::new(Mutex::new(Vec::new()));
+        let subscriber = SpanNames { names: names.clone(), next_id: AtomicU64::new(1) };
+
+        let last_name = tracing::subscriber::with_default(subscriber, || -> Result<String> {
+            let stmt = session.prepare("SELECT last_name FROM hr.employees WHERE employee_id = :ID")?;
+            let rows = stmt.query(100)?;
+            let row = rows.next()?.expect("first row");
+            let name : String = row.get(0)?;
+            Ok(name)
+        })?;
+        assert_eq!(last_name, "King");
+
+        let names = names.lock().unwrap();
+        assert!(names.contains(&"OCIStmtPrepare2"));
+        assert!(names.contains(&"OCIStmtExecute"));
+        assert!(names.contains(&"OCIStmtFetch2"));
+        Ok(())
+    }
+}